@@ -0,0 +1,9 @@
+//! Client interface for the token-bound account contract deployed by the registry.
+
+use soroban_sdk::{Address, Env, contractclient};
+
+#[allow(dead_code)]
+#[contractclient(name = "AccountClient")]
+pub trait AccountInterface {
+    fn initialize(env: Env, nft: Address, token_id: u64);
+}
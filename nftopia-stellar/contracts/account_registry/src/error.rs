@@ -0,0 +1,14 @@
+use soroban_sdk::contracterror;
+
+/// Contract-specific errors for the account registry.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    /// Registry has already been initialized.
+    AlreadyInitialized = 1,
+    /// Registry has not been initialized.
+    NotInitialized = 2,
+    /// The account for this token has already been deployed.
+    AccountExists = 3,
+}
@@ -0,0 +1,19 @@
+use soroban_sdk::{Address, Env, contractevent};
+
+/// A token-bound account was deployed for `token_id` of `nft`.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct AccountCreated {
+    pub nft: Address,
+    pub token_id: u64,
+    pub account: Address,
+}
+
+pub fn emit_account_created(env: &Env, nft: Address, token_id: u64, account: Address) {
+    AccountCreated {
+        nft,
+        token_id,
+        account,
+    }
+    .publish(env);
+}
@@ -0,0 +1,89 @@
+#![no_std]
+
+mod account;
+mod error;
+mod events;
+
+pub use error::ContractError;
+
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, contract, contractimpl, contracttype};
+
+use crate::account::AccountClient;
+use crate::error::ContractError as Err;
+
+/// Storage keys for the account registry.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// WASM hash of the token-bound account contract.
+    AccountWasmHash,
+    /// Set once the account for (nft, token_id) has been deployed.
+    Created(Address, u64),
+}
+
+/// Deploys token-bound accounts at addresses derived from (nft, token_id), so every token's
+/// account is known before it exists and can receive assets in advance.
+#[contract]
+pub struct AccountRegistry;
+
+#[contractimpl]
+impl AccountRegistry {
+    /// Sets the account contract WASM. Fixed afterwards, so every account runs the same code.
+    pub fn initialize(env: Env, admin: Address, account_wasm_hash: BytesN<32>) -> Result<(), Err> {
+        if env.storage().instance().has(&DataKey::AccountWasmHash) {
+            return Err(Err::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::AccountWasmHash, &account_wasm_hash);
+        Ok(())
+    }
+
+    /// Deploys and binds the account for `token_id` of `nft`. Anyone may call it; the account
+    /// is always controlled by the token's owner.
+    pub fn create_account(env: Env, nft: Address, token_id: u64) -> Result<Address, Err> {
+        let wasm_hash: BytesN<32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::AccountWasmHash)
+            .ok_or(Err::NotInitialized)?;
+        let created = DataKey::Created(nft.clone(), token_id);
+        if env.storage().instance().has(&created) {
+            return Err(Err::AccountExists);
+        }
+        // Deploy and bind in the same call so no one can front-run `initialize`.
+        let address = env
+            .deployer()
+            .with_current_contract(salt(&env, &nft, token_id))
+            .deploy_v2(wasm_hash, ());
+        AccountClient::new(&env, &address).initialize(&nft, &token_id);
+        env.storage().instance().set(&created, &true);
+        events::emit_account_created(&env, nft, token_id, address.clone());
+        Ok(address)
+    }
+
+    /// Address of the account for `token_id` of `nft`, whether or not it is deployed yet.
+    pub fn account(env: Env, nft: Address, token_id: u64) -> Address {
+        env.deployer()
+            .with_current_contract(salt(&env, &nft, token_id))
+            .deployed_address()
+    }
+
+    pub fn is_created(env: Env, nft: Address, token_id: u64) -> bool {
+        env.storage()
+            .instance()
+            .has(&DataKey::Created(nft, token_id))
+    }
+}
+
+/// Deployment salt: `sha256(xdr(nft) || token_id as big-endian u64)`.
+fn salt(env: &Env, nft: &Address, token_id: u64) -> BytesN<32> {
+    let mut data: Bytes = nft.clone().to_xdr(env);
+    data.extend_from_array(&token_id.to_be_bytes());
+    env.crypto().sha256(&data).into()
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,38 @@
+#![cfg(test)]
+
+use crate::{AccountRegistry, AccountRegistryClient, ContractError};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env};
+
+#[test]
+fn test_account_addresses_are_deterministic() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let registry_id = env.register(AccountRegistry, ());
+    let registry = AccountRegistryClient::new(&env, &registry_id);
+    let nft = Address::generate(&env);
+    let other_nft = Address::generate(&env);
+
+    assert_eq!(
+        registry.try_create_account(&nft, &1),
+        Err(Ok(ContractError::NotInitialized))
+    );
+    registry.initialize(
+        &Address::generate(&env),
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+    assert_eq!(
+        registry.try_initialize(
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &[2; 32])
+        ),
+        Err(Ok(ContractError::AlreadyInitialized))
+    );
+
+    let account = registry.account(&nft, &1);
+    assert_eq!(registry.account(&nft, &1), account);
+    assert_ne!(registry.account(&nft, &2), account);
+    assert_ne!(registry.account(&other_nft, &1), account);
+    assert!(!registry.is_created(&nft, &1));
+}
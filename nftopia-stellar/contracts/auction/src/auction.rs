@@ -0,0 +1,193 @@
+use crate::error::ContractError;
+use crate::events;
+use crate::storage::{self, DataKey};
+use crate::types::{Auction, AuctionConfig};
+use nft_interface::NftClient;
+use soroban_sdk::{Address, Env, token};
+
+/// Basis points denominator (10000 = 100%).
+const BPS_DENOMINATOR: i128 = 10_000;
+
+pub fn validate_config(config: &AuctionConfig) -> Result<(), ContractError> {
+    if (config.extension_window > 0 && config.extension_duration == 0)
+        || config.min_bid_increment_bps > BPS_DENOMINATOR as u32
+    {
+        return Err(ContractError::InvalidConfig);
+    }
+    Ok(())
+}
+
+/// Creates an auction, moving the token into escrow. Bidding is open until `now + duration`.
+pub fn create_auction(
+    env: &Env,
+    seller: Address,
+    collection: Address,
+    token_id: u64,
+    currency: Address,
+    reserve_price: i128,
+    duration: u64,
+) -> Result<u64, ContractError> {
+    seller.require_auth();
+    if reserve_price <= 0 {
+        return Err(ContractError::InvalidPrice);
+    }
+    if duration == 0 {
+        return Err(ContractError::InvalidDuration);
+    }
+    let auction_id = storage::next_auction_id(env)?;
+    NftClient::new(env, &collection).transfer(&seller, &env.current_contract_address(), &token_id);
+
+    let now = env.ledger().timestamp();
+    let auction = Auction {
+        id: auction_id,
+        seller,
+        collection,
+        token_id,
+        currency,
+        reserve_price,
+        start_time: now,
+        end_time: now + duration,
+        highest_bidder: None,
+        highest_bid: 0,
+    };
+    storage::set_auction(env, &auction);
+    events::emit_auction_created(env, &auction);
+    Ok(auction_id)
+}
+
+/// Places a bid, escrowing `amount` and refunding the previous highest bid to its bidder. If
+/// that transfer fails, the bid is credited to the bidder's refund balance instead, withdrawn
+/// with `withdraw_refund`. Bids inside the extension window push the end time back.
+pub fn bid(env: &Env, bidder: Address, auction_id: u64, amount: i128) -> Result<(), ContractError> {
+    bidder.require_auth();
+    let mut auction = storage::get_auction(env, auction_id)?;
+    let config = storage::get_config(env)?;
+    let now = env.ledger().timestamp();
+    if now >= auction.end_time {
+        return Err(ContractError::AuctionEnded);
+    }
+    if bidder == auction.seller {
+        return Err(ContractError::InvalidBidder);
+    }
+    let min_bid = match auction.highest_bidder {
+        None => auction.reserve_price,
+        Some(_) => {
+            let increment =
+                auction.highest_bid * config.min_bid_increment_bps as i128 / BPS_DENOMINATOR;
+            auction.highest_bid + increment.max(1)
+        }
+    };
+    if amount < min_bid {
+        return Err(ContractError::BidTooLow);
+    }
+
+    let contract = env.current_contract_address();
+    let payment = token::Client::new(env, &auction.currency);
+    payment.transfer(&bidder, &contract, &amount);
+    // Falling back to a credit keeps a bidder that rejects payments from blocking being outbid.
+    if let Some(previous) = auction.highest_bidder.take() {
+        let refunded = payment.try_transfer(&contract, &previous, &auction.highest_bid);
+        if !matches!(refunded, Ok(Ok(()))) {
+            storage::credit_refund(env, &previous, &auction.currency, auction.highest_bid);
+        }
+    }
+    auction.highest_bidder = Some(bidder.clone());
+    auction.highest_bid = amount;
+
+    let extended = auction.end_time - now < config.extension_window
+        && now + config.extension_duration > auction.end_time;
+    if extended {
+        auction.end_time = now + config.extension_duration;
+    }
+    storage::set_auction(env, &auction);
+    events::emit_bid_placed(env, auction_id, bidder, amount);
+    if extended {
+        events::emit_auction_extended(env, auction_id, auction.end_time);
+    }
+    Ok(())
+}
+
+/// Settles an ended auction. Anyone may call. Pays royalty and seller from the winning bid
+/// and releases the token to the winner, or returns it to the seller if there were no bids.
+pub fn settle(env: &Env, auction_id: u64) -> Result<(), ContractError> {
+    let auction = storage::get_auction(env, auction_id)?;
+    if env.ledger().timestamp() < auction.end_time {
+        return Err(ContractError::AuctionNotEnded);
+    }
+    env.storage()
+        .instance()
+        .remove(&DataKey::Auction(auction_id));
+
+    let nft = NftClient::new(env, &auction.collection);
+    let contract = env.current_contract_address();
+    let Some(winner) = auction.highest_bidder else {
+        nft.transfer(&contract, &auction.seller, &auction.token_id);
+        events::emit_auction_settled(env, auction_id, None, 0, 0);
+        return Ok(());
+    };
+
+    let (royalty_recipient, royalty_amount) =
+        nft.get_royalty_info(&auction.token_id, &auction.highest_bid);
+    if royalty_amount < 0 || royalty_amount > auction.highest_bid {
+        return Err(ContractError::InvalidRoyalty);
+    }
+    let payment = token::Client::new(env, &auction.currency);
+    if royalty_amount > 0 {
+        payment.transfer(&contract, &royalty_recipient, &royalty_amount);
+    }
+    payment.transfer(
+        &contract,
+        &auction.seller,
+        &(auction.highest_bid - royalty_amount),
+    );
+    nft.transfer(&contract, &winner, &auction.token_id);
+    events::emit_auction_settled(
+        env,
+        auction_id,
+        Some(winner),
+        auction.highest_bid,
+        royalty_amount,
+    );
+    Ok(())
+}
+
+/// Cancels an auction that has no bids and returns the token to the seller.
+pub fn cancel(env: &Env, seller: Address, auction_id: u64) -> Result<(), ContractError> {
+    seller.require_auth();
+    let auction = storage::get_auction(env, auction_id)?;
+    if auction.seller != seller {
+        return Err(ContractError::NotAuthorized);
+    }
+    if auction.highest_bidder.is_some() {
+        return Err(ContractError::HasBids);
+    }
+    env.storage()
+        .instance()
+        .remove(&DataKey::Auction(auction_id));
+    NftClient::new(env, &auction.collection).transfer(
+        &env.current_contract_address(),
+        &seller,
+        &auction.token_id,
+    );
+    events::emit_auction_cancelled(env, auction_id);
+    Ok(())
+}
+
+/// Sends `owner` every refund owed to them in `currency`. Returns the amount.
+pub fn withdraw_refund(
+    env: &Env,
+    owner: Address,
+    currency: Address,
+) -> Result<i128, ContractError> {
+    owner.require_auth();
+    let amount = storage::refund_of(env, &owner, &currency);
+    if amount <= 0 {
+        return Err(ContractError::NothingToRefund);
+    }
+    env.storage()
+        .instance()
+        .remove(&DataKey::Refund(owner.clone(), currency.clone()));
+    token::Client::new(env, &currency).transfer(&env.current_contract_address(), &owner, &amount);
+    events::emit_refund_withdrawn(env, owner, currency, amount);
+    Ok(amount)
+}
@@ -0,0 +1,36 @@
+use soroban_sdk::contracterror;
+
+/// Contract-specific errors for the auction contract.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    /// Caller is not authorized for the operation.
+    NotAuthorized = 1,
+    /// Contract has already been initialized.
+    AlreadyInitialized = 2,
+    /// Contract has not been initialized.
+    NotInitialized = 3,
+    /// Auction not found (never created, settled, or cancelled).
+    AuctionNotFound = 4,
+    /// Reserve price must be positive.
+    InvalidPrice = 5,
+    /// Duration must be positive.
+    InvalidDuration = 6,
+    /// Invalid auction configuration.
+    InvalidConfig = 7,
+    /// Auction has already ended.
+    AuctionEnded = 8,
+    /// Auction has not ended yet.
+    AuctionNotEnded = 9,
+    /// Bid is below the reserve price or the minimum increment.
+    BidTooLow = 10,
+    /// Seller cannot bid on their own auction.
+    InvalidBidder = 11,
+    /// Auction already has bids and cannot be cancelled.
+    HasBids = 12,
+    /// Royalty reported by the collection exceeds the sale price.
+    InvalidRoyalty = 13,
+    /// No refund is owed to the caller in this currency.
+    NothingToRefund = 14,
+}
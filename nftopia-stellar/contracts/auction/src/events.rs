@@ -0,0 +1,114 @@
+use soroban_sdk::{Address, Env, contractevent};
+
+/// Auction created.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct AuctionCreated {
+    pub auction_id: u64,
+    pub seller: Address,
+    pub collection: Address,
+    pub token_id: u64,
+    pub reserve_price: i128,
+    pub end_time: u64,
+}
+
+/// New highest bid placed.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct BidPlaced {
+    pub auction_id: u64,
+    pub bidder: Address,
+    pub amount: i128,
+}
+
+/// Auction end time pushed back by a late bid.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct AuctionExtended {
+    pub auction_id: u64,
+    pub end_time: u64,
+}
+
+/// Auction settled. `winner` is None when the auction ended without bids.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct AuctionSettled {
+    pub auction_id: u64,
+    pub winner: Option<Address>,
+    pub amount: i128,
+    pub royalty_amount: i128,
+}
+
+/// Auction cancelled by the seller.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct AuctionCancelled {
+    pub auction_id: u64,
+}
+
+/// Refund withdrawn by its owner.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RefundWithdrawn {
+    pub owner: Address,
+    pub currency: Address,
+    pub amount: i128,
+}
+
+pub fn emit_auction_created(env: &Env, auction: &crate::types::Auction) {
+    AuctionCreated {
+        auction_id: auction.id,
+        seller: auction.seller.clone(),
+        collection: auction.collection.clone(),
+        token_id: auction.token_id,
+        reserve_price: auction.reserve_price,
+        end_time: auction.end_time,
+    }
+    .publish(env);
+}
+
+pub fn emit_bid_placed(env: &Env, auction_id: u64, bidder: Address, amount: i128) {
+    BidPlaced {
+        auction_id,
+        bidder,
+        amount,
+    }
+    .publish(env);
+}
+
+pub fn emit_auction_extended(env: &Env, auction_id: u64, end_time: u64) {
+    AuctionExtended {
+        auction_id,
+        end_time,
+    }
+    .publish(env);
+}
+
+pub fn emit_auction_settled(
+    env: &Env,
+    auction_id: u64,
+    winner: Option<Address>,
+    amount: i128,
+    royalty_amount: i128,
+) {
+    AuctionSettled {
+        auction_id,
+        winner,
+        amount,
+        royalty_amount,
+    }
+    .publish(env);
+}
+
+pub fn emit_auction_cancelled(env: &Env, auction_id: u64) {
+    AuctionCancelled { auction_id }.publish(env);
+}
+
+pub fn emit_refund_withdrawn(env: &Env, owner: Address, currency: Address, amount: i128) {
+    RefundWithdrawn {
+        owner,
+        currency,
+        amount,
+    }
+    .publish(env);
+}
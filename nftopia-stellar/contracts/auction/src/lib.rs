@@ -0,0 +1,93 @@
+#![no_std]
+
+mod auction;
+mod error;
+mod events;
+mod storage;
+mod types;
+
+pub use error::ContractError;
+pub use types::{Auction, AuctionConfig};
+
+use soroban_sdk::{Address, Env, contract, contractimpl};
+
+use crate::error::ContractError as Err;
+use crate::storage::DataKey;
+
+#[contract]
+pub struct AuctionHouse;
+
+#[contractimpl]
+impl AuctionHouse {
+    /// Initializes the auction house with its anti-sniping configuration.
+    pub fn initialize(env: Env, admin: Address, config: AuctionConfig) -> Result<(), Err> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Err::AlreadyInitialized);
+        }
+        auction::validate_config(&config)?;
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Config, &config);
+        env.storage().instance().set(&DataKey::NextAuctionId, &0u64);
+        Ok(())
+    }
+
+    pub fn set_config(env: Env, config: AuctionConfig) -> Result<(), Err> {
+        storage::require_admin(&env)?;
+        auction::validate_config(&config)?;
+        env.storage().instance().set(&DataKey::Config, &config);
+        Ok(())
+    }
+
+    pub fn get_config(env: Env) -> Result<AuctionConfig, Err> {
+        storage::get_config(&env)
+    }
+
+    // --- Auctions ---
+    pub fn create_auction(
+        env: Env,
+        seller: Address,
+        collection: Address,
+        token_id: u64,
+        currency: Address,
+        reserve_price: i128,
+        duration: u64,
+    ) -> Result<u64, Err> {
+        auction::create_auction(
+            &env,
+            seller,
+            collection,
+            token_id,
+            currency,
+            reserve_price,
+            duration,
+        )
+    }
+
+    pub fn bid(env: Env, bidder: Address, auction_id: u64, amount: i128) -> Result<(), Err> {
+        auction::bid(&env, bidder, auction_id, amount)
+    }
+
+    pub fn settle(env: Env, auction_id: u64) -> Result<(), Err> {
+        auction::settle(&env, auction_id)
+    }
+
+    pub fn cancel(env: Env, seller: Address, auction_id: u64) -> Result<(), Err> {
+        auction::cancel(&env, seller, auction_id)
+    }
+
+    // --- Refunds ---
+    pub fn withdraw_refund(env: Env, bidder: Address, currency: Address) -> Result<i128, Err> {
+        auction::withdraw_refund(&env, bidder, currency)
+    }
+
+    pub fn get_refund(env: Env, bidder: Address, currency: Address) -> i128 {
+        storage::refund_of(&env, &bidder, &currency)
+    }
+
+    pub fn get_auction(env: Env, auction_id: u64) -> Result<Auction, Err> {
+        storage::get_auction(&env, auction_id)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,77 @@
+use crate::error::ContractError;
+use crate::types::{Auction, AuctionConfig};
+use soroban_sdk::{Address, Env, contracttype};
+
+/// Storage keys for the auction contract.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// Contract admin.
+    Admin,
+    /// Anti-sniping and bidding configuration.
+    Config,
+    /// Next auction id to assign.
+    NextAuctionId,
+    /// Active auction by id.
+    Auction(u64),
+    /// Outbid amounts whose refund transfer failed, awaiting `withdraw_refund`: (bidder,
+    /// currency).
+    Refund(Address, Address),
+}
+
+pub fn require_admin(env: &Env) -> Result<Address, ContractError> {
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(ContractError::NotInitialized)?;
+    admin.require_auth();
+    Ok(admin)
+}
+
+pub fn get_config(env: &Env) -> Result<AuctionConfig, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config)
+        .ok_or(ContractError::NotInitialized)
+}
+
+pub fn get_auction(env: &Env, auction_id: u64) -> Result<Auction, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Auction(auction_id))
+        .ok_or(ContractError::AuctionNotFound)
+}
+
+pub fn set_auction(env: &Env, auction: &Auction) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Auction(auction.id), auction);
+}
+
+/// Amount of `currency` owed to `owner` and awaiting `withdraw_refund`.
+pub fn refund_of(env: &Env, owner: &Address, currency: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Refund(owner.clone(), currency.clone()))
+        .unwrap_or(0)
+}
+
+pub fn credit_refund(env: &Env, owner: &Address, currency: &Address, amount: i128) {
+    let total = refund_of(env, owner, currency) + amount;
+    env.storage()
+        .instance()
+        .set(&DataKey::Refund(owner.clone(), currency.clone()), &total);
+}
+
+pub fn next_auction_id(env: &Env) -> Result<u64, ContractError> {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextAuctionId)
+        .ok_or(ContractError::NotInitialized)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::NextAuctionId, &(id + 1));
+    Ok(id)
+}
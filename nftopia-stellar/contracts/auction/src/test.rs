@@ -0,0 +1,171 @@
+#![cfg(test)]
+
+use crate::{AuctionConfig, AuctionHouse, AuctionHouseClient, ContractError};
+use nft_contract::NftContractClient;
+use nft_contract::testutils::deploy_test_collection;
+use soroban_sdk::testutils::{Address as _, IssuerFlags, Ledger, StellarAssetIssuer};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, String, Vec};
+
+struct Setup<'a> {
+    env: Env,
+    creator: Address,
+    seller: Address,
+    nft: NftContractClient<'a>,
+    token: TokenClient<'a>,
+    token_admin: StellarAssetClient<'a>,
+    issuer: StellarAssetIssuer,
+    house: AuctionHouseClient<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let creator = Address::generate(&env);
+    let seller = Address::generate(&env);
+
+    let nft = deploy_test_collection(&env, &creator, 1_000);
+
+    let sac = env.register_stellar_asset_contract_v2(creator.clone());
+    let token = TokenClient::new(&env, &sac.address());
+    let token_admin = StellarAssetClient::new(&env, &sac.address());
+
+    let house_id = env.register(AuctionHouse, ());
+    let house = AuctionHouseClient::new(&env, &house_id);
+    house.initialize(
+        &creator,
+        &AuctionConfig {
+            extension_window: 300,
+            extension_duration: 600,
+            min_bid_increment_bps: 500,
+        },
+    );
+
+    Setup {
+        env,
+        creator,
+        seller,
+        nft,
+        token,
+        token_admin,
+        issuer: sac.issuer(),
+        house,
+    }
+}
+
+fn start_auction(s: &Setup) -> (u64, u64) {
+    let uri = String::from_str(&s.env, "ipfs://hash");
+    let token_id = s
+        .nft
+        .mint(&s.creator, &s.seller, &uri, &Vec::new(&s.env), &None);
+    let auction_id = s.house.create_auction(
+        &s.seller,
+        &s.nft.address,
+        &token_id,
+        &s.token.address,
+        &1_000,
+        &3_600,
+    );
+    (token_id, auction_id)
+}
+
+#[test]
+fn test_bid_refund_and_settle() {
+    let s = setup();
+    let (token_id, auction_id) = start_auction(&s);
+    assert_eq!(s.nft.owner_of(&token_id), s.house.address);
+
+    let alice = Address::generate(&s.env);
+    let bob = Address::generate(&s.env);
+    s.token_admin.mint(&alice, &5_000);
+    s.token_admin.mint(&bob, &5_000);
+
+    assert_eq!(
+        s.house.try_bid(&alice, &auction_id, &999),
+        Err(Ok(ContractError::BidTooLow))
+    );
+    s.house.bid(&alice, &auction_id, &1_000);
+    assert_eq!(
+        s.house.try_bid(&bob, &auction_id, &1_040),
+        Err(Ok(ContractError::BidTooLow))
+    );
+    s.house.bid(&bob, &auction_id, &2_000);
+    assert_eq!(s.token.balance(&alice), 5_000);
+    assert_eq!(s.token.balance(&s.house.address), 2_000);
+    assert_eq!(s.house.get_refund(&alice, &s.token.address), 0);
+    assert_eq!(
+        s.house.try_withdraw_refund(&alice, &s.token.address),
+        Err(Ok(ContractError::NothingToRefund))
+    );
+
+    assert_eq!(
+        s.house.try_settle(&auction_id),
+        Err(Ok(ContractError::AuctionNotEnded))
+    );
+    s.env.ledger().set_timestamp(1_000 + 3_600);
+    s.house.settle(&auction_id);
+
+    assert_eq!(s.nft.owner_of(&token_id), bob);
+    assert_eq!(s.token.balance(&s.creator), 200);
+    assert_eq!(s.token.balance(&s.seller), 1_800);
+    assert_eq!(s.token.balance(&s.house.address), 0);
+}
+
+#[test]
+fn test_late_bid_extends_auction() {
+    let s = setup();
+    let (_, auction_id) = start_auction(&s);
+    let alice = Address::generate(&s.env);
+    s.token_admin.mint(&alice, &5_000);
+
+    s.env.ledger().set_timestamp(1_000 + 3_500);
+    s.house.bid(&alice, &auction_id, &1_000);
+    assert_eq!(
+        s.house.get_auction(&auction_id).end_time,
+        1_000 + 3_500 + 600
+    );
+
+    s.env.ledger().set_timestamp(1_000 + 3_600);
+    assert_eq!(
+        s.house.try_settle(&auction_id),
+        Err(Ok(ContractError::AuctionNotEnded))
+    );
+}
+
+#[test]
+fn test_no_bids_returns_token() {
+    let s = setup();
+    let (token_id, auction_id) = start_auction(&s);
+
+    s.env.ledger().set_timestamp(1_000 + 3_600);
+    s.house.settle(&auction_id);
+    assert_eq!(s.nft.owner_of(&token_id), s.seller);
+    assert_eq!(
+        s.house.try_get_auction(&auction_id),
+        Err(Ok(ContractError::AuctionNotFound))
+    );
+}
+
+#[test]
+fn test_failed_refund_is_credited() {
+    let s = setup();
+    let (_, auction_id) = start_auction(&s);
+    let alice = Address::generate(&s.env);
+    let bob = Address::generate(&s.env);
+    s.token_admin.mint(&alice, &5_000);
+    s.token_admin.mint(&bob, &5_000);
+
+    s.house.bid(&alice, &auction_id, &1_000);
+    s.issuer.set_flag(IssuerFlags::RevocableFlag);
+    s.token_admin.set_authorized(&alice, &false);
+    s.house.bid(&bob, &auction_id, &2_000);
+    assert_eq!(s.token.balance(&alice), 4_000);
+    assert_eq!(s.house.get_refund(&alice, &s.token.address), 1_000);
+
+    s.token_admin.set_authorized(&alice, &true);
+    assert_eq!(s.house.withdraw_refund(&alice, &s.token.address), 1_000);
+    assert_eq!(s.token.balance(&alice), 5_000);
+    assert_eq!(s.token.balance(&s.house.address), 2_000);
+}
@@ -0,0 +1,30 @@
+use soroban_sdk::{Address, contracttype};
+
+/// Anti-sniping and bidding rules shared by all auctions.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct AuctionConfig {
+    /// A bid landing within this many seconds of the end extends the auction.
+    pub extension_window: u64,
+    /// After a late bid, the auction ends no earlier than this many seconds after it.
+    pub extension_duration: u64,
+    /// Minimum raise over the current highest bid, in basis points.
+    pub min_bid_increment_bps: u32,
+}
+
+/// English auction. The NFT and the highest bid are held in escrow until settlement.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Auction {
+    pub id: u64,
+    pub seller: Address,
+    pub collection: Address,
+    pub token_id: u64,
+    /// SEP-41 token bids are denominated in.
+    pub currency: Address,
+    pub reserve_price: i128,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub highest_bidder: Option<Address>,
+    pub highest_bid: i128,
+}
@@ -0,0 +1,143 @@
+use crate::error::ContractError;
+use crate::events;
+use crate::storage::{self, DataKey};
+use crate::types::{LockedToken, WrappedOrigin};
+use nft_interface::NftClient;
+use soroban_sdk::{Address, BytesN, Env, String, vec};
+
+/// Locks a local token in the bridge and emits the outbound message for relayers.
+pub fn lock(
+    env: &Env,
+    owner: Address,
+    collection: Address,
+    token_id: u64,
+    dest_chain: String,
+    dest_address: String,
+) -> Result<u64, ContractError> {
+    owner.require_auth();
+    if dest_chain.is_empty() || dest_address.is_empty() {
+        return Err(ContractError::InvalidDestination);
+    }
+    let nonce = storage::next_nonce(env)?;
+    NftClient::new(env, &collection).transfer(&owner, &env.current_contract_address(), &token_id);
+    let locked = LockedToken {
+        collection: collection.clone(),
+        token_id,
+        owner: owner.clone(),
+        dest_chain: dest_chain.clone(),
+        dest_address: dest_address.clone(),
+    };
+    env.storage()
+        .instance()
+        .set(&DataKey::Locked(collection.clone(), token_id), &locked);
+    events::emit_bridge_out(
+        env,
+        nonce,
+        collection,
+        token_id,
+        owner,
+        dest_chain,
+        dest_address,
+    );
+    Ok(nonce)
+}
+
+/// Releases a locked token to `recipient` once the relayer reports its counterpart was
+/// burned on the other chain.
+pub fn release(
+    env: &Env,
+    relayer: Address,
+    message_id: BytesN<32>,
+    collection: Address,
+    token_id: u64,
+    recipient: Address,
+) -> Result<(), ContractError> {
+    require_relayer(env, &relayer)?;
+    storage::get_locked(env, &collection, token_id).ok_or(ContractError::NotLocked)?;
+    storage::consume_message(env, &message_id)?;
+    env.storage()
+        .instance()
+        .remove(&DataKey::Locked(collection.clone(), token_id));
+    NftClient::new(env, &collection).transfer(
+        &env.current_contract_address(),
+        &recipient,
+        &token_id,
+    );
+    events::emit_released(env, message_id, collection, token_id, recipient);
+    Ok(())
+}
+
+/// Mints a wrapped representation of a foreign NFT on `collection`, which must have
+/// granted the bridge the minter role.
+pub fn mint_wrapped(
+    env: &Env,
+    relayer: Address,
+    message_id: BytesN<32>,
+    collection: Address,
+    recipient: Address,
+    origin: WrappedOrigin,
+    metadata_uri: String,
+) -> Result<u64, ContractError> {
+    require_relayer(env, &relayer)?;
+    storage::consume_message(env, &message_id)?;
+    let token_id = NftClient::new(env, &collection)
+        .airdrop(
+            &env.current_contract_address(),
+            &vec![env, recipient.clone()],
+            &metadata_uri,
+        )
+        .get(0)
+        .unwrap();
+    env.storage()
+        .instance()
+        .set(&DataKey::Wrapped(collection.clone(), token_id), &origin);
+    events::emit_wrapped_minted(env, message_id, collection, token_id, recipient, origin);
+    Ok(token_id)
+}
+
+/// Burns a wrapped token so the original can be released to `dest_address` on its
+/// origin chain.
+pub fn burn_wrapped(
+    env: &Env,
+    owner: Address,
+    collection: Address,
+    token_id: u64,
+    dest_address: String,
+) -> Result<u64, ContractError> {
+    owner.require_auth();
+    if dest_address.is_empty() {
+        return Err(ContractError::InvalidDestination);
+    }
+    let origin =
+        storage::get_wrapped(env, &collection, token_id).ok_or(ContractError::NotWrapped)?;
+    let nonce = storage::next_nonce(env)?;
+    env.storage()
+        .instance()
+        .remove(&DataKey::Wrapped(collection.clone(), token_id));
+
+    // Take custody first so the bridge burns as the token owner.
+    let nft = NftClient::new(env, &collection);
+    let contract = env.current_contract_address();
+    nft.transfer(&owner, &contract, &token_id);
+    nft.burn(&contract, &token_id, &true);
+
+    events::emit_bridge_return(env, nonce, owner, origin, dest_address);
+    Ok(nonce)
+}
+
+pub fn set_relayer(env: &Env, admin: Address, relayer: Address) -> Result<(), ContractError> {
+    admin.require_auth();
+    if storage::get_admin(env)? != admin {
+        return Err(ContractError::NotAuthorized);
+    }
+    env.storage().instance().set(&DataKey::Relayer, &relayer);
+    Ok(())
+}
+
+fn require_relayer(env: &Env, caller: &Address) -> Result<(), ContractError> {
+    caller.require_auth();
+    if storage::get_relayer(env)? != *caller {
+        return Err(ContractError::NotAuthorized);
+    }
+    Ok(())
+}
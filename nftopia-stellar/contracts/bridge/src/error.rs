@@ -0,0 +1,22 @@
+use soroban_sdk::contracterror;
+
+/// Contract-specific errors for the bridge contract.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    /// Caller is not the admin or relayer.
+    NotAuthorized = 1,
+    /// Contract has already been initialized.
+    AlreadyInitialized = 2,
+    /// Contract has not been initialized.
+    NotInitialized = 3,
+    /// Inbound message id has already been processed.
+    MessageProcessed = 4,
+    /// Token is not locked in the bridge.
+    NotLocked = 5,
+    /// Token is not a wrapped representation minted by the bridge.
+    NotWrapped = 6,
+    /// Destination chain or address is empty.
+    InvalidDestination = 7,
+}
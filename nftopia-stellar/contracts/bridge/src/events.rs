@@ -0,0 +1,116 @@
+use crate::types::WrappedOrigin;
+use soroban_sdk::{Address, BytesN, Env, String, contractevent};
+
+/// Canonical outbound message: a local token was locked for delivery to another chain.
+/// Relayers watch this event and mint the counterpart on `dest_chain`.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct BridgeOut {
+    pub nonce: u64,
+    pub collection: Address,
+    pub token_id: u64,
+    pub sender: Address,
+    pub dest_chain: String,
+    pub dest_address: String,
+}
+
+/// Outbound message for a wrapped token burned to return to its origin chain.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct BridgeReturn {
+    pub nonce: u64,
+    pub sender: Address,
+    pub origin: WrappedOrigin,
+    pub dest_address: String,
+}
+
+/// Locked token released after returning from another chain.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Released {
+    pub message_id: BytesN<32>,
+    pub collection: Address,
+    pub token_id: u64,
+    pub recipient: Address,
+}
+
+/// Wrapped representation of a foreign NFT minted.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct WrappedMinted {
+    pub message_id: BytesN<32>,
+    pub collection: Address,
+    pub token_id: u64,
+    pub recipient: Address,
+    pub origin: WrappedOrigin,
+}
+
+pub fn emit_bridge_out(
+    env: &Env,
+    nonce: u64,
+    collection: Address,
+    token_id: u64,
+    sender: Address,
+    dest_chain: String,
+    dest_address: String,
+) {
+    BridgeOut {
+        nonce,
+        collection,
+        token_id,
+        sender,
+        dest_chain,
+        dest_address,
+    }
+    .publish(env);
+}
+
+pub fn emit_bridge_return(
+    env: &Env,
+    nonce: u64,
+    sender: Address,
+    origin: WrappedOrigin,
+    dest_address: String,
+) {
+    BridgeReturn {
+        nonce,
+        sender,
+        origin,
+        dest_address,
+    }
+    .publish(env);
+}
+
+pub fn emit_released(
+    env: &Env,
+    message_id: BytesN<32>,
+    collection: Address,
+    token_id: u64,
+    recipient: Address,
+) {
+    Released {
+        message_id,
+        collection,
+        token_id,
+        recipient,
+    }
+    .publish(env);
+}
+
+pub fn emit_wrapped_minted(
+    env: &Env,
+    message_id: BytesN<32>,
+    collection: Address,
+    token_id: u64,
+    recipient: Address,
+    origin: WrappedOrigin,
+) {
+    WrappedMinted {
+        message_id,
+        collection,
+        token_id,
+        recipient,
+        origin,
+    }
+    .publish(env);
+}
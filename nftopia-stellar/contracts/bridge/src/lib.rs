@@ -0,0 +1,110 @@
+#![no_std]
+
+mod bridge;
+mod error;
+mod events;
+mod storage;
+mod types;
+
+pub use error::ContractError;
+pub use types::{LockedToken, WrappedOrigin};
+
+use soroban_sdk::{Address, BytesN, Env, String, contract, contractimpl};
+
+use crate::error::ContractError as Err;
+use crate::storage::DataKey;
+
+#[contract]
+pub struct NftBridge;
+
+#[contractimpl]
+impl NftBridge {
+    /// Initializes the bridge with its admin and the relayer trusted to deliver inbound messages.
+    pub fn initialize(env: Env, admin: Address, relayer: Address) -> Result<(), Err> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Err::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Relayer, &relayer);
+        env.storage().instance().set(&DataKey::NextNonce, &0u64);
+        Ok(())
+    }
+
+    pub fn set_relayer(env: Env, admin: Address, relayer: Address) -> Result<(), Err> {
+        bridge::set_relayer(&env, admin, relayer)
+    }
+
+    pub fn relayer(env: Env) -> Result<Address, Err> {
+        storage::get_relayer(&env)
+    }
+
+    // --- Outbound ---
+    pub fn lock(
+        env: Env,
+        owner: Address,
+        collection: Address,
+        token_id: u64,
+        dest_chain: String,
+        dest_address: String,
+    ) -> Result<u64, Err> {
+        bridge::lock(&env, owner, collection, token_id, dest_chain, dest_address)
+    }
+
+    pub fn burn_wrapped(
+        env: Env,
+        owner: Address,
+        collection: Address,
+        token_id: u64,
+        dest_address: String,
+    ) -> Result<u64, Err> {
+        bridge::burn_wrapped(&env, owner, collection, token_id, dest_address)
+    }
+
+    // --- Inbound (relayer only) ---
+    pub fn release(
+        env: Env,
+        relayer: Address,
+        message_id: BytesN<32>,
+        collection: Address,
+        token_id: u64,
+        recipient: Address,
+    ) -> Result<(), Err> {
+        bridge::release(&env, relayer, message_id, collection, token_id, recipient)
+    }
+
+    pub fn mint_wrapped(
+        env: Env,
+        relayer: Address,
+        message_id: BytesN<32>,
+        collection: Address,
+        recipient: Address,
+        origin: WrappedOrigin,
+        metadata_uri: String,
+    ) -> Result<u64, Err> {
+        bridge::mint_wrapped(
+            &env,
+            relayer,
+            message_id,
+            collection,
+            recipient,
+            origin,
+            metadata_uri,
+        )
+    }
+
+    // --- Views ---
+    pub fn get_locked(env: Env, collection: Address, token_id: u64) -> Option<LockedToken> {
+        storage::get_locked(&env, &collection, token_id)
+    }
+
+    pub fn wrapped_origin(env: Env, collection: Address, token_id: u64) -> Option<WrappedOrigin> {
+        storage::get_wrapped(&env, &collection, token_id)
+    }
+
+    pub fn is_processed(env: Env, message_id: BytesN<32>) -> bool {
+        storage::is_processed(&env, &message_id)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,76 @@
+use crate::error::ContractError;
+use crate::types::{LockedToken, WrappedOrigin};
+use soroban_sdk::{Address, BytesN, Env, contracttype};
+
+/// Storage keys for the bridge contract.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// Contract admin.
+    Admin,
+    /// Address allowed to deliver inbound messages.
+    Relayer,
+    /// Next outbound message nonce.
+    NextNonce,
+    /// Locked local token: (collection, token_id).
+    Locked(Address, u64),
+    /// Origin of a wrapped token: (wrapped collection, token_id).
+    Wrapped(Address, u64),
+    /// Inbound message ids already processed.
+    Processed(BytesN<32>),
+}
+
+pub fn get_admin(env: &Env) -> Result<Address, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(ContractError::NotInitialized)
+}
+
+pub fn get_relayer(env: &Env) -> Result<Address, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Relayer)
+        .ok_or(ContractError::NotInitialized)
+}
+
+pub fn next_nonce(env: &Env) -> Result<u64, ContractError> {
+    let nonce: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextNonce)
+        .ok_or(ContractError::NotInitialized)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::NextNonce, &(nonce + 1));
+    Ok(nonce)
+}
+
+pub fn get_locked(env: &Env, collection: &Address, token_id: u64) -> Option<LockedToken> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Locked(collection.clone(), token_id))
+}
+
+pub fn get_wrapped(env: &Env, collection: &Address, token_id: u64) -> Option<WrappedOrigin> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Wrapped(collection.clone(), token_id))
+}
+
+pub fn is_processed(env: &Env, message_id: &BytesN<32>) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::Processed(message_id.clone()))
+}
+
+/// Marks an inbound message as processed, failing if it already was.
+pub fn consume_message(env: &Env, message_id: &BytesN<32>) -> Result<(), ContractError> {
+    if is_processed(env, message_id) {
+        return Err(ContractError::MessageProcessed);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::Processed(message_id.clone()), &true);
+    Ok(())
+}
@@ -0,0 +1,119 @@
+#![cfg(test)]
+
+use crate::{ContractError, NftBridge, NftBridgeClient, WrappedOrigin};
+use nft_contract::NftContractClient;
+use nft_contract::testutils::deploy_test_collection;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
+
+struct Setup<'a> {
+    env: Env,
+    admin: Address,
+    relayer: Address,
+    alice: Address,
+    nft: NftContractClient<'a>,
+    bridge: NftBridgeClient<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let nft = deploy_test_collection(&env, &admin, 0);
+
+    let bridge_id = env.register(NftBridge, ());
+    let bridge = NftBridgeClient::new(&env, &bridge_id);
+    bridge.initialize(&admin, &relayer);
+
+    Setup {
+        env,
+        admin,
+        relayer,
+        alice,
+        nft,
+        bridge,
+    }
+}
+
+#[test]
+fn test_lock_and_release() {
+    let s = setup();
+    let uri = String::from_str(&s.env, "ipfs://hash");
+    let token_id = s
+        .nft
+        .mint(&s.admin, &s.alice, &uri, &Vec::new(&s.env), &None);
+
+    let dest_chain = String::from_str(&s.env, "ethereum");
+    let dest_address = String::from_str(&s.env, "0xabc");
+    s.bridge.lock(
+        &s.alice,
+        &s.nft.address,
+        &token_id,
+        &dest_chain,
+        &dest_address,
+    );
+    assert_eq!(s.nft.owner_of(&token_id), s.bridge.address);
+    assert!(s.bridge.get_locked(&s.nft.address, &token_id).is_some());
+
+    let message_id = BytesN::from_array(&s.env, &[1; 32]);
+    assert_eq!(
+        s.bridge
+            .try_release(&s.alice, &message_id, &s.nft.address, &token_id, &s.alice),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+    s.bridge
+        .release(&s.relayer, &message_id, &s.nft.address, &token_id, &s.alice);
+    assert_eq!(s.nft.owner_of(&token_id), s.alice);
+    assert!(s.bridge.is_processed(&message_id));
+}
+
+#[test]
+fn test_mint_and_burn_wrapped() {
+    let s = setup();
+    let wrapped = deploy_test_collection(&s.env, &s.admin, 0);
+    wrapped.set_minter(&s.admin, &s.bridge.address, &true);
+
+    let origin = WrappedOrigin {
+        chain: String::from_str(&s.env, "ethereum"),
+        contract: String::from_str(&s.env, "0xcollection"),
+        token_id: String::from_str(&s.env, "42"),
+    };
+    let message_id = BytesN::from_array(&s.env, &[2; 32]);
+    let uri = String::from_str(&s.env, "ipfs://wrapped");
+    let token_id = s.bridge.mint_wrapped(
+        &s.relayer,
+        &message_id,
+        &wrapped.address,
+        &s.alice,
+        &origin,
+        &uri,
+    );
+    assert_eq!(wrapped.owner_of(&token_id), s.alice);
+    assert_eq!(
+        s.bridge.try_mint_wrapped(
+            &s.relayer,
+            &message_id,
+            &wrapped.address,
+            &s.alice,
+            &origin,
+            &uri
+        ),
+        Err(Ok(ContractError::MessageProcessed))
+    );
+
+    s.bridge.burn_wrapped(
+        &s.alice,
+        &wrapped.address,
+        &token_id,
+        &String::from_str(&s.env, "0xalice"),
+    );
+    assert_eq!(wrapped.total_supply(), 0);
+    assert!(
+        s.bridge
+            .wrapped_origin(&wrapped.address, &token_id)
+            .is_none()
+    );
+}
@@ -0,0 +1,22 @@
+use soroban_sdk::{Address, String, contracttype};
+
+/// A local token held by the bridge while it is represented on another chain.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LockedToken {
+    pub collection: Address,
+    pub token_id: u64,
+    pub owner: Address,
+    pub dest_chain: String,
+    pub dest_address: String,
+}
+
+/// The foreign NFT a wrapped token represents.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct WrappedOrigin {
+    pub chain: String,
+    pub contract: String,
+    /// Foreign token id as a string, since id formats differ between chains.
+    pub token_id: String,
+}
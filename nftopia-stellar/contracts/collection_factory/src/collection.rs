@@ -36,7 +36,7 @@ impl NftCollection {
     pub fn mint(
         env: Env,
         to: Address,
-        token_id: u32,
+        token_id: u64,
         uri: String,
         attributes: Vec<(String, String)>,
     ) -> Result<(), ContractError> {
@@ -101,7 +101,7 @@ impl NftCollection {
             .instance()
             .set(&DataKey::TotalSupply, &(total_supply + 1));
 
-        events::emit_mint(&env, env.current_contract_address(), to, token_id, 1);
+        events::emit_mint(&env, to, token_id, admin);
 
         Ok(())
     }
@@ -110,7 +110,7 @@ impl NftCollection {
         env: Env,
         from: Address,
         to: Address,
-        token_id: u32,
+        token_id: u64,
     ) -> Result<(), ContractError> {
         from.require_auth();
 
@@ -153,12 +153,12 @@ impl NftCollection {
             .instance()
             .set(&DataKey::Balance(to.clone(), token_id), &(to_balance + 1));
 
-        events::emit_transfer(&env, env.current_contract_address(), from, to, token_id, 1);
+        events::emit_transfer(&env, from, to, token_id);
 
         Ok(())
     }
 
-    pub fn burn(env: Env, from: Address, token_id: u32) -> Result<(), ContractError> {
+    pub fn burn(env: Env, from: Address, token_id: u64) -> Result<(), ContractError> {
         from.require_auth();
 
         let owner: Address = env
@@ -189,12 +189,12 @@ impl NftCollection {
             .instance()
             .set(&DataKey::TotalSupply, &(total_supply - 1));
 
-        events::emit_burn(&env, env.current_contract_address(), from, token_id, 1);
+        events::emit_burn(&env, from, token_id);
 
         Ok(())
     }
 
-    pub fn get_token_uri(env: Env, token_id: u32) -> Option<String> {
+    pub fn get_token_uri(env: Env, token_id: u64) -> Option<String> {
         let metadata: TokenMetadata = env
             .storage()
             .instance()
@@ -202,7 +202,7 @@ impl NftCollection {
         Some(metadata.uri)
     }
 
-    pub fn get_token_metadata(env: Env, token_id: u32) -> Option<TokenMetadata> {
+    pub fn get_token_metadata(env: Env, token_id: u64) -> Option<TokenMetadata> {
         env.storage()
             .instance()
             .get(&DataKey::TokenMetadata(token_id))
@@ -215,14 +215,14 @@ impl NftCollection {
             .unwrap_or(0)
     }
 
-    pub fn balance_of(env: Env, owner: Address, token_id: u32) -> u32 {
+    pub fn balance_of(env: Env, owner: Address, token_id: u64) -> u32 {
         env.storage()
             .instance()
             .get(&DataKey::Balance(owner, token_id))
             .unwrap_or(0)
     }
 
-    pub fn owner_of(env: Env, token_id: u32) -> Option<Address> {
+    pub fn owner_of(env: Env, token_id: u64) -> Option<Address> {
         env.storage().instance().get(&DataKey::Owner(token_id))
     }
 
@@ -0,0 +1,77 @@
+//! Curator-maintained list of verified collections.
+
+use crate::error::ContractError;
+use crate::events;
+use crate::registry;
+use crate::storage::DataKey;
+use crate::types::CollectionInfo;
+use soroban_sdk::{Address, Env, Vec};
+
+pub fn is_curator(env: &Env, address: &Address) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Curator(address.clone()))
+        .unwrap_or(false)
+}
+
+pub fn set_curator(env: &Env, curator: Address, granted: bool) {
+    if granted {
+        env.storage()
+            .instance()
+            .set(&DataKey::Curator(curator), &true);
+    } else {
+        env.storage().instance().remove(&DataKey::Curator(curator));
+    }
+}
+
+pub fn is_verified(env: &Env, id: u32) -> bool {
+    verified_ids(env).contains(id)
+}
+
+fn verified_ids(env: &Env) -> Vec<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::VerifiedCollections)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_verified(
+    env: &Env,
+    curator: &Address,
+    id: u32,
+    verified: bool,
+) -> Result<(), ContractError> {
+    curator.require_auth();
+    if !is_curator(env, curator) {
+        return Err(ContractError::NotAuthorized);
+    }
+    if registry::get_collection(env, id).is_none() {
+        return Err(ContractError::CollectionNotFound);
+    }
+
+    let mut ids = verified_ids(env);
+    match (ids.first_index_of(id), verified) {
+        (None, true) => ids.push_back(id),
+        (Some(index), false) => {
+            ids.remove(index);
+        }
+        _ => return Ok(()),
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::VerifiedCollections, &ids);
+    events::emit_verification_changed(env, curator.clone(), id, verified);
+    Ok(())
+}
+
+/// Verified collections in the order they were verified, paginated like `registry::list`.
+pub fn list_verified(env: &Env, start: u32, limit: u32) -> Vec<CollectionInfo> {
+    let ids = verified_ids(env);
+    let mut page = Vec::new(env);
+    for index in nft_interface::page_range(start, limit, ids.len()) {
+        if let Some(info) = registry::get_collection(env, ids.get_unchecked(index)) {
+            page.push_back(info);
+        }
+    }
+    page
+}
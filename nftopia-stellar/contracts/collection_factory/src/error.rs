@@ -16,4 +16,10 @@ pub enum ContractError {
     InvalidRoyalty = 10,
     InvalidRecipient = 11,
     TokenAlreadyExists = 12,
+    NotInitialized = 13,
+    TemplateNotFound = 14,
+    FeeNotConfigured = 15,
+    UpgradeOptedOut = 16,
+    BatchTooLarge = 17,
+    UpgradeFailed = 18,
 }
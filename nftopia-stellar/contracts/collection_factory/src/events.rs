@@ -1,39 +1,37 @@
-use soroban_sdk::{Address, Env, contractevent};
+use nft_interface::{Burn, Mint, Transfer};
+use soroban_sdk::{Address, BytesN, Env, contractevent};
 
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct Created {
+    #[topic]
     pub creator: Address,
+    #[topic]
     pub collection: Address,
     pub id: u32,
 }
 
 #[contractevent]
 #[derive(Clone, Debug)]
-pub struct Mint {
+pub struct CollectionUpgraded {
     pub collection: Address,
-    pub to: Address,
-    pub token_id: u32,
-    pub amount: u32,
+    pub id: u32,
+    pub wasm_hash: BytesN<32>,
 }
 
 #[contractevent]
 #[derive(Clone, Debug)]
-pub struct Transfer {
+pub struct CollectionUpgradeFailed {
     pub collection: Address,
-    pub from: Address,
-    pub to: Address,
-    pub token_id: u32,
-    pub amount: u32,
+    pub id: u32,
 }
 
 #[contractevent]
 #[derive(Clone, Debug)]
-pub struct Burn {
-    pub collection: Address,
-    pub from: Address,
-    pub token_id: u32,
-    pub amount: u32,
+pub struct VerificationChanged {
+    pub curator: Address,
+    pub id: u32,
+    pub verified: bool,
 }
 
 pub fn emit_collection_created(
@@ -50,40 +48,41 @@ pub fn emit_collection_created(
     .publish(env);
 }
 
-pub fn emit_mint(env: &Env, collection: Address, to: Address, token_id: u32, amount: u32) {
-    Mint {
+pub fn emit_collection_upgraded(env: &Env, collection: Address, id: u32, wasm_hash: BytesN<32>) {
+    CollectionUpgraded {
         collection,
-        to,
-        token_id,
-        amount,
+        id,
+        wasm_hash,
     }
     .publish(env);
 }
 
-pub fn emit_transfer(
-    env: &Env,
-    collection: Address,
-    from: Address,
-    to: Address,
-    token_id: u32,
-    amount: u32,
-) {
-    Transfer {
-        collection,
-        from,
-        to,
-        token_id,
-        amount,
+pub fn emit_collection_upgrade_failed(env: &Env, collection: Address, id: u32) {
+    CollectionUpgradeFailed { collection, id }.publish(env);
+}
+
+pub fn emit_verification_changed(env: &Env, curator: Address, id: u32, verified: bool) {
+    VerificationChanged {
+        curator,
+        id,
+        verified,
     }
     .publish(env);
 }
 
-pub fn emit_burn(env: &Env, collection: Address, from: Address, token_id: u32, amount: u32) {
-    Burn {
-        collection,
-        from,
+pub fn emit_mint(env: &Env, to: Address, token_id: u64, creator: Address) {
+    Mint {
+        to,
         token_id,
-        amount,
+        creator,
     }
     .publish(env);
 }
+
+pub fn emit_transfer(env: &Env, from: Address, to: Address, token_id: u64) {
+    Transfer { from, to, token_id }.publish(env);
+}
+
+pub fn emit_burn(env: &Env, from: Address, token_id: u64) {
+    Burn { from, token_id }.publish(env);
+}
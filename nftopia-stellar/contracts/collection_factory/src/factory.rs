@@ -1,8 +1,14 @@
+use crate::curation;
 use crate::error::ContractError;
 use crate::events;
+use crate::registry;
 use crate::storage::DataKey;
-use crate::types::{CollectionConfig, CollectionInfo};
-use soroban_sdk::{Address, BytesN, Env, Val, Vec, contract, contractimpl, panic_with_error};
+use crate::types::{CollectionInfo, CreationFee, NftCollectionConfig};
+use crate::upgrades;
+use nft_interface::NftClient;
+use soroban_sdk::{
+    Address, BytesN, Env, Symbol, Vec, contract, contractimpl, panic_with_error, token,
+};
 
 #[contract]
 pub struct CollectionFactory;
@@ -19,72 +25,122 @@ impl CollectionFactory {
             .set(&DataKey::CollectionCount, &0u32);
     }
 
+    // --- Templates ---
+    /// Registers (or replaces) a named collection template, e.g. "standard" or "soulbound".
+    /// The WASM must already be uploaded and expose `initialize(owner, config)` with the
+    /// `nft_contract` config layout.
+    pub fn set_template(
+        env: Env,
+        name: Symbol,
+        wasm_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        require_admin(&env)?;
+        let storage = env.storage().instance();
+        if !storage.has(&DataKey::Template(name.clone())) {
+            let mut names = Self::list_templates(env.clone());
+            names.push_back(name.clone());
+            storage.set(&DataKey::TemplateNames, &names);
+        }
+        storage.set(&DataKey::Template(name), &wasm_hash);
+        Ok(())
+    }
+
+    /// Removes a template. Collections already deployed from it are unaffected.
+    pub fn remove_template(env: Env, name: Symbol) -> Result<(), ContractError> {
+        require_admin(&env)?;
+        let storage = env.storage().instance();
+        if !storage.has(&DataKey::Template(name.clone())) {
+            return Err(ContractError::TemplateNotFound);
+        }
+        storage.remove(&DataKey::Template(name.clone()));
+        let mut names = Self::list_templates(env.clone());
+        if let Some(index) = names.first_index_of(&name) {
+            names.remove(index);
+        }
+        storage.set(&DataKey::TemplateNames, &names);
+        Ok(())
+    }
+
+    pub fn get_template(env: Env, name: Symbol) -> Option<BytesN<32>> {
+        env.storage().instance().get(&DataKey::Template(name))
+    }
+
+    pub fn list_templates(env: Env) -> Vec<Symbol> {
+        env.storage()
+            .instance()
+            .get(&DataKey::TemplateNames)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Deploys a new collection from `template`, owned by `creator`, and records it in the
+    /// registry.
     pub fn create_collection(
         env: Env,
         creator: Address,
-        wasm_hash: BytesN<32>,
+        template: Symbol,
+        config: NftCollectionConfig,
         salt: BytesN<32>,
-        config: CollectionConfig,
     ) -> Result<Address, ContractError> {
         creator.require_auth();
 
-        let admin: Address = env
+        let wasm_hash: BytesN<32> = env
             .storage()
             .instance()
-            .get(&DataKey::FactoryAdmin)
-            .unwrap();
+            .get(&DataKey::Template(template.clone()))
+            .ok_or(ContractError::TemplateNotFound)?;
         let collection_id: u32 = env
             .storage()
             .instance()
             .get(&DataKey::CollectionCount)
-            .unwrap_or(0);
+            .ok_or(ContractError::NotInitialized)?;
 
-        let constructor_args: Vec<Val> = Vec::new(&env);
+        if let Some(fee) = env
+            .storage()
+            .instance()
+            .get::<_, CreationFee>(&DataKey::FactoryFee)
+            && fee.amount > 0
+        {
+            let factory = env.current_contract_address();
+            token::Client::new(&env, &fee.token).transfer(&creator, &factory, &fee.amount);
+        }
 
-        // Deploy the collection contract
+        // Deploy and initialize in the same call so no one can front-run `initialize`.
         let collection_address = env
             .deployer()
             .with_address(creator.clone(), salt)
-            .deploy_v2(wasm_hash, constructor_args);
-
-        // Initialize the collection
-        // We use a cross-contract call to initialize
-        // Since we don't have the client here easily without the trait, we use dynamic call
-        env.invoke_contract::<()>(
-            &collection_address,
-            &soroban_sdk::symbol_short!("init"),
-            soroban_sdk::vec![&env, admin.into_val(&env), config.clone().into_val(&env)],
-        );
+            .deploy_v2(wasm_hash.clone(), ());
+        let nft = NftClient::new(&env, &collection_address);
+        nft.initialize(&creator, &config);
+        // Lets the factory push template upgrades; creators can opt out per collection.
+        nft.set_upgrader(&creator, &Some(env.current_contract_address()));
 
         let info = CollectionInfo {
             address: collection_address.clone(),
             creator: creator.clone(),
-            config: config.clone(),
+            template,
+            wasm_hash,
+            config,
             created_at: env.ledger().timestamp(),
             total_tokens: 0,
         };
 
-        env.storage().instance().set(
-            &DataKey::CollectionAddress(collection_id),
-            &collection_address,
-        );
-        env.storage()
-            .instance()
-            .set(&DataKey::CollectionInfo(collection_id), &info);
-        env.storage()
-            .instance()
-            .set(&DataKey::CollectionCount, &(collection_id + 1));
+        registry::record(&env, collection_id, &info);
 
         events::emit_collection_created(&env, creator, collection_address.clone(), collection_id);
 
         Ok(collection_address)
     }
 
+    /// Address `create_collection` will deploy to for this creator and salt, so clients can
+    /// reference the collection before it exists.
+    pub fn predict_collection_address(env: Env, creator: Address, salt: BytesN<32>) -> Address {
+        env.deployer()
+            .with_address(creator, salt)
+            .deployed_address()
+    }
+
     pub fn get_collection_count(env: Env) -> u32 {
-        env.storage()
-            .instance()
-            .get(&DataKey::CollectionCount)
-            .unwrap_or(0)
+        registry::collection_count(&env)
     }
 
     pub fn get_collection_address(env: Env, id: u32) -> Option<Address> {
@@ -94,7 +150,106 @@ impl CollectionFactory {
     }
 
     pub fn get_collection_info(env: Env, id: u32) -> Option<CollectionInfo> {
-        env.storage().instance().get(&DataKey::CollectionInfo(id))
+        registry::get_collection(&env, id)
+    }
+
+    // --- Registry ---
+    pub fn collection_count(env: Env) -> u32 {
+        registry::collection_count(&env)
+    }
+
+    pub fn get_collection(env: Env, id: u32) -> Result<CollectionInfo, ContractError> {
+        registry::get_collection(&env, id).ok_or(ContractError::CollectionNotFound)
+    }
+
+    /// Collections with ids in `[start, start + limit)`; at most 100 per page.
+    pub fn list_collections(env: Env, start: u32, limit: u32) -> Vec<CollectionInfo> {
+        registry::list(&env, start, limit)
+    }
+
+    pub fn creator_collection_count(env: Env, creator: Address) -> u32 {
+        registry::creator_collection_count(&env, &creator)
+    }
+
+    /// The creator's collections in deployment order; at most 100 per page.
+    pub fn collections_by_creator(
+        env: Env,
+        creator: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<CollectionInfo> {
+        registry::list_by_creator(&env, &creator, start, limit)
+    }
+
+    // --- Curation ---
+    pub fn set_curator(env: Env, curator: Address, granted: bool) -> Result<(), ContractError> {
+        require_admin(&env)?;
+        curation::set_curator(&env, curator, granted);
+        Ok(())
+    }
+
+    pub fn is_curator(env: Env, address: Address) -> bool {
+        curation::is_curator(&env, &address)
+    }
+
+    /// Marks a collection as curated (or removes the mark) so frontends can tell it apart
+    /// from arbitrary deployments.
+    pub fn set_verified(
+        env: Env,
+        curator: Address,
+        id: u32,
+        verified: bool,
+    ) -> Result<(), ContractError> {
+        curation::set_verified(&env, &curator, id, verified)
+    }
+
+    pub fn is_verified(env: Env, id: u32) -> bool {
+        curation::is_verified(&env, id)
+    }
+
+    /// Verified collections in verification order; at most 100 per page.
+    pub fn list_verified(env: Env, start: u32, limit: u32) -> Vec<CollectionInfo> {
+        curation::list_verified(&env, start, limit)
+    }
+
+    // --- Upgrades ---
+    /// Upgrades one collection to the current WASM of its template. Register the new WASM with
+    /// `set_template` first. Returns `false` if the collection was already up to date.
+    pub fn upgrade_collection(env: Env, id: u32) -> Result<bool, ContractError> {
+        require_admin(&env)?;
+        upgrades::upgrade(&env, id)
+    }
+
+    /// Upgrades up to 20 collections, skipping those that opted out or are already up to date.
+    /// A collection that rejects the upgrade is skipped with a `CollectionUpgradeFailed` event
+    /// instead of reverting the batch. Returns the number of collections upgraded.
+    pub fn upgrade_collections(env: Env, ids: Vec<u32>) -> Result<u32, ContractError> {
+        require_admin(&env)?;
+        if ids.len() > upgrades::MAX_UPGRADE_BATCH {
+            return Err(ContractError::BatchTooLarge);
+        }
+        let mut upgraded = 0;
+        for id in ids.iter() {
+            if upgrades::is_opted_out(&env, id) {
+                continue;
+            }
+            match upgrades::upgrade(&env, id) {
+                Ok(true) => upgraded += 1,
+                Ok(false) => {}
+                Err(ContractError::UpgradeFailed) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(upgraded)
+    }
+
+    /// Creator-only switch that excludes a collection from factory-pushed upgrades.
+    pub fn set_upgrade_opt_out(env: Env, id: u32, opted_out: bool) -> Result<(), ContractError> {
+        upgrades::set_opt_out(&env, id, opted_out)
+    }
+
+    pub fn is_upgrade_opted_out(env: Env, id: u32) -> bool {
+        upgrades::is_opted_out(&env, id)
     }
 
     pub fn set_admin(env: Env, new_admin: Address) {
@@ -109,20 +264,45 @@ impl CollectionFactory {
             .set(&DataKey::FactoryAdmin, &new_admin);
     }
 
-    pub fn withdraw_fees(env: Env, _to: Address) -> Result<(), ContractError> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::FactoryAdmin)
-            .unwrap();
-        admin.require_auth();
+    // --- Fees ---
+    /// Sets the fee charged by `create_collection`. An amount of zero makes creation free.
+    pub fn set_creation_fee(env: Env, fee: CreationFee) -> Result<(), ContractError> {
+        require_admin(&env)?;
+        if fee.amount < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        env.storage().instance().set(&DataKey::FactoryFee, &fee);
+        Ok(())
+    }
 
-        // Fee collection logic would go here
-        // For now, we don't have native asset logic implemented here
+    pub fn get_creation_fee(env: Env) -> Option<CreationFee> {
+        env.storage().instance().get(&DataKey::FactoryFee)
+    }
 
-        Ok(())
+    /// Sends the factory's whole balance of the fee token to the treasury.
+    pub fn withdraw_fees(env: Env) -> Result<i128, ContractError> {
+        require_admin(&env)?;
+        let fee: CreationFee = env
+            .storage()
+            .instance()
+            .get(&DataKey::FactoryFee)
+            .ok_or(ContractError::FeeNotConfigured)?;
+        let token = token::Client::new(&env, &fee.token);
+        let factory = env.current_contract_address();
+        let balance = token.balance(&factory);
+        if balance > 0 {
+            token.transfer(&factory, &fee.treasury, &balance);
+        }
+        Ok(balance)
     }
 }
 
-// Helper trait to convert Address to Val
-use soroban_sdk::IntoVal;
+fn require_admin(env: &Env) -> Result<Address, ContractError> {
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::FactoryAdmin)
+        .ok_or(ContractError::NotInitialized)?;
+    admin.require_auth();
+    Ok(admin)
+}
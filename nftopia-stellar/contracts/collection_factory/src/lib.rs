@@ -1,10 +1,13 @@
 #![no_std]
 pub mod collection;
+mod curation;
 pub mod error;
 pub mod events;
 pub mod factory;
+mod registry;
 pub mod storage;
 pub mod types;
+mod upgrades;
 
 pub use crate::collection::NftCollection;
 pub use crate::factory::CollectionFactory;
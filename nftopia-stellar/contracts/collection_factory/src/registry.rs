@@ -0,0 +1,73 @@
+//! Index of collections deployed through the factory, by id and by creator.
+
+use crate::storage::DataKey;
+use crate::types::CollectionInfo;
+use nft_interface::page_range;
+use soroban_sdk::{Address, Env, Vec};
+
+pub fn collection_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::CollectionCount)
+        .unwrap_or(0)
+}
+
+pub fn creator_collection_count(env: &Env, creator: &Address) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::CreatorCollectionCount(creator.clone()))
+        .unwrap_or(0)
+}
+
+pub fn get_collection(env: &Env, id: u32) -> Option<CollectionInfo> {
+    env.storage().instance().get(&DataKey::CollectionInfo(id))
+}
+
+/// Stores a newly deployed collection under the next id and indexes it by creator.
+pub fn record(env: &Env, id: u32, info: &CollectionInfo) {
+    let storage = env.storage().instance();
+    storage.set(&DataKey::CollectionAddress(id), &info.address);
+    storage.set(&DataKey::CollectionInfo(id), info);
+    storage.set(&DataKey::CollectionCount, &(id + 1));
+
+    let index = creator_collection_count(env, &info.creator);
+    storage.set(
+        &DataKey::CreatorCollection(info.creator.clone(), index),
+        &id,
+    );
+    storage.set(
+        &DataKey::CreatorCollectionCount(info.creator.clone()),
+        &(index + 1),
+    );
+}
+
+/// Collections with ids in `[start, start + limit)`, with `limit` capped at `MAX_PAGE_SIZE`.
+pub fn list(env: &Env, start: u32, limit: u32) -> Vec<CollectionInfo> {
+    let mut page = Vec::new(env);
+    for id in page_range(start, limit, collection_count(env)) {
+        if let Some(info) = get_collection(env, id) {
+            page.push_back(info);
+        }
+    }
+    page
+}
+
+/// The creator's collections in deployment order, paginated like `list`.
+pub fn list_by_creator(
+    env: &Env,
+    creator: &Address,
+    start: u32,
+    limit: u32,
+) -> Vec<CollectionInfo> {
+    let mut page = Vec::new(env);
+    for index in page_range(start, limit, creator_collection_count(env, creator)) {
+        let id: Option<u32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::CreatorCollection(creator.clone(), index));
+        if let Some(info) = id.and_then(|id| get_collection(env, id)) {
+            page.push_back(info);
+        }
+    }
+    page
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, contracttype};
+use soroban_sdk::{Address, Symbol, contracttype};
 
 #[derive(Clone)]
 #[contracttype]
@@ -9,13 +9,20 @@ pub enum DataKey {
     CollectionCount,
     CollectionAddress(u32),
     CollectionInfo(u32),
+    Template(Symbol),
+    TemplateNames,
+    CreatorCollectionCount(Address),
+    CreatorCollection(Address, u32),
+    UpgradeOptOut(u32),
+    Curator(Address),
+    VerifiedCollections,
 
     // Collection Keys
     CollectionConfig,
     TotalSupply,
-    TokenMetadata(u32),
-    Balance(Address, u32),
-    Owner(u32),
+    TokenMetadata(u64),
+    Balance(Address, u64),
+    Owner(u64),
     RoyaltyInfo,
     Minter(Address),
     Whitelist(Address),
@@ -1,9 +1,14 @@
 #![cfg(test)]
 
 use crate::collection::{NftCollection, NftCollectionClient};
+use crate::error::ContractError;
 use crate::factory::{CollectionFactory, CollectionFactoryClient};
-use crate::types::CollectionConfig;
-use soroban_sdk::{Address, Env, String, Vec, testutils::Address as _};
+use crate::registry;
+use crate::types::{
+    CollectionConfig, CollectionInfo, CreationFee, NftCollectionConfig, RoyaltyInfo,
+};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, BytesN, Env, String, Vec, symbol_short, testutils::Address as _};
 
 #[test]
 fn test_factory_logic() {
@@ -14,7 +19,7 @@ fn test_factory_logic() {
     let _creator = Address::generate(&env);
 
     // Register Factory
-    let factory_id = env.register_contract(None, CollectionFactory);
+    let factory_id = env.register(CollectionFactory, ());
     let factory_client = CollectionFactoryClient::new(&env, &factory_id);
 
     // Initialize Factory
@@ -23,8 +28,96 @@ fn test_factory_logic() {
     assert_eq!(factory_client.get_collection_count(), 0);
 }
 
+#[test]
+fn test_predict_collection_address() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let factory_id = env.register(CollectionFactory, ());
+    let factory_client = CollectionFactoryClient::new(&env, &factory_id);
+    factory_client.initialize(&admin);
+
+    let salt = BytesN::from_array(&env, &[0; 32]);
+    let predicted = factory_client.predict_collection_address(&alice, &salt);
+    assert_eq!(
+        predicted,
+        env.as_contract(&factory_id, || env
+            .deployer()
+            .with_address(alice.clone(), salt.clone())
+            .deployed_address())
+    );
+    assert_ne!(
+        predicted,
+        factory_client.predict_collection_address(&bob, &salt)
+    );
+    assert_ne!(
+        predicted,
+        factory_client.predict_collection_address(&alice, &BytesN::from_array(&env, &[1; 32]))
+    );
+}
+
+#[test]
+fn test_templates() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+
+    let factory_id = env.register(CollectionFactory, ());
+    let factory_client = CollectionFactoryClient::new(&env, &factory_id);
+    factory_client.initialize(&admin);
+
+    let config = NftCollectionConfig {
+        name: String::from_str(&env, "Test NFT"),
+        symbol: String::from_str(&env, "TNFT"),
+        base_uri: String::from_str(&env, "https://test.com/"),
+        max_supply: None,
+        is_revealed: true,
+        royalty_default: RoyaltyInfo {
+            recipient: creator.clone(),
+            percentage: 500,
+        },
+        metadata_is_frozen: false,
+        content_addressed_ids: false,
+        max_batch_size: None,
+    };
+    let salt = BytesN::from_array(&env, &[0; 32]);
+    let standard = symbol_short!("standard");
+    assert_eq!(
+        factory_client.try_create_collection(&creator, &standard, &config, &salt),
+        Err(Ok(ContractError::TemplateNotFound))
+    );
+
+    let standard_hash = BytesN::from_array(&env, &[1; 32]);
+    factory_client.set_template(&standard, &standard_hash);
+    factory_client.set_template(
+        &symbol_short!("soulbound"),
+        &BytesN::from_array(&env, &[2; 32]),
+    );
+    factory_client.set_template(&standard, &standard_hash);
+    assert_eq!(factory_client.list_templates().len(), 2);
+    assert_eq!(factory_client.get_template(&standard), Some(standard_hash));
+
+    factory_client.remove_template(&standard);
+    assert_eq!(
+        factory_client.list_templates(),
+        Vec::from_array(&env, [symbol_short!("soulbound")])
+    );
+    assert_eq!(
+        factory_client.try_remove_template(&standard),
+        Err(Ok(ContractError::TemplateNotFound))
+    );
+}
+
 #[test]
 fn test_collection_logic() {
+    use soroban_sdk::testutils::Events;
+    use soroban_sdk::xdr::{ContractEventBody, ScVal};
+
     let env = Env::default();
     env.mock_all_auths();
 
@@ -32,7 +125,7 @@ fn test_collection_logic() {
     let user1 = Address::generate(&env);
     let user2 = Address::generate(&env);
 
-    let collection_id = env.register_contract(None, NftCollection);
+    let collection_id = env.register(NftCollection, ());
     let collection_client = NftCollectionClient::new(&env, &collection_id);
 
     // Collection Config
@@ -63,6 +156,12 @@ fn test_collection_logic() {
     // Transfer NFT
     collection_client.transfer(&user1, &user2, &token_id);
 
+    // Same shape as nft_contract's Transfer: name, from, to and a u64 token id as topics.
+    let events = env.events().all().filter_by_contract(&collection_id);
+    let ContractEventBody::V0(transfer) = &events.events().last().unwrap().body;
+    assert_eq!(transfer.topics.len(), 4);
+    assert_eq!(transfer.topics[3], ScVal::U64(token_id));
+
     assert_eq!(collection_client.owner_of(&token_id), Some(user2.clone()));
     assert_eq!(collection_client.balance_of(&user1, &token_id), 0);
     assert_eq!(collection_client.balance_of(&user2, &token_id), 1);
@@ -81,7 +180,7 @@ fn test_unauthorized_mint() {
     let admin = Address::generate(&env);
     let _user = Address::generate(&env);
 
-    let collection_id = env.register_contract(None, NftCollection);
+    let collection_id = env.register(NftCollection, ());
     let collection_client = NftCollectionClient::new(&env, &collection_id);
 
     let config = CollectionConfig {
@@ -102,3 +201,243 @@ fn test_unauthorized_mint() {
     // Wait, the mint function checks if the env.storage().instance().get(&DataKey::FactoryAdmin) is the minter.
     // Actually, it checks Self::is_minter(&env, &admin).
 }
+
+#[test]
+fn test_registry_pagination_and_creator_lookup() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let factory_id = env.register(CollectionFactory, ());
+    let factory_client = CollectionFactoryClient::new(&env, &factory_id);
+    factory_client.initialize(&admin);
+
+    // Deployment needs the nft_contract WASM, so record registry entries directly.
+    let creators = [&alice, &bob, &alice];
+    env.as_contract(&factory_id, || {
+        for (id, creator) in creators.iter().enumerate() {
+            let info = CollectionInfo {
+                address: Address::generate(&env),
+                creator: (*creator).clone(),
+                template: symbol_short!("standard"),
+                wasm_hash: BytesN::from_array(&env, &[1; 32]),
+                config: NftCollectionConfig {
+                    name: String::from_str(&env, "Test NFT"),
+                    symbol: String::from_str(&env, "TNFT"),
+                    base_uri: String::from_str(&env, "https://test.com/"),
+                    max_supply: None,
+                    is_revealed: true,
+                    royalty_default: RoyaltyInfo {
+                        recipient: (*creator).clone(),
+                        percentage: 0,
+                    },
+                    metadata_is_frozen: false,
+                    content_addressed_ids: false,
+                    max_batch_size: None,
+                },
+                created_at: 0,
+                total_tokens: 0,
+            };
+            registry::record(&env, id as u32, &info);
+        }
+    });
+
+    assert_eq!(factory_client.collection_count(), 3);
+    let page = factory_client.list_collections(&1, &10);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().creator, bob);
+
+    let by_alice = factory_client.collections_by_creator(&alice, &0, &10);
+    assert_eq!(by_alice.len(), 2);
+    assert_eq!(
+        by_alice.get(1).unwrap().address,
+        factory_client.get_collection(&2).address
+    );
+    assert_eq!(factory_client.creator_collection_count(&bob), 1);
+    assert!(matches!(
+        factory_client.try_get_collection(&3),
+        Err(Ok(ContractError::CollectionNotFound))
+    ));
+}
+
+#[test]
+fn test_creation_fee_config_and_withdraw() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let factory_id = env.register(CollectionFactory, ());
+    let factory_client = CollectionFactoryClient::new(&env, &factory_id);
+    factory_client.initialize(&admin);
+    assert_eq!(
+        factory_client.try_withdraw_fees(),
+        Err(Ok(ContractError::FeeNotConfigured))
+    );
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = TokenClient::new(&env, &sac.address());
+    let fee = CreationFee {
+        token: token.address.clone(),
+        amount: -1,
+        treasury: treasury.clone(),
+    };
+    assert_eq!(
+        factory_client.try_set_creation_fee(&fee),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+    factory_client.set_creation_fee(&CreationFee { amount: 250, ..fee });
+    assert_eq!(factory_client.get_creation_fee().unwrap().amount, 250);
+
+    // Fees collected by create_collection accumulate in the factory until withdrawn.
+    StellarAssetClient::new(&env, &sac.address()).mint(&factory_id, &750);
+    assert_eq!(factory_client.withdraw_fees(), 750);
+    assert_eq!(token.balance(&treasury), 750);
+}
+
+#[test]
+fn test_upgrade_opt_out() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+
+    let factory_id = env.register(CollectionFactory, ());
+    let factory_client = CollectionFactoryClient::new(&env, &factory_id);
+    factory_client.initialize(&admin);
+
+    let standard = symbol_short!("standard");
+    let hash = BytesN::from_array(&env, &[1; 32]);
+    factory_client.set_template(&standard, &hash);
+    env.as_contract(&factory_id, || {
+        let info = CollectionInfo {
+            address: Address::generate(&env),
+            creator: creator.clone(),
+            template: standard.clone(),
+            wasm_hash: hash.clone(),
+            config: NftCollectionConfig {
+                name: String::from_str(&env, "Test NFT"),
+                symbol: String::from_str(&env, "TNFT"),
+                base_uri: String::from_str(&env, "https://test.com/"),
+                max_supply: None,
+                is_revealed: true,
+                royalty_default: RoyaltyInfo {
+                    recipient: creator.clone(),
+                    percentage: 0,
+                },
+                metadata_is_frozen: false,
+                content_addressed_ids: false,
+                max_batch_size: None,
+            },
+            created_at: 0,
+            total_tokens: 0,
+        };
+        registry::record(&env, 0, &info);
+    });
+
+    // Already on the template's WASM, so nothing is pushed.
+    assert!(!factory_client.upgrade_collection(&0));
+
+    factory_client.set_upgrade_opt_out(&0, &true);
+    assert!(factory_client.is_upgrade_opted_out(&0));
+    factory_client.set_template(&standard, &BytesN::from_array(&env, &[2; 32]));
+    assert_eq!(
+        factory_client.try_upgrade_collection(&0),
+        Err(Ok(ContractError::UpgradeOptedOut))
+    );
+    assert_eq!(
+        factory_client.upgrade_collections(&Vec::from_array(&env, [0])),
+        0
+    );
+    assert_eq!(
+        factory_client.try_upgrade_collections(&Vec::from_array(&env, [0; 21])),
+        Err(Ok(ContractError::BatchTooLarge))
+    );
+
+    // A collection that cannot be upgraded is skipped instead of reverting the batch.
+    env.as_contract(&factory_id, || {
+        let mut info = registry::get_collection(&env, 0).unwrap();
+        info.address = Address::generate(&env);
+        registry::record(&env, 1, &info);
+    });
+    assert_eq!(
+        factory_client.try_upgrade_collection(&1),
+        Err(Ok(ContractError::UpgradeFailed))
+    );
+    assert_eq!(
+        factory_client.upgrade_collections(&Vec::from_array(&env, [0, 1])),
+        0
+    );
+}
+
+#[test]
+fn test_verified_curation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let curator = Address::generate(&env);
+    let creator = Address::generate(&env);
+
+    let factory_id = env.register(CollectionFactory, ());
+    let factory_client = CollectionFactoryClient::new(&env, &factory_id);
+    factory_client.initialize(&admin);
+
+    env.as_contract(&factory_id, || {
+        for id in 0..3 {
+            let info = CollectionInfo {
+                address: Address::generate(&env),
+                creator: creator.clone(),
+                template: symbol_short!("standard"),
+                wasm_hash: BytesN::from_array(&env, &[1; 32]),
+                config: NftCollectionConfig {
+                    name: String::from_str(&env, "Test NFT"),
+                    symbol: String::from_str(&env, "TNFT"),
+                    base_uri: String::from_str(&env, "https://test.com/"),
+                    max_supply: None,
+                    is_revealed: true,
+                    royalty_default: RoyaltyInfo {
+                        recipient: creator.clone(),
+                        percentage: 0,
+                    },
+                    metadata_is_frozen: false,
+                    content_addressed_ids: false,
+                    max_batch_size: None,
+                },
+                created_at: 0,
+                total_tokens: 0,
+            };
+            registry::record(&env, id, &info);
+        }
+    });
+
+    assert_eq!(
+        factory_client.try_set_verified(&curator, &0, &true),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+    factory_client.set_curator(&curator, &true);
+    assert_eq!(
+        factory_client.try_set_verified(&curator, &3, &true),
+        Err(Ok(ContractError::CollectionNotFound))
+    );
+
+    factory_client.set_verified(&curator, &2, &true);
+    factory_client.set_verified(&curator, &0, &true);
+    factory_client.set_verified(&curator, &0, &true);
+    assert!(factory_client.is_verified(&2));
+    assert!(!factory_client.is_verified(&1));
+    let verified = factory_client.list_verified(&0, &10);
+    assert_eq!(verified.len(), 2);
+    assert_eq!(
+        verified.get(0).unwrap().address,
+        factory_client.get_collection(&2).address
+    );
+
+    factory_client.set_verified(&curator, &2, &false);
+    assert_eq!(factory_client.list_verified(&0, &10).len(), 1);
+}
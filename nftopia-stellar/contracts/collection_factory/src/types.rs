@@ -1,4 +1,8 @@
-use soroban_sdk::{Address, String, Vec, contracttype};
+use soroban_sdk::{Address, BytesN, String, Symbol, Vec, contracttype};
+
+/// Configuration of a collection deployed from the registered `nft_contract` WASM.
+pub use nft_interface::CollectionConfig as NftCollectionConfig;
+pub use nft_interface::RoyaltyInfo;
 
 #[derive(Clone, Debug)]
 #[contracttype]
@@ -16,7 +20,7 @@ pub struct CollectionConfig {
 #[derive(Clone, Debug)]
 #[contracttype]
 pub struct TokenMetadata {
-    pub token_id: u32,
+    pub token_id: u64,
     pub uri: String,
     pub attributes: Vec<(String, String)>,
     pub creator: Address,
@@ -28,14 +32,22 @@ pub struct TokenMetadata {
 pub struct CollectionInfo {
     pub address: Address,
     pub creator: Address,
-    pub config: CollectionConfig,
+    /// Template the collection was deployed from.
+    pub template: Symbol,
+    /// WASM the collection currently runs; updated when the factory pushes an upgrade.
+    pub wasm_hash: BytesN<32>,
+    pub config: NftCollectionConfig,
     pub created_at: u64,
     pub total_tokens: u32,
 }
 
+/// Fee charged to creators for each collection deployed through the factory.
 #[derive(Clone, Debug)]
 #[contracttype]
-pub struct RoyaltyInfo {
-    pub recipient: Address,
-    pub percentage: u32, // Basis points
+pub struct CreationFee {
+    /// SEP-41 token the fee is paid in.
+    pub token: Address,
+    pub amount: i128,
+    /// Receives accumulated fees on withdrawal.
+    pub treasury: Address,
 }
@@ -0,0 +1,64 @@
+//! Pushing template WASM upgrades to collections deployed by the factory.
+
+use crate::error::ContractError;
+use crate::events;
+use crate::registry;
+use crate::storage::DataKey;
+use nft_interface::NftClient;
+use soroban_sdk::{BytesN, Env};
+
+/// Upper bound on collections upgraded in one `upgrade_collections` call.
+pub const MAX_UPGRADE_BATCH: u32 = 20;
+
+pub fn is_opted_out(env: &Env, id: u32) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::UpgradeOptOut(id))
+        .unwrap_or(false)
+}
+
+/// Lets the collection's creator opt out of (or back into) factory-pushed upgrades.
+pub fn set_opt_out(env: &Env, id: u32, opted_out: bool) -> Result<(), ContractError> {
+    let info = registry::get_collection(env, id).ok_or(ContractError::CollectionNotFound)?;
+    info.creator.require_auth();
+    if opted_out {
+        env.storage()
+            .instance()
+            .set(&DataKey::UpgradeOptOut(id), &true);
+    } else {
+        env.storage().instance().remove(&DataKey::UpgradeOptOut(id));
+    }
+    Ok(())
+}
+
+/// Moves collection `id` to the current WASM of its template. Returns `false` when the
+/// collection already runs that WASM, and `UpgradeFailed` when the collection rejects the
+/// upgrade (e.g. its creator revoked the factory as upgrader).
+pub fn upgrade(env: &Env, id: u32) -> Result<bool, ContractError> {
+    let mut info = registry::get_collection(env, id).ok_or(ContractError::CollectionNotFound)?;
+    if is_opted_out(env, id) {
+        return Err(ContractError::UpgradeOptedOut);
+    }
+    let wasm_hash: BytesN<32> = env
+        .storage()
+        .instance()
+        .get(&DataKey::Template(info.template.clone()))
+        .ok_or(ContractError::TemplateNotFound)?;
+    if info.wasm_hash == wasm_hash {
+        return Ok(false);
+    }
+
+    if !matches!(
+        NftClient::new(env, &info.address).try_upgrade(&env.current_contract_address(), &wasm_hash),
+        Ok(Ok(()))
+    ) {
+        events::emit_collection_upgrade_failed(env, info.address, id);
+        return Err(ContractError::UpgradeFailed);
+    }
+    info.wasm_hash = wasm_hash.clone();
+    env.storage()
+        .instance()
+        .set(&DataKey::CollectionInfo(id), &info);
+    events::emit_collection_upgraded(env, info.address, id, wasm_hash);
+    Ok(true)
+}
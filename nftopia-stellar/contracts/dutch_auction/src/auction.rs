@@ -0,0 +1,105 @@
+use crate::error::ContractError;
+use crate::events;
+use crate::storage::{self, DataKey};
+use crate::types::{DutchAuction, PriceSchedule};
+use nft_interface::NftClient;
+use soroban_sdk::{Address, Env, token};
+
+fn validate_schedule(schedule: &PriceSchedule) -> Result<(), ContractError> {
+    if schedule.floor_price <= 0
+        || schedule.start_price < schedule.floor_price
+        || schedule.decay_interval == 0
+        || schedule.price_drop < 0
+    {
+        return Err(ContractError::InvalidSchedule);
+    }
+    Ok(())
+}
+
+/// Returns the price of `auction` at `timestamp`, never below the floor.
+pub fn price_at(auction: &DutchAuction, timestamp: u64) -> i128 {
+    let schedule = &auction.schedule;
+    let steps = timestamp.saturating_sub(auction.start_time) / schedule.decay_interval;
+    let drop = schedule.price_drop.saturating_mul(steps as i128);
+    schedule
+        .start_price
+        .saturating_sub(drop)
+        .max(schedule.floor_price)
+}
+
+/// Creates a Dutch auction, moving the token into escrow. The price starts decaying immediately.
+pub fn create_auction(
+    env: &Env,
+    seller: Address,
+    collection: Address,
+    token_id: u64,
+    currency: Address,
+    schedule: PriceSchedule,
+) -> Result<u64, ContractError> {
+    seller.require_auth();
+    validate_schedule(&schedule)?;
+    let auction_id = storage::next_auction_id(env)?;
+    NftClient::new(env, &collection).transfer(&seller, &env.current_contract_address(), &token_id);
+
+    let auction = DutchAuction {
+        id: auction_id,
+        seller,
+        collection,
+        token_id,
+        currency,
+        schedule,
+        start_time: env.ledger().timestamp(),
+    };
+    env.storage()
+        .instance()
+        .set(&DataKey::Auction(auction_id), &auction);
+    events::emit_auction_created(env, &auction);
+    Ok(auction_id)
+}
+
+/// Buys at the current price: pays royalty and seller, then releases the token. Returns the price paid.
+pub fn buy(env: &Env, buyer: Address, auction_id: u64) -> Result<i128, ContractError> {
+    buyer.require_auth();
+    let auction = storage::get_auction(env, auction_id)?;
+    if buyer == auction.seller {
+        return Err(ContractError::InvalidBuyer);
+    }
+    env.storage()
+        .instance()
+        .remove(&DataKey::Auction(auction_id));
+
+    let price = price_at(&auction, env.ledger().timestamp());
+    let nft = NftClient::new(env, &auction.collection);
+    let (royalty_recipient, royalty_amount) = nft.get_royalty_info(&auction.token_id, &price);
+    if royalty_amount < 0 || royalty_amount > price {
+        return Err(ContractError::InvalidRoyalty);
+    }
+    let payment = token::Client::new(env, &auction.currency);
+    if royalty_amount > 0 {
+        payment.transfer(&buyer, &royalty_recipient, &royalty_amount);
+    }
+    payment.transfer(&buyer, &auction.seller, &(price - royalty_amount));
+    nft.transfer(&env.current_contract_address(), &buyer, &auction.token_id);
+
+    events::emit_auction_sold(env, auction_id, buyer, price, royalty_amount);
+    Ok(price)
+}
+
+/// Cancels an unsold auction and returns the token to the seller.
+pub fn cancel(env: &Env, seller: Address, auction_id: u64) -> Result<(), ContractError> {
+    seller.require_auth();
+    let auction = storage::get_auction(env, auction_id)?;
+    if auction.seller != seller {
+        return Err(ContractError::NotAuthorized);
+    }
+    env.storage()
+        .instance()
+        .remove(&DataKey::Auction(auction_id));
+    NftClient::new(env, &auction.collection).transfer(
+        &env.current_contract_address(),
+        &seller,
+        &auction.token_id,
+    );
+    events::emit_auction_cancelled(env, auction_id);
+    Ok(())
+}
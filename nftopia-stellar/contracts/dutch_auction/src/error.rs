@@ -0,0 +1,22 @@
+use soroban_sdk::contracterror;
+
+/// Contract-specific errors for the Dutch auction contract.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    /// Caller is not authorized for the operation.
+    NotAuthorized = 1,
+    /// Contract has already been initialized.
+    AlreadyInitialized = 2,
+    /// Contract has not been initialized.
+    NotInitialized = 3,
+    /// Auction not found (never created, sold, or cancelled).
+    AuctionNotFound = 4,
+    /// Invalid price schedule (e.g. floor above start price or zero interval).
+    InvalidSchedule = 5,
+    /// Buyer cannot be the seller.
+    InvalidBuyer = 6,
+    /// Royalty reported by the collection exceeds the sale price.
+    InvalidRoyalty = 7,
+}
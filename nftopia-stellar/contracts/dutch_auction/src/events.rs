@@ -0,0 +1,61 @@
+use crate::types::PriceSchedule;
+use soroban_sdk::{Address, Env, contractevent};
+
+/// Dutch auction created.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct AuctionCreated {
+    pub auction_id: u64,
+    pub seller: Address,
+    pub collection: Address,
+    pub token_id: u64,
+    pub schedule: PriceSchedule,
+}
+
+/// Dutch auction bought at the instantaneous price.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct AuctionSold {
+    pub auction_id: u64,
+    pub buyer: Address,
+    pub price: i128,
+    pub royalty_amount: i128,
+}
+
+/// Dutch auction cancelled by the seller.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct AuctionCancelled {
+    pub auction_id: u64,
+}
+
+pub fn emit_auction_created(env: &Env, auction: &crate::types::DutchAuction) {
+    AuctionCreated {
+        auction_id: auction.id,
+        seller: auction.seller.clone(),
+        collection: auction.collection.clone(),
+        token_id: auction.token_id,
+        schedule: auction.schedule.clone(),
+    }
+    .publish(env);
+}
+
+pub fn emit_auction_sold(
+    env: &Env,
+    auction_id: u64,
+    buyer: Address,
+    price: i128,
+    royalty_amount: i128,
+) {
+    AuctionSold {
+        auction_id,
+        buyer,
+        price,
+        royalty_amount,
+    }
+    .publish(env);
+}
+
+pub fn emit_auction_cancelled(env: &Env, auction_id: u64) {
+    AuctionCancelled { auction_id }.publish(env);
+}
@@ -0,0 +1,63 @@
+#![no_std]
+
+mod auction;
+mod error;
+mod events;
+mod storage;
+mod types;
+
+pub use error::ContractError;
+pub use types::{DutchAuction, PriceSchedule};
+
+use soroban_sdk::{Address, Env, contract, contractimpl};
+
+use crate::error::ContractError as Err;
+use crate::storage::DataKey;
+
+#[contract]
+pub struct DutchAuctionHouse;
+
+#[contractimpl]
+impl DutchAuctionHouse {
+    /// Initializes the Dutch auction contract.
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Err> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Err::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::NextAuctionId, &0u64);
+        Ok(())
+    }
+
+    // --- Auctions ---
+    pub fn create_auction(
+        env: Env,
+        seller: Address,
+        collection: Address,
+        token_id: u64,
+        currency: Address,
+        schedule: PriceSchedule,
+    ) -> Result<u64, Err> {
+        auction::create_auction(&env, seller, collection, token_id, currency, schedule)
+    }
+
+    pub fn current_price(env: Env, auction_id: u64) -> Result<i128, Err> {
+        let auction = storage::get_auction(&env, auction_id)?;
+        Ok(auction::price_at(&auction, env.ledger().timestamp()))
+    }
+
+    pub fn buy(env: Env, buyer: Address, auction_id: u64) -> Result<i128, Err> {
+        auction::buy(&env, buyer, auction_id)
+    }
+
+    pub fn cancel(env: Env, seller: Address, auction_id: u64) -> Result<(), Err> {
+        auction::cancel(&env, seller, auction_id)
+    }
+
+    pub fn get_auction(env: Env, auction_id: u64) -> Result<DutchAuction, Err> {
+        storage::get_auction(&env, auction_id)
+    }
+}
+
+#[cfg(test)]
+mod test;
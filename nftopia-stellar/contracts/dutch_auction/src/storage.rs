@@ -0,0 +1,34 @@
+use crate::error::ContractError;
+use crate::types::DutchAuction;
+use soroban_sdk::{Env, contracttype};
+
+/// Storage keys for the Dutch auction contract.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// Contract admin.
+    Admin,
+    /// Next auction id to assign.
+    NextAuctionId,
+    /// Active auction by id.
+    Auction(u64),
+}
+
+pub fn get_auction(env: &Env, auction_id: u64) -> Result<DutchAuction, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Auction(auction_id))
+        .ok_or(ContractError::AuctionNotFound)
+}
+
+pub fn next_auction_id(env: &Env) -> Result<u64, ContractError> {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextAuctionId)
+        .ok_or(ContractError::NotInitialized)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::NextAuctionId, &(id + 1));
+    Ok(id)
+}
@@ -0,0 +1,100 @@
+#![cfg(test)]
+
+use crate::{ContractError, DutchAuctionHouse, DutchAuctionHouseClient, PriceSchedule};
+use nft_contract::NftContractClient;
+use nft_contract::testutils::deploy_test_collection;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, String, Vec};
+
+struct Setup<'a> {
+    env: Env,
+    creator: Address,
+    seller: Address,
+    buyer: Address,
+    nft: NftContractClient<'a>,
+    token: TokenClient<'a>,
+    house: DutchAuctionHouseClient<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let creator = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let nft = deploy_test_collection(&env, &creator, 1_000);
+
+    let sac = env.register_stellar_asset_contract_v2(creator.clone());
+    let token = TokenClient::new(&env, &sac.address());
+    StellarAssetClient::new(&env, &sac.address()).mint(&buyer, &10_000);
+
+    let house_id = env.register(DutchAuctionHouse, ());
+    let house = DutchAuctionHouseClient::new(&env, &house_id);
+    house.initialize(&creator);
+
+    Setup {
+        env,
+        creator,
+        seller,
+        buyer,
+        nft,
+        token,
+        house,
+    }
+}
+
+fn start_auction(s: &Setup) -> (u64, u64) {
+    let uri = String::from_str(&s.env, "ipfs://hash");
+    let token_id = s
+        .nft
+        .mint(&s.creator, &s.seller, &uri, &Vec::new(&s.env), &None);
+    let schedule = PriceSchedule {
+        start_price: 5_000,
+        floor_price: 1_000,
+        decay_interval: 60,
+        price_drop: 500,
+    };
+    let auction_id = s.house.create_auction(
+        &s.seller,
+        &s.nft.address,
+        &token_id,
+        &s.token.address,
+        &schedule,
+    );
+    (token_id, auction_id)
+}
+
+#[test]
+fn test_price_decays_to_floor() {
+    let s = setup();
+    let (_, auction_id) = start_auction(&s);
+
+    assert_eq!(s.house.current_price(&auction_id), 5_000);
+    s.env.ledger().set_timestamp(1_000 + 59);
+    assert_eq!(s.house.current_price(&auction_id), 5_000);
+    s.env.ledger().set_timestamp(1_000 + 120);
+    assert_eq!(s.house.current_price(&auction_id), 4_000);
+    s.env.ledger().set_timestamp(1_000 + 100_000);
+    assert_eq!(s.house.current_price(&auction_id), 1_000);
+}
+
+#[test]
+fn test_buy_at_instantaneous_price() {
+    let s = setup();
+    let (token_id, auction_id) = start_auction(&s);
+
+    s.env.ledger().set_timestamp(1_000 + 180);
+    assert_eq!(s.house.buy(&s.buyer, &auction_id), 3_500);
+
+    assert_eq!(s.nft.owner_of(&token_id), s.buyer);
+    assert_eq!(s.token.balance(&s.creator), 350);
+    assert_eq!(s.token.balance(&s.seller), 3_150);
+    assert_eq!(
+        s.house.try_buy(&s.buyer, &auction_id),
+        Err(Ok(ContractError::AuctionNotFound))
+    );
+}
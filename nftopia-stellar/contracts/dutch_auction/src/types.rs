@@ -0,0 +1,26 @@
+use soroban_sdk::{Address, contracttype};
+
+/// Descending price schedule: the price starts at `start_price` and drops by
+/// `price_drop` every `decay_interval` seconds until it reaches `floor_price`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PriceSchedule {
+    pub start_price: i128,
+    pub floor_price: i128,
+    pub decay_interval: u64,
+    pub price_drop: i128,
+}
+
+/// Dutch auction. The NFT is held in escrow until bought or cancelled.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct DutchAuction {
+    pub id: u64,
+    pub seller: Address,
+    pub collection: Address,
+    pub token_id: u64,
+    /// SEP-41 token the price is denominated in.
+    pub currency: Address,
+    pub schedule: PriceSchedule,
+    pub start_time: u64,
+}
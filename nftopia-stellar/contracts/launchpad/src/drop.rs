@@ -0,0 +1,179 @@
+use crate::error::ContractError;
+use crate::events;
+use crate::merkle;
+use crate::storage;
+use crate::types::{Drop, Phase};
+use nft_interface::{DelegationScope, NftClient};
+use soroban_sdk::{Address, BytesN, Env, String, Vec, token};
+
+/// Maximum number of phases per drop.
+pub const MAX_PHASES: u32 = 10;
+/// Maximum tokens minted in one purchase (below the collection's airdrop limit).
+pub const MAX_MINT_PER_TX: u32 = 20;
+
+/// Creates a drop for `collection`. Grants the launchpad the collection's minter role on the
+/// creator's behalf, which also proves the creator administers the collection.
+#[allow(clippy::too_many_arguments)]
+pub fn create_drop(
+    env: &Env,
+    creator: Address,
+    collection: Address,
+    currency: Address,
+    payout: Address,
+    metadata_uri: String,
+    supply: u32,
+    phases: Vec<Phase>,
+) -> Result<u64, ContractError> {
+    creator.require_auth();
+    validate_phases(&phases)?;
+    if supply == 0 {
+        return Err(ContractError::InvalidQuantity);
+    }
+    let drop_id = storage::next_drop_id(env)?;
+    NftClient::new(env, &collection).set_minter(&creator, &env.current_contract_address(), &true);
+
+    let drop = Drop {
+        id: drop_id,
+        creator: creator.clone(),
+        collection: collection.clone(),
+        currency,
+        payout,
+        metadata_uri,
+        supply,
+        minted: 0,
+        phases,
+    };
+    storage::set_drop(env, &drop);
+    events::emit_drop_created(env, drop_id, creator, collection, supply);
+    Ok(drop_id)
+}
+
+/// Replaces a drop's phases. Per-wallet purchase counts are kept by phase index.
+pub fn set_phases(
+    env: &Env,
+    creator: Address,
+    drop_id: u64,
+    phases: Vec<Phase>,
+) -> Result<(), ContractError> {
+    creator.require_auth();
+    let mut drop = storage::get_drop(env, drop_id)?;
+    if drop.creator != creator {
+        return Err(ContractError::NotAuthorized);
+    }
+    validate_phases(&phases)?;
+    drop.phases = phases;
+    storage::set_drop(env, &drop);
+    events::emit_phases_updated(env, drop_id);
+    Ok(())
+}
+
+/// Buys `quantity` tokens in the active phase. `proof` is the buyer's allowlist proof and
+/// is ignored for public phases. Payment goes straight to the drop's payout address.
+pub fn mint(
+    env: &Env,
+    buyer: Address,
+    drop_id: u64,
+    quantity: u32,
+    proof: Vec<BytesN<32>>,
+) -> Result<Vec<u64>, ContractError> {
+    buyer.require_auth();
+    let drop = storage::get_drop(env, drop_id)?;
+    mint_for(env, buyer.clone(), &buyer, drop, quantity, proof)
+}
+
+/// Buys on behalf of `vault`'s allowlist spot: `proof` and the wallet cap apply to `vault`,
+/// which must have delegated the drop's collection to `buyer` there. `buyer` pays and
+/// receives the tokens, so the vault never has to sign.
+pub fn mint_as_delegate(
+    env: &Env,
+    buyer: Address,
+    vault: Address,
+    drop_id: u64,
+    quantity: u32,
+    proof: Vec<BytesN<32>>,
+) -> Result<Vec<u64>, ContractError> {
+    buyer.require_auth();
+    let drop = storage::get_drop(env, drop_id)?;
+    if !NftClient::new(env, &drop.collection).is_delegate(
+        &vault,
+        &buyer,
+        &DelegationScope::Collection,
+    ) {
+        return Err(ContractError::NotDelegate);
+    }
+    mint_for(env, buyer, &vault, drop, quantity, proof)
+}
+
+/// Shared purchase path; `wallet` is the address checked against the allowlist and cap.
+fn mint_for(
+    env: &Env,
+    buyer: Address,
+    wallet: &Address,
+    mut drop: Drop,
+    quantity: u32,
+    proof: Vec<BytesN<32>>,
+) -> Result<Vec<u64>, ContractError> {
+    if quantity == 0 || quantity > MAX_MINT_PER_TX {
+        return Err(ContractError::InvalidQuantity);
+    }
+    let drop_id = drop.id;
+    let (index, phase) = active_phase(env, &drop).ok_or(ContractError::NoActivePhase)?;
+
+    if let Some(root) = &phase.allowlist_root
+        && !merkle::verify(env, root, merkle::leaf(env, wallet), &proof)
+    {
+        return Err(ContractError::NotAllowlisted);
+    }
+    let bought = storage::purchased(env, drop_id, index, wallet) + quantity;
+    if phase.wallet_limit > 0 && bought > phase.wallet_limit {
+        return Err(ContractError::WalletCapExceeded);
+    }
+    if drop.minted + quantity > drop.supply {
+        return Err(ContractError::SoldOut);
+    }
+
+    storage::set_purchased(env, drop_id, index, wallet, bought);
+    drop.minted += quantity;
+    storage::set_drop(env, &drop);
+
+    let paid = phase.price * quantity as i128;
+    if paid > 0 {
+        token::Client::new(env, &drop.currency).transfer(&buyer, &drop.payout, &paid);
+    }
+    let mut recipients = Vec::new(env);
+    for _ in 0..quantity {
+        recipients.push_back(buyer.clone());
+    }
+    let token_ids = NftClient::new(env, &drop.collection).airdrop(
+        &env.current_contract_address(),
+        &recipients,
+        &drop.metadata_uri,
+    );
+    events::emit_purchased(env, drop_id, buyer, index, token_ids.clone(), paid);
+    Ok(token_ids)
+}
+
+/// Returns the index and phase covering the current ledger time, if any.
+pub fn active_phase(env: &Env, drop: &Drop) -> Option<(u32, Phase)> {
+    let now = env.ledger().timestamp();
+    drop.phases
+        .iter()
+        .enumerate()
+        .find(|(_, p)| p.start_time <= now && now < p.end_time)
+        .map(|(i, p)| (i as u32, p))
+}
+
+fn validate_phases(phases: &Vec<Phase>) -> Result<(), ContractError> {
+    if phases.is_empty() || phases.len() > MAX_PHASES {
+        return Err(ContractError::InvalidPhases);
+    }
+    let mut previous_end = 0;
+    for phase in phases.iter() {
+        if phase.start_time >= phase.end_time || phase.start_time < previous_end || phase.price < 0
+        {
+            return Err(ContractError::InvalidPhases);
+        }
+        previous_end = phase.end_time;
+    }
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use soroban_sdk::contracterror;
+
+/// Contract-specific errors for the launchpad contract.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    /// Caller is not the drop creator.
+    NotAuthorized = 1,
+    /// Contract has already been initialized.
+    AlreadyInitialized = 2,
+    /// Contract has not been initialized.
+    NotInitialized = 3,
+    /// Drop not found.
+    DropNotFound = 4,
+    /// Phases are empty, too many, unordered, overlapping, or have a negative price.
+    InvalidPhases = 5,
+    /// No sale phase is active at the current ledger time.
+    NoActivePhase = 6,
+    /// Buyer is not on the active phase's allowlist.
+    NotAllowlisted = 7,
+    /// Purchase would exceed the buyer's per-wallet cap for the phase.
+    WalletCapExceeded = 8,
+    /// Purchase would exceed the drop's supply.
+    SoldOut = 9,
+    /// Quantity must be between 1 and the per-transaction maximum.
+    InvalidQuantity = 10,
+    /// Buyer is not a collection-wide delegate of the vault it mints for.
+    NotDelegate = 11,
+}
@@ -0,0 +1,67 @@
+use soroban_sdk::{Address, Env, Vec, contractevent};
+
+/// Drop created.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct DropCreated {
+    pub drop_id: u64,
+    pub creator: Address,
+    pub collection: Address,
+    pub supply: u32,
+}
+
+/// Drop phases replaced by the creator.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct PhasesUpdated {
+    pub drop_id: u64,
+}
+
+/// Tokens bought from a drop.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Purchased {
+    pub drop_id: u64,
+    pub buyer: Address,
+    pub phase: u32,
+    pub token_ids: Vec<u64>,
+    pub paid: i128,
+}
+
+pub fn emit_drop_created(
+    env: &Env,
+    drop_id: u64,
+    creator: Address,
+    collection: Address,
+    supply: u32,
+) {
+    DropCreated {
+        drop_id,
+        creator,
+        collection,
+        supply,
+    }
+    .publish(env);
+}
+
+pub fn emit_phases_updated(env: &Env, drop_id: u64) {
+    PhasesUpdated { drop_id }.publish(env);
+}
+
+pub fn emit_purchased(
+    env: &Env,
+    drop_id: u64,
+    buyer: Address,
+    phase: u32,
+    token_ids: Vec<u64>,
+    paid: i128,
+) {
+    Purchased {
+        drop_id,
+        buyer,
+        phase,
+        token_ids,
+        paid,
+    }
+    .publish(env);
+}
@@ -0,0 +1,106 @@
+#![no_std]
+
+mod drop;
+mod error;
+mod events;
+mod merkle;
+mod storage;
+mod types;
+
+pub use drop::{MAX_MINT_PER_TX, MAX_PHASES};
+pub use error::ContractError;
+pub use types::{Drop, Phase};
+
+use soroban_sdk::{Address, BytesN, Env, String, Vec, contract, contractimpl};
+
+use crate::error::ContractError as Err;
+use crate::storage::DataKey;
+
+#[contract]
+pub struct Launchpad;
+
+#[contractimpl]
+impl Launchpad {
+    /// Initializes the launchpad contract.
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Err> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Err::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::NextDropId, &0u64);
+        Ok(())
+    }
+
+    // --- Drops ---
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_drop(
+        env: Env,
+        creator: Address,
+        collection: Address,
+        currency: Address,
+        payout: Address,
+        metadata_uri: String,
+        supply: u32,
+        phases: Vec<Phase>,
+    ) -> Result<u64, Err> {
+        drop::create_drop(
+            &env,
+            creator,
+            collection,
+            currency,
+            payout,
+            metadata_uri,
+            supply,
+            phases,
+        )
+    }
+
+    pub fn set_phases(
+        env: Env,
+        creator: Address,
+        drop_id: u64,
+        phases: Vec<Phase>,
+    ) -> Result<(), Err> {
+        drop::set_phases(&env, creator, drop_id, phases)
+    }
+
+    pub fn mint(
+        env: Env,
+        buyer: Address,
+        drop_id: u64,
+        quantity: u32,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<Vec<u64>, Err> {
+        drop::mint(&env, buyer, drop_id, quantity, proof)
+    }
+
+    /// Mints using `vault`'s allowlist spot for a hot wallet the vault delegated the
+    /// collection to.
+    pub fn mint_as_delegate(
+        env: Env,
+        buyer: Address,
+        vault: Address,
+        drop_id: u64,
+        quantity: u32,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<Vec<u64>, Err> {
+        drop::mint_as_delegate(&env, buyer, vault, drop_id, quantity, proof)
+    }
+
+    pub fn get_drop(env: Env, drop_id: u64) -> Result<Drop, Err> {
+        storage::get_drop(&env, drop_id)
+    }
+
+    /// Index of the phase active now, if any.
+    pub fn active_phase(env: Env, drop_id: u64) -> Result<Option<u32>, Err> {
+        let drop = storage::get_drop(&env, drop_id)?;
+        Ok(drop::active_phase(&env, &drop).map(|(index, _)| index))
+    }
+
+    pub fn purchased(env: Env, drop_id: u64, phase: u32, wallet: Address) -> u32 {
+        storage::purchased(&env, drop_id, phase, &wallet)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,53 @@
+use crate::error::ContractError;
+use crate::types::Drop;
+use soroban_sdk::{Address, Env, contracttype};
+
+/// Storage keys for the launchpad contract.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// Contract admin.
+    Admin,
+    /// Next drop id to assign.
+    NextDropId,
+    /// Drop by id.
+    Drop(u64),
+    /// Tokens bought by a wallet in one phase: (drop id, phase index, wallet).
+    Purchased(u64, u32, Address),
+}
+
+pub fn get_drop(env: &Env, drop_id: u64) -> Result<Drop, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Drop(drop_id))
+        .ok_or(ContractError::DropNotFound)
+}
+
+pub fn set_drop(env: &Env, drop: &Drop) {
+    env.storage().instance().set(&DataKey::Drop(drop.id), drop);
+}
+
+pub fn next_drop_id(env: &Env) -> Result<u64, ContractError> {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextDropId)
+        .ok_or(ContractError::NotInitialized)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::NextDropId, &(id + 1));
+    Ok(id)
+}
+
+pub fn purchased(env: &Env, drop_id: u64, phase: u32, wallet: &Address) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Purchased(drop_id, phase, wallet.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_purchased(env: &Env, drop_id: u64, phase: u32, wallet: &Address, count: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Purchased(drop_id, phase, wallet.clone()), &count);
+}
@@ -0,0 +1,197 @@
+#![cfg(test)]
+
+use crate::merkle;
+use crate::{ContractError, Launchpad, LaunchpadClient, Phase};
+use nft_contract::testutils::deploy_test_collection;
+use nft_contract::{DelegationScope, NftContractClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, String, Vec, vec};
+
+struct Setup<'a> {
+    env: Env,
+    creator: Address,
+    alice: Address,
+    bob: Address,
+    nft: NftContractClient<'a>,
+    token: TokenClient<'a>,
+    launchpad: LaunchpadClient<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let nft = deploy_test_collection(&env, &creator, 0);
+
+    let sac = env.register_stellar_asset_contract_v2(creator.clone());
+    let token = TokenClient::new(&env, &sac.address());
+    let assets = StellarAssetClient::new(&env, &sac.address());
+    assets.mint(&alice, &1_000);
+    assets.mint(&bob, &1_000);
+
+    let launchpad_id = env.register(Launchpad, ());
+    let launchpad = LaunchpadClient::new(&env, &launchpad_id);
+    launchpad.initialize(&creator);
+
+    Setup {
+        env,
+        creator,
+        alice,
+        bob,
+        nft,
+        token,
+        launchpad,
+    }
+}
+
+fn create_drop(s: &Setup, phases: Vec<Phase>, supply: u32) -> u64 {
+    s.launchpad.create_drop(
+        &s.creator,
+        &s.nft.address,
+        &s.token.address,
+        &s.creator,
+        &String::from_str(&s.env, "ipfs://drop"),
+        &supply,
+        &phases,
+    )
+}
+
+#[test]
+fn test_allowlist_then_public_phase() {
+    let s = setup();
+    let alice_leaf = merkle::leaf(&s.env, &s.alice);
+    let other_leaf = merkle::leaf(&s.env, &Address::generate(&s.env));
+    let root = merkle::hash_pair(&s.env, &alice_leaf, &other_leaf);
+    let phases = vec![
+        &s.env,
+        Phase {
+            start_time: 100,
+            end_time: 200,
+            price: 50,
+            allowlist_root: Some(root),
+            wallet_limit: 2,
+        },
+        Phase {
+            start_time: 200,
+            end_time: 300,
+            price: 100,
+            allowlist_root: None,
+            wallet_limit: 0,
+        },
+    ];
+    let drop_id = create_drop(&s, phases, 10);
+
+    assert_eq!(
+        s.launchpad
+            .try_mint(&s.alice, &drop_id, &1, &Vec::new(&s.env)),
+        Err(Ok(ContractError::NoActivePhase))
+    );
+
+    s.env.ledger().set_timestamp(150);
+    let proof = vec![&s.env, other_leaf];
+    let ids = s.launchpad.mint(&s.alice, &drop_id, &2, &proof);
+    assert_eq!(ids.len(), 2);
+    assert_eq!(s.nft.owner_of(&ids.get(0).unwrap()), s.alice);
+    assert_eq!(s.token.balance(&s.creator), 100);
+    assert_eq!(
+        s.launchpad.try_mint(&s.alice, &drop_id, &1, &proof),
+        Err(Ok(ContractError::WalletCapExceeded))
+    );
+    assert_eq!(
+        s.launchpad.try_mint(&s.bob, &drop_id, &1, &proof),
+        Err(Ok(ContractError::NotAllowlisted))
+    );
+
+    s.env.ledger().set_timestamp(250);
+    s.launchpad.mint(&s.bob, &drop_id, &3, &Vec::new(&s.env));
+    assert_eq!(s.nft.balance_of(&s.bob), 3);
+    assert_eq!(s.token.balance(&s.creator), 400);
+    assert_eq!(s.launchpad.get_drop(&drop_id).minted, 5);
+}
+
+#[test]
+fn test_supply_and_phase_validation() {
+    let s = setup();
+    let overlapping = vec![
+        &s.env,
+        Phase {
+            start_time: 0,
+            end_time: 200,
+            price: 0,
+            allowlist_root: None,
+            wallet_limit: 0,
+        },
+        Phase {
+            start_time: 100,
+            end_time: 300,
+            price: 0,
+            allowlist_root: None,
+            wallet_limit: 0,
+        },
+    ];
+    assert_eq!(
+        s.launchpad.try_create_drop(
+            &s.creator,
+            &s.nft.address,
+            &s.token.address,
+            &s.creator,
+            &String::from_str(&s.env, "ipfs://drop"),
+            &10,
+            &overlapping,
+        ),
+        Err(Ok(ContractError::InvalidPhases))
+    );
+
+    let phases = vec![&s.env, overlapping.get(0).unwrap()];
+    let drop_id = create_drop(&s, phases, 2);
+    s.launchpad.mint(&s.alice, &drop_id, &2, &Vec::new(&s.env));
+    assert_eq!(
+        s.launchpad
+            .try_mint(&s.bob, &drop_id, &1, &Vec::new(&s.env)),
+        Err(Ok(ContractError::SoldOut))
+    );
+}
+
+#[test]
+fn test_mint_as_delegate() {
+    let s = setup();
+    let vault = Address::generate(&s.env);
+    let vault_leaf = merkle::leaf(&s.env, &vault);
+    let other_leaf = merkle::leaf(&s.env, &Address::generate(&s.env));
+    let root = merkle::hash_pair(&s.env, &vault_leaf, &other_leaf);
+    let phases = vec![
+        &s.env,
+        Phase {
+            start_time: 0,
+            end_time: 100,
+            price: 10,
+            allowlist_root: Some(root),
+            wallet_limit: 1,
+        },
+    ];
+    let drop_id = create_drop(&s, phases, 10);
+    let proof = vec![&s.env, other_leaf];
+
+    assert_eq!(
+        s.launchpad
+            .try_mint_as_delegate(&s.alice, &vault, &drop_id, &1, &proof),
+        Err(Ok(ContractError::NotDelegate))
+    );
+    s.nft
+        .delegate(&vault, &s.alice, &DelegationScope::Collection);
+    let ids = s
+        .launchpad
+        .mint_as_delegate(&s.alice, &vault, &drop_id, &1, &proof);
+    assert_eq!(s.nft.owner_of(&ids.get(0).unwrap()), s.alice);
+    assert_eq!(s.launchpad.purchased(&drop_id, &0, &vault), 1);
+    assert_eq!(
+        s.launchpad
+            .try_mint_as_delegate(&s.alice, &vault, &drop_id, &1, &proof),
+        Err(Ok(ContractError::WalletCapExceeded))
+    );
+}
@@ -0,0 +1,36 @@
+use soroban_sdk::{Address, BytesN, String, Vec, contracttype};
+
+/// One stage of a drop's sale, e.g. an allowlist presale followed by a public sale.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Phase {
+    /// Inclusive start, ledger timestamp in seconds.
+    pub start_time: u64,
+    /// Exclusive end, ledger timestamp in seconds.
+    pub end_time: u64,
+    /// Price per token in the drop's currency.
+    pub price: i128,
+    /// Merkle root of allowed buyers; `None` makes the phase public.
+    pub allowlist_root: Option<BytesN<32>>,
+    /// Maximum tokens one wallet can buy during this phase (0 = unlimited).
+    pub wallet_limit: u32,
+}
+
+/// A primary sale for an NFTopia collection.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Drop {
+    pub id: u64,
+    pub creator: Address,
+    pub collection: Address,
+    /// SEP-41 token buyers pay in.
+    pub currency: Address,
+    /// Receives sale proceeds.
+    pub payout: Address,
+    /// Metadata URI given to every token minted by the drop.
+    pub metadata_uri: String,
+    /// Maximum tokens the drop can mint.
+    pub supply: u32,
+    pub minted: u32,
+    pub phases: Vec<Phase>,
+}
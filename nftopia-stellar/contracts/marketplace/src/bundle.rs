@@ -0,0 +1,126 @@
+use crate::error::ContractError;
+use crate::events;
+use crate::storage::{self, DataKey};
+use crate::types::{BundleItem, BundleListing};
+use nft_interface::NftClient;
+use soroban_sdk::{Address, Env, Map, Vec, token};
+
+/// Maximum tokens per bundle, keeping escrow and settlement within Soroban budget.
+pub const MAX_BUNDLE_ITEMS: u32 = 20;
+
+/// Lists several tokens as one bundle, moving all of them into marketplace escrow.
+pub fn list_bundle(
+    env: &Env,
+    seller: Address,
+    items: Vec<BundleItem>,
+    price: i128,
+    currency: Address,
+) -> Result<u64, ContractError> {
+    seller.require_auth();
+    if price <= 0 {
+        return Err(ContractError::InvalidPrice);
+    }
+    if items.is_empty() || items.len() > MAX_BUNDLE_ITEMS {
+        return Err(ContractError::InvalidBundle);
+    }
+    for i in 0..items.len() {
+        let item = items.get(i).unwrap();
+        if items
+            .slice(i + 1..)
+            .iter()
+            .any(|other| other.collection == item.collection && other.token_id == item.token_id)
+        {
+            return Err(ContractError::InvalidBundle);
+        }
+    }
+    let listing_id = storage::next_listing_id(env)?;
+
+    let contract = env.current_contract_address();
+    for item in items.iter() {
+        NftClient::new(env, &item.collection).transfer(&seller, &contract, &item.token_id);
+    }
+
+    let bundle = BundleListing {
+        id: listing_id,
+        seller: seller.clone(),
+        items: items.clone(),
+        price,
+        currency: currency.clone(),
+        created_at: env.ledger().timestamp(),
+    };
+    env.storage()
+        .instance()
+        .set(&DataKey::Bundle(listing_id), &bundle);
+    events::emit_bundle_listed(env, listing_id, seller, items, price, currency);
+    Ok(listing_id)
+}
+
+/// Buys a bundle. Every collection in the bundle is paid its royalty on the full bundle price,
+/// once: the highest its items report, to that item's recipient. The seller receives the rest,
+/// so no part of the royalty depends on how the seller values the items. Fails with
+/// `InvalidRoyalty` if the royalties together exceed the price.
+pub fn buy_bundle(env: &Env, buyer: Address, listing_id: u64) -> Result<(), ContractError> {
+    buyer.require_auth();
+    let bundle = storage::get_bundle(env, listing_id)?;
+    if buyer == bundle.seller {
+        return Err(ContractError::InvalidBuyer);
+    }
+    env.storage()
+        .instance()
+        .remove(&DataKey::Bundle(listing_id));
+
+    let payment = token::Client::new(env, &bundle.currency);
+    let contract = env.current_contract_address();
+    let mut royalties: Map<Address, (Address, i128)> = Map::new(env);
+    for item in bundle.items.iter() {
+        let (recipient, amount) =
+            NftClient::new(env, &item.collection).get_royalty_info(&item.token_id, &bundle.price);
+        if amount < 0 || amount > bundle.price {
+            return Err(ContractError::InvalidRoyalty);
+        }
+        let highest = royalties
+            .get(item.collection.clone())
+            .is_some_and(|(_, current)| current >= amount);
+        if !highest {
+            royalties.set(item.collection.clone(), (recipient, amount));
+        }
+    }
+    let mut royalty_total: i128 = 0;
+    for (_, (_, amount)) in royalties.iter() {
+        royalty_total = royalty_total
+            .checked_add(amount)
+            .filter(|total| *total <= bundle.price)
+            .ok_or(ContractError::InvalidRoyalty)?;
+    }
+
+    for (_, (recipient, amount)) in royalties.iter() {
+        if amount > 0 {
+            payment.transfer(&buyer, &recipient, &amount);
+        }
+    }
+    for item in bundle.items.iter() {
+        NftClient::new(env, &item.collection).transfer(&contract, &buyer, &item.token_id);
+    }
+    payment.transfer(&buyer, &bundle.seller, &(bundle.price - royalty_total));
+
+    events::emit_bundle_sold(env, listing_id, buyer, bundle.price, royalty_total);
+    Ok(())
+}
+
+/// Cancels a bundle listing and returns every escrowed token to the seller.
+pub fn cancel_bundle(env: &Env, seller: Address, listing_id: u64) -> Result<(), ContractError> {
+    seller.require_auth();
+    let bundle = storage::get_bundle(env, listing_id)?;
+    if bundle.seller != seller {
+        return Err(ContractError::NotAuthorized);
+    }
+    env.storage()
+        .instance()
+        .remove(&DataKey::Bundle(listing_id));
+    let contract = env.current_contract_address();
+    for item in bundle.items.iter() {
+        NftClient::new(env, &item.collection).transfer(&contract, &seller, &item.token_id);
+    }
+    events::emit_cancelled(env, listing_id);
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use soroban_sdk::contracterror;
+
+/// Contract-specific errors for the marketplace contract.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    /// Caller is not authorized for the operation.
+    NotAuthorized = 1,
+    /// Contract has already been initialized.
+    AlreadyInitialized = 2,
+    /// Contract has not been initialized.
+    NotInitialized = 3,
+    /// Listing not found (never created, sold, or cancelled).
+    ListingNotFound = 4,
+    /// Price must be positive.
+    InvalidPrice = 5,
+    /// Buyer cannot be the seller.
+    InvalidBuyer = 6,
+    /// Royalty reported by the collection exceeds the sale price, or a bundle's royalties
+    /// together exceed the bundle price.
+    InvalidRoyalty = 7,
+    /// Bundle is empty, too large, or contains duplicate tokens.
+    InvalidBundle = 8,
+}
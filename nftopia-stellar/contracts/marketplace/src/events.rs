@@ -0,0 +1,129 @@
+use crate::types::BundleItem;
+use soroban_sdk::{Address, Env, Vec, contractevent};
+
+/// Listing created.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Listed {
+    pub listing_id: u64,
+    pub seller: Address,
+    pub collection: Address,
+    pub token_id: u64,
+    pub price: i128,
+    pub currency: Address,
+}
+
+/// Listing purchased.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Sold {
+    pub listing_id: u64,
+    pub buyer: Address,
+    pub price: i128,
+    pub royalty_recipient: Address,
+    pub royalty_amount: i128,
+}
+
+/// Listing cancelled by the seller.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Cancelled {
+    pub listing_id: u64,
+}
+
+/// Bundle listing created.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct BundleListed {
+    pub listing_id: u64,
+    pub seller: Address,
+    pub items: Vec<BundleItem>,
+    pub price: i128,
+    pub currency: Address,
+}
+
+/// Bundle listing purchased. `royalty_total` is the sum paid across all collections.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct BundleSold {
+    pub listing_id: u64,
+    pub buyer: Address,
+    pub price: i128,
+    pub royalty_total: i128,
+}
+
+pub fn emit_listed(
+    env: &Env,
+    listing_id: u64,
+    seller: Address,
+    collection: Address,
+    token_id: u64,
+    price: i128,
+    currency: Address,
+) {
+    Listed {
+        listing_id,
+        seller,
+        collection,
+        token_id,
+        price,
+        currency,
+    }
+    .publish(env);
+}
+
+pub fn emit_sold(
+    env: &Env,
+    listing_id: u64,
+    buyer: Address,
+    price: i128,
+    royalty_recipient: Address,
+    royalty_amount: i128,
+) {
+    Sold {
+        listing_id,
+        buyer,
+        price,
+        royalty_recipient,
+        royalty_amount,
+    }
+    .publish(env);
+}
+
+pub fn emit_cancelled(env: &Env, listing_id: u64) {
+    Cancelled { listing_id }.publish(env);
+}
+
+pub fn emit_bundle_listed(
+    env: &Env,
+    listing_id: u64,
+    seller: Address,
+    items: Vec<BundleItem>,
+    price: i128,
+    currency: Address,
+) {
+    BundleListed {
+        listing_id,
+        seller,
+        items,
+        price,
+        currency,
+    }
+    .publish(env);
+}
+
+pub fn emit_bundle_sold(
+    env: &Env,
+    listing_id: u64,
+    buyer: Address,
+    price: i128,
+    royalty_total: i128,
+) {
+    BundleSold {
+        listing_id,
+        buyer,
+        price,
+        royalty_total,
+    }
+    .publish(env);
+}
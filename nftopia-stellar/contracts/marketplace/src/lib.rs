@@ -0,0 +1,89 @@
+#![no_std]
+
+mod bundle;
+mod error;
+mod events;
+mod listing;
+mod storage;
+mod types;
+
+pub use error::ContractError;
+pub use types::{BundleItem, BundleListing, Listing};
+
+use soroban_sdk::{Address, Env, Vec, contract, contractimpl};
+
+use crate::error::ContractError as Err;
+use crate::storage::DataKey;
+
+#[contract]
+pub struct Marketplace;
+
+#[contractimpl]
+impl Marketplace {
+    /// Initializes the marketplace.
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Err> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Err::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::NextListingId, &0u64);
+        Ok(())
+    }
+
+    // --- Listings ---
+    pub fn list(
+        env: Env,
+        seller: Address,
+        collection: Address,
+        token_id: u64,
+        price: i128,
+        currency: Address,
+    ) -> Result<u64, Err> {
+        listing::list(&env, seller, collection, token_id, price, currency)
+    }
+
+    pub fn buy(env: Env, buyer: Address, listing_id: u64) -> Result<(), Err> {
+        listing::buy(&env, buyer, listing_id)
+    }
+
+    pub fn cancel(env: Env, seller: Address, listing_id: u64) -> Result<(), Err> {
+        listing::cancel(&env, seller, listing_id)
+    }
+
+    pub fn get_listing(env: Env, listing_id: u64) -> Result<Listing, Err> {
+        storage::get_listing(&env, listing_id)
+    }
+
+    // --- Bundles ---
+    pub fn list_bundle(
+        env: Env,
+        seller: Address,
+        items: Vec<BundleItem>,
+        price: i128,
+        currency: Address,
+    ) -> Result<u64, Err> {
+        bundle::list_bundle(&env, seller, items, price, currency)
+    }
+
+    pub fn buy_bundle(env: Env, buyer: Address, listing_id: u64) -> Result<(), Err> {
+        bundle::buy_bundle(&env, buyer, listing_id)
+    }
+
+    pub fn cancel_bundle(env: Env, seller: Address, listing_id: u64) -> Result<(), Err> {
+        bundle::cancel_bundle(&env, seller, listing_id)
+    }
+
+    pub fn get_bundle(env: Env, listing_id: u64) -> Result<BundleListing, Err> {
+        storage::get_bundle(&env, listing_id)
+    }
+
+    pub fn admin(env: Env) -> Result<Address, Err> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Err::NotInitialized)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,97 @@
+use crate::error::ContractError;
+use crate::events;
+use crate::storage::{self, DataKey};
+use crate::types::Listing;
+use nft_interface::NftClient;
+use soroban_sdk::{Address, Env, token};
+
+/// Lists a token for a fixed price, moving it into marketplace escrow. Seller must own the token.
+pub fn list(
+    env: &Env,
+    seller: Address,
+    collection: Address,
+    token_id: u64,
+    price: i128,
+    currency: Address,
+) -> Result<u64, ContractError> {
+    seller.require_auth();
+    if price <= 0 {
+        return Err(ContractError::InvalidPrice);
+    }
+    let listing_id = storage::next_listing_id(env)?;
+
+    let nft = NftClient::new(env, &collection);
+    nft.transfer(&seller, &env.current_contract_address(), &token_id);
+
+    let listing = Listing {
+        id: listing_id,
+        seller: seller.clone(),
+        collection: collection.clone(),
+        token_id,
+        price,
+        currency: currency.clone(),
+        created_at: env.ledger().timestamp(),
+    };
+    env.storage()
+        .instance()
+        .set(&DataKey::Listing(listing_id), &listing);
+    events::emit_listed(
+        env, listing_id, seller, collection, token_id, price, currency,
+    );
+    Ok(listing_id)
+}
+
+/// Buys a listing: pays royalty and seller in the listing currency, then releases the NFT to the buyer.
+pub fn buy(env: &Env, buyer: Address, listing_id: u64) -> Result<(), ContractError> {
+    buyer.require_auth();
+    let listing = storage::get_listing(env, listing_id)?;
+    if buyer == listing.seller {
+        return Err(ContractError::InvalidBuyer);
+    }
+    env.storage()
+        .instance()
+        .remove(&DataKey::Listing(listing_id));
+
+    let nft = NftClient::new(env, &listing.collection);
+    let (royalty_recipient, royalty_amount) =
+        nft.get_royalty_info(&listing.token_id, &listing.price);
+    if royalty_amount < 0 || royalty_amount > listing.price {
+        return Err(ContractError::InvalidRoyalty);
+    }
+
+    let payment = token::Client::new(env, &listing.currency);
+    if royalty_amount > 0 {
+        payment.transfer(&buyer, &royalty_recipient, &royalty_amount);
+    }
+    payment.transfer(&buyer, &listing.seller, &(listing.price - royalty_amount));
+    nft.transfer(&env.current_contract_address(), &buyer, &listing.token_id);
+
+    events::emit_sold(
+        env,
+        listing_id,
+        buyer,
+        listing.price,
+        royalty_recipient,
+        royalty_amount,
+    );
+    Ok(())
+}
+
+/// Cancels a listing and returns the escrowed NFT to the seller.
+pub fn cancel(env: &Env, seller: Address, listing_id: u64) -> Result<(), ContractError> {
+    seller.require_auth();
+    let listing = storage::get_listing(env, listing_id)?;
+    if listing.seller != seller {
+        return Err(ContractError::NotAuthorized);
+    }
+    env.storage()
+        .instance()
+        .remove(&DataKey::Listing(listing_id));
+    NftClient::new(env, &listing.collection).transfer(
+        &env.current_contract_address(),
+        &seller,
+        &listing.token_id,
+    );
+    events::emit_cancelled(env, listing_id);
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use crate::error::ContractError;
+use crate::types::{BundleListing, Listing};
+use soroban_sdk::{Env, contracttype};
+
+/// Storage keys for the marketplace contract.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// Marketplace admin.
+    Admin,
+    /// Next listing id to assign.
+    NextListingId,
+    /// Active listing by id.
+    Listing(u64),
+    /// Active bundle listing by id (shares the listing id sequence).
+    Bundle(u64),
+}
+
+pub fn get_listing(env: &Env, listing_id: u64) -> Result<Listing, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Listing(listing_id))
+        .ok_or(ContractError::ListingNotFound)
+}
+
+pub fn get_bundle(env: &Env, listing_id: u64) -> Result<BundleListing, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Bundle(listing_id))
+        .ok_or(ContractError::ListingNotFound)
+}
+
+pub fn next_listing_id(env: &Env) -> Result<u64, ContractError> {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextListingId)
+        .ok_or(ContractError::NotInitialized)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::NextListingId, &(id + 1));
+    Ok(id)
+}
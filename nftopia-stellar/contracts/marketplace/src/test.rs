@@ -0,0 +1,206 @@
+#![cfg(test)]
+
+use crate::{BundleItem, ContractError, Marketplace, MarketplaceClient};
+use nft_contract::NftContractClient;
+use nft_contract::testutils::deploy_test_collection;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, String, Vec, vec};
+
+struct Setup<'a> {
+    env: Env,
+    creator: Address,
+    seller: Address,
+    buyer: Address,
+    nft: NftContractClient<'a>,
+    token: TokenClient<'a>,
+    market: MarketplaceClient<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let nft = deploy_test_collection(&env, &creator, 500);
+
+    let sac = env.register_stellar_asset_contract_v2(creator.clone());
+    let token = TokenClient::new(&env, &sac.address());
+    StellarAssetClient::new(&env, &sac.address()).mint(&buyer, &10_000);
+
+    let market_id = env.register(Marketplace, ());
+    let market = MarketplaceClient::new(&env, &market_id);
+    market.initialize(&creator);
+
+    Setup {
+        env,
+        creator,
+        seller,
+        buyer,
+        nft,
+        token,
+        market,
+    }
+}
+
+fn mint_to(s: &Setup, to: &Address) -> u64 {
+    let uri = String::from_str(&s.env, "ipfs://hash");
+    s.nft.mint(&s.creator, to, &uri, &Vec::new(&s.env), &None)
+}
+
+#[test]
+fn test_list_and_buy_pays_royalty() {
+    let s = setup();
+    let token_id = mint_to(&s, &s.seller);
+
+    let listing_id = s.market.list(
+        &s.seller,
+        &s.nft.address,
+        &token_id,
+        &1_000,
+        &s.token.address,
+    );
+    assert_eq!(s.nft.owner_of(&token_id), s.market.address);
+
+    s.market.buy(&s.buyer, &listing_id);
+
+    assert_eq!(s.nft.owner_of(&token_id), s.buyer);
+    assert_eq!(s.token.balance(&s.creator), 50);
+    assert_eq!(s.token.balance(&s.seller), 950);
+    assert_eq!(s.token.balance(&s.buyer), 9_000);
+    assert_eq!(
+        s.market.try_get_listing(&listing_id),
+        Err(Ok(ContractError::ListingNotFound))
+    );
+}
+
+#[test]
+fn test_cancel_returns_nft() {
+    let s = setup();
+    let token_id = mint_to(&s, &s.seller);
+
+    let listing_id = s.market.list(
+        &s.seller,
+        &s.nft.address,
+        &token_id,
+        &1_000,
+        &s.token.address,
+    );
+    assert_eq!(
+        s.market.try_cancel(&s.buyer, &listing_id),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+    s.market.cancel(&s.seller, &listing_id);
+    assert_eq!(s.nft.owner_of(&token_id), s.seller);
+    assert_eq!(
+        s.market.try_buy(&s.buyer, &listing_id),
+        Err(Ok(ContractError::ListingNotFound))
+    );
+}
+
+#[test]
+fn test_bundle_across_collections_pays_each_royalty() {
+    let s = setup();
+    let token_a = mint_to(&s, &s.seller);
+
+    let other_creator = Address::generate(&s.env);
+    let other = deploy_test_collection(&s.env, &other_creator, 1_000);
+    let uri = String::from_str(&s.env, "ipfs://other");
+    let token_b = other.mint(&other_creator, &s.seller, &uri, &Vec::new(&s.env), &None);
+
+    let items = vec![
+        &s.env,
+        BundleItem {
+            collection: s.nft.address.clone(),
+            token_id: token_a,
+        },
+        BundleItem {
+            collection: other.address.clone(),
+            token_id: token_b,
+        },
+    ];
+    let listing_id = s
+        .market
+        .list_bundle(&s.seller, &items, &2_000, &s.token.address);
+    assert_eq!(other.owner_of(&token_b), s.market.address);
+
+    s.market.buy_bundle(&s.buyer, &listing_id);
+
+    assert_eq!(s.nft.owner_of(&token_a), s.buyer);
+    assert_eq!(other.owner_of(&token_b), s.buyer);
+    assert_eq!(s.token.balance(&s.creator), 100);
+    assert_eq!(s.token.balance(&other_creator), 200);
+    assert_eq!(s.token.balance(&s.seller), 1_700);
+}
+
+#[test]
+fn test_bundle_pays_each_collection_once() {
+    let s = setup();
+    let items = vec![
+        &s.env,
+        BundleItem {
+            collection: s.nft.address.clone(),
+            token_id: mint_to(&s, &s.seller),
+        },
+        BundleItem {
+            collection: s.nft.address.clone(),
+            token_id: mint_to(&s, &s.seller),
+        },
+    ];
+    let listing_id = s
+        .market
+        .list_bundle(&s.seller, &items, &2_000, &s.token.address);
+    s.market.buy_bundle(&s.buyer, &listing_id);
+
+    assert_eq!(s.token.balance(&s.creator), 100);
+    assert_eq!(s.token.balance(&s.seller), 1_900);
+}
+
+#[test]
+fn test_bundle_rejects_royalties_above_price() {
+    let s = setup();
+    let token_a = mint_to(&s, &s.seller);
+    let other_creator = Address::generate(&s.env);
+    let other = deploy_test_collection(&s.env, &other_creator, 9_600);
+    let uri = String::from_str(&s.env, "ipfs://other");
+    let token_b = other.mint(&other_creator, &s.seller, &uri, &Vec::new(&s.env), &None);
+
+    let items = vec![
+        &s.env,
+        BundleItem {
+            collection: s.nft.address.clone(),
+            token_id: token_a,
+        },
+        BundleItem {
+            collection: other.address.clone(),
+            token_id: token_b,
+        },
+    ];
+    let listing_id = s
+        .market
+        .list_bundle(&s.seller, &items, &2_000, &s.token.address);
+    assert_eq!(
+        s.market.try_buy_bundle(&s.buyer, &listing_id),
+        Err(Ok(ContractError::InvalidRoyalty))
+    );
+    assert_eq!(s.nft.owner_of(&token_a), s.market.address);
+}
+
+#[test]
+fn test_bundle_rejects_duplicates() {
+    let s = setup();
+    let token_id = mint_to(&s, &s.seller);
+    let item = BundleItem {
+        collection: s.nft.address.clone(),
+        token_id,
+    };
+    let items = vec![&s.env, item.clone(), item];
+    assert_eq!(
+        s.market
+            .try_list_bundle(&s.seller, &items, &2_000, &s.token.address),
+        Err(Ok(ContractError::InvalidBundle))
+    );
+}
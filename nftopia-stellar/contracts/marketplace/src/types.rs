@@ -0,0 +1,37 @@
+use soroban_sdk::{Address, Vec, contracttype};
+
+/// Fixed-price listing. The NFT is held in escrow by the marketplace while the listing is active.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Listing {
+    pub id: u64,
+    pub seller: Address,
+    pub collection: Address,
+    pub token_id: u64,
+    pub price: i128,
+    /// SEP-41 token the price is denominated in.
+    pub currency: Address,
+    pub created_at: u64,
+}
+
+/// A token included in a bundle listing.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct BundleItem {
+    pub collection: Address,
+    pub token_id: u64,
+}
+
+/// Several tokens, possibly from different collections, sold together for one price.
+/// All items are held in escrow by the marketplace while the listing is active.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct BundleListing {
+    pub id: u64,
+    pub seller: Address,
+    pub items: Vec<BundleItem>,
+    pub price: i128,
+    /// SEP-41 token the price is denominated in.
+    pub currency: Address,
+    pub created_at: u64,
+}
@@ -0,0 +1,93 @@
+use crate::error::ContractError;
+use crate::events;
+use crate::merkle;
+use crate::storage;
+use crate::types::Distribution;
+use nft_interface::NftClient;
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
+
+/// Largest batch minted per collection call, matching the collection's airdrop limit.
+pub const MINT_CHUNK: u32 = 100;
+
+/// Commits a merkle root of allocations for `collection`. The collection admin must have
+/// granted this contract the minter role.
+pub fn create_distribution(
+    env: &Env,
+    admin: Address,
+    collection: Address,
+    root: BytesN<32>,
+    metadata_uri: String,
+) -> Result<u64, ContractError> {
+    require_admin(env, &admin)?;
+    let id = storage::next_distribution_id(env)?;
+    let distribution = Distribution {
+        id,
+        collection: collection.clone(),
+        root: root.clone(),
+        metadata_uri,
+        closed: false,
+    };
+    storage::set_distribution(env, &distribution);
+    events::emit_distribution_created(env, id, collection, root);
+    Ok(id)
+}
+
+pub fn close_distribution(env: &Env, admin: Address, id: u64) -> Result<(), ContractError> {
+    require_admin(env, &admin)?;
+    let mut distribution = storage::get_distribution(env, id)?;
+    distribution.closed = true;
+    storage::set_distribution(env, &distribution);
+    events::emit_distribution_closed(env, id);
+    Ok(())
+}
+
+/// Mints `token_count` tokens to `claimant` if `(claimant, token_count)` is in the tree.
+/// Each address can claim once per distribution.
+pub fn claim(
+    env: &Env,
+    claimant: Address,
+    id: u64,
+    token_count: u32,
+    proof: Vec<BytesN<32>>,
+) -> Result<Vec<u64>, ContractError> {
+    claimant.require_auth();
+    if token_count == 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    let distribution = storage::get_distribution(env, id)?;
+    if distribution.closed {
+        return Err(ContractError::DistributionClosed);
+    }
+    if storage::is_claimed(env, id, &claimant) {
+        return Err(ContractError::AlreadyClaimed);
+    }
+    let leaf = merkle::leaf(env, &claimant, token_count);
+    if !merkle::verify(env, &distribution.root, leaf, &proof) {
+        return Err(ContractError::InvalidProof);
+    }
+    storage::set_claimed(env, id, &claimant);
+
+    let nft = NftClient::new(env, &distribution.collection);
+    let contract = env.current_contract_address();
+    let mut token_ids = Vec::new(env);
+    let mut remaining = token_count;
+    while remaining > 0 {
+        let chunk = remaining.min(MINT_CHUNK);
+        let mut recipients = Vec::new(env);
+        for _ in 0..chunk {
+            recipients.push_back(claimant.clone());
+        }
+        token_ids.append(&nft.airdrop(&contract, &recipients, &distribution.metadata_uri));
+        remaining -= chunk;
+    }
+    events::emit_claimed(env, id, claimant, token_count);
+    Ok(token_ids)
+}
+
+fn require_admin(env: &Env, caller: &Address) -> Result<(), ContractError> {
+    caller.require_auth();
+    if storage::get_admin(env)? != *caller {
+        return Err(ContractError::NotAuthorized);
+    }
+    Ok(())
+}
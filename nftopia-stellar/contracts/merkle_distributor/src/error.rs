@@ -0,0 +1,24 @@
+use soroban_sdk::contracterror;
+
+/// Contract-specific errors for the merkle distributor.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    /// Caller is not the distributor admin.
+    NotAuthorized = 1,
+    /// Contract has already been initialized.
+    AlreadyInitialized = 2,
+    /// Contract has not been initialized.
+    NotInitialized = 3,
+    /// Distribution not found.
+    DistributionNotFound = 4,
+    /// Distribution has been closed by the admin.
+    DistributionClosed = 5,
+    /// Proof does not match the distribution root.
+    InvalidProof = 6,
+    /// Allocation has already been claimed.
+    AlreadyClaimed = 7,
+    /// Allocation must be at least one token.
+    InvalidAmount = 8,
+}
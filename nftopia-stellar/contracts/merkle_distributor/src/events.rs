@@ -0,0 +1,53 @@
+use soroban_sdk::{Address, BytesN, Env, contractevent};
+
+/// Distribution root committed.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct DistributionCreated {
+    pub distribution_id: u64,
+    pub collection: Address,
+    pub root: BytesN<32>,
+}
+
+/// Distribution closed to further claims.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct DistributionClosed {
+    pub distribution_id: u64,
+}
+
+/// Allocation claimed and minted.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Claimed {
+    pub distribution_id: u64,
+    pub claimant: Address,
+    pub token_count: u32,
+}
+
+pub fn emit_distribution_created(
+    env: &Env,
+    distribution_id: u64,
+    collection: Address,
+    root: BytesN<32>,
+) {
+    DistributionCreated {
+        distribution_id,
+        collection,
+        root,
+    }
+    .publish(env);
+}
+
+pub fn emit_distribution_closed(env: &Env, distribution_id: u64) {
+    DistributionClosed { distribution_id }.publish(env);
+}
+
+pub fn emit_claimed(env: &Env, distribution_id: u64, claimant: Address, token_count: u32) {
+    Claimed {
+        distribution_id,
+        claimant,
+        token_count,
+    }
+    .publish(env);
+}
@@ -0,0 +1,70 @@
+#![no_std]
+
+mod distributor;
+mod error;
+mod events;
+mod merkle;
+mod storage;
+mod types;
+
+pub use error::ContractError;
+pub use types::Distribution;
+
+use soroban_sdk::{Address, BytesN, Env, String, Vec, contract, contractimpl};
+
+use crate::error::ContractError as Err;
+use crate::storage::DataKey;
+
+#[contract]
+pub struct MerkleDistributor;
+
+#[contractimpl]
+impl MerkleDistributor {
+    /// Initializes the distributor contract.
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Err> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Err::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextDistributionId, &0u64);
+        Ok(())
+    }
+
+    // --- Distributions ---
+    pub fn create_distribution(
+        env: Env,
+        admin: Address,
+        collection: Address,
+        root: BytesN<32>,
+        metadata_uri: String,
+    ) -> Result<u64, Err> {
+        distributor::create_distribution(&env, admin, collection, root, metadata_uri)
+    }
+
+    pub fn close_distribution(env: Env, admin: Address, distribution_id: u64) -> Result<(), Err> {
+        distributor::close_distribution(&env, admin, distribution_id)
+    }
+
+    pub fn claim(
+        env: Env,
+        claimant: Address,
+        distribution_id: u64,
+        token_count: u32,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<Vec<u64>, Err> {
+        distributor::claim(&env, claimant, distribution_id, token_count, proof)
+    }
+
+    pub fn get_distribution(env: Env, distribution_id: u64) -> Result<Distribution, Err> {
+        storage::get_distribution(&env, distribution_id)
+    }
+
+    pub fn is_claimed(env: Env, distribution_id: u64, claimant: Address) -> bool {
+        storage::is_claimed(&env, distribution_id, &claimant)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,30 @@
+//! Allocation proofs. Leaves are `sha256(xdr(address) || token_count as 4 big-endian bytes)`;
+//! parents hash the sorted pair of children, so proofs need no left/right flags.
+
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec};
+
+pub fn leaf(env: &Env, address: &Address, token_count: u32) -> BytesN<32> {
+    let mut data = address.clone().to_xdr(env);
+    data.extend_from_array(&token_count.to_be_bytes());
+    env.crypto().sha256(&data).into()
+}
+
+pub fn verify(env: &Env, root: &BytesN<32>, leaf: BytesN<32>, proof: &Vec<BytesN<32>>) -> bool {
+    let mut computed = leaf;
+    for node in proof.iter() {
+        computed = hash_pair(env, &computed, &node);
+    }
+    computed == *root
+}
+
+pub fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a.to_array() <= b.to_array() {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    let mut data = Bytes::from(first.clone());
+    data.append(&Bytes::from(second.clone()));
+    env.crypto().sha256(&data).into()
+}
@@ -0,0 +1,62 @@
+use crate::error::ContractError;
+use crate::types::Distribution;
+use soroban_sdk::{Address, Env, contracttype};
+
+/// Storage keys for the merkle distributor.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// Contract admin.
+    Admin,
+    /// Next distribution id to assign.
+    NextDistributionId,
+    /// Distribution by id.
+    Distribution(u64),
+    /// Whether an address has claimed its allocation: (distribution id, claimant).
+    Claimed(u64, Address),
+}
+
+pub fn get_admin(env: &Env) -> Result<Address, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(ContractError::NotInitialized)
+}
+
+pub fn get_distribution(env: &Env, id: u64) -> Result<Distribution, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Distribution(id))
+        .ok_or(ContractError::DistributionNotFound)
+}
+
+pub fn set_distribution(env: &Env, distribution: &Distribution) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Distribution(distribution.id), distribution);
+}
+
+pub fn next_distribution_id(env: &Env) -> Result<u64, ContractError> {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextDistributionId)
+        .ok_or(ContractError::NotInitialized)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::NextDistributionId, &(id + 1));
+    Ok(id)
+}
+
+pub fn is_claimed(env: &Env, id: u64, claimant: &Address) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Claimed(id, claimant.clone()))
+        .unwrap_or(false)
+}
+
+pub fn set_claimed(env: &Env, id: u64, claimant: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Claimed(id, claimant.clone()), &true);
+}
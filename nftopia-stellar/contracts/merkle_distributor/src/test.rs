@@ -0,0 +1,94 @@
+#![cfg(test)]
+
+use crate::merkle;
+use crate::{ContractError, MerkleDistributor, MerkleDistributorClient};
+use nft_contract::NftContractClient;
+use nft_contract::testutils::deploy_test_collection;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, String, vec};
+
+struct Setup<'a> {
+    env: Env,
+    admin: Address,
+    alice: Address,
+    bob: Address,
+    nft: NftContractClient<'a>,
+    distributor: MerkleDistributorClient<'a>,
+    alice_leaf: BytesN<32>,
+    bob_leaf: BytesN<32>,
+    distribution_id: u64,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let nft = deploy_test_collection(&env, &admin, 0);
+
+    let distributor_id = env.register(MerkleDistributor, ());
+    let distributor = MerkleDistributorClient::new(&env, &distributor_id);
+    distributor.initialize(&admin);
+    nft.set_minter(&admin, &distributor_id, &true);
+
+    let alice_leaf = merkle::leaf(&env, &alice, 3);
+    let bob_leaf = merkle::leaf(&env, &bob, 1);
+    let root = merkle::hash_pair(&env, &alice_leaf, &bob_leaf);
+    let distribution_id = distributor.create_distribution(
+        &admin,
+        &nft.address,
+        &root,
+        &String::from_str(&env, "ipfs://claim"),
+    );
+
+    Setup {
+        env,
+        admin,
+        alice,
+        bob,
+        nft,
+        distributor,
+        alice_leaf,
+        bob_leaf,
+        distribution_id,
+    }
+}
+
+#[test]
+fn test_claim_mints_allocation_once() {
+    let s = setup();
+    let proof = vec![&s.env, s.bob_leaf.clone()];
+
+    assert_eq!(
+        s.distributor
+            .try_claim(&s.alice, &s.distribution_id, &5, &proof),
+        Err(Ok(ContractError::InvalidProof))
+    );
+    let ids = s
+        .distributor
+        .claim(&s.alice, &s.distribution_id, &3, &proof);
+    assert_eq!(ids.len(), 3);
+    assert_eq!(s.nft.balance_of(&s.alice), 3);
+    assert!(s.distributor.is_claimed(&s.distribution_id, &s.alice));
+    assert_eq!(
+        s.distributor
+            .try_claim(&s.alice, &s.distribution_id, &3, &proof),
+        Err(Ok(ContractError::AlreadyClaimed))
+    );
+}
+
+#[test]
+fn test_closed_distribution_rejects_claims() {
+    let s = setup();
+    s.distributor
+        .close_distribution(&s.admin, &s.distribution_id);
+    let proof = vec![&s.env, s.alice_leaf.clone()];
+    assert_eq!(
+        s.distributor
+            .try_claim(&s.bob, &s.distribution_id, &1, &proof),
+        Err(Ok(ContractError::DistributionClosed))
+    );
+}
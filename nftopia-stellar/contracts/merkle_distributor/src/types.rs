@@ -0,0 +1,14 @@
+use soroban_sdk::{Address, BytesN, String, contracttype};
+
+/// A committed set of (address, token_count) allocations for one collection.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Distribution {
+    pub id: u64,
+    pub collection: Address,
+    pub root: BytesN<32>,
+    /// Metadata URI given to every claimed token.
+    pub metadata_uri: String,
+    /// Closed distributions reject further claims.
+    pub closed: bool,
+}
@@ -0,0 +1,24 @@
+use soroban_sdk::contracterror;
+
+/// Contract-specific errors for the multi-token contract.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    /// Contract has already been initialized.
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized.
+    NotInitialized = 2,
+    /// Caller is not the admin, the holder, or an approved operator.
+    NotAuthorized = 3,
+    /// Amount must be positive.
+    InvalidAmount = 4,
+    /// Holder does not own enough of the token id.
+    InsufficientBalance = 5,
+    /// Batch ids and amounts have different lengths.
+    BatchLengthMismatch = 6,
+    /// Batch is empty or exceeds the per-call limit.
+    BatchTooLarge = 7,
+    /// Minting would exceed the token id's max supply.
+    SupplyLimitExceeded = 8,
+}
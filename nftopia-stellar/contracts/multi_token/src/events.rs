@@ -0,0 +1,91 @@
+use soroban_sdk::{Address, Env, Vec, contractevent};
+
+/// `amount` of token `id` minted to `to`.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Mint {
+    pub to: Address,
+    pub id: u64,
+    pub amount: u64,
+}
+
+/// `amount` of token `id` moved between holders.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Transfer {
+    pub from: Address,
+    pub to: Address,
+    pub id: u64,
+    pub amount: u64,
+}
+
+/// Several ids moved between the same holders in one call; `amounts` is index-aligned with `ids`.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct TransferBatch {
+    pub from: Address,
+    pub to: Address,
+    pub ids: Vec<u64>,
+    pub amounts: Vec<u64>,
+}
+
+/// `amount` of token `id` destroyed.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Burn {
+    pub from: Address,
+    pub id: u64,
+    pub amount: u64,
+}
+
+/// Operator approval for all of an owner's tokens.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ApprovalForAll {
+    pub owner: Address,
+    pub operator: Address,
+    pub approved: bool,
+}
+
+pub fn emit_mint(env: &Env, to: Address, id: u64, amount: u64) {
+    Mint { to, id, amount }.publish(env);
+}
+
+pub fn emit_transfer(env: &Env, from: Address, to: Address, id: u64, amount: u64) {
+    Transfer {
+        from,
+        to,
+        id,
+        amount,
+    }
+    .publish(env);
+}
+
+pub fn emit_transfer_batch(
+    env: &Env,
+    from: Address,
+    to: Address,
+    ids: Vec<u64>,
+    amounts: Vec<u64>,
+) {
+    TransferBatch {
+        from,
+        to,
+        ids,
+        amounts,
+    }
+    .publish(env);
+}
+
+pub fn emit_burn(env: &Env, from: Address, id: u64, amount: u64) {
+    Burn { from, id, amount }.publish(env);
+}
+
+pub fn emit_approval_for_all(env: &Env, owner: Address, operator: Address, approved: bool) {
+    ApprovalForAll {
+        owner,
+        operator,
+        approved,
+    }
+    .publish(env);
+}
@@ -0,0 +1,149 @@
+#![no_std]
+
+mod error;
+mod events;
+mod storage;
+mod token;
+
+pub use error::ContractError;
+pub use token::MAX_BATCH_SIZE;
+
+use soroban_sdk::{Address, Env, String, Vec, contract, contractimpl};
+
+use crate::error::ContractError as Err;
+use crate::storage::DataKey;
+
+/// Semi-fungible (ERC-1155 style) token contract: every id has a balance per holder, so one
+/// contract can hold game items, fungible resources and open editions side by side.
+#[contract]
+pub struct MultiToken;
+
+#[contractimpl]
+impl MultiToken {
+    /// Initializes the contract. `uri` is shared by all ids; clients substitute `{id}`.
+    pub fn initialize(env: Env, admin: Address, uri: String) -> Result<(), Err> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Err::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Uri, &uri);
+        Ok(())
+    }
+
+    // --- Minting ---
+    pub fn mint(env: Env, caller: Address, to: Address, id: u64, amount: u64) -> Result<(), Err> {
+        token::mint(&env, &caller, &to, id, amount)
+    }
+
+    pub fn mint_batch(
+        env: Env,
+        caller: Address,
+        to: Address,
+        ids: Vec<u64>,
+        amounts: Vec<u64>,
+    ) -> Result<(), Err> {
+        token::mint_batch(&env, &caller, &to, &ids, &amounts)
+    }
+
+    /// Caps an id's supply; ids without a cap are open editions.
+    pub fn set_max_supply(env: Env, caller: Address, id: u64, max_supply: u64) -> Result<(), Err> {
+        token::set_max_supply(&env, &caller, id, max_supply)
+    }
+
+    // --- Transfers ---
+    pub fn transfer(env: Env, from: Address, to: Address, id: u64, amount: u64) -> Result<(), Err> {
+        token::transfer(&env, &from, &from, &to, id, amount)
+    }
+
+    /// Transfers on behalf of `from`; `operator` must be approved for all of its tokens.
+    pub fn transfer_from(
+        env: Env,
+        operator: Address,
+        from: Address,
+        to: Address,
+        id: u64,
+        amount: u64,
+    ) -> Result<(), Err> {
+        token::transfer(&env, &operator, &from, &to, id, amount)
+    }
+
+    /// Transfers several ids at once; `spender` is `from` or an approved operator.
+    pub fn batch_transfer(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        ids: Vec<u64>,
+        amounts: Vec<u64>,
+    ) -> Result<(), Err> {
+        token::batch_transfer(&env, &spender, &from, &to, ids, amounts)
+    }
+
+    pub fn burn(
+        env: Env,
+        spender: Address,
+        from: Address,
+        id: u64,
+        amount: u64,
+    ) -> Result<(), Err> {
+        token::burn(&env, &spender, &from, id, amount)
+    }
+
+    // --- Approvals ---
+    pub fn set_approval_for_all(
+        env: Env,
+        owner: Address,
+        operator: Address,
+        approved: bool,
+    ) -> Result<(), Err> {
+        token::set_approval_for_all(&env, &owner, &operator, approved)
+    }
+
+    pub fn is_approved_for_all(env: Env, owner: Address, operator: Address) -> bool {
+        storage::is_operator(&env, &owner, &operator)
+    }
+
+    // --- Views ---
+    pub fn balance_of(env: Env, owner: Address, id: u64) -> u64 {
+        storage::balance(&env, &owner, id)
+    }
+
+    /// Balances for index-aligned `owners` and `ids`.
+    pub fn balance_of_batch(
+        env: Env,
+        owners: Vec<Address>,
+        ids: Vec<u64>,
+    ) -> Result<Vec<u64>, Err> {
+        if owners.len() != ids.len() {
+            return Err(Err::BatchLengthMismatch);
+        }
+        if owners.len() > MAX_BATCH_SIZE {
+            return Err(Err::BatchTooLarge);
+        }
+        let mut balances = Vec::new(&env);
+        for (owner, id) in owners.iter().zip(ids.iter()) {
+            balances.push_back(storage::balance(&env, &owner, id));
+        }
+        Ok(balances)
+    }
+
+    pub fn total_supply(env: Env, id: u64) -> u64 {
+        storage::supply(&env, id)
+    }
+
+    pub fn max_supply(env: Env, id: u64) -> Option<u64> {
+        storage::max_supply(&env, id)
+    }
+
+    pub fn uri(env: Env) -> Result<String, Err> {
+        storage::uri(&env)
+    }
+
+    pub fn admin(env: Env) -> Result<Address, Err> {
+        storage::admin(&env)
+    }
+}
+
+#[cfg(test)]
+mod test;
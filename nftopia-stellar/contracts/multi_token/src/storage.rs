@@ -0,0 +1,72 @@
+use crate::error::ContractError;
+use soroban_sdk::{Address, Env, String, contracttype};
+
+/// Storage keys for the multi-token contract.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// Contract admin; the only minter.
+    Admin,
+    /// Metadata URI shared by all ids; clients substitute `{id}`.
+    Uri,
+    /// Amount of a token id held by an address: (owner, id).
+    Balance(Address, u64),
+    /// Circulating supply of a token id.
+    Supply(u64),
+    /// Optional supply cap of a token id; uncapped (open edition) when absent.
+    MaxSupply(u64),
+    /// Operator approved for all of an owner's tokens: (owner, operator).
+    Operator(Address, Address),
+}
+
+pub fn admin(env: &Env) -> Result<Address, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(ContractError::NotInitialized)
+}
+
+pub fn uri(env: &Env) -> Result<String, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Uri)
+        .ok_or(ContractError::NotInitialized)
+}
+
+pub fn balance(env: &Env, owner: &Address, id: u64) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Balance(owner.clone(), id))
+        .unwrap_or(0)
+}
+
+pub fn set_balance(env: &Env, owner: &Address, id: u64, amount: u64) {
+    let key = DataKey::Balance(owner.clone(), id);
+    if amount == 0 {
+        env.storage().instance().remove(&key);
+    } else {
+        env.storage().instance().set(&key, &amount);
+    }
+}
+
+pub fn supply(env: &Env, id: u64) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Supply(id))
+        .unwrap_or(0)
+}
+
+pub fn set_supply(env: &Env, id: u64, amount: u64) {
+    env.storage().instance().set(&DataKey::Supply(id), &amount);
+}
+
+pub fn max_supply(env: &Env, id: u64) -> Option<u64> {
+    env.storage().instance().get(&DataKey::MaxSupply(id))
+}
+
+pub fn is_operator(env: &Env, owner: &Address, operator: &Address) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Operator(owner.clone(), operator.clone()))
+        .unwrap_or(false)
+}
@@ -0,0 +1,119 @@
+#![cfg(test)]
+
+use crate::{ContractError, MultiToken, MultiTokenClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String, vec};
+
+struct Setup<'a> {
+    env: Env,
+    admin: Address,
+    alice: Address,
+    bob: Address,
+    token: MultiTokenClient<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let contract_id = env.register(MultiToken, ());
+    let token = MultiTokenClient::new(&env, &contract_id);
+    token.initialize(&admin, &String::from_str(&env, "ipfs://items/{id}.json"));
+
+    Setup {
+        env,
+        admin,
+        alice,
+        bob,
+        token,
+    }
+}
+
+#[test]
+fn test_mint_and_transfer_amounts() {
+    let s = setup();
+    s.token.mint(&s.admin, &s.alice, &1, &100);
+    s.token.transfer(&s.alice, &s.bob, &1, &30);
+
+    assert_eq!(s.token.balance_of(&s.alice, &1), 70);
+    assert_eq!(s.token.balance_of(&s.bob, &1), 30);
+    assert_eq!(s.token.total_supply(&1), 100);
+    assert_eq!(
+        s.token.try_transfer(&s.bob, &s.alice, &1, &31),
+        Err(Ok(ContractError::InsufficientBalance))
+    );
+    assert_eq!(
+        s.token.try_mint(&s.alice, &s.alice, &1, &1),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+
+    s.token.burn(&s.bob, &s.bob, &1, &30);
+    assert_eq!(s.token.balance_of(&s.bob, &1), 0);
+    assert_eq!(s.token.total_supply(&1), 70);
+}
+
+#[test]
+fn test_batch_mint_transfer_and_balances() {
+    let s = setup();
+    let ids = vec![&s.env, 1u64, 2, 3];
+    s.token
+        .mint_batch(&s.admin, &s.alice, &ids, &vec![&s.env, 10u64, 1, 5]);
+    s.token.batch_transfer(
+        &s.alice,
+        &s.alice,
+        &s.bob,
+        &vec![&s.env, 1u64, 3],
+        &vec![&s.env, 4u64, 5],
+    );
+
+    let owners = vec![&s.env, s.alice.clone(), s.bob.clone(), s.bob.clone()];
+    assert_eq!(
+        s.token.balance_of_batch(&owners, &vec![&s.env, 1u64, 1, 3]),
+        vec![&s.env, 6u64, 4, 5]
+    );
+    assert_eq!(
+        s.token
+            .try_batch_transfer(&s.alice, &s.alice, &s.bob, &ids, &vec![&s.env, 1u64]),
+        Err(Ok(ContractError::BatchLengthMismatch))
+    );
+    // A failing entry rolls back the whole batch.
+    assert_eq!(
+        s.token.try_batch_transfer(
+            &s.alice,
+            &s.alice,
+            &s.bob,
+            &vec![&s.env, 1u64, 3],
+            &vec![&s.env, 1u64, 1],
+        ),
+        Err(Ok(ContractError::InsufficientBalance))
+    );
+    assert_eq!(s.token.balance_of(&s.alice, &1), 6);
+}
+
+#[test]
+fn test_operator_and_max_supply() {
+    let s = setup();
+    s.token.mint(&s.admin, &s.alice, &7, &5);
+    assert_eq!(
+        s.token.try_transfer_from(&s.bob, &s.alice, &s.bob, &7, &1),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+    s.token.set_approval_for_all(&s.alice, &s.bob, &true);
+    s.token.transfer_from(&s.bob, &s.alice, &s.bob, &7, &2);
+    assert_eq!(s.token.balance_of(&s.bob, &7), 2);
+
+    assert_eq!(
+        s.token.try_set_max_supply(&s.admin, &7, &4),
+        Err(Ok(ContractError::SupplyLimitExceeded))
+    );
+    s.token.set_max_supply(&s.admin, &7, &6);
+    s.token.mint(&s.admin, &s.bob, &7, &1);
+    assert_eq!(
+        s.token.try_mint(&s.admin, &s.bob, &7, &1),
+        Err(Ok(ContractError::SupplyLimitExceeded))
+    );
+}
@@ -0,0 +1,191 @@
+use crate::error::ContractError;
+use crate::events;
+use crate::storage::{self, DataKey};
+use soroban_sdk::{Address, Env, Vec};
+
+/// Maximum ids per batch call, keeping a single invocation within Soroban budget.
+pub const MAX_BATCH_SIZE: u32 = 100;
+
+/// Mints `amount` of token `id` to `to`. Admin only; respects the id's max supply if set.
+pub fn mint(
+    env: &Env,
+    caller: &Address,
+    to: &Address,
+    id: u64,
+    amount: u64,
+) -> Result<(), ContractError> {
+    require_admin(env, caller)?;
+    credit_minted(env, to, id, amount)?;
+    events::emit_mint(env, to.clone(), id, amount);
+    Ok(())
+}
+
+/// Mints several ids to `to` in one call; `amounts` is index-aligned with `ids`.
+pub fn mint_batch(
+    env: &Env,
+    caller: &Address,
+    to: &Address,
+    ids: &Vec<u64>,
+    amounts: &Vec<u64>,
+) -> Result<(), ContractError> {
+    validate_batch(ids, amounts)?;
+    require_admin(env, caller)?;
+    for (id, amount) in ids.iter().zip(amounts.iter()) {
+        credit_minted(env, to, id, amount)?;
+        events::emit_mint(env, to.clone(), id, amount);
+    }
+    Ok(())
+}
+
+/// Caps the supply of token `id`. The cap cannot be below the current supply.
+pub fn set_max_supply(
+    env: &Env,
+    caller: &Address,
+    id: u64,
+    max_supply: u64,
+) -> Result<(), ContractError> {
+    require_admin(env, caller)?;
+    if max_supply < storage::supply(env, id) {
+        return Err(ContractError::SupplyLimitExceeded);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::MaxSupply(id), &max_supply);
+    Ok(())
+}
+
+/// Moves `amount` of token `id` from `from` to `to`. `spender` must be `from` or one of its
+/// approved operators.
+pub fn transfer(
+    env: &Env,
+    spender: &Address,
+    from: &Address,
+    to: &Address,
+    id: u64,
+    amount: u64,
+) -> Result<(), ContractError> {
+    require_spender(env, spender, from)?;
+    move_balance(env, from, to, id, amount)?;
+    events::emit_transfer(env, from.clone(), to.clone(), id, amount);
+    Ok(())
+}
+
+/// Moves several ids from `from` to `to` atomically, emitting a single TransferBatch event.
+pub fn batch_transfer(
+    env: &Env,
+    spender: &Address,
+    from: &Address,
+    to: &Address,
+    ids: Vec<u64>,
+    amounts: Vec<u64>,
+) -> Result<(), ContractError> {
+    validate_batch(&ids, &amounts)?;
+    require_spender(env, spender, from)?;
+    for (id, amount) in ids.iter().zip(amounts.iter()) {
+        move_balance(env, from, to, id, amount)?;
+    }
+    events::emit_transfer_batch(env, from.clone(), to.clone(), ids, amounts);
+    Ok(())
+}
+
+/// Destroys `amount` of token `id` held by `from`. `spender` must be `from` or an operator.
+pub fn burn(
+    env: &Env,
+    spender: &Address,
+    from: &Address,
+    id: u64,
+    amount: u64,
+) -> Result<(), ContractError> {
+    require_spender(env, spender, from)?;
+    debit(env, from, id, amount)?;
+    storage::set_supply(env, id, storage::supply(env, id) - amount);
+    events::emit_burn(env, from.clone(), id, amount);
+    Ok(())
+}
+
+/// Approves or revokes `operator` for all of `owner`'s tokens.
+pub fn set_approval_for_all(
+    env: &Env,
+    owner: &Address,
+    operator: &Address,
+    approved: bool,
+) -> Result<(), ContractError> {
+    owner.require_auth();
+    if owner == operator {
+        return Err(ContractError::NotAuthorized);
+    }
+    let key = DataKey::Operator(owner.clone(), operator.clone());
+    if approved {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+    events::emit_approval_for_all(env, owner.clone(), operator.clone(), approved);
+    Ok(())
+}
+
+fn require_admin(env: &Env, caller: &Address) -> Result<(), ContractError> {
+    caller.require_auth();
+    if *caller != storage::admin(env)? {
+        return Err(ContractError::NotAuthorized);
+    }
+    Ok(())
+}
+
+fn require_spender(env: &Env, spender: &Address, from: &Address) -> Result<(), ContractError> {
+    spender.require_auth();
+    if spender != from && !storage::is_operator(env, from, spender) {
+        return Err(ContractError::NotAuthorized);
+    }
+    Ok(())
+}
+
+fn validate_batch(ids: &Vec<u64>, amounts: &Vec<u64>) -> Result<(), ContractError> {
+    if ids.len() != amounts.len() {
+        return Err(ContractError::BatchLengthMismatch);
+    }
+    if ids.is_empty() || ids.len() > MAX_BATCH_SIZE {
+        return Err(ContractError::BatchTooLarge);
+    }
+    Ok(())
+}
+
+fn credit_minted(env: &Env, to: &Address, id: u64, amount: u64) -> Result<(), ContractError> {
+    if amount == 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    let supply = storage::supply(env, id)
+        .checked_add(amount)
+        .ok_or(ContractError::SupplyLimitExceeded)?;
+    if storage::max_supply(env, id).is_some_and(|max| supply > max) {
+        return Err(ContractError::SupplyLimitExceeded);
+    }
+    storage::set_supply(env, id, supply);
+    // Balances never exceed supply, so this cannot overflow.
+    storage::set_balance(env, to, id, storage::balance(env, to, id) + amount);
+    Ok(())
+}
+
+fn debit(env: &Env, from: &Address, id: u64, amount: u64) -> Result<(), ContractError> {
+    if amount == 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    let balance = storage::balance(env, from, id);
+    if balance < amount {
+        return Err(ContractError::InsufficientBalance);
+    }
+    storage::set_balance(env, from, id, balance - amount);
+    Ok(())
+}
+
+fn move_balance(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    id: u64,
+    amount: u64,
+) -> Result<(), ContractError> {
+    debit(env, from, id, amount)?;
+    storage::set_balance(env, to, id, storage::balance(env, to, id) + amount);
+    Ok(())
+}
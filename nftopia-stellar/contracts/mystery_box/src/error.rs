@@ -0,0 +1,36 @@
+use soroban_sdk::contracterror;
+
+/// Contract-specific errors for the mystery box contract.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    /// Caller is not the series operator or box owner.
+    NotAuthorized = 1,
+    /// Contract has already been initialized.
+    AlreadyInitialized = 2,
+    /// Contract has not been initialized.
+    NotInitialized = 3,
+    /// Series not found.
+    SeriesNotFound = 4,
+    /// Box not found (never bought, opened, or refunded).
+    BoxNotFound = 5,
+    /// Pool is empty or too large, price is negative, or the timeline is invalid.
+    InvalidTerms = 6,
+    /// Box sales have ended.
+    SaleEnded = 7,
+    /// Box sales are still open.
+    SaleActive = 8,
+    /// Every token in the pool has been sold.
+    SoldOut = 9,
+    /// Series or box secret has already been revealed.
+    AlreadyRevealed = 10,
+    /// Revealed secret does not match its commitment.
+    InvalidReveal = 12,
+    /// Reveal deadline has passed.
+    RevealExpired = 13,
+    /// Reveal deadline has not passed yet.
+    RevealPending = 14,
+    /// Sold boxes are still unopened.
+    BoxesOutstanding = 15,
+}
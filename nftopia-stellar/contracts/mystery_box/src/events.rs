@@ -0,0 +1,103 @@
+use soroban_sdk::{Address, Env, contractevent};
+
+/// Series created and token pool escrowed.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct SeriesCreated {
+    pub series_id: u64,
+    pub operator: Address,
+    pub collection: Address,
+    pub pool_size: u32,
+}
+
+/// Sealed box bought.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct BoxBought {
+    pub series_id: u64,
+    pub box_id: u64,
+    pub buyer: Address,
+}
+
+/// Buyer revealed the secret of a box.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct BoxRevealed {
+    pub box_id: u64,
+    pub owner: Address,
+}
+
+/// Operator revealed the series secret; escrowed proceeds paid out.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct SeriesRevealed {
+    pub series_id: u64,
+    pub proceeds: i128,
+}
+
+/// Box opened and its token delivered.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct BoxOpened {
+    pub box_id: u64,
+    pub owner: Address,
+    pub token_id: u64,
+}
+
+/// Box price refunded after a missed reveal.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct BoxRefunded {
+    pub box_id: u64,
+    pub owner: Address,
+}
+
+pub fn emit_series_created(
+    env: &Env,
+    series_id: u64,
+    operator: Address,
+    collection: Address,
+    pool_size: u32,
+) {
+    SeriesCreated {
+        series_id,
+        operator,
+        collection,
+        pool_size,
+    }
+    .publish(env);
+}
+
+pub fn emit_box_bought(env: &Env, series_id: u64, box_id: u64, buyer: Address) {
+    BoxBought {
+        series_id,
+        box_id,
+        buyer,
+    }
+    .publish(env);
+}
+
+pub fn emit_box_revealed(env: &Env, box_id: u64, owner: Address) {
+    BoxRevealed { box_id, owner }.publish(env);
+}
+
+pub fn emit_series_revealed(env: &Env, series_id: u64, proceeds: i128) {
+    SeriesRevealed {
+        series_id,
+        proceeds,
+    }
+    .publish(env);
+}
+
+pub fn emit_box_opened(env: &Env, box_id: u64, owner: Address, token_id: u64) {
+    BoxOpened {
+        box_id,
+        owner,
+        token_id,
+    }
+    .publish(env);
+}
+
+pub fn emit_box_refunded(env: &Env, box_id: u64, owner: Address) {
+    BoxRefunded { box_id, owner }.publish(env);
+}
@@ -0,0 +1,92 @@
+#![no_std]
+
+mod error;
+mod events;
+mod mystery_box;
+mod storage;
+mod types;
+
+pub use error::ContractError;
+pub use mystery_box::MAX_POOL_SIZE;
+pub use types::{MysteryBox, Series, SeriesTerms};
+
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec, contract, contractimpl};
+
+use crate::error::ContractError as Err;
+use crate::storage::DataKey;
+
+#[contract]
+pub struct MysteryBoxes;
+
+#[contractimpl]
+impl MysteryBoxes {
+    /// Initializes the mystery box contract.
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Err> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Err::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::NextSeriesId, &0u64);
+        env.storage().instance().set(&DataKey::NextBoxId, &0u64);
+        Ok(())
+    }
+
+    // --- Series (operator) ---
+    pub fn create_series(
+        env: Env,
+        operator: Address,
+        collection: Address,
+        pool: Vec<u64>,
+        terms: SeriesTerms,
+        commitment: BytesN<32>,
+    ) -> Result<u64, Err> {
+        mystery_box::create_series(&env, operator, collection, pool, terms, commitment)
+    }
+
+    pub fn reveal_series(
+        env: Env,
+        operator: Address,
+        series_id: u64,
+        secret: Bytes,
+    ) -> Result<(), Err> {
+        mystery_box::reveal_series(&env, operator, series_id, secret)
+    }
+
+    pub fn withdraw_remaining(
+        env: Env,
+        operator: Address,
+        series_id: u64,
+    ) -> Result<Vec<u64>, Err> {
+        mystery_box::withdraw_remaining(&env, operator, series_id)
+    }
+
+    // --- Boxes (buyers) ---
+    pub fn buy_box(
+        env: Env,
+        buyer: Address,
+        series_id: u64,
+        commitment: BytesN<32>,
+    ) -> Result<u64, Err> {
+        mystery_box::buy_box(&env, buyer, series_id, commitment)
+    }
+
+    pub fn reveal_box(env: Env, owner: Address, box_id: u64, secret: Bytes) -> Result<(), Err> {
+        mystery_box::reveal_box(&env, owner, box_id, secret)
+    }
+
+    pub fn open_box(env: Env, owner: Address, box_id: u64) -> Result<u64, Err> {
+        mystery_box::open_box(&env, owner, box_id)
+    }
+
+    // --- Views ---
+    pub fn get_series(env: Env, series_id: u64) -> Result<Series, Err> {
+        storage::get_series(&env, series_id)
+    }
+
+    pub fn get_box(env: Env, box_id: u64) -> Result<MysteryBox, Err> {
+        storage::get_box(&env, box_id)
+    }
+}
+
+#[cfg(test)]
+mod test;
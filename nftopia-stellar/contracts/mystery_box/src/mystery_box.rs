@@ -0,0 +1,268 @@
+use crate::error::ContractError;
+use crate::events;
+use crate::storage::{self, DataKey};
+use crate::types::{MysteryBox, Series, SeriesTerms};
+use nft_interface::NftClient;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec, token};
+
+/// Maximum tokens per series pool, bounding the escrow loop at creation.
+pub const MAX_POOL_SIZE: u32 = 100;
+
+/// Creates a series, moving every pool token into escrow. `commitment` is
+/// `sha256(secret)` for the operator's secret, revealed once sales close.
+pub fn create_series(
+    env: &Env,
+    operator: Address,
+    collection: Address,
+    pool: Vec<u64>,
+    terms: SeriesTerms,
+    commitment: BytesN<32>,
+) -> Result<u64, ContractError> {
+    operator.require_auth();
+    if pool.is_empty()
+        || pool.len() > MAX_POOL_SIZE
+        || terms.price < 0
+        || terms.sale_end <= env.ledger().timestamp()
+        || terms.reveal_deadline <= terms.sale_end
+    {
+        return Err(ContractError::InvalidTerms);
+    }
+    let series_id = storage::next_id(env, DataKey::NextSeriesId)?;
+    let nft = NftClient::new(env, &collection);
+    let contract = env.current_contract_address();
+    for token_id in pool.iter() {
+        nft.transfer(&operator, &contract, &token_id);
+    }
+
+    let pool_size = pool.len();
+    let series = Series {
+        id: series_id,
+        operator: operator.clone(),
+        collection: collection.clone(),
+        terms,
+        pool,
+        entropy: commitment.clone(),
+        commitment,
+        secret: None,
+        shuffled: false,
+        boxes_sold: 0,
+        boxes_outstanding: 0,
+        escrowed: 0,
+    };
+    storage::set_series(env, &series);
+    events::emit_series_created(env, series_id, operator, collection, pool_size);
+    Ok(series_id)
+}
+
+/// Buys a sealed box. `commitment` is `sha256(secret)` for a buyer secret, revealed with
+/// `reveal_box` once sales close.
+pub fn buy_box(
+    env: &Env,
+    buyer: Address,
+    series_id: u64,
+    commitment: BytesN<32>,
+) -> Result<u64, ContractError> {
+    buyer.require_auth();
+    let mut series = storage::get_series(env, series_id)?;
+    if env.ledger().timestamp() >= series.terms.sale_end {
+        return Err(ContractError::SaleEnded);
+    }
+    if series.boxes_sold >= series.pool.len() {
+        return Err(ContractError::SoldOut);
+    }
+
+    let price = series.terms.price;
+    if price > 0 {
+        let contract = env.current_contract_address();
+        token::Client::new(env, &series.terms.currency).transfer(&buyer, &contract, &price);
+    }
+    let index = series.boxes_sold;
+    series.boxes_sold += 1;
+    series.boxes_outstanding += 1;
+    series.escrowed += price;
+    storage::set_series(env, &series);
+
+    let box_id = storage::next_id(env, DataKey::NextBoxId)?;
+    storage::set_box(
+        env,
+        &MysteryBox {
+            id: box_id,
+            series_id,
+            owner: buyer.clone(),
+            index,
+            commitment,
+            revealed: false,
+            price,
+        },
+    );
+    events::emit_box_bought(env, series_id, box_id, buyer);
+    Ok(box_id)
+}
+
+/// Reveals a buyer secret after sales close, chaining it into the series entropy. Nobody
+/// knows these secrets while boxes are on sale, so the shuffle cannot be ground in advance.
+pub fn reveal_box(
+    env: &Env,
+    owner: Address,
+    box_id: u64,
+    secret: Bytes,
+) -> Result<(), ContractError> {
+    owner.require_auth();
+    let mut mystery_box = storage::get_box(env, box_id)?;
+    if mystery_box.owner != owner {
+        return Err(ContractError::NotAuthorized);
+    }
+    if mystery_box.revealed {
+        return Err(ContractError::AlreadyRevealed);
+    }
+    let mut series = storage::get_series(env, mystery_box.series_id)?;
+    require_reveal_window(env, &series)?;
+    if BytesN::from(env.crypto().sha256(&secret)) != mystery_box.commitment {
+        return Err(ContractError::InvalidReveal);
+    }
+
+    let mut chained = Bytes::from(series.entropy.clone());
+    chained.append(&secret);
+    series.entropy = env.crypto().sha256(&chained).into();
+    storage::set_series(env, &series);
+    mystery_box.revealed = true;
+    storage::set_box(env, &mystery_box);
+    events::emit_box_revealed(env, box_id, owner);
+    Ok(())
+}
+
+/// Reveals the operator secret after sales close and releases the escrowed proceeds. The
+/// pool is shuffled after the reveal deadline, so revealing early gains nothing.
+pub fn reveal_series(
+    env: &Env,
+    operator: Address,
+    series_id: u64,
+    secret: Bytes,
+) -> Result<(), ContractError> {
+    operator.require_auth();
+    let mut series = storage::get_series(env, series_id)?;
+    if series.operator != operator {
+        return Err(ContractError::NotAuthorized);
+    }
+    if series.secret.is_some() {
+        return Err(ContractError::AlreadyRevealed);
+    }
+    require_reveal_window(env, &series)?;
+    if BytesN::from(env.crypto().sha256(&secret)) != series.commitment {
+        return Err(ContractError::InvalidReveal);
+    }
+
+    let proceeds = series.escrowed;
+    if proceeds > 0 {
+        token::Client::new(env, &series.terms.currency).transfer(
+            &env.current_contract_address(),
+            &operator,
+            &proceeds,
+        );
+    }
+    series.secret = Some(secret);
+    series.escrowed = 0;
+    storage::set_series(env, &series);
+    events::emit_series_revealed(env, series_id, proceeds);
+    Ok(())
+}
+
+fn require_reveal_window(env: &Env, series: &Series) -> Result<(), ContractError> {
+    let now = env.ledger().timestamp();
+    if now < series.terms.sale_end {
+        return Err(ContractError::SaleActive);
+    }
+    if now >= series.terms.reveal_deadline {
+        return Err(ContractError::RevealExpired);
+    }
+    Ok(())
+}
+
+/// Fisher-Yates shuffle driven by `sha256(seed || i)`.
+fn shuffle(env: &Env, pool: &mut Vec<u64>, seed: &BytesN<32>) {
+    for i in (1..pool.len()).rev() {
+        let mut input = Bytes::from(seed.clone());
+        input.extend_from_array(&i.to_be_bytes());
+        let digest = env.crypto().sha256(&input).to_array();
+        let mut head = [0u8; 8];
+        head.copy_from_slice(&digest[..8]);
+        let j = (u64::from_be_bytes(head) % (i as u64 + 1)) as u32;
+        let (a, b) = (pool.get(i).unwrap(), pool.get(j).unwrap());
+        pool.set(i, b);
+        pool.set(j, a);
+    }
+}
+
+/// Opens a box after the reveal deadline, transferring the token at its position in sale
+/// order. The first box opened shuffles the pool with `sha256(operator_secret || entropy)`,
+/// so opening order has no effect on which token is received. If the operator missed the
+/// reveal, the shuffle runs without its secret and the box price is refunded as well: the
+/// operator forfeits both the proceeds and the sold tokens.
+pub fn open_box(env: &Env, owner: Address, box_id: u64) -> Result<u64, ContractError> {
+    owner.require_auth();
+    let mystery_box = storage::get_box(env, box_id)?;
+    if mystery_box.owner != owner {
+        return Err(ContractError::NotAuthorized);
+    }
+    let mut series = storage::get_series(env, mystery_box.series_id)?;
+    if env.ledger().timestamp() < series.terms.reveal_deadline {
+        return Err(ContractError::RevealPending);
+    }
+    if !series.shuffled {
+        let mut seed = series.secret.clone().unwrap_or(Bytes::new(env));
+        seed.extend_from_array(&series.entropy.to_array());
+        shuffle(env, &mut series.pool, &env.crypto().sha256(&seed).into());
+        series.shuffled = true;
+    }
+
+    let token_id = series.pool.get(mystery_box.index).unwrap();
+    let refund = if series.secret.is_none() {
+        mystery_box.price
+    } else {
+        0
+    };
+    series.boxes_outstanding -= 1;
+    series.escrowed -= refund;
+    storage::set_series(env, &series);
+    storage::remove_box(env, box_id);
+
+    let contract = env.current_contract_address();
+    NftClient::new(env, &series.collection).transfer(&contract, &owner, &token_id);
+    if refund > 0 {
+        token::Client::new(env, &series.terms.currency).transfer(&contract, &owner, &refund);
+        events::emit_box_refunded(env, box_id, owner.clone());
+    }
+    events::emit_box_opened(env, box_id, owner, token_id);
+    Ok(token_id)
+}
+
+/// Returns unsold pool tokens to the operator after the reveal deadline, once every sold
+/// box is opened.
+pub fn withdraw_remaining(
+    env: &Env,
+    operator: Address,
+    series_id: u64,
+) -> Result<Vec<u64>, ContractError> {
+    operator.require_auth();
+    let mut series = storage::get_series(env, series_id)?;
+    if series.operator != operator {
+        return Err(ContractError::NotAuthorized);
+    }
+    if env.ledger().timestamp() < series.terms.reveal_deadline {
+        return Err(ContractError::RevealPending);
+    }
+    if series.boxes_outstanding > 0 {
+        return Err(ContractError::BoxesOutstanding);
+    }
+    // The first `boxes_sold` tokens of the shuffled pool went to the opened boxes.
+    let assigned = series.boxes_sold;
+    let remaining = series.pool.slice(assigned..);
+    let nft = NftClient::new(env, &series.collection);
+    let contract = env.current_contract_address();
+    for token_id in remaining.iter() {
+        nft.transfer(&contract, &operator, &token_id);
+    }
+    series.pool = series.pool.slice(..assigned);
+    storage::set_series(env, &series);
+    Ok(remaining)
+}
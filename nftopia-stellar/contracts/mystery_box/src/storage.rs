@@ -0,0 +1,59 @@
+use crate::error::ContractError;
+use crate::types::{MysteryBox, Series};
+use soroban_sdk::{Env, contracttype};
+
+/// Storage keys for the mystery box contract.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// Contract admin.
+    Admin,
+    /// Next series id to assign.
+    NextSeriesId,
+    /// Next box id to assign.
+    NextBoxId,
+    /// Series by id.
+    Series(u64),
+    /// Unopened box by id.
+    Box(u64),
+}
+
+pub fn get_series(env: &Env, series_id: u64) -> Result<Series, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Series(series_id))
+        .ok_or(ContractError::SeriesNotFound)
+}
+
+pub fn set_series(env: &Env, series: &Series) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Series(series.id), series);
+}
+
+pub fn get_box(env: &Env, box_id: u64) -> Result<MysteryBox, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Box(box_id))
+        .ok_or(ContractError::BoxNotFound)
+}
+
+pub fn set_box(env: &Env, mystery_box: &MysteryBox) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Box(mystery_box.id), mystery_box);
+}
+
+pub fn remove_box(env: &Env, box_id: u64) {
+    env.storage().instance().remove(&DataKey::Box(box_id));
+}
+
+pub fn next_id(env: &Env, key: DataKey) -> Result<u64, ContractError> {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&key)
+        .ok_or(ContractError::NotInitialized)?;
+    env.storage().instance().set(&key, &(id + 1));
+    Ok(id)
+}
@@ -0,0 +1,193 @@
+#![cfg(test)]
+
+use crate::{ContractError, MysteryBoxes, MysteryBoxesClient, SeriesTerms};
+use nft_contract::NftContractClient;
+use nft_contract::testutils::deploy_test_collection;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Bytes, BytesN, Env, String, Vec};
+
+struct Setup<'a> {
+    env: Env,
+    operator: Address,
+    alice: Address,
+    nft: NftContractClient<'a>,
+    token: TokenClient<'a>,
+    boxes: MysteryBoxesClient<'a>,
+    series_id: u64,
+    operator_secret: Bytes,
+}
+
+fn commit(env: &Env, secret: &Bytes) -> BytesN<32> {
+    env.crypto().sha256(secret).into()
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let operator = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    let nft = deploy_test_collection(&env, &operator, 0);
+    let uri = String::from_str(&env, "ipfs://item");
+    let mut pool = Vec::new(&env);
+    for _ in 0..3 {
+        pool.push_back(nft.mint(&operator, &operator, &uri, &Vec::new(&env), &None));
+    }
+
+    let sac = env.register_stellar_asset_contract_v2(operator.clone());
+    let token = TokenClient::new(&env, &sac.address());
+    StellarAssetClient::new(&env, &sac.address()).mint(&alice, &1_000);
+
+    let boxes_id = env.register(MysteryBoxes, ());
+    let boxes = MysteryBoxesClient::new(&env, &boxes_id);
+    boxes.initialize(&operator);
+
+    let operator_secret = Bytes::from_array(&env, &[9; 32]);
+    let series_id = boxes.create_series(
+        &operator,
+        &nft.address,
+        &pool,
+        &SeriesTerms {
+            currency: token.address.clone(),
+            price: 100,
+            sale_end: 1_000,
+            reveal_deadline: 2_000,
+        },
+        &commit(&env, &operator_secret),
+    );
+
+    Setup {
+        env,
+        operator,
+        alice,
+        nft,
+        token,
+        boxes,
+        series_id,
+        operator_secret,
+    }
+}
+
+#[test]
+fn test_buy_reveal_and_open() {
+    let s = setup();
+    let secret = Bytes::from_array(&s.env, &[1; 32]);
+    let box_id = s
+        .boxes
+        .buy_box(&s.alice, &s.series_id, &commit(&s.env, &secret));
+    assert_eq!(
+        s.boxes.try_reveal_box(&s.alice, &box_id, &secret),
+        Err(Ok(ContractError::SaleActive))
+    );
+    assert_eq!(
+        s.boxes
+            .try_reveal_series(&s.operator, &s.series_id, &s.operator_secret),
+        Err(Ok(ContractError::SaleActive))
+    );
+
+    s.env.ledger().set_timestamp(1_000);
+    assert_eq!(
+        s.boxes
+            .try_reveal_box(&s.alice, &box_id, &s.operator_secret),
+        Err(Ok(ContractError::InvalidReveal))
+    );
+    s.boxes.reveal_box(&s.alice, &box_id, &secret);
+    assert_eq!(
+        s.boxes.try_reveal_box(&s.alice, &box_id, &secret),
+        Err(Ok(ContractError::AlreadyRevealed))
+    );
+    s.boxes
+        .reveal_series(&s.operator, &s.series_id, &s.operator_secret);
+    assert_eq!(s.token.balance(&s.operator), 100);
+    assert_eq!(
+        s.boxes.try_open_box(&s.alice, &box_id),
+        Err(Ok(ContractError::RevealPending))
+    );
+
+    s.env.ledger().set_timestamp(2_000);
+    let token_id = s.boxes.open_box(&s.alice, &box_id);
+    assert_eq!(s.nft.owner_of(&token_id), s.alice);
+    assert_eq!(s.token.balance(&s.alice), 900);
+
+    let remaining = s.boxes.withdraw_remaining(&s.operator, &s.series_id);
+    assert_eq!(remaining.len(), 2);
+    assert!(!remaining.contains(token_id));
+    assert_eq!(s.nft.balance_of(&s.operator), 2);
+}
+
+#[test]
+fn test_missed_reveal_forfeits_pool_and_proceeds() {
+    let s = setup();
+    let secret = Bytes::from_array(&s.env, &[1; 32]);
+    let box_id = s
+        .boxes
+        .buy_box(&s.alice, &s.series_id, &commit(&s.env, &secret));
+
+    s.env.ledger().set_timestamp(1_500);
+    s.boxes.reveal_box(&s.alice, &box_id, &secret);
+    assert_eq!(
+        s.boxes.try_withdraw_remaining(&s.operator, &s.series_id),
+        Err(Ok(ContractError::RevealPending))
+    );
+    s.env.ledger().set_timestamp(2_000);
+    assert_eq!(
+        s.boxes
+            .try_reveal_series(&s.operator, &s.series_id, &s.operator_secret),
+        Err(Ok(ContractError::RevealExpired))
+    );
+
+    // The box still gets its token, and its price comes back.
+    let token_id = s.boxes.open_box(&s.alice, &box_id);
+    assert_eq!(s.nft.owner_of(&token_id), s.alice);
+    assert_eq!(s.token.balance(&s.alice), 1_000);
+    assert_eq!(s.token.balance(&s.operator), 0);
+
+    s.boxes.withdraw_remaining(&s.operator, &s.series_id);
+    assert_eq!(s.nft.balance_of(&s.operator), 2);
+}
+
+#[test]
+fn test_assignment_fixed_at_shuffle() {
+    let open_in_order = |reverse: bool| {
+        let s = setup();
+        let mut box_ids = Vec::new(&s.env);
+        for n in 1..=3u8 {
+            let secret = Bytes::from_array(&s.env, &[n; 32]);
+            box_ids.push_back(
+                s.boxes
+                    .buy_box(&s.alice, &s.series_id, &commit(&s.env, &secret)),
+            );
+        }
+        s.env.ledger().set_timestamp(1_000);
+        for (i, box_id) in box_ids.iter().enumerate() {
+            let secret = Bytes::from_array(&s.env, &[i as u8 + 1; 32]);
+            s.boxes.reveal_box(&s.alice, &box_id, &secret);
+        }
+        s.boxes
+            .reveal_series(&s.operator, &s.series_id, &s.operator_secret);
+
+        s.env.ledger().set_timestamp(2_000);
+        for i in 0..box_ids.len() {
+            let i = if reverse { box_ids.len() - 1 - i } else { i };
+            s.boxes.open_box(&s.alice, &box_ids.get(i).unwrap());
+        }
+        // Each box receives the token at its sale position, whatever the opening order.
+        let series = s.boxes.get_series(&s.series_id);
+        assert!(series.shuffled);
+        let mut tokens = Vec::new(&s.env);
+        for (index, box_id) in box_ids.iter().enumerate() {
+            let token_id = series.pool.get(index as u32).unwrap();
+            assert_eq!(s.nft.owner_of(&token_id), s.alice);
+            assert_eq!(
+                s.boxes.try_get_box(&box_id),
+                Err(Ok(ContractError::BoxNotFound))
+            );
+            tokens.push_back(token_id);
+        }
+        tokens
+    };
+
+    assert_eq!(open_in_order(false), open_in_order(true));
+}
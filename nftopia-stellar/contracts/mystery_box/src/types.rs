@@ -0,0 +1,58 @@
+use soroban_sdk::{Address, Bytes, BytesN, Vec, contracttype};
+
+/// Sale terms chosen by the operator.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct SeriesTerms {
+    /// SEP-41 token boxes are paid in.
+    pub currency: Address,
+    pub price: i128,
+    /// Box sales close at this timestamp.
+    pub sale_end: u64,
+    /// Buyer and operator secrets are revealed before this timestamp; boxes open after it.
+    /// An operator that misses it forfeits the proceeds.
+    pub reveal_deadline: u64,
+}
+
+/// A batch of sealed boxes backed by a pool of escrowed tokens.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Series {
+    pub id: u64,
+    pub operator: Address,
+    pub collection: Address,
+    pub terms: SeriesTerms,
+    /// Escrowed tokens. Shuffled once after the reveal deadline; the first `boxes_sold`
+    /// entries then belong to the sold boxes, in sale order.
+    pub pool: Vec<u64>,
+    /// `sha256(secret)`, committed by the operator at creation.
+    pub commitment: BytesN<32>,
+    /// Hash chain over the operator commitment and the buyer secrets, in reveal order.
+    pub entropy: BytesN<32>,
+    /// Operator secret, set once revealed.
+    pub secret: Option<Bytes>,
+    /// Whether the pool has been shuffled, which the first box opened does.
+    pub shuffled: bool,
+    pub boxes_sold: u32,
+    /// Sold boxes not yet opened or refunded.
+    pub boxes_outstanding: u32,
+    /// Box payments held until the operator reveals, or refunded as boxes open after a
+    /// missed reveal.
+    pub escrowed: i128,
+}
+
+/// A sealed box. Its token is fixed when the pool is shuffled after the reveal deadline.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct MysteryBox {
+    pub id: u64,
+    pub series_id: u64,
+    pub owner: Address,
+    /// Position in sale order; selects the box's token from the shuffled pool.
+    pub index: u32,
+    /// `sha256(secret)` for the buyer secret mixed into the series entropy.
+    pub commitment: BytesN<32>,
+    /// Whether the buyer secret has been revealed.
+    pub revealed: bool,
+    pub price: i128,
+}
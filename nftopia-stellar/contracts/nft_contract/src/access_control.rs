@@ -1,143 +1,291 @@
 use crate::error::ContractError;
 use crate::storage::DataKey;
-use soroban_sdk::Address;
+use crate::types::{MintAllowance, Role};
 use soroban_sdk::Env;
+use soroban_sdk::{Address, Vec};
 
-/// Requires that the contract is not paused.
-pub fn require_not_paused(env: &Env) -> Result<(), ContractError> {
-    let paused: bool = env
-        .storage()
-        .instance()
-        .get(&DataKey::Paused)
-        .unwrap_or(false);
-    if paused {
-        return Err(ContractError::ContractPaused);
+/// Whether the operation guarded by `flag` (`MintPaused`, `TransferPaused` or `BurnPaused`)
+/// is paused.
+pub fn is_paused(env: &Env, flag: &DataKey) -> bool {
+    env.storage().instance().get(flag).unwrap_or(false)
+}
+
+/// Sets every pause flag in `flags` to `paused` and emits the resulting pause state.
+pub fn set_paused(env: &Env, by: Address, flags: &[DataKey], paused: bool) {
+    for flag in flags {
+        env.storage().instance().set(flag, &paused);
     }
-    Ok(())
+    crate::events::emit_paused(
+        env,
+        by,
+        is_paused(env, &DataKey::MintPaused),
+        is_paused(env, &DataKey::TransferPaused),
+        is_paused(env, &DataKey::BurnPaused),
+    );
+}
+
+/// Requires that the operation guarded by `flag` is not paused.
+pub fn require_not_paused(env: &Env, flag: &DataKey) -> Result<(), ContractError> {
+    if !is_paused(env, flag) {
+        return Ok(());
+    }
+    Err(match flag {
+        DataKey::MintPaused => ContractError::MintPaused,
+        DataKey::TransferPaused => ContractError::TransferPaused,
+        DataKey::BurnPaused => ContractError::BurnPaused,
+        _ => ContractError::ContractPaused,
+    })
 }
 
-/// Requires that the caller is the contract owner.
+/// Fails with `NotFound` until the contract is initialized.
+pub fn require_initialized(env: &Env) -> Result<(), ContractError> {
+    if env.storage().instance().has(&DataKey::Initialized) {
+        Ok(())
+    } else {
+        Err(ContractError::NotFound)
+    }
+}
+
+/// Requires that the caller is the contract owner. Once ownership is renounced nobody is.
 pub fn require_owner(env: &Env) -> Result<Address, ContractError> {
+    require_initialized(env)?;
     let owner: Address = env
         .storage()
         .instance()
         .get(&DataKey::OwnerRole)
-        .ok_or(ContractError::NotFound)?;
+        .ok_or(ContractError::NotAuthorized)?;
     owner.require_auth();
     Ok(owner)
 }
 
+/// Gives up ownership for good. Owner only; admins and other roles keep theirs.
+pub fn renounce_ownership(env: &Env) -> Result<(), ContractError> {
+    let owner = require_owner(env)?;
+    env.storage().instance().remove(&DataKey::OwnerRole);
+    crate::events::emit_ownership_renounced(env, owner);
+    Ok(())
+}
+
+/// Gives up the caller's own `role`. The owner uses `renounce_ownership` instead.
+pub fn renounce_role(env: &Env, caller: Address, role: Role) -> Result<(), ContractError> {
+    caller.require_auth();
+    let key = role_key(role, &caller).ok_or(ContractError::InvalidRole)?;
+    if !has_role(env, &caller, role) {
+        return Err(ContractError::NotFound);
+    }
+    env.storage().instance().remove(&key);
+    env.storage()
+        .instance()
+        .remove(&DataKey::RoleExpiry(role, caller.clone()));
+    crate::events::emit_role_updated(env, role, caller.clone(), caller, false);
+    Ok(())
+}
+
 /// Requires that the caller has admin role.
 pub fn require_admin(env: &Env, caller: &Address) -> Result<(), ContractError> {
     caller.require_auth();
-    let owner: Address = env
-        .storage()
-        .instance()
-        .get(&DataKey::OwnerRole)
-        .ok_or(ContractError::NotFound)?;
-    if *caller == owner {
-        return Ok(());
+    require_initialized(env)?;
+    if has_role(env, caller, Role::Owner) || has_role(env, caller, Role::Admin) {
+        Ok(())
+    } else {
+        Err(ContractError::NotAdmin)
     }
-    let is_admin: bool = env
-        .storage()
-        .instance()
-        .get(&DataKey::Admin(caller.clone()))
-        .unwrap_or(false);
-    if is_admin {
+}
+
+/// Requires that the caller may set the collection's pause state to `paused`: admins either
+/// way, guardians only to pause.
+pub fn require_pauser(env: &Env, caller: &Address, paused: bool) -> Result<(), ContractError> {
+    caller.require_auth();
+    if has_role(env, caller, Role::Owner)
+        || has_role(env, caller, Role::Admin)
+        || (paused && has_role(env, caller, Role::Guardian))
+    {
         Ok(())
     } else {
-        Err(ContractError::MissingRole)
+        Err(ContractError::NotAdmin)
     }
 }
 
 /// Requires that the caller has minter role.
 pub fn require_minter(env: &Env, caller: &Address) -> Result<(), ContractError> {
     caller.require_auth();
-    if has_role(env, caller, crate::types::Role::Owner)
-        || has_role(env, caller, crate::types::Role::Admin)
-        || env
-            .storage()
-            .instance()
-            .get(&DataKey::Minter(caller.clone()))
-            .unwrap_or(false)
+    if has_role(env, caller, Role::Owner)
+        || has_role(env, caller, Role::Admin)
+        || has_role(env, caller, Role::Minter)
     {
         Ok(())
     } else {
-        Err(ContractError::MissingRole)
+        Err(ContractError::NotMinter)
     }
 }
 
-/// Requires that the caller has burner role.
-pub fn require_burner(env: &Env, caller: &Address) -> Result<(), ContractError> {
+/// Requires that the caller has the relayer role.
+pub fn require_relayer(env: &Env, caller: &Address) -> Result<(), ContractError> {
     caller.require_auth();
-    if has_role(env, caller, crate::types::Role::Owner)
-        || has_role(env, caller, crate::types::Role::Admin)
-        || env
-            .storage()
-            .instance()
-            .get(&DataKey::Burner(caller.clone()))
-            .unwrap_or(false)
-    {
+    if has_role(env, caller, Role::Relayer) {
         Ok(())
     } else {
-        Err(ContractError::MissingRole)
+        Err(ContractError::NotRelayer)
     }
 }
 
+/// Whether `caller` may burn tokens it does not own (burner role, owner or admin). No auth check.
+pub fn is_burner(env: &Env, caller: &Address) -> bool {
+    has_role(env, caller, Role::Owner)
+        || has_role(env, caller, Role::Admin)
+        || has_role(env, caller, Role::Burner)
+}
+
 /// Requires that the caller has metadata updater role (or owner/admin).
 pub fn require_metadata_updater(env: &Env, caller: &Address) -> Result<(), ContractError> {
     caller.require_auth();
-    if has_role(env, caller, crate::types::Role::Owner)
-        || has_role(env, caller, crate::types::Role::Admin)
-        || env
-            .storage()
-            .instance()
-            .get(&DataKey::MetadataUpdater(caller.clone()))
-            .unwrap_or(false)
+    if is_metadata_updater(env, caller) {
+        Ok(())
+    } else {
+        Err(ContractError::NotMetadataUpdater)
+    }
+}
+
+/// Whether `caller` may edit any token's metadata (metadata updater role, owner or admin).
+/// No auth check.
+pub fn is_metadata_updater(env: &Env, caller: &Address) -> bool {
+    has_role(env, caller, Role::Owner)
+        || has_role(env, caller, Role::Admin)
+        || has_role(env, caller, Role::MetadataUpdater)
+}
+
+/// Requires that the caller has renewer role (or owner/admin).
+pub fn require_renewer(env: &Env, caller: &Address) -> Result<(), ContractError> {
+    caller.require_auth();
+    if has_role(env, caller, Role::Owner)
+        || has_role(env, caller, Role::Admin)
+        || has_role(env, caller, Role::Renewer)
     {
         Ok(())
     } else {
-        Err(ContractError::MissingRole)
+        Err(ContractError::NotRenewer)
     }
 }
 
-fn has_role(env: &Env, address: &Address, role: crate::types::Role) -> bool {
-    match role {
-        crate::types::Role::Owner => {
+/// Whether `address` holds `role` (explicitly; Owner and Admin do not imply other roles here).
+/// A time-bound grant lapses once its expiry is reached.
+pub fn has_role(env: &Env, address: &Address, role: Role) -> bool {
+    match role_key(role, address) {
+        Some(key) => {
+            env.storage().instance().get(&key).unwrap_or(false)
+                && role_expiry(env, role, address)
+                    .is_none_or(|expires_at| env.ledger().timestamp() < expires_at)
+        }
+        None => {
             let owner: Option<Address> = env.storage().instance().get(&DataKey::OwnerRole);
             owner.map(|o| *address == o).unwrap_or(false)
         }
-        crate::types::Role::Admin => env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin(address.clone()))
-            .unwrap_or(false),
-        crate::types::Role::Minter => env
-            .storage()
-            .instance()
-            .get(&DataKey::Minter(address.clone()))
-            .unwrap_or(false),
-        crate::types::Role::Burner => env
-            .storage()
-            .instance()
-            .get(&DataKey::Burner(address.clone()))
-            .unwrap_or(false),
-        crate::types::Role::MetadataUpdater => env
+    }
+}
+
+/// Storage key recording that `address` holds `role`; `None` for the single Owner.
+fn role_key(role: Role, address: &Address) -> Option<DataKey> {
+    let address = address.clone();
+    match role {
+        Role::Owner => None,
+        Role::Admin => Some(DataKey::Admin(address)),
+        Role::Minter => Some(DataKey::Minter(address)),
+        Role::Burner => Some(DataKey::Burner(address)),
+        Role::MetadataUpdater => Some(DataKey::MetadataUpdater(address)),
+        Role::Renewer => Some(DataKey::Renewer(address)),
+        Role::MinterAdmin | Role::Guardian | Role::Relayer => {
+            Some(DataKey::RoleMember(role, address))
+        }
+    }
+}
+
+/// Role whose holders may grant and revoke `role`. The owner administers admins; every other
+/// role is administered by admins unless `set_role_admin` chose otherwise.
+pub fn role_admin(env: &Env, role: Role) -> Role {
+    match role {
+        Role::Owner | Role::Admin => Role::Owner,
+        _ => env
             .storage()
             .instance()
-            .get(&DataKey::MetadataUpdater(address.clone()))
-            .unwrap_or(false),
+            .get(&DataKey::RoleAdmin(role))
+            .unwrap_or(Role::Admin),
+    }
+}
+
+/// Makes `admin_role` the administering role of `role`. Owner and Admin keep the owner as
+/// their administrator.
+pub fn set_role_admin(env: &Env, role: Role, admin_role: Role) -> Result<(), ContractError> {
+    require_owner(env)?;
+    if matches!(role, Role::Owner | Role::Admin) || role == admin_role {
+        return Err(ContractError::InvalidRole);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::RoleAdmin(role), &admin_role);
+    Ok(())
+}
+
+/// Timestamp at which `account`'s grant of `role` lapses, if it is time-bound.
+pub fn role_expiry(env: &Env, role: Role, account: &Address) -> Option<u64> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RoleExpiry(role, account.clone()))
+}
+
+/// Removes `account`'s grant of `role` once its expiry has passed; returns whether it did.
+pub fn clear_expired_role(env: &Env, role: Role, account: &Address) -> bool {
+    let expired = role_expiry(env, role, account)
+        .is_some_and(|expires_at| env.ledger().timestamp() >= expires_at);
+    if !expired {
+        return false;
+    }
+    if let Some(key) = role_key(role, account) {
+        env.storage().instance().remove(&key);
+    }
+    env.storage()
+        .instance()
+        .remove(&DataKey::RoleExpiry(role, account.clone()));
+    true
+}
+
+/// Grants or revokes `role` for `account`, optionally only until `expires_at`. The caller must
+/// hold the role's admin role; the owner may always. In council mode admins change only by
+/// proposal.
+pub fn set_role(
+    env: &Env,
+    caller: Address,
+    role: Role,
+    account: Address,
+    granted: bool,
+    expires_at: Option<u64>,
+) -> Result<(), ContractError> {
+    caller.require_auth();
+    let key = role_key(role, &account).ok_or(ContractError::InvalidRole)?;
+    if role == Role::Admin {
+        crate::council::require_no_council(env)?;
+    }
+    if !has_role(env, &caller, Role::Owner) && !has_role(env, &caller, role_admin(env, role)) {
+        return Err(ContractError::NotAdmin);
     }
+    let expiry_key = DataKey::RoleExpiry(role, account.clone());
+    match expires_at.filter(|_| granted) {
+        Some(expires_at) => {
+            if expires_at <= env.ledger().timestamp() {
+                return Err(ContractError::InvalidAmount);
+            }
+            env.storage().instance().set(&expiry_key, &expires_at);
+        }
+        None => env.storage().instance().remove(&expiry_key),
+    }
+    env.storage().instance().set(&key, &granted);
+    crate::events::emit_role_updated(env, role, account, caller, granted);
+    Ok(())
 }
 
 /// Requires that the caller is whitelisted (when whitelist is enforced).
 pub fn require_whitelisted(env: &Env, address: &Address) -> Result<(), ContractError> {
-    let owner: Address = env
-        .storage()
-        .instance()
-        .get(&DataKey::OwnerRole)
-        .ok_or(ContractError::NotFound)?;
-    if *address == owner {
+    require_initialized(env)?;
+    if has_role(env, address, Role::Owner) {
         return Ok(());
     }
     let is_whitelisted: bool = env
@@ -151,3 +299,160 @@ pub fn require_whitelisted(env: &Env, address: &Address) -> Result<(), ContractE
         Err(ContractError::NotWhitelisted)
     }
 }
+
+/// Mints left under `minter`'s allowance; 0 if it has none or it has expired.
+pub fn remaining_allowance(env: &Env, minter: &Address) -> u32 {
+    env.storage()
+        .instance()
+        .get::<_, MintAllowance>(&DataKey::MintAllowance(minter.clone()))
+        .filter(|a| env.ledger().timestamp() <= a.expires_at)
+        .map(|a| a.remaining)
+        .unwrap_or(0)
+}
+
+/// Deducts `count` mints from the allowance of `minter`, which lacks the minter role.
+pub fn consume_mint_allowance(
+    env: &Env,
+    minter: &Address,
+    count: u32,
+) -> Result<(), ContractError> {
+    let key = DataKey::MintAllowance(minter.clone());
+    let mut allowance: MintAllowance = env
+        .storage()
+        .instance()
+        .get(&key)
+        .filter(|a: &MintAllowance| env.ledger().timestamp() <= a.expires_at)
+        .ok_or(ContractError::NotMinter)?;
+    allowance.remaining = allowance
+        .remaining
+        .checked_sub(count)
+        .ok_or(ContractError::AllocationExhausted)?;
+    env.storage().instance().set(&key, &allowance);
+    Ok(())
+}
+
+/// Mint allocation left for a whitelisted address; `None` means unlimited.
+pub fn allocation(env: &Env, address: &Address) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::WhitelistAllocation(address.clone()))
+}
+
+/// Deducts `count` whitelist mints from `address`'s allocation. The owner and addresses
+/// without an allocation are unlimited.
+pub fn consume_allocation(env: &Env, address: &Address, count: u32) -> Result<(), ContractError> {
+    let Some(left) = allocation(env, address) else {
+        return Ok(());
+    };
+    if has_role(env, address, Role::Owner) {
+        return Ok(());
+    }
+    let left = left
+        .checked_sub(count)
+        .ok_or(ContractError::AllocationExhausted)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::WhitelistAllocation(address.clone()), &left);
+    Ok(())
+}
+
+/// In restricted-transfer mode, requires that `to` is on the transfer allowlist.
+pub fn require_transfer_allowed(env: &Env, to: &Address) -> Result<(), ContractError> {
+    let restricted: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey::TransferRestricted)
+        .unwrap_or(false);
+    let allowed: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey::TransferAllowlist(to.clone()))
+        .unwrap_or(false);
+    if restricted && !allowed {
+        return Err(ContractError::RecipientNotAllowed);
+    }
+    Ok(())
+}
+
+/// Operators on the allowlist of royalty-honoring marketplaces.
+pub fn allowed_operators(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::AllowedOperators)
+        .unwrap_or(Vec::new(env))
+}
+
+/// While the operator filter is on, requires `operator` to be on the operator allowlist.
+pub fn require_operator_allowed(env: &Env, operator: &Address) -> Result<(), ContractError> {
+    let filtered: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey::OperatorFilter)
+        .unwrap_or(false);
+    if filtered && !allowed_operators(env).contains(operator) {
+        return Err(ContractError::OperatorNotAllowed);
+    }
+    Ok(())
+}
+
+/// Adds or removes `operator` from the operator allowlist. Admin only.
+pub fn set_operator_allowed(
+    env: &Env,
+    caller: Address,
+    operator: Address,
+    allowed: bool,
+) -> Result<(), ContractError> {
+    require_admin(env, &caller)?;
+    let mut operators = allowed_operators(env);
+    match (operators.first_index_of(&operator), allowed) {
+        (None, true) => {
+            if operators.len() >= crate::utils::MAX_ALLOWED_OPERATORS {
+                return Err(ContractError::BatchTooLarge);
+            }
+            operators.push_back(operator.clone());
+        }
+        (Some(index), false) => {
+            operators.remove(index);
+        }
+        _ => {}
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::AllowedOperators, &operators);
+    crate::events::emit_operator_allowlist_updated(env, operator, allowed);
+    Ok(())
+}
+
+/// Requires that `address` is not on the denylist.
+pub fn require_not_denylisted(env: &Env, address: &Address) -> Result<(), ContractError> {
+    let denylisted: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey::Denylisted(address.clone()))
+        .unwrap_or(false);
+    if denylisted {
+        return Err(ContractError::Denylisted);
+    }
+    Ok(())
+}
+
+/// Requires that no locker contract holds a lock on `token_id`.
+pub fn require_not_locked(env: &Env, token_id: u64) -> Result<(), ContractError> {
+    if env.storage().instance().has(&DataKey::Locker(token_id)) {
+        return Err(ContractError::TokenLocked);
+    }
+    Ok(())
+}
+
+/// Requires that `token_id` is not frozen by an admin.
+pub fn require_not_frozen(env: &Env, token_id: u64) -> Result<(), ContractError> {
+    let frozen: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey::TokenFrozen(token_id))
+        .unwrap_or(false);
+    if frozen {
+        return Err(ContractError::TokenFrozen);
+    }
+    Ok(())
+}
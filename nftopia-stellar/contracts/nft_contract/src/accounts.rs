@@ -0,0 +1,27 @@
+//! Token-bound accounts: each token maps to a contract account deployed by an external
+//! registry (see the `account_registry` and `token_bound_account` crates).
+
+use crate::error::ContractError;
+use crate::storage::DataKey;
+use soroban_sdk::{Address, Env, contractclient};
+
+#[allow(dead_code)]
+#[contractclient(name = "AccountRegistryClient")]
+pub trait AccountRegistryInterface {
+    fn account(env: Env, nft: Address, token_id: u64) -> Address;
+}
+
+/// Deterministic account address of `token_id`, controlled by the token's current owner.
+/// The account may not be deployed yet; anyone can deploy it through the registry.
+pub fn account_of(env: &Env, token_id: u64) -> Result<Address, ContractError> {
+    if !crate::record::exists(env, token_id) {
+        return Err(ContractError::TokenNotFound);
+    }
+    let registry: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::AccountRegistry)
+        .ok_or(ContractError::NotFound)?;
+    Ok(AccountRegistryClient::new(env, &registry)
+        .account(&env.current_contract_address(), &token_id))
+}
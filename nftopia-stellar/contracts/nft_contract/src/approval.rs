@@ -0,0 +1,213 @@
+//! Per-token approvals, optionally time-limited.
+
+use crate::access_control;
+use crate::error::ContractError;
+use crate::events;
+use crate::storage::DataKey;
+use soroban_sdk::{Address, Env, Vec};
+
+/// Approves `approved` for `token_id` until `expires_at` (a ledger timestamp), or indefinitely.
+/// Caller must be the owner or an operator of the owner.
+pub fn approve(
+    env: &Env,
+    caller: Address,
+    approved: Address,
+    token_id: u64,
+    expires_at: Option<u64>,
+) -> Result<(), ContractError> {
+    caller.require_auth();
+    approve_authorized(env, caller, approved, token_id, expires_at)
+}
+
+/// Approves `approved` for each of `token_ids` with one authorization; the caller must be the
+/// owner or an operator of every token's owner. Emits one Approval event per token.
+pub fn approve_batch(
+    env: &Env,
+    caller: Address,
+    approved: Address,
+    token_ids: Vec<u64>,
+) -> Result<(), ContractError> {
+    if token_ids.is_empty() {
+        return Err(ContractError::InvalidAmount);
+    }
+    if token_ids.len() > crate::utils::MAX_BATCH_TRANSFER {
+        return Err(ContractError::BatchTooLarge);
+    }
+    caller.require_auth();
+    for token_id in token_ids.iter() {
+        approve_authorized(env, caller.clone(), approved.clone(), token_id, None)?;
+    }
+    Ok(())
+}
+
+/// `approve` after the caller's auth has been checked.
+pub(crate) fn approve_authorized(
+    env: &Env,
+    caller: Address,
+    approved: Address,
+    token_id: u64,
+    expires_at: Option<u64>,
+) -> Result<(), ContractError> {
+    let owner: Address = crate::record::owner(env, token_id).ok_or(ContractError::TokenNotFound)?;
+    if owner != caller {
+        let is_operator: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::OperatorApproval(owner.clone(), caller))
+            .unwrap_or(false);
+        if !is_operator {
+            return Err(ContractError::NotAuthorized);
+        }
+    }
+    access_control::require_not_denylisted(env, &owner)?;
+    access_control::require_not_denylisted(env, &approved)?;
+    access_control::require_operator_allowed(env, &approved)?;
+    access_control::require_not_frozen(env, token_id)?;
+    access_control::require_not_locked(env, token_id)?;
+
+    env.storage()
+        .instance()
+        .set(&DataKey::Approved(token_id), &approved);
+    let mut approved_tokens = approved_tokens(env, &owner);
+    if !approved_tokens.contains(token_id) {
+        approved_tokens.push_back(token_id);
+        set_approved_tokens(env, &owner, &approved_tokens);
+    }
+    match expires_at {
+        Some(expires_at) => {
+            if expires_at <= env.ledger().timestamp() {
+                return Err(ContractError::InvalidAmount);
+            }
+            env.storage()
+                .instance()
+                .set(&DataKey::ApprovalExpiry(token_id), &expires_at);
+        }
+        None => env
+            .storage()
+            .instance()
+            .remove(&DataKey::ApprovalExpiry(token_id)),
+    }
+    events::emit_approval(env, owner, approved, token_id);
+    Ok(())
+}
+
+/// The token's approved address, treating an expired approval as absent.
+pub fn approved(env: &Env, token_id: u64) -> Option<Address> {
+    let approved: Address = env.storage().instance().get(&DataKey::Approved(token_id))?;
+    let expires_at: Option<u64> = env
+        .storage()
+        .instance()
+        .get(&DataKey::ApprovalExpiry(token_id));
+    match expires_at {
+        Some(expires_at) if env.ledger().timestamp() >= expires_at => None,
+        _ => Some(approved),
+    }
+}
+
+/// Whether `spender` was approved for `token_id` but the approval has since expired.
+pub fn is_expired_for(env: &Env, spender: &Address, token_id: u64) -> bool {
+    let approved: Option<Address> = env.storage().instance().get(&DataKey::Approved(token_id));
+    approved.as_ref() == Some(spender) && self::approved(env, token_id).is_none()
+}
+
+/// Removes any approval on `owner`'s token (on transfer and burn).
+pub fn clear(env: &Env, owner: &Address, token_id: u64) {
+    env.storage()
+        .instance()
+        .remove(&DataKey::Approved(token_id));
+    env.storage()
+        .instance()
+        .remove(&DataKey::ApprovalExpiry(token_id));
+    let mut approved_tokens = approved_tokens(env, owner);
+    if let Some(index) = approved_tokens.first_index_of(token_id) {
+        approved_tokens.remove(index);
+        set_approved_tokens(env, owner, &approved_tokens);
+    }
+}
+
+/// Grants or revokes `operator` over all of `owner`'s tokens.
+pub fn set_approval_for_all(
+    env: &Env,
+    owner: Address,
+    operator: Address,
+    approved: bool,
+) -> Result<(), ContractError> {
+    owner.require_auth();
+    if approved {
+        access_control::require_not_denylisted(env, &owner)?;
+        access_control::require_not_denylisted(env, &operator)?;
+        access_control::require_operator_allowed(env, &operator)?;
+    }
+    env.storage().instance().set(
+        &DataKey::OperatorApproval(owner.clone(), operator.clone()),
+        &approved,
+    );
+    let mut operators = operators(env, &owner);
+    match (operators.first_index_of(&operator), approved) {
+        (None, true) => operators.push_back(operator.clone()),
+        (Some(index), false) => {
+            operators.remove(index);
+        }
+        _ => {}
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::OwnerOperators(owner.clone()), &operators);
+    events::emit_approval_for_all(env, owner, operator, approved);
+    Ok(())
+}
+
+/// Clears every token approval and operator approval granted by `owner`.
+pub fn revoke_all(env: &Env, owner: Address) -> Result<(), ContractError> {
+    owner.require_auth();
+    let tokens = approved_tokens(env, &owner);
+    for token_id in tokens.iter() {
+        env.storage()
+            .instance()
+            .remove(&DataKey::Approved(token_id));
+        env.storage()
+            .instance()
+            .remove(&DataKey::ApprovalExpiry(token_id));
+    }
+    let operators = operators(env, &owner);
+    for operator in operators.iter() {
+        env.storage()
+            .instance()
+            .remove(&DataKey::OperatorApproval(owner.clone(), operator));
+    }
+    env.storage()
+        .instance()
+        .remove(&DataKey::OwnerApprovedTokens(owner.clone()));
+    env.storage()
+        .instance()
+        .remove(&DataKey::OwnerOperators(owner.clone()));
+    events::emit_approvals_revoked(env, owner, tokens.len(), operators.len());
+    Ok(())
+}
+
+/// Operators currently approved by `owner`.
+pub fn operators(env: &Env, owner: &Address) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::OwnerOperators(owner.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn approved_tokens(env: &Env, owner: &Address) -> Vec<u64> {
+    env.storage()
+        .instance()
+        .get(&DataKey::OwnerApprovedTokens(owner.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_approved_tokens(env: &Env, owner: &Address, tokens: &Vec<u64>) {
+    if tokens.is_empty() {
+        env.storage()
+            .instance()
+            .remove(&DataKey::OwnerApprovedTokens(owner.clone()));
+    } else {
+        env.storage()
+            .instance()
+            .set(&DataKey::OwnerApprovedTokens(owner.clone()), tokens);
+    }
+}
@@ -0,0 +1,77 @@
+//! Mint-to-claim escrow: tokens minted for a claimant are held by the collection itself until
+//! the claimant takes them, so nobody receives a token they never asked for.
+
+use crate::error::ContractError;
+use crate::reentrancy;
+use crate::storage::DataKey;
+use crate::types::{ClaimableMint, RoyaltyInfo, TokenAttribute};
+use soroban_sdk::{Address, Env, String, Vec};
+
+/// Mints a token into escrow for `claimant`, claimable until `expires_at`. Minter role.
+/// In a content-addressed collection, a URI already held in escrow is rejected rather than
+/// reassigned to the new claimant.
+pub fn mint_claimable(
+    env: &Env,
+    caller: Address,
+    claimant: Address,
+    metadata_uri: String,
+    attributes: Vec<TokenAttribute>,
+    royalty_override: Option<RoyaltyInfo>,
+    expires_at: u64,
+) -> Result<u64, ContractError> {
+    if expires_at <= env.ledger().timestamp() {
+        return Err(ContractError::InvalidAmount);
+    }
+    crate::token::require_can_mint(env, &caller, 1)?;
+    let token_id = reentrancy::with_lock(env, || {
+        crate::token::mint_internal(
+            env,
+            caller.clone(),
+            env.current_contract_address(),
+            metadata_uri,
+            attributes,
+            royalty_override,
+        )
+    })?;
+    if claimable(env, token_id).is_some() {
+        return Err(ContractError::TokenAlreadyExists);
+    }
+    env.storage().instance().set(
+        &DataKey::Claimable(token_id),
+        &ClaimableMint {
+            claimant,
+            minter: caller,
+            expires_at,
+        },
+    );
+    Ok(token_id)
+}
+
+/// Pending escrow entry for `token_id`, if any.
+pub fn claimable(env: &Env, token_id: u64) -> Option<ClaimableMint> {
+    env.storage().instance().get(&DataKey::Claimable(token_id))
+}
+
+/// Moves an escrowed token to its claimant (before expiry) or back to its minter (after).
+pub fn claim(env: &Env, caller: Address, token_id: u64) -> Result<(), ContractError> {
+    caller.require_auth();
+    let pending = claimable(env, token_id).ok_or(ContractError::NotClaimable)?;
+    let expired = env.ledger().timestamp() > pending.expires_at;
+    if caller == pending.claimant {
+        if expired {
+            return Err(ContractError::ClaimExpired);
+        }
+    } else if caller == pending.minter {
+        if !expired {
+            return Err(ContractError::ClaimNotExpired);
+        }
+    } else {
+        return Err(ContractError::NotAuthorized);
+    }
+    env.storage()
+        .instance()
+        .remove(&DataKey::Claimable(token_id));
+    reentrancy::with_lock(env, || {
+        crate::transfer::do_transfer(env, &env.current_contract_address(), &caller, token_id)
+    })
+}
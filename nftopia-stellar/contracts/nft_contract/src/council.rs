@@ -0,0 +1,177 @@
+//! Council mode: once a council is registered, upgrades, metadata freezing, default royalty
+//! and admin changes can no longer be made directly. A member proposes the operation, and any
+//! member executes it once `threshold` current members have approved. While a timelock delay
+//! is set, an executed upgrade or default royalty proposal is queued in the timelock rather
+//! than applied, and only a `CancelOperation` proposal can drop it again.
+
+use crate::access_control;
+use crate::error::ContractError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::timelock;
+use crate::types::{Council, CouncilAction, Proposal, TimelockAction};
+use crate::utils::{MAX_COUNCIL_MEMBERS, validate_royalty_bps};
+use soroban_sdk::{Address, Env, Vec};
+
+/// The registered council, if council mode is on.
+pub fn council(env: &Env) -> Option<Council> {
+    env.storage().instance().get(&DataKey::Council)
+}
+
+/// Fails with `CouncilRequired` when the operation must go through a council proposal.
+pub fn require_no_council(env: &Env) -> Result<(), ContractError> {
+    if env.storage().instance().has(&DataKey::Council) {
+        return Err(ContractError::CouncilRequired);
+    }
+    Ok(())
+}
+
+/// Turns council mode on. Owner only; afterwards the council changes itself by proposal.
+pub fn set_council(env: &Env, members: Vec<Address>, threshold: u32) -> Result<(), ContractError> {
+    access_control::require_owner(env)?;
+    require_no_council(env)?;
+    if members.is_empty() {
+        return Err(ContractError::InvalidAmount);
+    }
+    store_council(env, members, threshold)
+}
+
+/// Stores a council of `members` needing `threshold` approvals; no members turns council
+/// mode off.
+fn store_council(env: &Env, members: Vec<Address>, threshold: u32) -> Result<(), ContractError> {
+    if members.is_empty() {
+        env.storage().instance().remove(&DataKey::Council);
+        return Ok(());
+    }
+    if members.len() > MAX_COUNCIL_MEMBERS {
+        return Err(ContractError::BatchTooLarge);
+    }
+    if threshold == 0 || threshold > members.len() {
+        return Err(ContractError::InvalidAmount);
+    }
+    for (i, member) in members.iter().enumerate() {
+        if members.first_index_of(&member) != Some(i as u32) {
+            return Err(ContractError::InvalidAmount);
+        }
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::Council, &Council { members, threshold });
+    Ok(())
+}
+
+fn require_member(env: &Env, member: &Address) -> Result<Council, ContractError> {
+    member.require_auth();
+    let council = council(env).ok_or(ContractError::NotFound)?;
+    if !council.members.contains(member) {
+        return Err(ContractError::NotCouncilMember);
+    }
+    Ok(council)
+}
+
+/// Pending proposal `proposal_id`, if any.
+pub fn proposal(env: &Env, proposal_id: u32) -> Option<Proposal> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Proposal(proposal_id))
+}
+
+/// Records `action` as a pending proposal, approved by its proposer; returns its id.
+pub fn propose(env: &Env, proposer: Address, action: CouncilAction) -> Result<u32, ContractError> {
+    require_member(env, &proposer)?;
+    if let CouncilAction::SetDefaultRoyalty(info) = &action {
+        validate_royalty_bps(info.percentage)?;
+    }
+    let proposal_id: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextProposalId)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextProposalId, &(proposal_id + 1));
+    env.storage().instance().set(
+        &DataKey::Proposal(proposal_id),
+        &Proposal {
+            action: action.clone(),
+            proposer: proposer.clone(),
+            approvals: Vec::from_array(env, [proposer.clone()]),
+        },
+    );
+    events::emit_proposal_created(env, proposal_id, proposer, action);
+    Ok(proposal_id)
+}
+
+pub fn approve(env: &Env, member: Address, proposal_id: u32) -> Result<(), ContractError> {
+    require_member(env, &member)?;
+    let mut proposal = proposal(env, proposal_id).ok_or(ContractError::NotFound)?;
+    if proposal.approvals.contains(&member) {
+        return Err(ContractError::AlreadyApproved);
+    }
+    proposal.approvals.push_back(member.clone());
+    env.storage()
+        .instance()
+        .set(&DataKey::Proposal(proposal_id), &proposal);
+    events::emit_proposal_approved(env, proposal_id, member);
+    Ok(())
+}
+
+/// Carries out an approved proposal. Only approvals from current members count.
+pub fn execute(env: &Env, member: Address, proposal_id: u32) -> Result<(), ContractError> {
+    let council = require_member(env, &member)?;
+    let proposal = proposal(env, proposal_id).ok_or(ContractError::NotFound)?;
+    let approvals = proposal
+        .approvals
+        .iter()
+        .filter(|a| council.members.contains(a))
+        .count() as u32;
+    if approvals < council.threshold {
+        return Err(ContractError::InsufficientApprovals);
+    }
+    env.storage()
+        .instance()
+        .remove(&DataKey::Proposal(proposal_id));
+    let timelocked = timelock::delay(env) > 0;
+    match proposal.action {
+        CouncilAction::Upgrade(wasm_hash) if timelocked => {
+            timelock::enqueue(
+                env,
+                member.clone(),
+                TimelockAction::Upgrade(wasm_hash),
+                true,
+            );
+        }
+        CouncilAction::Upgrade(wasm_hash) => crate::apply_upgrade(env, member.clone(), wasm_hash),
+        CouncilAction::FreezeMetadata => crate::metadata::freeze(env, member.clone()),
+        CouncilAction::SetDefaultRoyalty(info) if timelocked => {
+            let action = TimelockAction::SetDefaultRoyalty(info);
+            timelock::enqueue(env, member.clone(), action, true);
+        }
+        CouncilAction::SetDefaultRoyalty(info) => {
+            crate::royalty::store_default_royalty(env, info.recipient, info.percentage)?
+        }
+        CouncilAction::SetAdmin(admin, granted) => {
+            crate::write_admin(env, admin, granted, member.clone())
+        }
+        CouncilAction::SetCouncil(members, threshold) => store_council(env, members, threshold)?,
+        CouncilAction::CancelOperation(operation_id) => {
+            timelock::cancel_by_council(env, member.clone(), operation_id)?
+        }
+    }
+    events::emit_proposal_executed(env, proposal_id, member);
+    Ok(())
+}
+
+/// Drops a pending proposal. Only its proposer may cancel.
+pub fn cancel(env: &Env, member: Address, proposal_id: u32) -> Result<(), ContractError> {
+    require_member(env, &member)?;
+    let proposal = proposal(env, proposal_id).ok_or(ContractError::NotFound)?;
+    if proposal.proposer != member {
+        return Err(ContractError::NotAuthorized);
+    }
+    env.storage()
+        .instance()
+        .remove(&DataKey::Proposal(proposal_id));
+    events::emit_proposal_cancelled(env, proposal_id, member);
+    Ok(())
+}
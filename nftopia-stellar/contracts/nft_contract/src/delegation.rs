@@ -0,0 +1,55 @@
+//! Delegation registry: a cold wallet lets a hot wallet exercise claim/utility rights over its
+//! tokens without moving them. Delegation grants no transfer or approval rights.
+
+use crate::error::ContractError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::DelegationScope;
+use soroban_sdk::{Address, Env};
+
+/// Grants or revokes `delegate`'s rights over `owner`'s tokens within `scope`. A token scope
+/// requires `owner` to hold the token; the delegation lapses once it is transferred.
+pub fn set_delegate(
+    env: &Env,
+    owner: Address,
+    delegate: Address,
+    scope: DelegationScope,
+    enabled: bool,
+) -> Result<(), ContractError> {
+    owner.require_auth();
+    if owner == delegate {
+        return Err(ContractError::NotAuthorized);
+    }
+    if let DelegationScope::Token(token_id) = scope {
+        let current: Address =
+            crate::record::owner(env, token_id).ok_or(ContractError::TokenNotFound)?;
+        if enabled && current != owner {
+            return Err(ContractError::NotAuthorized);
+        }
+    }
+    let key = DataKey::Delegation(owner.clone(), delegate.clone(), scope.clone());
+    if enabled {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+    events::emit_delegation_updated(env, owner, delegate, scope, enabled);
+    Ok(())
+}
+
+/// Whether `delegate` holds `owner`'s delegation for `scope`. A collection-wide delegation
+/// covers every token scope.
+pub fn is_delegate(env: &Env, owner: Address, delegate: Address, scope: DelegationScope) -> bool {
+    let storage = env.storage().instance();
+    storage.has(&DataKey::Delegation(
+        owner.clone(),
+        delegate.clone(),
+        DelegationScope::Collection,
+    )) || storage.has(&DataKey::Delegation(owner, delegate, scope))
+}
+
+/// Whether `delegate` may act for the current owner of `token_id`.
+pub fn is_token_delegate(env: &Env, delegate: Address, token_id: u64) -> bool {
+    let owner: Option<Address> = crate::record::owner(env, token_id);
+    owner.is_some_and(|owner| is_delegate(env, owner, delegate, DelegationScope::Token(token_id)))
+}
@@ -0,0 +1,34 @@
+use crate::storage::DataKey;
+use crate::types::CreatorEarnings;
+use soroban_sdk::{Address, Env};
+
+/// Earnings paid out to `creator` in `currency` (all zero if none).
+pub fn earnings_of(env: &Env, creator: Address, currency: Address) -> CreatorEarnings {
+    env.storage()
+        .instance()
+        .get(&DataKey::CreatorEarnings(creator, currency))
+        .unwrap_or(CreatorEarnings {
+            primary_sales: 0,
+            royalties: 0,
+        })
+}
+
+/// Adds `amount` of `currency` just paid to `creator` to their earnings, as a primary sale or
+/// a royalty.
+pub(crate) fn record_paid(
+    env: &Env,
+    creator: Address,
+    currency: Address,
+    amount: i128,
+    primary: bool,
+) {
+    let mut earnings = earnings_of(env, creator.clone(), currency.clone());
+    if primary {
+        earnings.primary_sales = earnings.primary_sales.saturating_add(amount);
+    } else {
+        earnings.royalties = earnings.royalties.saturating_add(amount);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::CreatorEarnings(creator, currency), &earnings);
+}
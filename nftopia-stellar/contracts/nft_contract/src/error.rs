@@ -35,7 +35,7 @@ pub enum ContractError {
     MetadataFrozen = 14,
     /// Invalid token ID.
     InvalidTokenId = 15,
-    /// Role-based access denied.
+    /// Role-based access denied (generic; the role checks return the specific `Not*` codes).
     MissingRole = 16,
     /// Caller not in whitelist for minting.
     NotWhitelisted = 17,
@@ -47,4 +47,180 @@ pub enum ContractError {
     BurnNotConfirmed = 20,
     /// Arithmetic overflow or underflow.
     Overflow = 21,
+    /// Batch exceeds the maximum number of items per call.
+    BatchTooLarge = 22,
+    /// Collection has already been revealed.
+    AlreadyRevealed = 23,
+    /// Provenance hash was already committed.
+    ProvenanceAlreadySet = 24,
+    /// Address is on the collection denylist.
+    Denylisted = 25,
+    /// Token is frozen by an admin.
+    TokenFrozen = 26,
+    /// Token was transferred too recently; the transfer cooldown has not elapsed.
+    TransferCooldown = 27,
+    /// A registered transfer hook rejected or failed the transfer.
+    TransferHookRejected = 28,
+    /// The maximum number of transfer hooks is already registered.
+    TooManyHooks = 29,
+    /// Attribute exists but does not hold a numeric value.
+    AttributeNotNumeric = 30,
+    /// Too many attributes, or a trait type or value exceeds the configured length.
+    AttributeLimitExceeded = 31,
+    /// Minting would exceed the master token's total_editions.
+    EditionsExhausted = 32,
+    /// The address has used up its whitelist mint allocation.
+    AllocationExhausted = 33,
+    /// Transfers are restricted and the recipient is not on the transfer allowlist.
+    RecipientNotAllowed = 34,
+    /// The requested token id has already been used.
+    TokenIdTaken = 35,
+    /// The token is not held in claim escrow.
+    NotClaimable = 36,
+    /// The claim window has closed; only the minter can reclaim the token.
+    ClaimExpired = 37,
+    /// The minter can only reclaim once the claim window has closed.
+    ClaimNotExpired = 38,
+    /// The token is locked by a staking or quest contract.
+    TokenLocked = 39,
+    /// Caller is neither the owner nor an admin.
+    NotAdmin = 40,
+    /// Caller lacks the minter role and holds no usable mint allowance.
+    NotMinter = 41,
+    /// Caller lacks the burner role.
+    NotBurner = 42,
+    /// Caller lacks the metadata updater role.
+    NotMetadataUpdater = 43,
+    /// Caller lacks the renewer role.
+    NotRenewer = 44,
+    /// Minting is paused.
+    MintPaused = 45,
+    /// Transfers are paused.
+    TransferPaused = 46,
+    /// Burning is paused.
+    BurnPaused = 47,
+    /// The caller's approval for the token has expired.
+    ApprovalExpired = 48,
+    /// The role cannot be granted or re-parented this way (e.g. Owner).
+    InvalidRole = 49,
+    /// Council mode is on; the operation must be proposed and approved by the council.
+    CouncilRequired = 50,
+    /// Caller is not a council member.
+    NotCouncilMember = 51,
+    /// The proposal lacks the council's approval threshold.
+    InsufficientApprovals = 52,
+    /// The member has already approved this proposal.
+    AlreadyApproved = 53,
+    /// The timelock is on; the action must be queued and executed after the delay.
+    TimelockRequired = 54,
+    /// The queued operation's delay has not yet elapsed.
+    TimelockNotReady = 55,
+    /// The supply cap can only be lowered, and never below the current supply.
+    InvalidMaxSupply = 56,
+    /// The currency is not accepted for paid mints.
+    CurrencyNotAccepted = 57,
+    /// The mint escrow has not reached its threshold or deadline yet.
+    EscrowPending = 58,
+    /// The drop missed its escrow threshold; only refunds remain.
+    EscrowFailed = 59,
+    /// The drop met its escrow threshold, so there is nothing to refund.
+    EscrowSucceeded = 60,
+    /// The call would mint more tokens than the per-transaction cap.
+    MaxPerTransactionExceeded = 61,
+    /// Minting is outside the configured mint window.
+    MintNotActive = 62,
+    /// Caller does not have the relayer role.
+    NotRelayer = 63,
+    /// The operator is not on the allowlist of royalty-honoring marketplaces.
+    OperatorNotAllowed = 64,
+    /// The collection's burn policy forbids this burn.
+    BurnNotAllowed = 65,
+    /// Earlier primary-sale proceeds must be withdrawn before the escrow is enabled.
+    ProceedsNotWithdrawn = 66,
+    /// An unrevealed collection must commit its provenance hash before minting or revealing.
+    ProvenanceNotSet = 67,
+    /// This migration step needs minting, transfers and burns paused.
+    MigrationNotPaused = 68,
+    /// A composed token URI (base URI, token id and suffix) would exceed 256 bytes.
+    UriTooLong = 69,
+    /// Tokens were minted under the current URI suffix, so it can no longer change.
+    UriSuffixInUse = 70,
+}
+
+/// Human-readable description of the error with discriminant `code`, for debugging failed
+/// invocations from the CLI.
+pub fn message(code: u32) -> &'static str {
+    match code {
+        1 => "caller is not authorized",
+        2 => "contract already initialized",
+        3 => "not found",
+        4 => "insufficient balance",
+        5 => "invalid amount or parameter",
+        6 => "max supply exceeded",
+        7 => "contract paused",
+        8 => "invalid royalty",
+        9 => "invalid recipient",
+        10 => "token already exists",
+        11 => "token not found",
+        12 => "not owner, approved or operator",
+        13 => "receiver rejected the transfer",
+        14 => "metadata frozen",
+        15 => "invalid token id",
+        16 => "missing role",
+        17 => "not whitelisted",
+        18 => "reentrancy detected",
+        19 => "batch length mismatch",
+        20 => "burn not confirmed",
+        21 => "arithmetic overflow",
+        22 => "batch too large",
+        23 => "already revealed",
+        24 => "provenance already set",
+        25 => "address denylisted",
+        26 => "token frozen",
+        27 => "transfer cooldown active",
+        28 => "transfer hook rejected",
+        29 => "too many hooks",
+        30 => "attribute not numeric",
+        31 => "attribute limit exceeded",
+        32 => "editions exhausted",
+        33 => "allocation exhausted",
+        34 => "recipient not allowed",
+        35 => "token id taken",
+        36 => "token not claimable",
+        37 => "claim expired",
+        38 => "claim not expired",
+        39 => "token locked",
+        40 => "not admin",
+        41 => "not minter",
+        42 => "not burner",
+        43 => "not metadata updater",
+        44 => "not renewer",
+        45 => "minting paused",
+        46 => "transfers paused",
+        47 => "burning paused",
+        48 => "approval expired",
+        49 => "invalid role",
+        50 => "council approval required",
+        51 => "not a council member",
+        52 => "insufficient approvals",
+        53 => "already approved",
+        54 => "timelock required",
+        55 => "timelock not ready",
+        56 => "invalid max supply",
+        57 => "currency not accepted",
+        58 => "mint escrow pending",
+        59 => "mint escrow failed",
+        60 => "mint escrow succeeded",
+        61 => "max per transaction exceeded",
+        62 => "mint not active",
+        63 => "caller is not a relayer",
+        64 => "operator not allowed",
+        65 => "burn not allowed",
+        66 => "proceeds not withdrawn",
+        67 => "provenance not committed",
+        68 => "migration requires minting, transfers and burns paused",
+        69 => "composed uri too long",
+        70 => "uri suffix in use",
+        _ => "unknown error",
+    }
 }
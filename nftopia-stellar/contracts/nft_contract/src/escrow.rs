@@ -0,0 +1,169 @@
+//! Fair-launch escrow: paid-mint proceeds stay locked until the drop sells `threshold` tokens.
+//! If the deadline passes first, holders of escrowed tokens can burn them for a refund and
+//! only payments no longer backing a live token can be withdrawn; otherwise the proceeds are
+//! released to `withdraw`.
+
+use crate::access_control;
+use crate::error::ContractError;
+use crate::events;
+use crate::reentrancy;
+use crate::storage::DataKey;
+use crate::types::{EscrowConfig, EscrowPayment, EscrowStatus};
+use soroban_sdk::{Address, Env, token};
+
+pub fn config(env: &Env) -> Option<EscrowConfig> {
+    env.storage().instance().get(&DataKey::MintEscrow)
+}
+
+/// Paid mints sold under the escrow so far.
+pub fn sold(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::EscrowSold)
+        .unwrap_or(0)
+}
+
+/// Where the drop stands; `None` when no escrow is configured.
+pub fn status(env: &Env) -> Option<EscrowStatus> {
+    let config = config(env)?;
+    Some(if sold(env) >= config.threshold {
+        EscrowStatus::Succeeded
+    } else if env.ledger().timestamp() >= config.deadline {
+        EscrowStatus::Failed
+    } else {
+        EscrowStatus::Pending
+    })
+}
+
+/// Holds paid-mint proceeds until `threshold` paid mints by `deadline`. Must be set before the
+/// first escrowed sale, with earlier proceeds in every accepted currency already withdrawn,
+/// and cannot change afterwards. Admin only.
+pub fn set_mint_escrow(
+    env: &Env,
+    caller: Address,
+    threshold: u64,
+    deadline: u64,
+) -> Result<(), ContractError> {
+    access_control::require_admin(env, &caller)?;
+    if sold(env) > 0 {
+        return Err(ContractError::EscrowPending);
+    }
+    if threshold == 0 || deadline <= env.ledger().timestamp() {
+        return Err(ContractError::InvalidAmount);
+    }
+    if crate::sale::mint_prices(env)
+        .iter()
+        .any(|p| crate::sale::proceeds(env, p.currency) != 0)
+    {
+        return Err(ContractError::ProceedsNotWithdrawn);
+    }
+    env.storage().instance().set(
+        &DataKey::MintEscrow,
+        &EscrowConfig {
+            threshold,
+            deadline,
+        },
+    );
+    events::emit_mint_escrow_set(env, threshold, deadline);
+    Ok(())
+}
+
+/// Fails once the drop has failed; paid mints are closed from then on.
+pub(crate) fn require_not_failed(env: &Env) -> Result<(), ContractError> {
+    match status(env) {
+        Some(EscrowStatus::Failed) => Err(ContractError::EscrowFailed),
+        _ => Ok(()),
+    }
+}
+
+/// Payments in `currency` still backing live escrowed tokens.
+pub fn held(env: &Env, currency: Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::EscrowHeld(currency))
+        .unwrap_or(0)
+}
+
+/// Proceeds in `currency` that `withdraw` must leave in place. Nothing is held without an
+/// escrow or once the drop succeeded; after a failure only the refundable payments are. Fails
+/// while the escrow is pending.
+pub(crate) fn locked(env: &Env, currency: Address) -> Result<i128, ContractError> {
+    match status(env) {
+        None | Some(EscrowStatus::Succeeded) => Ok(0),
+        Some(EscrowStatus::Pending) => Err(ContractError::EscrowPending),
+        Some(EscrowStatus::Failed) => Ok(held(env, currency)),
+    }
+}
+
+/// Records the payment for a paid mint while the escrow is still pending, so it can be
+/// refunded if the drop fails.
+pub(crate) fn record_sale(env: &Env, token_id: u64, currency: Address, amount: i128) {
+    if status(env) != Some(EscrowStatus::Pending) {
+        return;
+    }
+    let held = held(env, currency.clone());
+    env.storage()
+        .instance()
+        .set(&DataKey::EscrowHeld(currency.clone()), &(held + amount));
+    env.storage().instance().set(
+        &DataKey::EscrowPayment(token_id),
+        &EscrowPayment { currency, amount },
+    );
+    env.storage()
+        .instance()
+        .set(&DataKey::EscrowSold, &(sold(env) + 1));
+}
+
+/// Drops the payment held for `token_id`, which is forfeited unless the caller refunds it.
+pub(crate) fn release(env: &Env, token_id: u64) {
+    if let Some(payment) = payment(env, token_id) {
+        let held = held(env, payment.currency.clone());
+        env.storage().instance().set(
+            &DataKey::EscrowHeld(payment.currency),
+            &(held - payment.amount),
+        );
+        env.storage()
+            .instance()
+            .remove(&DataKey::EscrowPayment(token_id));
+    }
+}
+
+/// Payment held in escrow for `token_id`, if any.
+pub fn payment(env: &Env, token_id: u64) -> Option<EscrowPayment> {
+    env.storage()
+        .instance()
+        .get(&DataKey::EscrowPayment(token_id))
+}
+
+/// Burns `token_id` and returns its mint payment to `holder`, its current owner. Only after
+/// the drop has failed.
+pub fn refund(env: &Env, holder: Address, token_id: u64) -> Result<i128, ContractError> {
+    holder.require_auth();
+    match status(env) {
+        Some(EscrowStatus::Failed) => {}
+        Some(EscrowStatus::Succeeded) => return Err(ContractError::EscrowSucceeded),
+        _ => return Err(ContractError::EscrowPending),
+    }
+    let payment = payment(env, token_id).ok_or(ContractError::NotFound)?;
+    let owner: Address = crate::record::owner(env, token_id).ok_or(ContractError::TokenNotFound)?;
+    if owner != holder {
+        return Err(ContractError::NotAuthorized);
+    }
+    access_control::require_not_locked(env, token_id)?;
+    reentrancy::with_lock(env, || {
+        crate::token::destroy_token(env, holder.clone(), token_id);
+        let proceeds = crate::sale::proceeds(env, payment.currency.clone());
+        env.storage().instance().set(
+            &DataKey::Proceeds(payment.currency.clone()),
+            &(proceeds - payment.amount),
+        );
+        token::Client::new(env, &payment.currency).transfer(
+            &env.current_contract_address(),
+            &holder,
+            &payment.amount,
+        );
+        Ok(())
+    })?;
+    events::emit_mint_refunded(env, holder, token_id, payment.currency, payment.amount);
+    Ok(payment.amount)
+}
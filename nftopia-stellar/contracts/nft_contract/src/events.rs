@@ -1,20 +1,19 @@
-use soroban_sdk::{Address, Env, contractevent};
+use crate::types::{BurnPolicy, CouncilAction, Role, TimelockAction};
+use soroban_sdk::{Address, BytesN, Env, Vec, contractevent};
 
-/// Transfer event (ERC-721 equivalent).
-#[contractevent]
-#[derive(Clone, Debug)]
-pub struct Transfer {
-    pub from: Address,
-    pub to: Address,
-    pub token_id: u64,
-}
+// The core token events (`Transfer`, `Mint`, `Burn`) are defined in `nft_interface`, which
+// the factory's collections emit as well.
+pub use nft_interface::{Burn, Mint, Transfer};
 
 /// Approval event.
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct Approval {
+    #[topic]
     pub owner: Address,
+    #[topic]
     pub approved: Address,
+    #[topic]
     pub token_id: u64,
 }
 
@@ -27,21 +26,60 @@ pub struct ApprovalForAll {
     pub approved: bool,
 }
 
-/// Mint event.
+/// Numbered copies minted from a master token; ids run from `first_token_id` for `count` tokens.
 #[contractevent]
 #[derive(Clone, Debug)]
-pub struct Mint {
+pub struct EditionsMinted {
+    pub minter: Address,
+    pub master_token_id: u64,
     pub to: Address,
+    pub first_token_id: u64,
+    pub count: u32,
+}
+
+/// `old_token_id` was burned and replaced by `new_token_id` for the same owner.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Evolved {
+    pub owner: Address,
+    pub old_token_id: u64,
+    pub new_token_id: u64,
+}
+
+/// `locker` locked `owner`'s token in place.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Locked {
+    pub owner: Address,
     pub token_id: u64,
-    pub creator: Address,
+    pub locker: Address,
 }
 
-/// Burn event.
+/// `locker` released its lock on a token.
 #[contractevent]
 #[derive(Clone, Debug)]
-pub struct Burn {
-    pub from: Address,
+pub struct Unlocked {
+    pub locker: Address,
+    pub token_id: u64,
+}
+
+/// Subscription token renewed until `valid_until`.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Renewed {
+    pub token_id: u64,
+    pub valid_until: u64,
+}
+
+/// Royalty settled through pay_royalty.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RoyaltyPaid {
     pub token_id: u64,
+    pub payer: Address,
+    pub recipient: Address,
+    pub currency: Address,
+    pub amount: i128,
 }
 
 /// Royalty info updated.
@@ -53,6 +91,31 @@ pub struct RoyaltyUpdated {
     pub percentage: u32,
 }
 
+/// Owner offered a token to `to`, pending acceptance.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct TransferOffered {
+    pub from: Address,
+    pub to: Address,
+    pub token_id: u64,
+}
+
+/// Pending transfer offer withdrawn by the owner or declined by the recipient.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct TransferOfferCancelled {
+    pub by: Address,
+    pub token_id: u64,
+}
+
+/// Token royalty override removed; the collection default applies again.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RoyaltyReset {
+    pub token_id: u64,
+    pub by: Address,
+}
+
 /// Metadata frozen.
 #[contractevent]
 #[derive(Clone, Debug)]
@@ -67,6 +130,13 @@ pub struct BaseUriUpdated {
     pub base_uri: soroban_sdk::String,
 }
 
+/// Collection-level contract URI updated.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ContractUriUpdated {
+    pub uri: soroban_sdk::String,
+}
+
 /// Token URI updated.
 #[contractevent]
 #[derive(Clone, Debug)]
@@ -75,6 +145,353 @@ pub struct TokenUriUpdated {
     pub uri: soroban_sdk::String,
 }
 
+/// Airdrop summary. Token IDs run sequentially from `first_token_id` in recipient order.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Airdrop {
+    pub minter: Address,
+    pub first_token_id: u64,
+    pub recipients: Vec<Address>,
+}
+
+/// Provenance hash committed.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ProvenanceCommitted {
+    pub hash: BytesN<32>,
+}
+
+/// Collection revealed.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Revealed {
+    pub by: Address,
+    pub base_uri: soroban_sdk::String,
+    pub provenance_hash: Option<BytesN<32>>,
+}
+
+/// Token attribute set or changed.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct AttributeUpdated {
+    pub token_id: u64,
+    pub trait_type: soroban_sdk::String,
+    pub value: soroban_sdk::String,
+}
+
+/// Token attribute removed.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct AttributeRemoved {
+    pub token_id: u64,
+    pub trait_type: soroban_sdk::String,
+}
+
+/// Owner cleared all of their token and operator approvals.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ApprovalsRevoked {
+    pub owner: Address,
+    pub tokens: u32,
+    pub operators: u32,
+}
+
+/// Delegation granted or revoked.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct DelegationUpdated {
+    pub owner: Address,
+    pub delegate: Address,
+    pub scope: crate::types::DelegationScope,
+    pub enabled: bool,
+}
+
+/// Address added to or removed from the denylist.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct DenylistUpdated {
+    pub address: Address,
+    pub denylisted: bool,
+}
+
+/// Token frozen or unfrozen by an admin.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct TokenFrozen {
+    pub token_id: u64,
+    pub frozen: bool,
+}
+
+/// Contract code replaced with a new WASM.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Upgraded {
+    pub by: Address,
+    pub wasm_hash: BytesN<32>,
+}
+
+/// Storage migrated to a new schema version.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Migrated {
+    pub schema_version: u32,
+}
+
+/// Role granted to an account.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RoleGranted {
+    #[topic]
+    pub role: Role,
+    #[topic]
+    pub account: Address,
+    pub by: Address,
+}
+
+/// Role revoked from an account.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RoleRevoked {
+    #[topic]
+    pub role: Role,
+    #[topic]
+    pub account: Address,
+    pub by: Address,
+}
+
+/// Fair-launch escrow configured for paid mints.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct MintEscrowSet {
+    pub threshold: u64,
+    pub deadline: u64,
+}
+
+/// An escrowed token was burned and its mint payment refunded to its holder.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct MintRefunded {
+    #[topic]
+    pub holder: Address,
+    #[topic]
+    pub token_id: u64,
+    pub currency: Address,
+    pub amount: i128,
+}
+
+/// USD price feed for paid mints set (`feed` is `None` when cleared).
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct PriceOracleUpdated {
+    pub feed: Option<Address>,
+    pub usd_price: i128,
+    pub max_age: u64,
+}
+
+/// Paid-mint price changed.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct MintPriceUpdated {
+    pub price: i128,
+    pub currency: Address,
+}
+
+/// Primary-sale proceeds paid out across the payout splits.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ProceedsWithdrawn {
+    pub currency: Address,
+    pub amount: i128,
+}
+
+/// A relayer minted `token_id` to `to`, its true recipient, paid for by `payer`.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RelayedMint {
+    #[topic]
+    pub relayer: Address,
+    #[topic]
+    pub to: Address,
+    pub payer: Address,
+    pub token_id: u64,
+}
+
+/// Mint window rescheduled; `None` leaves that side open.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct MintWindowUpdated {
+    pub start: Option<u64>,
+    pub end: Option<u64>,
+}
+
+/// Per-transaction mint cap changed (`None` when removed).
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct MaxPerTransactionUpdated {
+    pub max: Option<u32>,
+}
+
+/// Supply cap lowered (or first set on an uncapped collection).
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct MaxSupplyReduced {
+    pub previous: Option<u64>,
+    pub max_supply: u64,
+}
+
+/// The owner gave up ownership for good.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct OwnershipRenounced {
+    pub previous_owner: Address,
+}
+
+/// Pause state changed; carries the resulting state of every operation.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Paused {
+    pub by: Address,
+    pub mint: bool,
+    pub transfer: bool,
+    pub burn: bool,
+}
+
+/// Address added to or removed from the mint whitelist; `allocation` is its mint cap, if any.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct WhitelistUpdated {
+    #[topic]
+    pub address: Address,
+    pub allowed: bool,
+    pub allocation: Option<u32>,
+}
+
+/// Whitelist-only minting switched on or off.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct WhitelistOnlyMintUpdated {
+    pub enabled: bool,
+}
+
+/// Council proposal created.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ProposalCreated {
+    #[topic]
+    pub proposal_id: u32,
+    pub proposer: Address,
+    pub action: CouncilAction,
+}
+
+/// Council member approved a proposal.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ProposalApproved {
+    #[topic]
+    pub proposal_id: u32,
+    pub member: Address,
+}
+
+/// Council proposal executed.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ProposalExecuted {
+    #[topic]
+    pub proposal_id: u32,
+    pub member: Address,
+}
+
+/// Council proposal cancelled by its proposer.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ProposalCancelled {
+    #[topic]
+    pub proposal_id: u32,
+    pub member: Address,
+}
+
+/// Sensitive action queued behind the timelock; executable from `eta`.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct OperationQueued {
+    #[topic]
+    pub operation_id: u32,
+    pub queued_by: Address,
+    pub action: TimelockAction,
+    pub eta: u64,
+}
+
+/// Timelock delay changed; 0 turns the timelock off.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct TimelockDelayUpdated {
+    pub delay: u64,
+}
+
+/// Timelocked operation executed.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct OperationExecuted {
+    #[topic]
+    pub operation_id: u32,
+    pub by: Address,
+}
+
+/// Timelocked operation cancelled.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct OperationCancelled {
+    #[topic]
+    pub operation_id: u32,
+    pub by: Address,
+}
+
+/// Stale entries were removed by `collect_garbage`.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct GarbageCollected {
+    pub removed: u32,
+}
+
+/// Burn policy changed.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct BurnPolicyUpdated {
+    pub policy: BurnPolicy,
+}
+
+/// Operator filter turned on or off.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct OperatorFilterUpdated {
+    pub by: Address,
+    pub enabled: bool,
+}
+
+/// A marketplace was added to or removed from the operator allowlist.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct OperatorAllowlistUpdated {
+    #[topic]
+    pub operator: Address,
+    pub allowed: bool,
+}
+
+/// Fallback royalty registry set (`None` when cleared).
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RoyaltyRegistryUpdated {
+    pub registry: Option<Address>,
+}
+
+/// Collection-wide default royalty changed.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RoyaltyDefaultUpdated {
+    pub recipient: Address,
+    pub percentage: u32,
+}
+
 pub fn emit_transfer(env: &Env, from: Address, to: Address, token_id: u64) {
     Transfer { from, to, token_id }.publish(env);
 }
@@ -110,6 +527,66 @@ pub fn emit_burn(env: &Env, from: Address, token_id: u64) {
     Burn { from, token_id }.publish(env);
 }
 
+pub fn emit_evolved(env: &Env, owner: Address, old_token_id: u64, new_token_id: u64) {
+    Evolved {
+        owner,
+        old_token_id,
+        new_token_id,
+    }
+    .publish(env);
+}
+
+pub fn emit_transfer_offered(env: &Env, from: Address, to: Address, token_id: u64) {
+    TransferOffered { from, to, token_id }.publish(env);
+}
+
+pub fn emit_transfer_offer_cancelled(env: &Env, by: Address, token_id: u64) {
+    TransferOfferCancelled { by, token_id }.publish(env);
+}
+
+pub fn emit_locked(env: &Env, owner: Address, token_id: u64, locker: Address) {
+    Locked {
+        owner,
+        token_id,
+        locker,
+    }
+    .publish(env);
+}
+
+pub fn emit_unlocked(env: &Env, locker: Address, token_id: u64) {
+    Unlocked { locker, token_id }.publish(env);
+}
+
+pub fn emit_renewed(env: &Env, token_id: u64, valid_until: u64) {
+    Renewed {
+        token_id,
+        valid_until,
+    }
+    .publish(env);
+}
+
+pub fn emit_royalty_reset(env: &Env, token_id: u64, by: Address) {
+    RoyaltyReset { token_id, by }.publish(env);
+}
+
+pub fn emit_royalty_paid(
+    env: &Env,
+    token_id: u64,
+    payer: Address,
+    recipient: Address,
+    currency: Address,
+    amount: i128,
+) {
+    RoyaltyPaid {
+        token_id,
+        payer,
+        recipient,
+        currency,
+        amount,
+    }
+    .publish(env);
+}
+
 pub fn emit_royalty_updated(env: &Env, token_id: u64, recipient: Address, percentage: u32) {
     RoyaltyUpdated {
         token_id,
@@ -127,6 +604,318 @@ pub fn emit_base_uri_updated(env: &Env, base_uri: soroban_sdk::String) {
     BaseUriUpdated { base_uri }.publish(env);
 }
 
+pub fn emit_contract_uri_updated(env: &Env, uri: soroban_sdk::String) {
+    ContractUriUpdated { uri }.publish(env);
+}
+
 pub fn emit_token_uri_updated(env: &Env, token_id: u64, uri: soroban_sdk::String) {
     TokenUriUpdated { token_id, uri }.publish(env);
 }
+
+pub fn emit_airdrop(env: &Env, minter: Address, first_token_id: u64, recipients: Vec<Address>) {
+    Airdrop {
+        minter,
+        first_token_id,
+        recipients,
+    }
+    .publish(env);
+}
+
+pub fn emit_editions_minted(
+    env: &Env,
+    minter: Address,
+    master_token_id: u64,
+    to: Address,
+    first_token_id: u64,
+    count: u32,
+) {
+    EditionsMinted {
+        minter,
+        master_token_id,
+        to,
+        first_token_id,
+        count,
+    }
+    .publish(env);
+}
+
+pub fn emit_provenance_committed(env: &Env, hash: BytesN<32>) {
+    ProvenanceCommitted { hash }.publish(env);
+}
+
+pub fn emit_revealed(
+    env: &Env,
+    by: Address,
+    base_uri: soroban_sdk::String,
+    provenance_hash: Option<BytesN<32>>,
+) {
+    Revealed {
+        by,
+        base_uri,
+        provenance_hash,
+    }
+    .publish(env);
+}
+
+pub fn emit_attribute_updated(
+    env: &Env,
+    token_id: u64,
+    trait_type: soroban_sdk::String,
+    value: soroban_sdk::String,
+) {
+    AttributeUpdated {
+        token_id,
+        trait_type,
+        value,
+    }
+    .publish(env);
+}
+
+pub fn emit_attribute_removed(env: &Env, token_id: u64, trait_type: soroban_sdk::String) {
+    AttributeRemoved {
+        token_id,
+        trait_type,
+    }
+    .publish(env);
+}
+
+pub fn emit_approvals_revoked(env: &Env, owner: Address, tokens: u32, operators: u32) {
+    ApprovalsRevoked {
+        owner,
+        tokens,
+        operators,
+    }
+    .publish(env);
+}
+
+pub fn emit_delegation_updated(
+    env: &Env,
+    owner: Address,
+    delegate: Address,
+    scope: crate::types::DelegationScope,
+    enabled: bool,
+) {
+    DelegationUpdated {
+        owner,
+        delegate,
+        scope,
+        enabled,
+    }
+    .publish(env);
+}
+
+pub fn emit_denylist_updated(env: &Env, address: Address, denylisted: bool) {
+    DenylistUpdated {
+        address,
+        denylisted,
+    }
+    .publish(env);
+}
+
+pub fn emit_token_frozen(env: &Env, token_id: u64, frozen: bool) {
+    TokenFrozen { token_id, frozen }.publish(env);
+}
+
+pub fn emit_upgraded(env: &Env, by: Address, wasm_hash: BytesN<32>) {
+    Upgraded { by, wasm_hash }.publish(env);
+}
+
+pub fn emit_migrated(env: &Env, schema_version: u32) {
+    Migrated { schema_version }.publish(env);
+}
+
+pub fn emit_role_updated(env: &Env, role: Role, account: Address, by: Address, granted: bool) {
+    if granted {
+        RoleGranted { role, account, by }.publish(env);
+    } else {
+        RoleRevoked { role, account, by }.publish(env);
+    }
+}
+
+pub fn emit_paused(env: &Env, by: Address, mint: bool, transfer: bool, burn: bool) {
+    Paused {
+        by,
+        mint,
+        transfer,
+        burn,
+    }
+    .publish(env);
+}
+
+pub fn emit_whitelist_updated(env: &Env, address: Address, allowed: bool, allocation: Option<u32>) {
+    WhitelistUpdated {
+        address,
+        allowed,
+        allocation,
+    }
+    .publish(env);
+}
+
+pub fn emit_whitelist_only_mint_updated(env: &Env, enabled: bool) {
+    WhitelistOnlyMintUpdated { enabled }.publish(env);
+}
+
+pub fn emit_royalty_default_updated(env: &Env, recipient: Address, percentage: u32) {
+    RoyaltyDefaultUpdated {
+        recipient,
+        percentage,
+    }
+    .publish(env);
+}
+
+pub fn emit_proposal_created(
+    env: &Env,
+    proposal_id: u32,
+    proposer: Address,
+    action: CouncilAction,
+) {
+    ProposalCreated {
+        proposal_id,
+        proposer,
+        action,
+    }
+    .publish(env);
+}
+
+pub fn emit_proposal_approved(env: &Env, proposal_id: u32, member: Address) {
+    ProposalApproved {
+        proposal_id,
+        member,
+    }
+    .publish(env);
+}
+
+pub fn emit_proposal_executed(env: &Env, proposal_id: u32, member: Address) {
+    ProposalExecuted {
+        proposal_id,
+        member,
+    }
+    .publish(env);
+}
+
+pub fn emit_proposal_cancelled(env: &Env, proposal_id: u32, member: Address) {
+    ProposalCancelled {
+        proposal_id,
+        member,
+    }
+    .publish(env);
+}
+
+pub fn emit_operation_queued(
+    env: &Env,
+    operation_id: u32,
+    queued_by: Address,
+    action: TimelockAction,
+    eta: u64,
+) {
+    OperationQueued {
+        operation_id,
+        queued_by,
+        action,
+        eta,
+    }
+    .publish(env);
+}
+
+pub fn emit_timelock_delay_updated(env: &Env, delay: u64) {
+    TimelockDelayUpdated { delay }.publish(env);
+}
+
+pub fn emit_operation_executed(env: &Env, operation_id: u32, by: Address) {
+    OperationExecuted { operation_id, by }.publish(env);
+}
+
+pub fn emit_operation_cancelled(env: &Env, operation_id: u32, by: Address) {
+    OperationCancelled { operation_id, by }.publish(env);
+}
+
+pub fn emit_ownership_renounced(env: &Env, previous_owner: Address) {
+    OwnershipRenounced { previous_owner }.publish(env);
+}
+
+pub fn emit_max_supply_reduced(env: &Env, previous: Option<u64>, max_supply: u64) {
+    MaxSupplyReduced {
+        previous,
+        max_supply,
+    }
+    .publish(env);
+}
+
+pub fn emit_mint_price_updated(env: &Env, price: i128, currency: Address) {
+    MintPriceUpdated { price, currency }.publish(env);
+}
+
+pub fn emit_proceeds_withdrawn(env: &Env, currency: Address, amount: i128) {
+    ProceedsWithdrawn { currency, amount }.publish(env);
+}
+
+pub fn emit_price_oracle_updated(env: &Env, feed: Option<Address>, usd_price: i128, max_age: u64) {
+    PriceOracleUpdated {
+        feed,
+        usd_price,
+        max_age,
+    }
+    .publish(env);
+}
+
+pub fn emit_mint_escrow_set(env: &Env, threshold: u64, deadline: u64) {
+    MintEscrowSet {
+        threshold,
+        deadline,
+    }
+    .publish(env);
+}
+
+pub fn emit_mint_refunded(
+    env: &Env,
+    holder: Address,
+    token_id: u64,
+    currency: Address,
+    amount: i128,
+) {
+    MintRefunded {
+        holder,
+        token_id,
+        currency,
+        amount,
+    }
+    .publish(env);
+}
+
+pub fn emit_max_per_transaction_updated(env: &Env, max: Option<u32>) {
+    MaxPerTransactionUpdated { max }.publish(env);
+}
+
+pub fn emit_mint_window_updated(env: &Env, start: Option<u64>, end: Option<u64>) {
+    MintWindowUpdated { start, end }.publish(env);
+}
+
+pub fn emit_relayed_mint(env: &Env, relayer: Address, to: Address, payer: Address, token_id: u64) {
+    RelayedMint {
+        relayer,
+        to,
+        payer,
+        token_id,
+    }
+    .publish(env);
+}
+
+pub fn emit_royalty_registry_updated(env: &Env, registry: Option<Address>) {
+    RoyaltyRegistryUpdated { registry }.publish(env);
+}
+
+pub fn emit_operator_filter_updated(env: &Env, by: Address, enabled: bool) {
+    OperatorFilterUpdated { by, enabled }.publish(env);
+}
+
+pub fn emit_operator_allowlist_updated(env: &Env, operator: Address, allowed: bool) {
+    OperatorAllowlistUpdated { operator, allowed }.publish(env);
+}
+
+pub fn emit_burn_policy_updated(env: &Env, policy: BurnPolicy) {
+    BurnPolicyUpdated { policy }.publish(env);
+}
+
+pub fn emit_garbage_collected(env: &Env, removed: u32) {
+    GarbageCollected { removed }.publish(env);
+}
@@ -0,0 +1,87 @@
+//! Registry of external transfer hooks (see the `nft_transfer_hook` crate).
+
+use crate::error::ContractError;
+use crate::storage::DataKey;
+use nft_transfer_hook::TransferHookClient;
+use soroban_sdk::{Address, Env, Vec};
+
+/// Maximum registered hooks, keeping every transfer within Soroban budget.
+pub const MAX_TRANSFER_HOOKS: u32 = 5;
+
+pub fn hooks(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::TransferHooks)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Registers `hook`; adding an already registered hook is a no-op.
+pub fn add(env: &Env, hook: Address) -> Result<(), ContractError> {
+    let mut hooks = hooks(env);
+    if hooks.contains(&hook) {
+        return Ok(());
+    }
+    if hooks.len() >= MAX_TRANSFER_HOOKS {
+        return Err(ContractError::TooManyHooks);
+    }
+    hooks.push_back(hook);
+    env.storage()
+        .instance()
+        .set(&DataKey::TransferHooks, &hooks);
+    Ok(())
+}
+
+pub fn remove(env: &Env, hook: &Address) {
+    let mut hooks = hooks(env);
+    if let Some(index) = hooks.first_index_of(hook) {
+        hooks.remove(index);
+        env.storage()
+            .instance()
+            .set(&DataKey::TransferHooks, &hooks);
+    }
+}
+
+/// Calls `before_transfer` on every hook. Any failure, including a hook that does not
+/// implement the interface, blocks the transfer.
+pub fn before_transfer(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    token_id: u64,
+) -> Result<(), ContractError> {
+    let collection = env.current_contract_address();
+    for hook in hooks(env).iter() {
+        let result = TransferHookClient::new(env, &hook).try_before_transfer(
+            &collection,
+            from,
+            to,
+            &token_id,
+        );
+        if !matches!(result, Ok(Ok(()))) {
+            return Err(ContractError::TransferHookRejected);
+        }
+    }
+    Ok(())
+}
+
+/// Calls `after_transfer` on every hook; any failure reverts the transfer.
+pub fn after_transfer(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    token_id: u64,
+) -> Result<(), ContractError> {
+    let collection = env.current_contract_address();
+    for hook in hooks(env).iter() {
+        let result = TransferHookClient::new(env, &hook).try_after_transfer(
+            &collection,
+            from,
+            to,
+            &token_id,
+        );
+        if !matches!(result, Ok(Ok(()))) {
+            return Err(ContractError::TransferHookRejected);
+        }
+    }
+    Ok(())
+}
@@ -1,11 +1,61 @@
 //! Contract interface identifiers (ERC-165 equivalent for Stellar).
-//! Interface IDs can be used by marketplaces/wallets to detect contract capabilities.
+//! Interface IDs can be used by marketplaces/wallets to detect contract capabilities; the
+//! constants are defined in the `nft_interface` crate.
+//! Supported IDs live in instance storage: the core set is registered at initialize and
+//! upgrade, and opt-in extensions register theirs when they are enabled.
 
-/// Interface ID for core NFT (ERC-721 equivalent).
-pub const INTERFACE_ID_NFT: u32 = 0x80ac58cd;
+use crate::storage::DataKey;
+use soroban_sdk::{Env, Vec};
 
-/// Interface ID for royalty info (EIP-2981 equivalent).
-pub const INTERFACE_ID_ROYALTY: u32 = 0x2a55205a;
+pub use nft_interface::{
+    INTERFACE_ID_CLAIMABLE, INTERFACE_ID_EDITIONS, INTERFACE_ID_EXPIRABLE, INTERFACE_ID_LOCKABLE,
+    INTERFACE_ID_METADATA, INTERFACE_ID_NFT, INTERFACE_ID_ROYALTY, INTERFACE_ID_TOKEN_BOUND,
+};
 
-/// Interface ID for metadata.
-pub const INTERFACE_ID_METADATA: u32 = 0x5b5e139f;
+/// Interfaces every collection supports.
+const CORE_INTERFACES: [u32; 7] = [
+    INTERFACE_ID_NFT,
+    INTERFACE_ID_ROYALTY,
+    INTERFACE_ID_METADATA,
+    INTERFACE_ID_LOCKABLE,
+    INTERFACE_ID_EXPIRABLE,
+    INTERFACE_ID_EDITIONS,
+    INTERFACE_ID_CLAIMABLE,
+];
+
+fn registered(env: &Env) -> Vec<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Interfaces)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Whether `interface_id` has been registered.
+pub fn supports(env: &Env, interface_id: u32) -> bool {
+    registered(env).contains(interface_id)
+}
+
+/// Registers `interface_id`; a no-op if already present.
+pub fn register(env: &Env, interface_id: u32) {
+    let mut ids = registered(env);
+    if !ids.contains(interface_id) {
+        ids.push_back(interface_id);
+        env.storage().instance().set(&DataKey::Interfaces, &ids);
+    }
+}
+
+/// Removes `interface_id` when the extension providing it is disabled.
+pub fn deregister(env: &Env, interface_id: u32) {
+    let mut ids = registered(env);
+    if let Some(index) = ids.first_index_of(interface_id) {
+        ids.remove(index);
+        env.storage().instance().set(&DataKey::Interfaces, &ids);
+    }
+}
+
+/// Registers the core interfaces (at initialize and upgrade).
+pub fn register_core(env: &Env) {
+    for interface_id in CORE_INTERFACES {
+        register(env, interface_id);
+    }
+}
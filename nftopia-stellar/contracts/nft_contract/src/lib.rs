@@ -1,23 +1,56 @@
 #![no_std]
 
 mod access_control;
+mod accounts;
+mod approval;
+mod claimable;
+mod council;
+mod delegation;
+mod earnings;
 mod error;
+mod escrow;
 mod events;
+mod hooks;
 mod interface;
+mod lock;
+mod maintenance;
 mod metadata;
+mod migration;
+mod multicall;
+mod oracle;
+mod provenance;
+mod record;
 mod reentrancy;
 mod royalty;
+mod sale;
 mod storage;
+mod subscription;
+#[cfg(any(test, feature = "testutils"))]
+pub mod testutils;
+mod timelock;
 mod token;
 mod transfer;
 mod types;
 mod utils;
 
 pub use error::ContractError;
-pub use types::{CollectionConfig, RoyaltyInfo, TokenAttribute, TokenMetadata};
+pub use interface::{
+    INTERFACE_ID_CLAIMABLE, INTERFACE_ID_EDITIONS, INTERFACE_ID_EXPIRABLE, INTERFACE_ID_LOCKABLE,
+    INTERFACE_ID_METADATA, INTERFACE_ID_NFT, INTERFACE_ID_ROYALTY, INTERFACE_ID_TOKEN_BOUND,
+};
+pub use metadata::{
+    FIELD_APPROVAL, FIELD_ATTRIBUTES, FIELD_EDITION, FIELD_EXTRAS, FIELD_ROYALTY, FIELD_URI,
+    FIELDS_ALL,
+};
+pub use types::{
+    AttributeLimits, AttributeValue, CallSpec, ClaimableMint, CollectionConfig, CollectionStats,
+    CreatorEarnings, DelegationScope, MintPrice, PayoutSplit, ProvenanceEntry, Role, RoyaltyDecay,
+    RoyaltyInfo, TokenAttribute, TokenMetadata,
+};
 
 use soroban_sdk::Address;
 use soroban_sdk::Bytes;
+use soroban_sdk::BytesN;
 use soroban_sdk::Env;
 use soroban_sdk::String;
 use soroban_sdk::Vec;
@@ -31,6 +64,48 @@ use crate::utils::validate_royalty_bps;
 #[contract]
 pub struct NftContract;
 
+/// Records the running code's version in storage.
+fn record_version(env: &Env) {
+    env.storage().instance().set(
+        &DataKey::ContractVersion,
+        &String::from_str(env, crate::utils::CONTRACT_VERSION),
+    );
+}
+
+/// Requires `caller` to be the owner or the configured upgrader, authorizing the call.
+fn require_upgrader(env: &Env, caller: &Address) -> Result<(), Err> {
+    caller.require_auth();
+    crate::access_control::require_initialized(env)?;
+    let upgrader: Option<Address> = env.storage().instance().get(&DataKey::Upgrader);
+    if !crate::access_control::has_role(env, caller, crate::types::Role::Owner)
+        && upgrader.as_ref() != Some(caller)
+    {
+        return Err(Err::NotAuthorized);
+    }
+    Ok(())
+}
+
+/// Replaces the contract code once the upgrade has been authorized.
+fn apply_upgrade(env: &Env, by: Address, new_wasm_hash: BytesN<32>) {
+    crate::interface::register_core(env);
+    record_version(env);
+    env.deployer()
+        .update_current_contract_wasm(new_wasm_hash.clone());
+    crate::events::emit_upgraded(env, by, new_wasm_hash);
+}
+
+/// Grants or revokes admin once authorized (by the owner or a council proposal).
+fn write_admin(env: &Env, admin: Address, granted: bool, by: Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Admin(admin.clone()), &granted);
+    env.storage().instance().remove(&DataKey::RoleExpiry(
+        crate::types::Role::Admin,
+        admin.clone(),
+    ));
+    crate::events::emit_role_updated(env, crate::types::Role::Admin, admin, by, granted);
+}
+
 #[contractimpl]
 impl NftContract {
     /// Initializes the NFT contract.
@@ -39,6 +114,9 @@ impl NftContract {
             return Err(Err::AlreadyInitialized);
         }
         validate_royalty_bps(config.royalty_default.percentage)?;
+        if config.max_batch_size == Some(0) {
+            return Err(Err::InvalidAmount);
+        }
 
         env.storage().instance().set(&DataKey::Initialized, &true);
         env.storage().instance().set(&DataKey::OwnerRole, &owner);
@@ -56,10 +134,20 @@ impl NftContract {
             .set(&DataKey::MetadataFrozen, &config.metadata_is_frozen);
         env.storage().instance().set(&DataKey::NextTokenId, &0u64);
         env.storage().instance().set(&DataKey::TotalSupply, &0u64);
-        env.storage().instance().set(&DataKey::Paused, &false);
         if let Some(max) = config.max_supply {
             env.storage().instance().set(&DataKey::MaxSupply, &max);
         }
+        if config.content_addressed_ids {
+            env.storage()
+                .instance()
+                .set(&DataKey::ContentAddressedIds, &true);
+        }
+        if let Some(max) = config.max_batch_size {
+            env.storage().instance().set(&DataKey::MaxBatchSize, &max);
+        }
+        crate::interface::register_core(&env);
+        crate::migration::mark_current(&env);
+        record_version(&env);
         Ok(())
     }
 
@@ -75,310 +163,1475 @@ impl NftContract {
         token::mint(&env, caller, to, metadata_uri, attributes, royalty_override)
     }
 
-    pub fn burn(env: Env, caller: Address, token_id: u64, confirm: bool) -> Result<(), Err> {
-        token::burn(&env, caller, token_id, confirm)
-    }
-
-    pub fn transfer(env: Env, from: Address, to: Address, token_id: u64) -> Result<(), Err> {
-        transfer::transfer(&env, from, to, token_id)
+    /// Mints one token to `to` for the mint price in `currency`, paid by `buyer`; no minter
+    /// role needed.
+    pub fn mint_paid(
+        env: Env,
+        buyer: Address,
+        to: Address,
+        currency: Address,
+        metadata_uri: String,
+        attributes: Vec<crate::types::TokenAttribute>,
+    ) -> Result<u64, Err> {
+        crate::sale::mint_paid(&env, buyer, to, currency, metadata_uri, attributes)
     }
 
-    pub fn safe_transfer_from(
+    /// Relayer-sponsored mint: `payer` pays the mint price in `currency` and `to` receives the
+    /// token. Relayer role only, within the relayer's quota.
+    pub fn relay_mint(
         env: Env,
-        from: Address,
+        relayer: Address,
+        payer: Address,
         to: Address,
-        token_id: u64,
-        data: Option<Bytes>,
-    ) -> Result<(), Err> {
-        transfer::safe_transfer_from(&env, from, to, token_id, data)
+        currency: Address,
+        metadata_uri: String,
+        attributes: Vec<crate::types::TokenAttribute>,
+    ) -> Result<u64, Err> {
+        crate::sale::relay_mint(&env, relayer, payer, to, currency, metadata_uri, attributes)
     }
 
-    pub fn batch_transfer(
+    /// Limits how many more mints `relayer` may relay; `None` for unlimited. Admin only.
+    pub fn set_relayer_quota(
         env: Env,
-        from: Address,
-        to: Address,
-        token_ids: Vec<u64>,
+        caller: Address,
+        relayer: Address,
+        quota: Option<u32>,
     ) -> Result<(), Err> {
-        transfer::batch_transfer(&env, from, to, token_ids)
+        crate::sale::set_relayer_quota(&env, caller, relayer, quota)
     }
 
-    // --- Ownership & Approvals ---
-    pub fn owner_of(env: Env, token_id: u64) -> Result<Address, Err> {
-        env.storage()
-            .instance()
-            .get(&DataKey::Owner(token_id))
-            .ok_or(Err::TokenNotFound)
+    pub fn relayer_quota(env: Env, relayer: Address) -> Option<u32> {
+        crate::sale::relayer_quota(&env, &relayer)
     }
 
-    pub fn balance_of(env: Env, owner: Address) -> u64 {
-        env.storage()
-            .instance()
-            .get(&DataKey::Balance(owner))
-            .unwrap_or(0)
+    /// Accepts a SEP-41 `currency` for `mint_paid` at `price`; 0 stops accepting it. Admin
+    /// only.
+    pub fn set_mint_price(
+        env: Env,
+        caller: Address,
+        price: i128,
+        currency: Address,
+    ) -> Result<(), Err> {
+        crate::sale::set_mint_price(&env, caller, price, currency)
     }
 
-    pub fn approve(env: Env, caller: Address, approved: Address, token_id: u64) -> Result<(), Err> {
-        caller.require_auth();
-        let owner: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Owner(token_id))
-            .ok_or(Err::TokenNotFound)?;
-        if owner != caller {
-            let is_operator: bool = env
-                .storage()
-                .instance()
-                .get(&DataKey::OperatorApproval(owner.clone(), caller))
-                .unwrap_or(false);
-            if !is_operator {
-                return Err(Err::NotAuthorized);
-            }
-        }
-        env.storage()
-            .instance()
-            .set(&DataKey::Approved(token_id), &approved);
-        crate::events::emit_approval(&env, owner, approved, token_id);
-        Ok(())
+    /// Mint price in `currency`, if accepted.
+    pub fn get_mint_price(env: Env, currency: Address) -> Option<i128> {
+        crate::sale::mint_price(&env, &currency)
     }
 
-    pub fn set_approval_for_all(
+    /// Prices mints in USD through a SEP-40 price feed (`None` reverts to fixed prices). Admin
+    /// only.
+    pub fn set_price_oracle(
         env: Env,
         caller: Address,
-        operator: Address,
-        approved: bool,
+        oracle: Option<crate::types::PriceOracle>,
     ) -> Result<(), Err> {
-        caller.require_auth();
-        env.storage().instance().set(
-            &DataKey::OperatorApproval(caller.clone(), operator.clone()),
-            &approved,
-        );
-        crate::events::emit_approval_for_all(&env, caller, operator, approved);
-        Ok(())
+        crate::sale::set_price_oracle(&env, caller, oracle)
     }
 
-    pub fn get_approved(env: Env, token_id: u64) -> Result<Option<Address>, Err> {
-        let _ = env
-            .storage()
-            .instance()
-            .get::<_, Address>(&DataKey::Owner(token_id))
-            .ok_or(Err::TokenNotFound)?;
-        let approved: Option<Address> = env.storage().instance().get(&DataKey::Approved(token_id));
-        Ok(approved)
+    pub fn price_oracle(env: Env) -> Option<crate::types::PriceOracle> {
+        crate::oracle::price_oracle(&env)
     }
 
-    pub fn is_approved_for_all(env: Env, owner: Address, operator: Address) -> bool {
-        env.storage()
-            .instance()
-            .get(&DataKey::OperatorApproval(owner, operator))
-            .unwrap_or(false)
+    /// Amount `mint_paid` charges right now in `currency`.
+    pub fn quote_mint_price(env: Env, currency: Address) -> Result<i128, Err> {
+        crate::sale::quote_mint_price(&env, &currency)
     }
 
-    // --- Metadata ---
-    pub fn token_uri(env: Env, token_id: u64) -> Result<String, Err> {
-        crate::metadata::token_uri(&env, token_id)
+    /// Every accepted currency with its price.
+    pub fn mint_prices(env: Env) -> Vec<crate::types::MintPrice> {
+        crate::sale::mint_prices(&env)
     }
 
-    pub fn token_metadata(env: Env, token_id: u64) -> Result<TokenMetadata, Err> {
-        crate::metadata::token_metadata(&env, token_id)
+    /// Splits primary-sale proceeds between recipients (shares in bps, summing to 10000);
+    /// empty pays the owner. Admin only.
+    pub fn set_payout_splits(
+        env: Env,
+        caller: Address,
+        splits: Vec<crate::types::PayoutSplit>,
+    ) -> Result<(), Err> {
+        crate::sale::set_payout_splits(&env, caller, splits)
     }
 
-    pub fn set_token_uri(env: Env, caller: Address, token_id: u64, uri: String) -> Result<(), Err> {
-        crate::metadata::set_token_uri(&env, token_id, uri, &caller)
+    pub fn payout_splits(env: Env) -> Vec<crate::types::PayoutSplit> {
+        crate::sale::payout_splits(&env)
     }
 
-    pub fn set_base_uri(env: Env, caller: Address, base_uri: String) -> Result<(), Err> {
-        crate::metadata::set_base_uri(&env, &caller, base_uri)
+    /// Paid-mint proceeds in `currency` not yet withdrawn.
+    pub fn proceeds(env: Env, currency: Address) -> i128 {
+        crate::sale::proceeds(&env, currency)
     }
 
-    pub fn freeze_metadata(env: Env, caller: Address) -> Result<(), Err> {
-        crate::metadata::freeze_metadata(&env, caller)
+    /// Pays out the accumulated proceeds in `currency` across the payout splits, leaving
+    /// refundable payments of a failed drop behind. Anyone may call; returns the amount
+    /// distributed.
+    pub fn withdraw(env: Env, currency: Address) -> Result<i128, Err> {
+        crate::sale::withdraw(&env, currency)
     }
 
-    pub fn set_edition_info(
+    /// Holds paid-mint proceeds in escrow until `threshold` paid mints sell by `deadline`;
+    /// if the drop falls short, holders can `refund`. Admin only, before the first sale and
+    /// with earlier proceeds withdrawn.
+    pub fn set_mint_escrow(
         env: Env,
         caller: Address,
-        token_id: u64,
-        edition_number: Option<u32>,
-        total_editions: Option<u32>,
+        threshold: u64,
+        deadline: u64,
     ) -> Result<(), Err> {
-        crate::metadata::set_edition_info(&env, token_id, edition_number, total_editions, &caller)
+        crate::escrow::set_mint_escrow(&env, caller, threshold, deadline)
     }
 
-    // --- Royalty ---
-    pub fn get_royalty_info(
-        env: Env,
-        token_id: u64,
-        sale_price: i128,
-    ) -> Result<(Address, i128), Err> {
-        crate::royalty::get_royalty_info(&env, token_id, sale_price)
+    pub fn mint_escrow(env: Env) -> Option<crate::types::EscrowConfig> {
+        crate::escrow::config(&env)
     }
 
-    pub fn set_default_royalty(
-        env: Env,
-        caller: Address,
-        recipient: Address,
-        percentage: u32,
-    ) -> Result<(), Err> {
-        crate::royalty::set_default_royalty(&env, caller, recipient, percentage)
+    pub fn escrow_status(env: Env) -> Option<crate::types::EscrowStatus> {
+        crate::escrow::status(&env)
     }
 
-    pub fn set_royalty_info(
+    /// Burns an escrowed token of a failed drop and refunds its mint payment to `holder`.
+    pub fn refund(env: Env, holder: Address, token_id: u64) -> Result<i128, Err> {
+        crate::escrow::refund(&env, holder, token_id)
+    }
+
+    /// Mints with an explicit token id; ids are never reused, even after a burn.
+    pub fn mint_with_id(
         env: Env,
         caller: Address,
+        to: Address,
         token_id: u64,
-        recipient: Address,
-        percentage: u32,
-    ) -> Result<(), Err> {
-        crate::royalty::set_royalty_info(&env, caller, token_id, recipient, percentage)
+        metadata_uri: String,
+        attributes: Vec<crate::types::TokenAttribute>,
+        royalty_override: Option<RoyaltyInfo>,
+    ) -> Result<u64, Err> {
+        token::mint_with_id(
+            &env,
+            caller,
+            to,
+            token_id,
+            metadata_uri,
+            attributes,
+            royalty_override,
+        )
     }
 
-    // --- Batch ---
-    pub fn batch_mint(
+    /// Mints like `mint` and records the sha256 `content_hash` of the token's media, which can
+    /// never be changed afterwards.
+    pub fn mint_with_content_hash(
         env: Env,
         caller: Address,
-        recipients: Vec<Address>,
-        metadata_uris: Vec<String>,
-        attributes: Vec<Vec<crate::types::TokenAttribute>>,
-    ) -> Result<Vec<u64>, Err> {
-        if recipients.len() != metadata_uris.len() || recipients.len() != attributes.len() {
-            return Err(Err::BatchLengthMismatch);
-        }
-        access_control::require_minter(&env, &caller)?;
-        access_control::require_not_paused(&env)?;
-        let whitelist_only: bool = env
-            .storage()
-            .instance()
-            .get(&DataKey::WhitelistOnlyMint)
-            .unwrap_or(false);
-        if whitelist_only {
-            access_control::require_whitelisted(&env, &caller)?;
-        }
-        reentrancy::acquire(&env)?;
-        let result = (|| {
-            let mut ids = Vec::new(&env);
-            for i in 0..recipients.len() {
-                let to = recipients.get(i).unwrap();
-                let uri = metadata_uris.get(i).unwrap();
-                let attrs = attributes.get(i).unwrap();
-                let id = token::mint_internal(&env, caller.clone(), to, uri, attrs, None)?;
-                ids.push_back(id);
-            }
-            Ok(ids)
-        })();
-        reentrancy::release(&env);
-        result
+        to: Address,
+        metadata_uri: String,
+        attributes: Vec<crate::types::TokenAttribute>,
+        royalty_override: Option<RoyaltyInfo>,
+        content_hash: BytesN<32>,
+    ) -> Result<u64, Err> {
+        token::mint_with_content_hash(
+            &env,
+            caller,
+            to,
+            metadata_uri,
+            attributes,
+            royalty_override,
+            content_hash,
+        )
     }
 
-    // --- Collection Info ---
-    pub fn name(env: Env) -> Result<String, Err> {
-        let config: CollectionConfig = env
-            .storage()
-            .instance()
-            .get(&DataKey::CollectionConfig)
-            .ok_or(Err::NotFound)?;
-        Ok(config.name)
+    /// Mints a token into escrow that only `claimant` can take, until `expires_at`.
+    pub fn mint_claimable(
+        env: Env,
+        caller: Address,
+        claimant: Address,
+        metadata_uri: String,
+        attributes: Vec<crate::types::TokenAttribute>,
+        royalty_override: Option<RoyaltyInfo>,
+        expires_at: u64,
+    ) -> Result<u64, Err> {
+        crate::claimable::mint_claimable(
+            &env,
+            caller,
+            claimant,
+            metadata_uri,
+            attributes,
+            royalty_override,
+            expires_at,
+        )
     }
 
-    pub fn symbol(env: Env) -> Result<String, Err> {
-        let config: CollectionConfig = env
-            .storage()
-            .instance()
-            .get(&DataKey::CollectionConfig)
-            .ok_or(Err::NotFound)?;
-        Ok(config.symbol)
+    /// Takes an escrowed token: the claimant before expiry, or its minter afterwards.
+    pub fn claim(env: Env, caller: Address, token_id: u64) -> Result<(), Err> {
+        crate::claimable::claim(&env, caller, token_id)
     }
 
-    pub fn total_supply(env: Env) -> u64 {
-        env.storage()
-            .instance()
-            .get(&DataKey::TotalSupply)
-            .unwrap_or(0)
+    pub fn claimable(env: Env, token_id: u64) -> Option<ClaimableMint> {
+        crate::claimable::claimable(&env, token_id)
     }
 
-    // --- Access Control ---
-    pub fn set_pause(env: Env, caller: Address, paused: bool) -> Result<(), Err> {
-        crate::access_control::require_admin(&env, &caller)?;
-        env.storage().instance().set(&DataKey::Paused, &paused);
-        Ok(())
+    pub fn burn(env: Env, caller: Address, token_id: u64, confirm: bool) -> Result<(), Err> {
+        token::burn(&env, caller, token_id, confirm)
     }
 
-    pub fn set_admin(env: Env, admin: Address, granted: bool) -> Result<(), Err> {
-        crate::access_control::require_owner(&env)?;
-        env.storage()
-            .instance()
-            .set(&DataKey::Admin(admin), &granted);
-        Ok(())
+    /// Replaces a token with an upgraded one for the same owner; returns the new token id.
+    pub fn evolve(
+        env: Env,
+        caller: Address,
+        token_id: u64,
+        new_uri: String,
+        new_attributes: Vec<crate::types::TokenAttribute>,
+    ) -> Result<u64, Err> {
+        token::evolve(&env, caller, token_id, new_uri, new_attributes)
     }
 
-    pub fn set_minter(
+    /// Burns up to 100 tokens; the caller must own each one or hold the burner role.
+    pub fn batch_burn(
         env: Env,
         caller: Address,
-        minter: Address,
-        granted: bool,
+        token_ids: Vec<u64>,
+        confirm: bool,
     ) -> Result<(), Err> {
-        crate::access_control::require_admin(&env, &caller)?;
-        env.storage()
-            .instance()
-            .set(&DataKey::Minter(minter), &granted);
-        Ok(())
+        token::batch_burn(&env, caller, token_ids, confirm)
     }
 
-    pub fn set_burner(
+    /// Sets who may burn: everyone entitled, nobody, owners only, or from a timestamp on.
+    /// Admin only.
+    pub fn set_burn_policy(
         env: Env,
         caller: Address,
-        burner: Address,
-        granted: bool,
+        policy: crate::types::BurnPolicy,
     ) -> Result<(), Err> {
-        crate::access_control::require_admin(&env, &caller)?;
-        env.storage()
-            .instance()
-            .set(&DataKey::Burner(burner), &granted);
-        Ok(())
+        token::set_burn_policy(&env, caller, policy)
     }
 
-    pub fn set_metadata_updater(
+    pub fn burn_policy(env: Env) -> crate::types::BurnPolicy {
+        token::burn_policy(&env)
+    }
+
+    /// Deletes the listed entries that are stale (expired approvals and roles, spent or
+    /// expired mint allowances, fully claimed whitelist allocations) so they stop paying
+    /// rent; returns how many went. Up to 100 entries; anyone may call.
+    pub fn collect_garbage(env: Env, entries: Vec<crate::types::StaleEntry>) -> Result<u32, Err> {
+        crate::maintenance::collect_garbage(&env, entries)
+    }
+
+    /// Whether `token_id` was minted and later burned; false for ids that never existed.
+    pub fn was_burned(env: Env, token_id: u64) -> bool {
+        token::was_burned(&env, token_id)
+    }
+
+    pub fn total_burned(env: Env) -> u64 {
+        token::total_burned(&env)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, token_id: u64) -> Result<(), Err> {
+        transfer::transfer(&env, from, to, token_id)
+    }
+
+    /// Owners of a token oldest first, with when each received it; `start`/`limit` page
+    /// through the most recent 50.
+    pub fn get_provenance(env: Env, token_id: u64, start: u32, limit: u32) -> Vec<ProvenanceEntry> {
+        crate::provenance::get_provenance(&env, token_id, start, limit)
+    }
+
+    /// Locks a token in `owner`'s wallet on behalf of `locker_contract` (e.g. staking); it
+    /// cannot be transferred, approved or burned until the locker unlocks it.
+    pub fn lock(
         env: Env,
-        caller: Address,
-        updater: Address,
-        granted: bool,
+        owner: Address,
+        token_id: u64,
+        locker_contract: Address,
     ) -> Result<(), Err> {
-        crate::access_control::require_admin(&env, &caller)?;
-        env.storage()
-            .instance()
-            .set(&DataKey::MetadataUpdater(updater), &granted);
-        Ok(())
+        crate::lock::lock(&env, owner, token_id, locker_contract)
     }
 
-    pub fn set_whitelist(
+    pub fn unlock(env: Env, locker_contract: Address, token_id: u64) -> Result<(), Err> {
+        crate::lock::unlock(&env, locker_contract, token_id)
+    }
+
+    pub fn is_locked(env: Env, token_id: u64) -> bool {
+        crate::lock::locker_of(&env, token_id).is_some()
+    }
+
+    pub fn locker_of(env: Env, token_id: u64) -> Option<Address> {
+        crate::lock::locker_of(&env, token_id)
+    }
+
+    /// Moves tokens minted before packed `TokenRecord`s onto one record each; returns how many
+    /// were migrated. Legacy tokens stay readable without this. Admin only.
+    pub fn migrate_token_records(
         env: Env,
-        caller: Address,
-        address: Address,
-        allowed: bool,
-    ) -> Result<(), Err> {
-        crate::access_control::require_admin(&env, &caller)?;
-        env.storage()
-            .instance()
-            .set(&DataKey::Whitelist(address), &allowed);
-        Ok(())
+        admin: Address,
+        token_ids: Vec<u64>,
+    ) -> Result<u32, Err> {
+        crate::access_control::require_admin(&env, &admin)?;
+        if token_ids.len() > crate::utils::MAX_BATCH_QUERY {
+            return Err(Err::BatchTooLarge);
+        }
+        let mut migrated = 0;
+        for token_id in token_ids.iter() {
+            if crate::record::migrate(&env, token_id) {
+                migrated += 1;
+            }
+        }
+        Ok(migrated)
     }
 
-    pub fn set_whitelist_only_mint(env: Env, caller: Address, enabled: bool) -> Result<(), Err> {
-        crate::access_control::require_admin(&env, &caller)?;
-        env.storage()
-            .instance()
-            .set(&DataKey::WhitelistOnlyMint, &enabled);
-        Ok(())
+    /// Offers a token to `to` instead of pushing it; nothing moves until they accept.
+    pub fn offer_transfer(env: Env, from: Address, to: Address, token_id: u64) -> Result<(), Err> {
+        transfer::offer_transfer(&env, from, to, token_id)
     }
 
-    // --- Interface detection (ERC-165 equivalent) ---
-    pub fn supports_interface(env: Env, interface_id: u32) -> bool {
-        let _ = env;
-        matches!(
-            interface_id,
-            crate::interface::INTERFACE_ID_NFT
-                | crate::interface::INTERFACE_ID_ROYALTY
-                | crate::interface::INTERFACE_ID_METADATA
-        )
+    pub fn accept_transfer(env: Env, to: Address, token_id: u64) -> Result<(), Err> {
+        transfer::accept_transfer(&env, to, token_id)
+    }
+
+    pub fn cancel_offer(env: Env, caller: Address, token_id: u64) -> Result<(), Err> {
+        transfer::cancel_offer(&env, caller, token_id)
+    }
+
+    pub fn pending_offer(env: Env, token_id: u64) -> Option<Address> {
+        transfer::pending_offer(&env, token_id)
+    }
+
+    pub fn safe_transfer_from(
+        env: Env,
+        from: Address,
+        to: Address,
+        token_id: u64,
+        data: Option<Bytes>,
+    ) -> Result<(), Err> {
+        transfer::safe_transfer_from(&env, from, to, token_id, data)
+    }
+
+    pub fn batch_transfer(
+        env: Env,
+        from: Address,
+        to: Address,
+        token_ids: Vec<u64>,
+    ) -> Result<(), Err> {
+        transfer::batch_transfer(&env, from, to, token_ids)
+    }
+
+    /// Sends up to 100 tokens to `to`; a receiver contract is notified once via `nft_batch_recv`.
+    pub fn safe_batch_transfer(
+        env: Env,
+        from: Address,
+        to: Address,
+        token_ids: Vec<u64>,
+        data: Option<Bytes>,
+    ) -> Result<(), Err> {
+        transfer::safe_batch_transfer(&env, from, to, token_ids, data)
+    }
+
+    /// Sends each token to the recipient at the same index (up to 100 tokens).
+    pub fn batch_transfer_to(
+        env: Env,
+        from: Address,
+        recipients: Vec<Address>,
+        token_ids: Vec<u64>,
+    ) -> Result<(), Err> {
+        transfer::batch_transfer_to(&env, from, recipients, token_ids)
+    }
+
+    /// Earliest timestamp at which the token may be transferred again (0 if transferable now
+    /// regardless of the clock).
+    pub fn transferable_at(env: Env, token_id: u64) -> Result<u64, Err> {
+        if !crate::record::exists(&env, token_id) {
+            return Err(Err::TokenNotFound);
+        }
+        Ok(transfer::transferable_at(&env, token_id))
+    }
+
+    /// Times the token has changed hands since mint.
+    pub fn transfer_count(env: Env, token_id: u64) -> Result<u32, Err> {
+        if !crate::record::exists(&env, token_id) {
+            return Err(Err::TokenNotFound);
+        }
+        Ok(transfer::transfer_count(&env, token_id))
+    }
+
+    /// Executes up to 20 operations as `caller` atomically (e.g. set_token_uri and
+    /// set_edition_info, or approve and transfer).
+    pub fn multicall(
+        env: Env,
+        caller: Address,
+        calls: Vec<crate::types::CallSpec>,
+    ) -> Result<(), Err> {
+        crate::multicall::multicall(&env, caller, calls)
+    }
+
+    // --- Delegation ---
+    /// Delegates claim/utility rights over `owner`'s tokens (all, or one) to a hot wallet.
+    /// Delegates cannot transfer or approve.
+    pub fn delegate(
+        env: Env,
+        owner: Address,
+        delegate: Address,
+        scope: DelegationScope,
+    ) -> Result<(), Err> {
+        crate::delegation::set_delegate(&env, owner, delegate, scope, true)
+    }
+
+    pub fn revoke_delegate(
+        env: Env,
+        owner: Address,
+        delegate: Address,
+        scope: DelegationScope,
+    ) -> Result<(), Err> {
+        crate::delegation::set_delegate(&env, owner, delegate, scope, false)
+    }
+
+    /// Whether `delegate` holds `owner`'s delegation for `scope` (collection-wide counts for
+    /// every token).
+    pub fn is_delegate(
+        env: Env,
+        owner: Address,
+        delegate: Address,
+        scope: DelegationScope,
+    ) -> bool {
+        crate::delegation::is_delegate(&env, owner, delegate, scope)
+    }
+
+    /// Whether `delegate` may act for the current owner of `token_id`.
+    pub fn is_token_delegate(env: Env, delegate: Address, token_id: u64) -> bool {
+        crate::delegation::is_token_delegate(&env, delegate, token_id)
+    }
+
+    // --- Ownership & Approvals ---
+    pub fn owner_of(env: Env, token_id: u64) -> Result<Address, Err> {
+        crate::record::owner(&env, token_id).ok_or(Err::TokenNotFound)
+    }
+
+    /// Owners of up to 200 tokens; `None` for tokens that do not exist (or were burned).
+    pub fn owners_of(env: Env, token_ids: Vec<u64>) -> Result<Vec<Option<Address>>, Err> {
+        if token_ids.len() > crate::utils::MAX_BATCH_QUERY {
+            return Err(Err::BatchTooLarge);
+        }
+        let mut owners = Vec::new(&env);
+        for token_id in token_ids.iter() {
+            owners.push_back(crate::record::owner(&env, token_id));
+        }
+        Ok(owners)
+    }
+
+    pub fn balance_of(env: Env, owner: Address) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Balance(owner))
+            .unwrap_or(0)
+    }
+
+    /// Balances of up to 200 owners in one read.
+    pub fn balance_of_batch(env: Env, owners: Vec<Address>) -> Result<Vec<u64>, Err> {
+        if owners.len() > crate::utils::MAX_BATCH_QUERY {
+            return Err(Err::BatchTooLarge);
+        }
+        let mut balances = Vec::new(&env);
+        for owner in owners.iter() {
+            balances.push_back(Self::balance_of(env.clone(), owner));
+        }
+        Ok(balances)
+    }
+
+    pub fn approve(env: Env, caller: Address, approved: Address, token_id: u64) -> Result<(), Err> {
+        crate::approval::approve(&env, caller, approved, token_id, None)
+    }
+
+    /// Approves `approved` for up to 100 tokens in one call.
+    pub fn approve_batch(
+        env: Env,
+        caller: Address,
+        approved: Address,
+        token_ids: Vec<u64>,
+    ) -> Result<(), Err> {
+        crate::approval::approve_batch(&env, caller, approved, token_ids)
+    }
+
+    /// Like `approve`, but the approval lapses at the `expires_at` ledger timestamp.
+    pub fn approve_with_expiry(
+        env: Env,
+        caller: Address,
+        approved: Address,
+        token_id: u64,
+        expires_at: u64,
+    ) -> Result<(), Err> {
+        crate::approval::approve(&env, caller, approved, token_id, Some(expires_at))
+    }
+
+    /// Expiry timestamp of the token's approval, if it is time-limited.
+    pub fn approval_expiry(env: Env, token_id: u64) -> Option<u64> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ApprovalExpiry(token_id))
+    }
+
+    pub fn set_approval_for_all(
+        env: Env,
+        caller: Address,
+        operator: Address,
+        approved: bool,
+    ) -> Result<(), Err> {
+        crate::approval::set_approval_for_all(&env, caller, operator, approved)
+    }
+
+    /// Clears every token approval and operator approval the owner has granted, e.g. after a
+    /// phishing attempt.
+    pub fn revoke_all_approvals(env: Env, owner: Address) -> Result<(), Err> {
+        crate::approval::revoke_all(&env, owner)
+    }
+
+    pub fn operators_of(env: Env, owner: Address) -> Vec<Address> {
+        crate::approval::operators(&env, &owner)
+    }
+
+    pub fn get_approved(env: Env, token_id: u64) -> Result<Option<Address>, Err> {
+        let _ = crate::record::owner(&env, token_id).ok_or(Err::TokenNotFound)?;
+        Ok(crate::approval::approved(&env, token_id))
+    }
+
+    /// Whether `spender` owns `token_id` or is approved for it (per token or as an operator).
+    /// Ignores pauses, freezes and cooldowns.
+    pub fn is_approved_or_owner(env: Env, spender: Address, token_id: u64) -> Result<bool, Err> {
+        let owner: Address = crate::record::owner(&env, token_id).ok_or(Err::TokenNotFound)?;
+        Ok(transfer::is_approved_or_owner(
+            &env, &spender, &owner, token_id,
+        ))
+    }
+
+    pub fn is_approved_for_all(env: Env, owner: Address, operator: Address) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::OperatorApproval(owner, operator))
+            .unwrap_or(false)
+    }
+
+    // --- Metadata ---
+    pub fn token_uri(env: Env, token_id: u64) -> Result<String, Err> {
+        crate::metadata::token_uri(&env, token_id)
+    }
+
+    pub fn token_metadata(env: Env, token_id: u64) -> Result<TokenMetadata, Err> {
+        crate::metadata::token_metadata(&env, token_id)
+    }
+
+    /// Metadata for up to 50 tokens in one read, loading only the field groups in
+    /// `fields_mask` (`FIELD_*` constants, or `FIELDS_ALL`). Missing tokens are skipped.
+    pub fn token_metadata_batch(
+        env: Env,
+        token_ids: Vec<u64>,
+        fields_mask: u32,
+    ) -> Result<Vec<TokenMetadata>, Err> {
+        crate::metadata::token_metadata_batch(&env, token_ids, fields_mask)
+    }
+
+    /// Whether `hash` matches the content hash committed at mint (false if none was committed).
+    pub fn verify_content(env: Env, token_id: u64, hash: BytesN<32>) -> Result<bool, Err> {
+        if !crate::record::exists(&env, token_id) {
+            return Err(Err::TokenNotFound);
+        }
+        let committed: Option<BytesN<32>> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ContentHash(token_id));
+        Ok(committed == Some(hash))
+    }
+
+    pub fn set_token_uri(env: Env, caller: Address, token_id: u64, uri: String) -> Result<(), Err> {
+        crate::metadata::set_token_uri(&env, token_id, uri, &caller)
+    }
+
+    pub fn set_base_uri(env: Env, caller: Address, base_uri: String) -> Result<(), Err> {
+        crate::metadata::set_base_uri(&env, &caller, base_uri)
+    }
+
+    /// Enables on-chain URIs (`base_uri + token_id + suffix`, e.g. suffix ".json") for tokens
+    /// minted with an empty URI; `None` disables composition for later mints. Tokens already
+    /// composed keep their suffix, which cannot change once a token was minted under it.
+    pub fn set_uri_composition(
+        env: Env,
+        caller: Address,
+        suffix: Option<String>,
+    ) -> Result<(), Err> {
+        crate::metadata::set_uri_composition(&env, &caller, suffix)
+    }
+
+    pub fn contract_uri(env: Env) -> Option<String> {
+        crate::metadata::contract_uri(&env)
+    }
+
+    pub fn set_contract_uri(env: Env, caller: Address, uri: String) -> Result<(), Err> {
+        crate::metadata::set_contract_uri(&env, &caller, uri)
+    }
+
+    pub fn freeze_metadata(env: Env, caller: Address) -> Result<(), Err> {
+        crate::metadata::freeze_metadata(&env, caller)
+    }
+
+    /// Mints `count` numbered copies of a master token (which must have `total_editions` set).
+    pub fn mint_edition(
+        env: Env,
+        caller: Address,
+        master_token_id: u64,
+        to: Address,
+        count: u32,
+    ) -> Result<Vec<u64>, Err> {
+        token::mint_edition(&env, caller, master_token_id, to, count)
+    }
+
+    pub fn set_edition_info(
+        env: Env,
+        caller: Address,
+        token_id: u64,
+        edition_number: Option<u32>,
+        total_editions: Option<u32>,
+    ) -> Result<(), Err> {
+        crate::metadata::set_edition_info(&env, token_id, edition_number, total_editions, &caller)
+    }
+
+    pub fn update_attribute(
+        env: Env,
+        caller: Address,
+        token_id: u64,
+        trait_type: String,
+        value: String,
+    ) -> Result<(), Err> {
+        crate::metadata::update_attribute(&env, &caller, token_id, trait_type, value)
+    }
+
+    /// Sets an attribute with a typed value (number, bool or timestamp); plain strings go
+    /// through `update_attribute`.
+    pub fn update_typed_attribute(
+        env: Env,
+        caller: Address,
+        token_id: u64,
+        trait_type: String,
+        value: crate::types::AttributeValue,
+    ) -> Result<(), Err> {
+        crate::metadata::update_typed_attribute(&env, &caller, token_id, trait_type, value)
+    }
+
+    pub fn get_attribute(
+        env: Env,
+        token_id: u64,
+        trait_type: String,
+    ) -> Result<TokenAttribute, Err> {
+        crate::metadata::get_attribute(&env, token_id, trait_type)
+    }
+
+    pub fn get_numeric_attribute(env: Env, token_id: u64, trait_type: String) -> Result<i128, Err> {
+        crate::metadata::get_numeric_attribute(&env, token_id, trait_type)
+    }
+
+    pub fn remove_attribute(
+        env: Env,
+        caller: Address,
+        token_id: u64,
+        trait_type: String,
+    ) -> Result<(), Err> {
+        crate::metadata::remove_attribute(&env, &caller, token_id, trait_type)
+    }
+
+    pub fn set_placeholder_uri(env: Env, caller: Address, uri: String) -> Result<(), Err> {
+        crate::metadata::set_placeholder_uri(&env, &caller, uri)
+    }
+
+    pub fn commit_provenance(env: Env, hash: BytesN<32>) -> Result<(), Err> {
+        crate::metadata::commit_provenance(&env, hash)
+    }
+
+    pub fn provenance_hash(env: Env) -> Option<BytesN<32>> {
+        crate::metadata::provenance_hash(&env)
+    }
+
+    pub fn reveal(env: Env, caller: Address, real_base_uri: String) -> Result<(), Err> {
+        crate::metadata::reveal(&env, &caller, real_base_uri)
+    }
+
+    pub fn is_revealed(env: Env) -> bool {
+        crate::metadata::is_revealed(&env)
+    }
+
+    // --- Royalty ---
+    pub fn get_royalty_info(
+        env: Env,
+        token_id: u64,
+        sale_price: i128,
+    ) -> Result<(Address, i128), Err> {
+        crate::royalty::get_royalty_info(&env, token_id, sale_price)
+    }
+
+    /// Canonical royalty settlement for marketplaces: collects the royalty on `sale_price` in
+    /// `currency` from `payer`, pays it to the royalty recipient and returns the amount.
+    pub fn pay_royalty(
+        env: Env,
+        payer: Address,
+        token_id: u64,
+        sale_price: i128,
+        currency: Address,
+    ) -> Result<i128, Err> {
+        crate::royalty::pay_royalty(&env, payer, token_id, sale_price, currency)
+    }
+
+    pub fn royalties_paid(env: Env, token_id: u64, currency: Address) -> i128 {
+        crate::royalty::royalties_paid(&env, token_id, currency)
+    }
+
+    /// Primary-sale proceeds and royalties paid out to `creator` in `currency` so far.
+    pub fn earnings_of(env: Env, creator: Address, currency: Address) -> CreatorEarnings {
+        crate::earnings::earnings_of(&env, creator, currency)
+    }
+
+    pub fn set_default_royalty(
+        env: Env,
+        caller: Address,
+        recipient: Address,
+        percentage: u32,
+    ) -> Result<(), Err> {
+        crate::royalty::set_default_royalty(&env, caller, recipient, percentage)
+    }
+
+    pub fn set_royalty_info(
+        env: Env,
+        caller: Address,
+        token_id: u64,
+        recipient: Address,
+        percentage: u32,
+    ) -> Result<(), Err> {
+        crate::royalty::set_royalty_info(&env, caller, token_id, recipient, percentage)
+    }
+
+    /// Drops a token's royalty override so the collection default applies again.
+    pub fn reset_token_royalty(env: Env, caller: Address, token_id: u64) -> Result<(), Err> {
+        crate::royalty::reset_token_royalty(&env, caller, token_id)
+    }
+
+    /// Sets (or clears) a schedule lowering royalties after a token's age or transfer count
+    /// crosses a threshold; reflected in `get_royalty_info`.
+    pub fn set_royalty_decay(
+        env: Env,
+        caller: Address,
+        decay: Option<RoyaltyDecay>,
+    ) -> Result<(), Err> {
+        crate::royalty::set_royalty_decay(&env, caller, decay)
+    }
+
+    pub fn royalty_decay(env: Env) -> Option<RoyaltyDecay> {
+        crate::royalty::royalty_decay(&env)
+    }
+
+    /// Sets (or clears) the platform royalty registry `get_royalty_info` falls back to when
+    /// neither the token nor the collection sets a royalty. Admin only.
+    pub fn set_royalty_registry(
+        env: Env,
+        caller: Address,
+        registry: Option<Address>,
+    ) -> Result<(), Err> {
+        crate::royalty::set_royalty_registry(&env, caller, registry)
+    }
+
+    pub fn royalty_registry(env: Env) -> Option<Address> {
+        crate::royalty::royalty_registry(&env)
+    }
+
+    // --- Batch ---
+    pub fn batch_mint(
+        env: Env,
+        caller: Address,
+        recipients: Vec<Address>,
+        metadata_uris: Vec<String>,
+        attributes: Vec<Vec<crate::types::TokenAttribute>>,
+    ) -> Result<Vec<u64>, Err> {
+        if recipients.len() != metadata_uris.len() || recipients.len() != attributes.len() {
+            return Err(Err::BatchLengthMismatch);
+        }
+        crate::utils::require_batch_size(&env, recipients.len())?;
+        token::require_can_mint(&env, &caller, recipients.len())?;
+        reentrancy::with_lock(&env, || {
+            let mut ids = Vec::new(&env);
+            for i in 0..recipients.len() {
+                let to = recipients.get(i).unwrap();
+                let uri = metadata_uris.get(i).unwrap();
+                let attrs = attributes.get(i).unwrap();
+                let id = token::mint_internal(&env, caller.clone(), to, uri, attrs, None)?;
+                ids.push_back(id);
+            }
+            Ok(ids)
+        })
+    }
+
+    pub fn airdrop(
+        env: Env,
+        caller: Address,
+        recipients: Vec<Address>,
+        uri_template: String,
+    ) -> Result<Vec<u64>, Err> {
+        token::airdrop(&env, caller, recipients, uri_template)
+    }
+
+    // --- Collection Info ---
+    pub fn name(env: Env) -> Result<String, Err> {
+        let config: CollectionConfig = env
+            .storage()
+            .instance()
+            .get(&DataKey::CollectionConfig)
+            .ok_or(Err::NotFound)?;
+        Ok(config.name)
+    }
+
+    pub fn symbol(env: Env) -> Result<String, Err> {
+        let config: CollectionConfig = env
+            .storage()
+            .instance()
+            .get(&DataKey::CollectionConfig)
+            .ok_or(Err::NotFound)?;
+        Ok(config.symbol)
+    }
+
+    /// Cuts the supply cap; it can never be raised or set below the current supply. Admin only.
+    pub fn set_max_supply(env: Env, caller: Address, new_max: u64) -> Result<(), Err> {
+        token::set_max_supply(&env, caller, new_max)
+    }
+
+    /// Schedules when minting opens and closes (`end` exclusive); `None` leaves a side open.
+    /// Admin only.
+    pub fn set_mint_window(
+        env: Env,
+        caller: Address,
+        start: Option<u64>,
+        end: Option<u64>,
+    ) -> Result<(), Err> {
+        token::set_mint_window(&env, caller, start, end)
+    }
+
+    pub fn mint_start(env: Env) -> Option<u64> {
+        token::mint_start(&env)
+    }
+
+    pub fn mint_end(env: Env) -> Option<u64> {
+        token::mint_end(&env)
+    }
+
+    /// Caps how many tokens one mint call (batch_mint, airdrop, mint_edition) may create;
+    /// `None` removes the cap. Admin only.
+    pub fn set_max_per_transaction(env: Env, caller: Address, max: Option<u32>) -> Result<(), Err> {
+        token::set_max_per_transaction(&env, caller, max)
+    }
+
+    pub fn max_per_transaction(env: Env) -> Option<u32> {
+        token::max_per_transaction(&env)
+    }
+
+    /// Per-call batch limit set in the collection config, if any.
+    pub fn max_batch_size(env: Env) -> Option<u32> {
+        env.storage().instance().get(&DataKey::MaxBatchSize)
+    }
+
+    pub fn total_supply(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalSupply)
+            .unwrap_or(0)
+    }
+
+    /// Supply, mint, burn and holder counters in one read.
+    pub fn get_collection_stats(env: Env) -> CollectionStats {
+        let storage = env.storage().instance();
+        let total_supply: u64 = storage.get(&DataKey::TotalSupply).unwrap_or(0);
+        let total_minted: u64 = storage
+            .get(&DataKey::TotalMinted)
+            .or_else(|| storage.get(&DataKey::NextTokenId))
+            .unwrap_or(0);
+        CollectionStats {
+            total_supply,
+            total_minted,
+            total_burned: token::total_burned(&env),
+            holders: storage.get(&DataKey::HolderCount).unwrap_or(0),
+            max_supply: storage.get(&DataKey::MaxSupply),
+            max_per_transaction: storage.get(&DataKey::MaxPerTransaction),
+        }
+    }
+
+    // --- Access Control ---
+    /// Pauses or unpauses minting, transfers and burning together. Admin; a guardian may
+    /// only pause.
+    pub fn set_pause(env: Env, caller: Address, paused: bool) -> Result<(), Err> {
+        crate::access_control::require_pauser(&env, &caller, paused)?;
+        crate::access_control::set_paused(
+            &env,
+            caller,
+            &[
+                DataKey::MintPaused,
+                DataKey::TransferPaused,
+                DataKey::BurnPaused,
+            ],
+            paused,
+        );
+        Ok(())
+    }
+
+    pub fn pause_mint(env: Env, caller: Address, paused: bool) -> Result<(), Err> {
+        crate::access_control::require_admin(&env, &caller)?;
+        crate::access_control::set_paused(&env, caller, &[DataKey::MintPaused], paused);
+        Ok(())
+    }
+
+    pub fn pause_transfer(env: Env, caller: Address, paused: bool) -> Result<(), Err> {
+        crate::access_control::require_admin(&env, &caller)?;
+        crate::access_control::set_paused(&env, caller, &[DataKey::TransferPaused], paused);
+        Ok(())
+    }
+
+    pub fn pause_burn(env: Env, caller: Address, paused: bool) -> Result<(), Err> {
+        crate::access_control::require_admin(&env, &caller)?;
+        crate::access_control::set_paused(&env, caller, &[DataKey::BurnPaused], paused);
+        Ok(())
+    }
+
+    pub fn is_mint_paused(env: Env) -> bool {
+        crate::access_control::is_paused(&env, &DataKey::MintPaused)
+    }
+
+    pub fn is_transfer_paused(env: Env) -> bool {
+        crate::access_control::is_paused(&env, &DataKey::TransferPaused)
+    }
+
+    pub fn is_burn_paused(env: Env) -> bool {
+        crate::access_control::is_paused(&env, &DataKey::BurnPaused)
+    }
+
+    pub fn set_admin(env: Env, admin: Address, granted: bool) -> Result<(), Err> {
+        let owner = crate::access_control::require_owner(&env)?;
+        crate::council::require_no_council(&env)?;
+        write_admin(&env, admin, granted, owner);
+        Ok(())
+    }
+
+    /// Current owner; `None` once ownership has been renounced.
+    pub fn owner(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::OwnerRole)
+    }
+
+    /// Gives up ownership permanently. With metadata frozen and no admins left, the
+    /// collection can no longer be changed by anyone.
+    pub fn renounce_ownership(env: Env) -> Result<(), Err> {
+        crate::access_control::renounce_ownership(&env)
+    }
+
+    /// Gives up one of the caller's own roles.
+    pub fn renounce_role(env: Env, caller: Address, role: Role) -> Result<(), Err> {
+        crate::access_control::renounce_role(&env, caller, role)
+    }
+
+    /// Grants or revokes any role but Owner; the caller must hold that role's admin role. A
+    /// grant with `expires_at` lapses at that ledger timestamp. In council mode, Admin changes
+    /// need a council proposal.
+    pub fn set_role(
+        env: Env,
+        caller: Address,
+        role: Role,
+        account: Address,
+        granted: bool,
+        expires_at: Option<u64>,
+    ) -> Result<(), Err> {
+        crate::access_control::set_role(&env, caller, role, account, granted, expires_at)
+    }
+
+    /// When `account`'s grant of `role` lapses, if it is time-bound.
+    pub fn role_expiry(env: Env, role: Role, account: Address) -> Option<u64> {
+        crate::access_control::role_expiry(&env, role, &account)
+    }
+
+    /// Makes `admin_role` the role that grants and revokes `role`. Owner only.
+    pub fn set_role_admin(env: Env, role: Role, admin_role: Role) -> Result<(), Err> {
+        crate::access_control::set_role_admin(&env, role, admin_role)
+    }
+
+    /// Role whose holders grant and revoke `role`.
+    pub fn get_role_admin(env: Env, role: Role) -> Role {
+        crate::access_control::role_admin(&env, role)
+    }
+
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        crate::access_control::has_role(&env, &account, role)
+    }
+
+    pub fn set_minter(
+        env: Env,
+        caller: Address,
+        minter: Address,
+        granted: bool,
+    ) -> Result<(), Err> {
+        crate::access_control::set_role(
+            &env,
+            caller,
+            crate::types::Role::Minter,
+            minter,
+            granted,
+            None,
+        )
+    }
+
+    pub fn set_burner(
+        env: Env,
+        caller: Address,
+        burner: Address,
+        granted: bool,
+    ) -> Result<(), Err> {
+        crate::access_control::set_role(
+            &env,
+            caller,
+            crate::types::Role::Burner,
+            burner,
+            granted,
+            None,
+        )
+    }
+
+    pub fn set_metadata_updater(
+        env: Env,
+        caller: Address,
+        updater: Address,
+        granted: bool,
+    ) -> Result<(), Err> {
+        crate::access_control::set_role(
+            &env,
+            caller,
+            crate::types::Role::MetadataUpdater,
+            updater,
+            granted,
+            None,
+        )
+    }
+
+    pub fn set_renewer(
+        env: Env,
+        caller: Address,
+        renewer: Address,
+        granted: bool,
+    ) -> Result<(), Err> {
+        crate::access_control::set_role(
+            &env,
+            caller,
+            crate::types::Role::Renewer,
+            renewer,
+            granted,
+            None,
+        )
+    }
+
+    /// Sets a subscription token's expiry. Renewer role.
+    pub fn renew(env: Env, caller: Address, token_id: u64, new_expiry: u64) -> Result<(), Err> {
+        crate::subscription::renew(&env, caller, token_id, new_expiry)
+    }
+
+    /// Whether the token exists and its subscription (if any) has not expired.
+    pub fn is_valid(env: Env, token_id: u64) -> Result<bool, Err> {
+        crate::subscription::is_valid(&env, token_id)
+    }
+
+    /// Adds or removes `address` from the mint whitelist, clearing any allocation (so a
+    /// whitelisted address may mint without limit).
+    pub fn set_whitelist(
+        env: Env,
+        caller: Address,
+        address: Address,
+        allowed: bool,
+    ) -> Result<(), Err> {
+        crate::access_control::require_admin(&env, &caller)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::Whitelist(address.clone()), &allowed);
+        env.storage()
+            .instance()
+            .remove(&DataKey::WhitelistAllocation(address.clone()));
+        crate::events::emit_whitelist_updated(&env, address, allowed, None);
+        Ok(())
+    }
+
+    /// Whitelists `address` for `allocation` mints in whitelist-only mode; each minted token
+    /// uses one.
+    pub fn set_whitelist_allocation(
+        env: Env,
+        caller: Address,
+        address: Address,
+        allocation: u32,
+    ) -> Result<(), Err> {
+        crate::access_control::require_admin(&env, &caller)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::Whitelist(address.clone()), &true);
+        env.storage()
+            .instance()
+            .set(&DataKey::WhitelistAllocation(address.clone()), &allocation);
+        crate::events::emit_whitelist_updated(&env, address, true, Some(allocation));
+        Ok(())
+    }
+
+    /// Lets `contract_address` (e.g. a launchpad) mint up to `max_mints` tokens until
+    /// `expires_at` without the minter role. Replaces any earlier grant; 0 revokes it.
+    pub fn grant_mint_allowance(
+        env: Env,
+        admin: Address,
+        contract_address: Address,
+        max_mints: u32,
+        expires_at: u64,
+    ) -> Result<(), Err> {
+        crate::access_control::require_admin(&env, &admin)?;
+        let key = DataKey::MintAllowance(contract_address);
+        if max_mints == 0 {
+            env.storage().instance().remove(&key);
+            return Ok(());
+        }
+        if expires_at <= env.ledger().timestamp() {
+            return Err(Err::InvalidAmount);
+        }
+        env.storage().instance().set(
+            &key,
+            &crate::types::MintAllowance {
+                remaining: max_mints,
+                expires_at,
+            },
+        );
+        Ok(())
+    }
+
+    /// Mints left under `contract_address`'s allowance; 0 if none or expired.
+    pub fn remaining_allowance(env: Env, contract_address: Address) -> u32 {
+        crate::access_control::remaining_allowance(&env, &contract_address)
+    }
+
+    /// Whitelist mints left for `address`; `None` if its allocation is unlimited.
+    pub fn get_allocation(env: Env, address: Address) -> Option<u32> {
+        crate::access_control::allocation(&env, &address)
+    }
+
+    /// Turns restricted-transfer (KYC) mode on or off. While on, every transfer recipient must
+    /// be on the transfer allowlist.
+    pub fn set_transfer_restricted(env: Env, caller: Address, restricted: bool) -> Result<(), Err> {
+        crate::access_control::require_admin(&env, &caller)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::TransferRestricted, &restricted);
+        Ok(())
+    }
+
+    pub fn is_transfer_restricted(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::TransferRestricted)
+            .unwrap_or(false)
+    }
+
+    /// Adds or removes `address` from the transfer allowlist (separate from the mint whitelist).
+    pub fn set_transfer_allowlisted(
+        env: Env,
+        caller: Address,
+        address: Address,
+        allowed: bool,
+    ) -> Result<(), Err> {
+        crate::access_control::require_admin(&env, &caller)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::TransferAllowlist(address), &allowed);
+        Ok(())
+    }
+
+    pub fn is_transfer_allowlisted(env: Env, address: Address) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::TransferAllowlist(address))
+            .unwrap_or(false)
+    }
+
+    /// Turns royalty-enforcement mode on or off. While on, `approve` and
+    /// `set_approval_for_all` only accept operators on the operator allowlist. Admin only.
+    pub fn set_operator_filter(env: Env, caller: Address, enabled: bool) -> Result<(), Err> {
+        crate::access_control::require_admin(&env, &caller)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::OperatorFilter, &enabled);
+        crate::events::emit_operator_filter_updated(&env, caller, enabled);
+        Ok(())
+    }
+
+    pub fn is_operator_filtered(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::OperatorFilter)
+            .unwrap_or(false)
+    }
+
+    /// Adds or removes a royalty-honoring marketplace on the operator allowlist. Admin only.
+    pub fn set_operator_allowed(
+        env: Env,
+        caller: Address,
+        operator: Address,
+        allowed: bool,
+    ) -> Result<(), Err> {
+        crate::access_control::set_operator_allowed(&env, caller, operator, allowed)
+    }
+
+    pub fn allowed_operators(env: Env) -> Vec<Address> {
+        crate::access_control::allowed_operators(&env)
+    }
+
+    /// Adds or removes `address` from the denylist. Denylisted addresses cannot send, receive,
+    /// mint or approve tokens.
+    pub fn set_denylisted(
+        env: Env,
+        admin: Address,
+        address: Address,
+        denylisted: bool,
+    ) -> Result<(), Err> {
+        crate::access_control::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::Denylisted(address.clone()), &denylisted);
+        crate::events::emit_denylist_updated(&env, address, denylisted);
+        Ok(())
+    }
+
+    pub fn is_denylisted(env: Env, address: Address) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::Denylisted(address))
+            .unwrap_or(false)
+    }
+
+    /// Sets the minimum number of seconds between transfers of the same token; 0 disables it.
+    pub fn set_transfer_cooldown(env: Env, caller: Address, seconds: u64) -> Result<(), Err> {
+        crate::access_control::require_admin(&env, &caller)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::TransferCooldown, &seconds);
+        Ok(())
+    }
+
+    pub fn transfer_cooldown(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TransferCooldown)
+            .unwrap_or(0)
+    }
+
+    /// Sets the per-token attribute count and length limits enforced at mint and on updates.
+    pub fn set_attribute_limits(
+        env: Env,
+        caller: Address,
+        limits: AttributeLimits,
+    ) -> Result<(), Err> {
+        crate::metadata::set_attribute_limits(&env, &caller, limits)
+    }
+
+    pub fn attribute_limits(env: Env) -> AttributeLimits {
+        crate::metadata::attribute_limits(&env)
+    }
+
+    /// Registers a contract implementing `nft_transfer_hook::TransferHook` (at most 5).
+    pub fn add_transfer_hook(env: Env, caller: Address, hook: Address) -> Result<(), Err> {
+        crate::access_control::require_admin(&env, &caller)?;
+        crate::hooks::add(&env, hook)
+    }
+
+    pub fn remove_transfer_hook(env: Env, caller: Address, hook: Address) -> Result<(), Err> {
+        crate::access_control::require_admin(&env, &caller)?;
+        crate::hooks::remove(&env, &hook);
+        Ok(())
+    }
+
+    pub fn transfer_hooks(env: Env) -> Vec<Address> {
+        crate::hooks::hooks(&env)
+    }
+
+    /// Sets (or clears) the registry that deploys token-bound accounts for this collection.
+    pub fn set_account_registry(
+        env: Env,
+        caller: Address,
+        registry: Option<Address>,
+    ) -> Result<(), Err> {
+        crate::access_control::require_admin(&env, &caller)?;
+        match registry {
+            Some(registry) => {
+                env.storage()
+                    .instance()
+                    .set(&DataKey::AccountRegistry, &registry);
+                crate::interface::register(&env, crate::interface::INTERFACE_ID_TOKEN_BOUND);
+            }
+            None => {
+                env.storage().instance().remove(&DataKey::AccountRegistry);
+                crate::interface::deregister(&env, crate::interface::INTERFACE_ID_TOKEN_BOUND);
+            }
+        }
+        Ok(())
+    }
+
+    /// Token-bound account of a token; assets sent there are controlled by the token's owner.
+    pub fn account_of(env: Env, token_id: u64) -> Result<Address, Err> {
+        crate::accounts::account_of(&env, token_id)
+    }
+
+    /// Freezes or unfreezes a single token, e.g. while a dispute or theft report is handled.
+    /// A frozen token cannot be transferred or approved.
+    pub fn freeze_token(env: Env, admin: Address, token_id: u64, frozen: bool) -> Result<(), Err> {
+        crate::access_control::require_admin(&env, &admin)?;
+        if !crate::record::exists(&env, token_id) {
+            return Err(Err::TokenNotFound);
+        }
+        if frozen {
+            env.storage()
+                .instance()
+                .set(&DataKey::TokenFrozen(token_id), &true);
+        } else {
+            env.storage()
+                .instance()
+                .remove(&DataKey::TokenFrozen(token_id));
+        }
+        crate::events::emit_token_frozen(&env, token_id, frozen);
+        Ok(())
+    }
+
+    pub fn set_whitelist_only_mint(env: Env, caller: Address, enabled: bool) -> Result<(), Err> {
+        crate::access_control::require_admin(&env, &caller)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::WhitelistOnlyMint, &enabled);
+        crate::events::emit_whitelist_only_mint_updated(&env, enabled);
+        Ok(())
+    }
+
+    // --- Upgrades ---
+    /// Sets (or clears) the address allowed to upgrade the contract alongside the owner.
+    /// Clearing it opts the collection out of factory-pushed upgrades.
+    pub fn set_upgrader(env: Env, caller: Address, upgrader: Option<Address>) -> Result<(), Err> {
+        crate::access_control::require_admin(&env, &caller)?;
+        match upgrader {
+            Some(upgrader) => env.storage().instance().set(&DataKey::Upgrader, &upgrader),
+            None => env.storage().instance().remove(&DataKey::Upgrader),
+        }
+        Ok(())
+    }
+
+    pub fn upgrader(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Upgrader)
+    }
+
+    /// Replaces the contract code with an already-uploaded WASM. Callable by the owner or the
+    /// configured upgrader.
+    pub fn upgrade(env: Env, caller: Address, new_wasm_hash: BytesN<32>) -> Result<(), Err> {
+        require_upgrader(&env, &caller)?;
+        crate::council::require_no_council(&env)?;
+        crate::timelock::require_no_timelock(&env)?;
+        apply_upgrade(&env, caller, new_wasm_hash);
+        Ok(())
+    }
+
+    // --- Timelock ---
+    /// Turns on or raises the timelock delay (seconds) for `upgrade`, `set_base_uri` and
+    /// `set_default_royalty`; lowering it must be queued. Owner only.
+    pub fn set_timelock_delay(env: Env, delay: u64) -> Result<(), Err> {
+        crate::timelock::set_delay(&env, delay)
+    }
+
+    pub fn timelock_delay(env: Env) -> u64 {
+        crate::timelock::delay(&env)
+    }
+
+    /// Queues a timelocked action under the same authorization as its direct call; returns
+    /// the operation id.
+    pub fn queue_operation(
+        env: Env,
+        caller: Address,
+        action: crate::types::TimelockAction,
+    ) -> Result<u32, Err> {
+        crate::timelock::queue(&env, caller, action)
+    }
+
+    /// Executes a queued operation once its delay has elapsed.
+    pub fn execute_operation(env: Env, caller: Address, operation_id: u32) -> Result<(), Err> {
+        crate::timelock::execute(&env, caller, operation_id)
+    }
+
+    /// Drops a queued operation. Admin only.
+    pub fn cancel_operation(env: Env, caller: Address, operation_id: u32) -> Result<(), Err> {
+        crate::timelock::cancel(&env, caller, operation_id)
+    }
+
+    pub fn queued_operation(env: Env, operation_id: u32) -> Option<crate::types::QueuedOperation> {
+        crate::timelock::queued(&env, operation_id)
+    }
+
+    // --- Council ---
+    /// Turns on council mode: `upgrade`, `freeze_metadata`, `set_default_royalty` and
+    /// `set_admin` then need `threshold` of `members` to approve a proposal. Owner only.
+    pub fn set_council(env: Env, members: Vec<Address>, threshold: u32) -> Result<(), Err> {
+        crate::council::set_council(&env, members, threshold)
+    }
+
+    pub fn council(env: Env) -> Option<crate::types::Council> {
+        crate::council::council(&env)
+    }
+
+    /// Proposes a privileged operation; the proposer's approval is counted. Returns its id.
+    pub fn propose(
+        env: Env,
+        proposer: Address,
+        action: crate::types::CouncilAction,
+    ) -> Result<u32, Err> {
+        crate::council::propose(&env, proposer, action)
+    }
+
+    pub fn approve_proposal(env: Env, member: Address, proposal_id: u32) -> Result<(), Err> {
+        crate::council::approve(&env, member, proposal_id)
+    }
+
+    /// Executes a proposal that has reached the council's threshold.
+    pub fn execute_proposal(env: Env, member: Address, proposal_id: u32) -> Result<(), Err> {
+        crate::council::execute(&env, member, proposal_id)
+    }
+
+    /// Drops a pending proposal; proposer only.
+    pub fn cancel_proposal(env: Env, member: Address, proposal_id: u32) -> Result<(), Err> {
+        crate::council::cancel(&env, member, proposal_id)
+    }
+
+    pub fn proposal(env: Env, proposal_id: u32) -> Option<crate::types::Proposal> {
+        crate::council::proposal(&env, proposal_id)
+    }
+
+    /// Semver of the running contract code.
+    pub fn get_version(env: Env) -> String {
+        String::from_str(&env, crate::utils::CONTRACT_VERSION)
+    }
+
+    /// Applies pending storage migrations and returns the schema version reached; large steps
+    /// resume across calls, so call again until the version stops changing. Admin only.
+    pub fn migrate(env: Env, caller: Address) -> Result<u32, Err> {
+        let version = crate::migration::migrate(&env, caller)?;
+        if version == crate::migration::CURRENT_SCHEMA_VERSION {
+            record_version(&env);
+        }
+        Ok(version)
+    }
+
+    /// Storage layout version of this collection (0 before any migration).
+    pub fn schema_version(env: Env) -> u32 {
+        crate::migration::schema_version(&env)
+    }
+
+    /// Version recorded when storage was last initialized, upgraded or migrated; differs from
+    /// `get_version` on a collection whose storage predates its current code.
+    pub fn storage_version(env: Env) -> Option<String> {
+        env.storage().instance().get(&DataKey::ContractVersion)
+    }
+
+    /// Human-readable description of a `ContractError` code, e.g. from a failed CLI invocation.
+    pub fn error_message(env: Env, code: u32) -> String {
+        String::from_str(&env, crate::error::message(code))
+    }
+
+    // --- Interface detection (ERC-165 equivalent) ---
+    pub fn supports_interface(env: Env, interface_id: u32) -> bool {
+        crate::interface::supports(&env, interface_id)
     }
 }
 
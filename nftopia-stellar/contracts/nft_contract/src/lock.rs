@@ -0,0 +1,49 @@
+//! Soft staking: a locker contract (staking, quests) pins a token in its owner's wallet instead
+//! of taking custody. A locked token cannot be transferred, approved or burned until the
+//! locker releases it.
+
+use crate::access_control;
+use crate::error::ContractError;
+use crate::events;
+use crate::storage::DataKey;
+use soroban_sdk::{Address, Env};
+
+/// Locks `token_id` to `locker`. Both the owner and the locker must authorize, so a locker
+/// cannot lock tokens unasked and an owner cannot assign a locker that will never unlock.
+pub fn lock(
+    env: &Env,
+    owner: Address,
+    token_id: u64,
+    locker: Address,
+) -> Result<(), ContractError> {
+    owner.require_auth();
+    locker.require_auth();
+    let current: Address =
+        crate::record::owner(env, token_id).ok_or(ContractError::TokenNotFound)?;
+    if current != owner {
+        return Err(ContractError::NotAuthorized);
+    }
+    access_control::require_not_locked(env, token_id)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::Locker(token_id), &locker);
+    crate::approval::clear(env, &owner, token_id);
+    events::emit_locked(env, owner, token_id, locker);
+    Ok(())
+}
+
+/// Releases `token_id`. Only the locker that locked it may unlock it.
+pub fn unlock(env: &Env, locker: Address, token_id: u64) -> Result<(), ContractError> {
+    locker.require_auth();
+    if locker_of(env, token_id).as_ref() != Some(&locker) {
+        return Err(ContractError::NotAuthorized);
+    }
+    env.storage().instance().remove(&DataKey::Locker(token_id));
+    events::emit_unlocked(env, locker, token_id);
+    Ok(())
+}
+
+/// The contract holding `token_id`'s lock, if any.
+pub fn locker_of(env: &Env, token_id: u64) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Locker(token_id))
+}
@@ -0,0 +1,79 @@
+//! Garbage collection of dead state. Instance storage cannot be enumerated, so callers (e.g.
+//! an indexer-driven keeper) name the candidate entries; anything not actually stale is left
+//! alone, which keeps the entrypoint safe to open to everyone.
+
+use crate::access_control;
+use crate::error::ContractError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::{MintAllowance, Role, StaleEntry};
+use crate::utils::MAX_GC_BATCH;
+use soroban_sdk::{Env, Vec};
+
+/// Removes every entry in `entries` that is stale; returns how many were removed.
+pub fn collect_garbage(env: &Env, entries: Vec<StaleEntry>) -> Result<u32, ContractError> {
+    if entries.len() > MAX_GC_BATCH {
+        return Err(ContractError::BatchTooLarge);
+    }
+    let mut removed = 0;
+    for entry in entries.iter() {
+        let cleared = match entry {
+            StaleEntry::Approval(token_id) => clear_expired_approval(env, token_id),
+            StaleEntry::Role(role, account) => {
+                access_control::clear_expired_role(env, role, &account)
+            }
+            StaleEntry::MintAllowance(minter) => {
+                let key = DataKey::MintAllowance(minter);
+                let stale = env
+                    .storage()
+                    .instance()
+                    .get::<_, MintAllowance>(&key)
+                    .is_some_and(|a| a.remaining == 0 || env.ledger().timestamp() > a.expires_at);
+                if stale {
+                    env.storage().instance().remove(&key);
+                }
+                stale
+            }
+            StaleEntry::WhitelistAllocation(address) => {
+                let stale = access_control::allocation(env, &address) == Some(0)
+                    && !access_control::has_role(env, &address, Role::Owner);
+                if stale {
+                    env.storage()
+                        .instance()
+                        .remove(&DataKey::Whitelist(address.clone()));
+                    env.storage()
+                        .instance()
+                        .remove(&DataKey::WhitelistAllocation(address));
+                }
+                stale
+            }
+        };
+        if cleared {
+            removed += 1;
+        }
+    }
+    if removed > 0 {
+        events::emit_garbage_collected(env, removed);
+    }
+    Ok(removed)
+}
+
+/// Drops `token_id`'s approval once it has expired.
+fn clear_expired_approval(env: &Env, token_id: u64) -> bool {
+    let has_approval = env.storage().instance().has(&DataKey::Approved(token_id));
+    if !has_approval || crate::approval::approved(env, token_id).is_some() {
+        return false;
+    }
+    match crate::record::owner(env, token_id) {
+        Some(owner) => crate::approval::clear(env, &owner, token_id),
+        None => {
+            env.storage()
+                .instance()
+                .remove(&DataKey::Approved(token_id));
+            env.storage()
+                .instance()
+                .remove(&DataKey::ApprovalExpiry(token_id));
+        }
+    }
+    true
+}
@@ -1,99 +1,392 @@
 use crate::error::ContractError;
 use crate::events;
 use crate::storage::DataKey;
-use crate::types::{TokenAttribute, TokenMetadata};
-use soroban_sdk::{Address, Env, String, Vec};
+use crate::types::{
+    AttributeLimits, AttributeValue, CollectionConfig, TokenAttribute, TokenMetadata, TokenRecord,
+};
+use crate::utils::{
+    decimal_string, default_attribute_limits, validate_attribute, validate_attributes,
+    write_decimal,
+};
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
 
-/// Returns the token metadata URI. For relative URIs, clients should combine with base_uri.
+/// `token_metadata_batch` field groups: metadata URI.
+pub const FIELD_URI: u32 = 1 << 0;
+/// Attributes.
+pub const FIELD_ATTRIBUTES: u32 = 1 << 1;
+/// Royalty percentage and recipient.
+pub const FIELD_ROYALTY: u32 = 1 << 2;
+/// Edition number and total editions.
+pub const FIELD_EDITION: u32 = 1 << 3;
+/// Approved address.
+pub const FIELD_APPROVAL: u32 = 1 << 4;
+/// Frozen flag, content hash, evolved_from, valid_until and transfer_count.
+pub const FIELD_EXTRAS: u32 = 1 << 5;
+/// Every field group.
+pub const FIELDS_ALL: u32 = u32::MAX;
+
+/// Most tokens per `token_metadata_batch` call.
+pub const MAX_METADATA_BATCH: u32 = 50;
+
+/// Longest URI composed on-chain from base_uri, token id and suffix.
+const MAX_COMPOSED_URI_LEN: usize = 256;
+
+/// Most decimal digits in a token id.
+const MAX_TOKEN_ID_DIGITS: u32 = 20;
+
+/// Returns the token metadata URI. Tokens without a stored URI get `base_uri + id + suffix`
+/// when URI composition is enabled. Before reveal, returns the placeholder URI instead.
 pub fn token_uri(env: &Env, token_id: u64) -> Result<String, ContractError> {
-    env.storage()
-        .instance()
-        .get(&DataKey::TokenUri(token_id))
-        .ok_or(ContractError::TokenNotFound)
+    let uri = stored_or_composed_uri(env, token_id)?;
+    Ok(resolve_uri(env, uri))
 }
 
-/// Returns structured on-chain metadata for a token.
-pub fn token_metadata(env: &Env, token_id: u64) -> Result<TokenMetadata, ContractError> {
-    let owner: Address = env
+/// The token's explicit URI, or the composed one if composition is enabled.
+pub(crate) fn stored_or_composed_uri(env: &Env, token_id: u64) -> Result<String, ContractError> {
+    let record = crate::record::load(env, token_id).ok_or(ContractError::TokenNotFound)?;
+    record_uri(env, token_id, &record)
+}
+
+/// `record`'s explicit URI, or the composed one. A token minted under composition keeps
+/// resolving to `base_uri + id + suffix` after composition is disabled.
+fn record_uri(env: &Env, token_id: u64, record: &TokenRecord) -> Result<String, ContractError> {
+    if let Some(uri) = &record.uri {
+        return Ok(uri.clone());
+    }
+    let suffix: String = env
         .storage()
         .instance()
-        .get(&DataKey::Owner(token_id))
-        .ok_or(ContractError::TokenNotFound)?;
-    let approved: Option<Address> = env.storage().instance().get(&DataKey::Approved(token_id));
-    let metadata_uri: String = env
-        .storage()
+        .get(&DataKey::UriSuffix)
+        .unwrap_or_else(|| String::from_str(env, ""));
+    compose_uri(env, &base_uri(env), token_id, &suffix)
+}
+
+/// Builds `base + decimal(token_id) + suffix`.
+fn compose_uri(
+    env: &Env,
+    base: &String,
+    token_id: u64,
+    suffix: &String,
+) -> Result<String, ContractError> {
+    let mut digits = [0u8; 40];
+    let start = write_decimal(token_id.into(), &mut digits);
+    let digits = &digits[start..];
+
+    let base_len = base.len() as usize;
+    let suffix_len = suffix.len() as usize;
+    let total = base_len + digits.len() + suffix_len;
+    if total > MAX_COMPOSED_URI_LEN {
+        return Err(ContractError::UriTooLong);
+    }
+    let mut buf = [0u8; MAX_COMPOSED_URI_LEN];
+    base.copy_into_slice(&mut buf[..base_len]);
+    buf[base_len..base_len + digits.len()].copy_from_slice(digits);
+    suffix.copy_into_slice(&mut buf[base_len + digits.len()..total]);
+    Ok(String::from_bytes(env, &buf[..total]))
+}
+
+/// Fails with `UriTooLong` if composing `base` with the stored suffix could exceed the
+/// composed URI limit. Nothing to check while no suffix was ever set.
+fn require_composable(
+    env: &Env,
+    base: &String,
+    suffix: Option<String>,
+) -> Result<(), ContractError> {
+    let Some(suffix) = suffix.or_else(|| env.storage().instance().get(&DataKey::UriSuffix)) else {
+        return Ok(());
+    };
+    if base.len() + MAX_TOKEN_ID_DIGITS + suffix.len() > MAX_COMPOSED_URI_LEN as u32 {
+        return Err(ContractError::UriTooLong);
+    }
+    Ok(())
+}
+
+fn base_uri(env: &Env) -> String {
+    env.storage()
         .instance()
-        .get(&DataKey::TokenUri(token_id))
-        .ok_or(ContractError::TokenNotFound)?;
-    let created_at: u64 = env
+        .get(&DataKey::BaseUri)
+        .unwrap_or_else(|| String::from_str(env, ""))
+}
+
+/// Enables (`Some(suffix)`) or disables on-chain URI composition. While enabled, tokens
+/// minted with an empty URI store none and resolve to `base_uri + id + suffix`. Disabling
+/// only affects later mints: earlier composed tokens keep their suffix, which cannot change
+/// once a token was minted under it.
+pub fn set_uri_composition(
+    env: &Env,
+    caller: &Address,
+    suffix: Option<String>,
+) -> Result<(), ContractError> {
+    let frozen: bool = env
         .storage()
         .instance()
-        .get(&DataKey::TokenCreatedAt(token_id))
-        .ok_or(ContractError::TokenNotFound)?;
-    let creator: Address = env
-        .storage()
+        .get(&DataKey::MetadataFrozen)
+        .unwrap_or(false);
+    if frozen {
+        return Err(ContractError::MetadataFrozen);
+    }
+    crate::access_control::require_admin(env, caller)?;
+    let Some(suffix) = suffix else {
+        env.storage().instance().remove(&DataKey::ComposeUris);
+        return Ok(());
+    };
+    let current: Option<String> = env.storage().instance().get(&DataKey::UriSuffix);
+    let locked = env.storage().instance().has(&DataKey::UriSuffixLocked);
+    if locked && current != Some(suffix.clone()) {
+        return Err(ContractError::UriSuffixInUse);
+    }
+    require_composable(env, &base_uri(env), Some(suffix.clone()))?;
+    env.storage().instance().set(&DataKey::UriSuffix, &suffix);
+    env.storage().instance().set(&DataKey::ComposeUris, &true);
+    Ok(())
+}
+
+/// Whether on-chain URI composition is enabled.
+pub fn composes_uris(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::ComposeUris)
+}
+
+/// Fixes the URI suffix once a token is minted with a composed URI.
+pub(crate) fn lock_uri_suffix(env: &Env) {
+    env.storage()
         .instance()
-        .get(&DataKey::TokenCreator(token_id))
-        .ok_or(ContractError::TokenNotFound)?;
-    let royalty_bps: u32 = env
-        .storage()
+        .set(&DataKey::UriSuffixLocked, &true);
+}
+
+/// Substitutes the placeholder URI for `uri` while the collection is unrevealed.
+fn resolve_uri(env: &Env, uri: String) -> String {
+    if is_revealed(env) {
+        return uri;
+    }
+    env.storage()
         .instance()
-        .get(&DataKey::TokenRoyaltyBps(token_id))
+        .get(&DataKey::PlaceholderUri)
         .unwrap_or_else(|| {
-            let def: crate::types::RoyaltyInfo = env
-                .storage()
+            env.storage()
                 .instance()
-                .get(&DataKey::DefaultRoyalty)
-                .unwrap();
-            def.percentage
-        });
-    let royalty_recipient: Address = env
-        .storage()
+                .get(&DataKey::BaseUri)
+                .unwrap_or_else(|| String::from_str(env, ""))
+        })
+}
+
+/// Returns whether the collection metadata has been revealed.
+pub fn is_revealed(env: &Env) -> bool {
+    env.storage()
         .instance()
-        .get(&DataKey::TokenRoyaltyRecipient(token_id))
-        .unwrap_or_else(|| {
-            let def: crate::types::RoyaltyInfo = env
-                .storage()
-                .instance()
-                .get(&DataKey::DefaultRoyalty)
-                .unwrap();
-            def.recipient
-        });
-    let attributes: Vec<TokenAttribute> = env
-        .storage()
+        .get::<_, CollectionConfig>(&DataKey::CollectionConfig)
+        .map(|c| c.is_revealed)
+        .unwrap_or(true)
+}
+
+/// Sets the URI served by token_uri before reveal. Admin only; fails once revealed.
+pub fn set_placeholder_uri(env: &Env, caller: &Address, uri: String) -> Result<(), ContractError> {
+    crate::access_control::require_admin(env, caller)?;
+    if is_revealed(env) {
+        return Err(ContractError::AlreadyRevealed);
+    }
+    env.storage().instance().set(&DataKey::PlaceholderUri, &uri);
+    Ok(())
+}
+
+/// Commits the provenance hash of the final metadata set. Owner only; can be set once, before
+/// reveal. An unrevealed collection cannot mint until it is set, so the hash binds every token.
+pub fn commit_provenance(env: &Env, hash: BytesN<32>) -> Result<(), ContractError> {
+    crate::access_control::require_owner(env)?;
+    if is_revealed(env) {
+        return Err(ContractError::AlreadyRevealed);
+    }
+    if env.storage().instance().has(&DataKey::ProvenanceHash) {
+        return Err(ContractError::ProvenanceAlreadySet);
+    }
+    env.storage()
         .instance()
-        .get(&DataKey::TokenAttributes(token_id))
-        .unwrap_or_else(|| Vec::new(env));
-    let edition_number: Option<u32> = env
+        .set(&DataKey::ProvenanceHash, &hash);
+    events::emit_provenance_committed(env, hash);
+    Ok(())
+}
+
+/// Returns the committed provenance hash, if any.
+pub fn provenance_hash(env: &Env) -> Option<BytesN<32>> {
+    env.storage().instance().get(&DataKey::ProvenanceHash)
+}
+
+/// Reveals the collection: sets the real base URI and flips is_revealed. Admin only; requires
+/// a committed provenance hash; irreversible.
+pub fn reveal(env: &Env, caller: &Address, real_base_uri: String) -> Result<(), ContractError> {
+    let frozen: bool = env
         .storage()
         .instance()
-        .get(&DataKey::TokenEditionNumber(token_id));
-    let total_editions: Option<u32> = env
+        .get(&DataKey::MetadataFrozen)
+        .unwrap_or(false);
+    if frozen {
+        return Err(ContractError::MetadataFrozen);
+    }
+    crate::access_control::require_admin(env, caller)?;
+    let mut config: CollectionConfig = env
         .storage()
         .instance()
-        .get(&DataKey::TokenTotalEditions(token_id));
+        .get(&DataKey::CollectionConfig)
+        .ok_or(ContractError::NotFound)?;
+    if config.is_revealed {
+        return Err(ContractError::AlreadyRevealed);
+    }
+    let provenance = provenance_hash(env).ok_or(ContractError::ProvenanceNotSet)?;
+    require_composable(env, &real_base_uri, None)?;
+    config.is_revealed = true;
+    config.base_uri = real_base_uri.clone();
+    env.storage()
+        .instance()
+        .set(&DataKey::CollectionConfig, &config);
+    env.storage()
+        .instance()
+        .set(&DataKey::BaseUri, &real_base_uri);
+    env.storage().instance().remove(&DataKey::PlaceholderUri);
+    events::emit_revealed(env, caller.clone(), real_base_uri, Some(provenance));
+    Ok(())
+}
+
+/// Returns structured on-chain metadata for a token.
+pub fn token_metadata(env: &Env, token_id: u64) -> Result<TokenMetadata, ContractError> {
+    token_metadata_fields(env, token_id, FIELDS_ALL)
+}
+
+/// Metadata for each existing token in `token_ids` (missing or burned ids are skipped), with
+/// only the fields selected by `fields_mask` loaded; see `token_metadata_fields`.
+pub fn token_metadata_batch(
+    env: &Env,
+    token_ids: Vec<u64>,
+    fields_mask: u32,
+) -> Result<Vec<TokenMetadata>, ContractError> {
+    if token_ids.len() > MAX_METADATA_BATCH {
+        return Err(ContractError::BatchTooLarge);
+    }
+    let mut result = Vec::new(env);
+    for token_id in token_ids.iter() {
+        if crate::record::exists(env, token_id) {
+            result.push_back(token_metadata_fields(env, token_id, fields_mask)?);
+        }
+    }
+    Ok(result)
+}
+
+/// Token metadata with only the `FIELD_*` groups in `fields_mask` read from storage. `id`,
+/// `owner`, `created_at` and `creator` are always filled; skipped fields hold empty values
+/// (royalty: 0% to the creator).
+pub fn token_metadata_fields(
+    env: &Env,
+    token_id: u64,
+    fields_mask: u32,
+) -> Result<TokenMetadata, ContractError> {
+    let wants = |field: u32| fields_mask & field != 0;
+    let record = crate::record::load(env, token_id).ok_or(ContractError::TokenNotFound)?;
+    let approved = if wants(FIELD_APPROVAL) {
+        crate::approval::approved(env, token_id)
+    } else {
+        None
+    };
+    let metadata_uri = if wants(FIELD_URI) {
+        resolve_uri(env, record_uri(env, token_id, &record)?)
+    } else {
+        String::from_str(env, "")
+    };
+    let (royalty_bps, royalty_recipient) = if wants(FIELD_ROYALTY) {
+        token_royalty(env, &record)
+    } else {
+        (0, record.creator.clone())
+    };
+    let attributes: Vec<TokenAttribute> = if wants(FIELD_ATTRIBUTES) {
+        env.storage()
+            .instance()
+            .get(&DataKey::TokenAttributes(token_id))
+            .unwrap_or_else(|| Vec::new(env))
+    } else {
+        Vec::new(env)
+    };
+    let (edition_number, total_editions) = if wants(FIELD_EDITION) {
+        (record.edition_number, record.total_editions)
+    } else {
+        (None, None)
+    };
+    let extras = wants(FIELD_EXTRAS);
 
     Ok(TokenMetadata {
         id: token_id,
-        owner,
+        owner: record.owner,
         approved,
         metadata_uri,
-        created_at,
-        creator,
+        created_at: record.created_at,
+        creator: record.creator,
         royalty_percentage: royalty_bps,
         royalty_recipient,
         attributes,
         edition_number,
         total_editions,
+        frozen: extras
+            && env
+                .storage()
+                .instance()
+                .get(&DataKey::TokenFrozen(token_id))
+                .unwrap_or(false),
+        content_hash: if extras {
+            env.storage()
+                .instance()
+                .get(&DataKey::ContentHash(token_id))
+        } else {
+            None
+        },
+        evolved_from: if extras {
+            env.storage()
+                .instance()
+                .get(&DataKey::EvolvedFrom(token_id))
+        } else {
+            None
+        },
+        valid_until: if extras {
+            crate::subscription::valid_until(env, token_id)
+        } else {
+            None
+        },
+        transfer_count: if extras {
+            crate::transfer::transfer_count(env, token_id)
+        } else {
+            0
+        },
     })
 }
 
+/// A token's stored royalty (basis points, recipient), falling back to the collection default.
+fn token_royalty(env: &Env, record: &TokenRecord) -> (u32, Address) {
+    if let (Some(bps), Some(recipient)) = (record.royalty_bps, &record.royalty_recipient) {
+        return (bps, recipient.clone());
+    }
+    let def: crate::types::RoyaltyInfo = env
+        .storage()
+        .instance()
+        .get(&DataKey::DefaultRoyalty)
+        .unwrap();
+    (
+        record.royalty_bps.unwrap_or(def.percentage),
+        record.royalty_recipient.clone().unwrap_or(def.recipient),
+    )
+}
+
 /// Updates token URI. Requires owner or metadata updater role; fails if metadata is frozen.
 pub fn set_token_uri(
     env: &Env,
     token_id: u64,
     uri: String,
     caller: &Address,
+) -> Result<(), ContractError> {
+    caller.require_auth();
+    set_token_uri_authorized(env, token_id, uri, caller)
+}
+
+/// `set_token_uri` after the caller's auth has been checked.
+pub(crate) fn set_token_uri_authorized(
+    env: &Env,
+    token_id: u64,
+    uri: String,
+    caller: &Address,
 ) -> Result<(), ContractError> {
     let frozen: bool = env
         .storage()
@@ -103,25 +396,25 @@ pub fn set_token_uri(
     if frozen {
         return Err(ContractError::MetadataFrozen);
     }
-    let owner: Address = env
-        .storage()
-        .instance()
-        .get(&DataKey::Owner(token_id))
-        .ok_or(ContractError::TokenNotFound)?;
-    if *caller != owner {
-        crate::access_control::require_metadata_updater(env, caller)?;
-    } else {
-        caller.require_auth();
+    let mut record = crate::record::load(env, token_id).ok_or(ContractError::TokenNotFound)?;
+    if *caller != record.owner && !crate::access_control::is_metadata_updater(env, caller) {
+        return Err(ContractError::NotMetadataUpdater);
     }
-    env.storage()
-        .instance()
-        .set(&DataKey::TokenUri(token_id), &uri);
+    record.uri = Some(uri.clone());
+    crate::record::save(env, token_id, &record);
     events::emit_token_uri_updated(env, token_id, uri);
     Ok(())
 }
 
 /// Updates base URI. Admin only. Fails if metadata is frozen.
 pub fn set_base_uri(env: &Env, caller: &Address, base_uri: String) -> Result<(), ContractError> {
+    crate::access_control::require_admin(env, caller)?;
+    crate::timelock::require_no_timelock(env)?;
+    store_base_uri(env, base_uri)
+}
+
+/// Stores the base URI once authorized (directly or through the timelock).
+pub(crate) fn store_base_uri(env: &Env, base_uri: String) -> Result<(), ContractError> {
     let frozen: bool = env
         .storage()
         .instance()
@@ -130,20 +423,47 @@ pub fn set_base_uri(env: &Env, caller: &Address, base_uri: String) -> Result<(),
     if frozen {
         return Err(ContractError::MetadataFrozen);
     }
-    crate::access_control::require_admin(env, caller)?;
+    require_composable(env, &base_uri, None)?;
     env.storage().instance().set(&DataKey::BaseUri, &base_uri);
     events::emit_base_uri_updated(env, base_uri);
     Ok(())
 }
 
+/// Returns the collection-level metadata URI (cover image, description, links), if set.
+pub fn contract_uri(env: &Env) -> Option<String> {
+    env.storage().instance().get(&DataKey::ContractUri)
+}
+
+/// Updates the collection-level metadata URI. Admin only. Fails if metadata is frozen.
+pub fn set_contract_uri(env: &Env, caller: &Address, uri: String) -> Result<(), ContractError> {
+    let frozen: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey::MetadataFrozen)
+        .unwrap_or(false);
+    if frozen {
+        return Err(ContractError::MetadataFrozen);
+    }
+    crate::access_control::require_admin(env, caller)?;
+    env.storage().instance().set(&DataKey::ContractUri, &uri);
+    events::emit_contract_uri_updated(env, uri);
+    Ok(())
+}
+
 /// Permanently freezes metadata. Owner only. Irreversible.
 pub fn freeze_metadata(env: &Env, caller: Address) -> Result<(), ContractError> {
     crate::access_control::require_owner(env)?;
+    crate::council::require_no_council(env)?;
+    freeze(env, caller);
+    Ok(())
+}
+
+/// Freezes metadata once authorized (by the owner or a council proposal).
+pub(crate) fn freeze(env: &Env, by: Address) {
     env.storage()
         .instance()
         .set(&DataKey::MetadataFrozen, &true);
-    events::emit_metadata_frozen(env, caller);
-    Ok(())
+    events::emit_metadata_frozen(env, by);
 }
 
 /// Sets edition number and total editions for a token (limited editions). Owner or metadata updater; fails if metadata frozen.
@@ -153,6 +473,18 @@ pub fn set_edition_info(
     edition_number: Option<u32>,
     total_editions: Option<u32>,
     caller: &Address,
+) -> Result<(), ContractError> {
+    caller.require_auth();
+    set_edition_info_authorized(env, token_id, edition_number, total_editions, caller)
+}
+
+/// `set_edition_info` after the caller's auth has been checked.
+pub(crate) fn set_edition_info_authorized(
+    env: &Env,
+    token_id: u64,
+    edition_number: Option<u32>,
+    total_editions: Option<u32>,
+    caller: &Address,
 ) -> Result<(), ContractError> {
     let frozen: bool = env
         .storage()
@@ -162,33 +494,222 @@ pub fn set_edition_info(
     if frozen {
         return Err(ContractError::MetadataFrozen);
     }
-    let owner: Address = env
+    let mut record = crate::record::load(env, token_id).ok_or(ContractError::TokenNotFound)?;
+    if *caller != record.owner && !crate::access_control::is_metadata_updater(env, caller) {
+        return Err(ContractError::NotMetadataUpdater);
+    }
+    record.edition_number = edition_number;
+    record.total_editions = total_editions;
+    crate::record::save(env, token_id, &record);
+    Ok(())
+}
+
+/// Sets `trait_type` to `value` on a token, appending the attribute if absent.
+/// Metadata updater role (or owner/admin) only; fails if metadata is frozen.
+pub fn update_attribute(
+    env: &Env,
+    caller: &Address,
+    token_id: u64,
+    trait_type: String,
+    value: String,
+) -> Result<(), ContractError> {
+    caller.require_auth();
+    update_attribute_authorized(env, caller, token_id, trait_type, value)
+}
+
+/// `update_attribute` after the caller's auth has been checked.
+pub(crate) fn update_attribute_authorized(
+    env: &Env,
+    caller: &Address,
+    token_id: u64,
+    trait_type: String,
+    value: String,
+) -> Result<(), ContractError> {
+    set_attribute(
+        env,
+        caller,
+        token_id,
+        trait_type,
+        value,
+        AttributeValue::String,
+    )
+}
+
+/// Sets or adds `trait_type` with a typed value; `value` holds its string rendering so
+/// off-chain readers keep working. Same permissions as `update_attribute`.
+pub fn update_typed_attribute(
+    env: &Env,
+    caller: &Address,
+    token_id: u64,
+    trait_type: String,
+    value: AttributeValue,
+) -> Result<(), ContractError> {
+    let rendered = match &value {
+        AttributeValue::String => return Err(ContractError::InvalidAmount),
+        AttributeValue::I128(n) => decimal_string(env, *n),
+        AttributeValue::Bool(b) => String::from_str(env, if *b { "true" } else { "false" }),
+        AttributeValue::Timestamp(t) => decimal_string(env, i128::from(*t)),
+    };
+    caller.require_auth();
+    set_attribute(env, caller, token_id, trait_type, rendered, value)
+}
+
+/// Attribute limits in force: the admin's configuration, or the defaults in utils.
+pub fn attribute_limits(env: &Env) -> AttributeLimits {
+    env.storage()
+        .instance()
+        .get(&DataKey::AttributeLimits)
+        .unwrap_or_else(default_attribute_limits)
+}
+
+/// Replaces the attribute limits. Admin only; applies to future mints and updates.
+pub fn set_attribute_limits(
+    env: &Env,
+    caller: &Address,
+    limits: AttributeLimits,
+) -> Result<(), ContractError> {
+    crate::access_control::require_admin(env, caller)?;
+    if limits.max_attributes == 0 || limits.max_trait_len == 0 || limits.max_value_len == 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::AttributeLimits, &limits);
+    Ok(())
+}
+
+/// Checks a full attribute set (e.g. at mint) against the configured limits.
+pub fn validate_token_attributes(
+    env: &Env,
+    attributes: &Vec<TokenAttribute>,
+) -> Result<(), ContractError> {
+    validate_attributes(attributes, &attribute_limits(env))
+}
+
+fn set_attribute(
+    env: &Env,
+    caller: &Address,
+    token_id: u64,
+    trait_type: String,
+    value: String,
+    typed_value: AttributeValue,
+) -> Result<(), ContractError> {
+    let mut attributes = load_attributes_for_update(env, caller, token_id)?;
+    let limits = attribute_limits(env);
+    let position = attributes.iter().position(|a| a.trait_type == trait_type);
+    match position {
+        Some(i) => {
+            let mut attr = attributes.get(i as u32).unwrap();
+            attr.value = value.clone();
+            attr.typed_value = typed_value;
+            validate_attribute(&attr, &limits)?;
+            attributes.set(i as u32, attr);
+        }
+        None => {
+            let attr = TokenAttribute {
+                trait_type: trait_type.clone(),
+                value: value.clone(),
+                display_type: None,
+                typed_value,
+            };
+            validate_attribute(&attr, &limits)?;
+            attributes.push_back(attr);
+            if attributes.len() > limits.max_attributes {
+                return Err(ContractError::AttributeLimitExceeded);
+            }
+        }
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::TokenAttributes(token_id), &attributes);
+    events::emit_attribute_updated(env, token_id, trait_type, value);
+    Ok(())
+}
+
+/// Removes `trait_type` from a token's attributes.
+/// Metadata updater role (or owner/admin) only; fails if metadata is frozen.
+pub fn remove_attribute(
+    env: &Env,
+    caller: &Address,
+    token_id: u64,
+    trait_type: String,
+) -> Result<(), ContractError> {
+    caller.require_auth();
+    remove_attribute_authorized(env, caller, token_id, trait_type)
+}
+
+/// `remove_attribute` after the caller's auth has been checked.
+pub(crate) fn remove_attribute_authorized(
+    env: &Env,
+    caller: &Address,
+    token_id: u64,
+    trait_type: String,
+) -> Result<(), ContractError> {
+    let mut attributes = load_attributes_for_update(env, caller, token_id)?;
+    let i = attributes
+        .iter()
+        .position(|a| a.trait_type == trait_type)
+        .ok_or(ContractError::NotFound)?;
+    attributes.remove(i as u32);
+    env.storage()
+        .instance()
+        .set(&DataKey::TokenAttributes(token_id), &attributes);
+    events::emit_attribute_removed(env, token_id, trait_type);
+    Ok(())
+}
+
+fn load_attributes_for_update(
+    env: &Env,
+    caller: &Address,
+    token_id: u64,
+) -> Result<Vec<TokenAttribute>, ContractError> {
+    let frozen: bool = env
         .storage()
         .instance()
-        .get(&DataKey::Owner(token_id))
-        .ok_or(ContractError::TokenNotFound)?;
-    if *caller != owner {
-        crate::access_control::require_metadata_updater(env, caller)?;
-    } else {
-        caller.require_auth();
+        .get(&DataKey::MetadataFrozen)
+        .unwrap_or(false);
+    if frozen {
+        return Err(ContractError::MetadataFrozen);
     }
-    if let Some(n) = edition_number {
-        env.storage()
-            .instance()
-            .set(&DataKey::TokenEditionNumber(token_id), &n);
-    } else {
-        env.storage()
-            .instance()
-            .remove(&DataKey::TokenEditionNumber(token_id));
+    if !crate::record::exists(env, token_id) {
+        return Err(ContractError::TokenNotFound);
     }
-    if let Some(n) = total_editions {
-        env.storage()
-            .instance()
-            .set(&DataKey::TokenTotalEditions(token_id), &n);
-    } else {
-        env.storage()
-            .instance()
-            .remove(&DataKey::TokenTotalEditions(token_id));
+    if !crate::access_control::is_metadata_updater(env, caller) {
+        return Err(ContractError::NotMetadataUpdater);
+    }
+    Ok(env
+        .storage()
+        .instance()
+        .get(&DataKey::TokenAttributes(token_id))
+        .unwrap_or_else(|| Vec::new(env)))
+}
+
+/// The attribute named `trait_type`, including its typed value.
+pub fn get_attribute(
+    env: &Env,
+    token_id: u64,
+    trait_type: String,
+) -> Result<TokenAttribute, ContractError> {
+    let attributes: Vec<TokenAttribute> = env
+        .storage()
+        .instance()
+        .get(&DataKey::TokenAttributes(token_id))
+        .ok_or(ContractError::TokenNotFound)?;
+    attributes
+        .iter()
+        .find(|a| a.trait_type == trait_type)
+        .ok_or(ContractError::NotFound)
+}
+
+/// Numeric value of `trait_type` (an `I128` or `Timestamp` attribute).
+pub fn get_numeric_attribute(
+    env: &Env,
+    token_id: u64,
+    trait_type: String,
+) -> Result<i128, ContractError> {
+    match get_attribute(env, token_id, trait_type)?.typed_value {
+        AttributeValue::I128(n) => Ok(n),
+        AttributeValue::Timestamp(t) => Ok(i128::from(t)),
+        _ => Err(ContractError::AttributeNotNumeric),
     }
-    Ok(())
 }
@@ -0,0 +1,130 @@
+//! Versioned storage migrations. `SchemaVersion` records the storage layout a collection is
+//! in (absent means it predates this framework); `migrate` applies every later step in order.
+//! Steps that touch many keys run in bounded chunks and resume on the next call, so `migrate`
+//! is repeated until it reports `CURRENT_SCHEMA_VERSION`. Every step is idempotent.
+
+use crate::access_control;
+use crate::error::ContractError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::utils::MAX_BATCH_QUERY;
+use soroban_sdk::{Address, Env};
+
+/// Storage layout written by this code.
+/// 1: token core fields packed into one `TokenRecord`.
+/// 2: `HolderCount` rebuilt for collections whose tokens predate it.
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+/// Storage layout this collection is in.
+pub fn schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(0)
+}
+
+/// Marks a freshly initialized collection as already on the current layout.
+pub fn mark_current(env: &Env) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SchemaVersion, &CURRENT_SCHEMA_VERSION);
+}
+
+/// Applies pending migration steps; returns the schema version reached. Admin only.
+pub fn migrate(env: &Env, caller: Address) -> Result<u32, ContractError> {
+    access_control::require_admin(env, &caller)?;
+    let mut version = schema_version(env);
+    while version < CURRENT_SCHEMA_VERSION {
+        let done = match version + 1 {
+            1 => pack_token_records(env),
+            2 => seed_holder_count(env)?,
+            _ => true,
+        };
+        if !done {
+            return Ok(version);
+        }
+        version += 1;
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &version);
+        env.storage().instance().remove(&DataKey::MigrationCursor);
+        events::emit_migrated(env, version);
+    }
+    Ok(version)
+}
+
+/// Step 1: packs sequentially minted legacy tokens into records, `MAX_BATCH_QUERY` ids per
+/// call. Ids outside the sequential range are packed with `migrate_token_records`.
+fn pack_token_records(env: &Env) -> bool {
+    let next_id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextTokenId)
+        .unwrap_or(0);
+    let start: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::MigrationCursor)
+        .unwrap_or(0);
+    let end = start.saturating_add(MAX_BATCH_QUERY as u64).min(next_id);
+    for token_id in start..end {
+        crate::record::migrate(env, token_id);
+    }
+    if end >= next_id {
+        return true;
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::MigrationCursor, &end);
+    false
+}
+
+/// Step 2: recounts distinct holders over the sequential id range, `MAX_BATCH_QUERY` ids per
+/// call. A first pass counts each owner once, marking them `HolderSeen`; a second pass over
+/// the same ids clears the marks. Ownership must not move mid-walk, so minting, transfers
+/// and burns have to stay paused until the step completes. Content-addressed ids cannot be
+/// walked, so those collections keep their count.
+fn seed_holder_count(env: &Env) -> Result<bool, ContractError> {
+    if env.storage().instance().has(&DataKey::ContentAddressedIds) {
+        return Ok(true);
+    }
+    for flag in [
+        DataKey::MintPaused,
+        DataKey::TransferPaused,
+        DataKey::BurnPaused,
+    ] {
+        if !access_control::is_paused(env, &flag) {
+            return Err(ContractError::MigrationNotPaused);
+        }
+    }
+    let storage = env.storage().instance();
+    let next_id: u64 = storage.get(&DataKey::NextTokenId).unwrap_or(0);
+    let start: u64 = storage.get(&DataKey::MigrationCursor).unwrap_or(0);
+    let total = next_id.saturating_mul(2);
+    let end = start.saturating_add(MAX_BATCH_QUERY as u64).min(total);
+    let mut holders: u64 = if start == 0 {
+        0
+    } else {
+        storage.get(&DataKey::HolderCount).unwrap_or(0)
+    };
+    for cursor in start..end {
+        let counting = cursor < next_id;
+        let token_id = if counting { cursor } else { cursor - next_id };
+        let Some(owner) = crate::record::owner(env, token_id) else {
+            continue;
+        };
+        let seen = DataKey::HolderSeen(owner);
+        if !counting {
+            storage.remove(&seen);
+        } else if !storage.has(&seen) {
+            storage.set(&seen, &true);
+            holders += 1;
+        }
+    }
+    storage.set(&DataKey::HolderCount, &holders);
+    if end >= total {
+        return Ok(true);
+    }
+    storage.set(&DataKey::MigrationCursor, &end);
+    Ok(false)
+}
@@ -0,0 +1,58 @@
+//! Executes several of the contract's own operations in one invocation.
+
+use crate::error::ContractError;
+use crate::types::CallSpec;
+use crate::{approval, metadata, token, transfer};
+use soroban_sdk::{Address, Env, Vec};
+
+/// Maximum operations per `multicall`.
+pub const MAX_MULTICALL_CALLS: u32 = 20;
+
+/// Runs `calls` in order as `caller`. The first failing call returns its error, which aborts
+/// the whole invocation, so either every call takes effect or none does. `caller` authorizes
+/// once for the batch; each call then runs its `*_authorized` internal, so nothing the batch
+/// invokes (receivers, hooks) can act as `caller` without its own authorization.
+pub fn multicall(env: &Env, caller: Address, calls: Vec<CallSpec>) -> Result<(), ContractError> {
+    if calls.is_empty() {
+        return Err(ContractError::InvalidAmount);
+    }
+    if calls.len() > MAX_MULTICALL_CALLS {
+        return Err(ContractError::BatchTooLarge);
+    }
+    caller.require_auth();
+    for call in calls.iter() {
+        match call {
+            CallSpec::Approve(approved, token_id) => {
+                approval::approve_authorized(env, caller.clone(), approved, token_id, None)?
+            }
+            CallSpec::Transfer(to, token_id) => {
+                transfer::transfer_authorized(env, caller.clone(), to, token_id)?
+            }
+            CallSpec::SafeTransfer(to, token_id, data) => {
+                transfer::safe_transfer_authorized(env, caller.clone(), to, token_id, data)?
+            }
+            CallSpec::Burn(token_id, confirm) => {
+                token::burn_authorized(env, caller.clone(), token_id, confirm)?
+            }
+            CallSpec::SetTokenUri(token_id, uri) => {
+                metadata::set_token_uri_authorized(env, token_id, uri, &caller)?
+            }
+            CallSpec::SetEditionInfo(token_id, edition_number, total_editions) => {
+                metadata::set_edition_info_authorized(
+                    env,
+                    token_id,
+                    edition_number,
+                    total_editions,
+                    &caller,
+                )?
+            }
+            CallSpec::UpdateAttribute(token_id, trait_type, value) => {
+                metadata::update_attribute_authorized(env, &caller, token_id, trait_type, value)?
+            }
+            CallSpec::RemoveAttribute(token_id, trait_type) => {
+                metadata::remove_attribute_authorized(env, &caller, token_id, trait_type)?
+            }
+        }
+    }
+    Ok(())
+}
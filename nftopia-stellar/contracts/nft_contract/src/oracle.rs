@@ -0,0 +1,60 @@
+//! USD-denominated mint pricing: converts the collection's USD price into a payment token
+//! through an external SEP-40 price feed (e.g. Reflector).
+
+use crate::storage::DataKey;
+use crate::types::PriceOracle;
+use crate::utils::USD_PRICE_DECIMALS;
+use soroban_sdk::{Address, Env, Symbol, contractclient, contracttype, token};
+
+/// Asset identifier used by SEP-40 price feeds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Asset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+/// A SEP-40 price: USD per whole unit of the asset, scaled by the feed's `decimals`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+#[allow(dead_code)]
+#[contractclient(name = "PriceFeedClient")]
+pub trait PriceFeedInterface {
+    fn decimals(env: Env) -> u32;
+    fn lastprice(env: Env, asset: Asset) -> Option<PriceData>;
+}
+
+/// Configured price feed, if mint prices are USD-denominated.
+pub fn price_oracle(env: &Env) -> Option<PriceOracle> {
+    env.storage().instance().get(&DataKey::PriceOracle)
+}
+
+/// Mint price in `currency` converted from the USD price at the feed's latest rate, rounded
+/// up. `None` when no feed is set or it has no fresh, positive price for `currency`.
+pub fn quote(env: &Env, currency: &Address) -> Option<i128> {
+    let oracle = price_oracle(env)?;
+    let feed = PriceFeedClient::new(env, &oracle.feed);
+    let data = match feed.try_lastprice(&Asset::Stellar(currency.clone())) {
+        Ok(Ok(Some(data))) => data,
+        _ => return None,
+    };
+    let age = env.ledger().timestamp().checked_sub(data.timestamp)?;
+    if age > oracle.max_age || data.price <= 0 {
+        return None;
+    }
+    let feed_decimals = match feed.try_decimals() {
+        Ok(Ok(decimals)) => decimals,
+        _ => return None,
+    };
+    let token_decimals = token::Client::new(env, currency).decimals();
+    let numerator = oracle
+        .usd_price
+        .checked_mul(10i128.checked_pow(token_decimals.checked_add(feed_decimals)?)?)?;
+    let denominator = data.price.checked_mul(10i128.pow(USD_PRICE_DECIMALS))?;
+    Some(numerator.checked_add(denominator - 1)? / denominator)
+}
@@ -0,0 +1,36 @@
+//! Chain-of-custody log: every owner a token has had, with when they received it. Only the
+//! most recent `MAX_PROVENANCE_ENTRIES` entries are kept.
+
+use crate::storage::DataKey;
+use crate::types::ProvenanceEntry;
+use crate::utils::MAX_PROVENANCE_ENTRIES;
+use soroban_sdk::{Address, Env, Vec};
+
+/// Appends `owner` receiving `token_id` now, dropping the oldest entry when full.
+pub(crate) fn record(env: &Env, token_id: u64, owner: &Address) {
+    let key = DataKey::Provenance(token_id);
+    let mut log: Vec<ProvenanceEntry> = env
+        .storage()
+        .instance()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+    if log.len() >= MAX_PROVENANCE_ENTRIES {
+        log.pop_front();
+    }
+    log.push_back(ProvenanceEntry {
+        owner: owner.clone(),
+        timestamp: env.ledger().timestamp(),
+        ledger: env.ledger().sequence(),
+    });
+    env.storage().instance().set(&key, &log);
+}
+
+/// Up to `limit` entries of `token_id`'s log starting at `start`, oldest first.
+pub fn get_provenance(env: &Env, token_id: u64, start: u32, limit: u32) -> Vec<ProvenanceEntry> {
+    let log: Vec<ProvenanceEntry> = env
+        .storage()
+        .instance()
+        .get(&DataKey::Provenance(token_id))
+        .unwrap_or_else(|| Vec::new(env));
+    log.slice(crate::utils::page_range(start, limit, log.len()))
+}
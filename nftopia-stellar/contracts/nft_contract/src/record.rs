@@ -0,0 +1,75 @@
+//! Packed per-token storage. A token's core fields live in one `TokenRecord` under
+//! `DataKey::Token`; tokens minted before the record existed keep their per-field keys until
+//! migrated, and are read through the same accessors meanwhile.
+
+use crate::storage::DataKey;
+use crate::types::TokenRecord;
+use soroban_sdk::{Address, Env};
+
+/// The record of `token_id`, falling back to its legacy per-field keys.
+pub(crate) fn load(env: &Env, token_id: u64) -> Option<TokenRecord> {
+    let storage = env.storage().instance();
+    if let Some(record) = storage.get(&DataKey::Token(token_id)) {
+        return Some(record);
+    }
+    let owner: Address = storage.get(&DataKey::Owner(token_id))?;
+    Some(TokenRecord {
+        uri: storage.get(&DataKey::TokenUri(token_id)),
+        creator: storage
+            .get(&DataKey::TokenCreator(token_id))
+            .unwrap_or_else(|| owner.clone()),
+        created_at: storage.get(&DataKey::TokenCreatedAt(token_id)).unwrap_or(0),
+        royalty_bps: storage.get(&DataKey::TokenRoyaltyBps(token_id)),
+        royalty_recipient: storage.get(&DataKey::TokenRoyaltyRecipient(token_id)),
+        edition_number: storage.get(&DataKey::TokenEditionNumber(token_id)),
+        total_editions: storage.get(&DataKey::TokenTotalEditions(token_id)),
+        owner,
+    })
+}
+
+pub(crate) fn save(env: &Env, token_id: u64, record: &TokenRecord) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Token(token_id), record);
+}
+
+/// Current owner of `token_id`, if it exists.
+pub(crate) fn owner(env: &Env, token_id: u64) -> Option<Address> {
+    load(env, token_id).map(|record| record.owner)
+}
+
+pub(crate) fn exists(env: &Env, token_id: u64) -> bool {
+    let storage = env.storage().instance();
+    storage.has(&DataKey::Token(token_id)) || storage.has(&DataKey::Owner(token_id))
+}
+
+/// Deletes the record and any legacy keys of `token_id`.
+pub(crate) fn remove(env: &Env, token_id: u64) {
+    env.storage().instance().remove(&DataKey::Token(token_id));
+    remove_legacy(env, token_id);
+}
+
+/// Moves a legacy token onto a packed record. Returns false if there was nothing to migrate.
+pub(crate) fn migrate(env: &Env, token_id: u64) -> bool {
+    if env.storage().instance().has(&DataKey::Token(token_id)) {
+        return false;
+    }
+    let Some(record) = load(env, token_id) else {
+        return false;
+    };
+    save(env, token_id, &record);
+    remove_legacy(env, token_id);
+    true
+}
+
+fn remove_legacy(env: &Env, token_id: u64) {
+    let storage = env.storage().instance();
+    storage.remove(&DataKey::Owner(token_id));
+    storage.remove(&DataKey::TokenUri(token_id));
+    storage.remove(&DataKey::TokenCreator(token_id));
+    storage.remove(&DataKey::TokenCreatedAt(token_id));
+    storage.remove(&DataKey::TokenRoyaltyBps(token_id));
+    storage.remove(&DataKey::TokenRoyaltyRecipient(token_id));
+    storage.remove(&DataKey::TokenEditionNumber(token_id));
+    storage.remove(&DataKey::TokenTotalEditions(token_id));
+}
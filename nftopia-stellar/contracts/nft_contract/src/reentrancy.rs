@@ -1,30 +1,45 @@
 //! Reentrancy protection for critical operations (mint, burn, transfer).
+//!
+//! The lock lives in temporary storage: it never touches the instance entry, and a lock that
+//! somehow outlives its invocation expires with the entry's TTL instead of bricking the
+//! contract.
 
 use crate::error::ContractError;
 use crate::storage::DataKey;
 use soroban_sdk::Env;
 
-/// Acquires the reentrancy lock. Returns error if already locked.
-#[inline]
-pub fn acquire(env: &Env) -> Result<(), ContractError> {
-    let locked: bool = env
-        .storage()
-        .instance()
-        .get(&DataKey::ReentrancyLock)
-        .unwrap_or(false);
-    if locked {
-        return Err(ContractError::ReentrancyDetected);
+/// Holds the reentrancy lock; dropping it releases the lock, so every exit path (including
+/// `?` and early returns) unlocks.
+pub struct Guard<'a> {
+    env: &'a Env,
+}
+
+impl<'a> Guard<'a> {
+    /// Acquires the reentrancy lock. Returns error if already locked.
+    pub fn acquire(env: &'a Env) -> Result<Self, ContractError> {
+        let storage = env.storage().temporary();
+        if storage.has(&DataKey::ReentrancyLock) {
+            return Err(ContractError::ReentrancyDetected);
+        }
+        storage.set(&DataKey::ReentrancyLock, &true);
+        Ok(Guard { env })
+    }
+}
+
+impl Drop for Guard<'_> {
+    fn drop(&mut self) {
+        self.env
+            .storage()
+            .temporary()
+            .remove(&DataKey::ReentrancyLock);
     }
-    env.storage()
-        .instance()
-        .set(&DataKey::ReentrancyLock, &true);
-    Ok(())
 }
 
-/// Releases the reentrancy lock. Call after critical section (on both success and failure paths).
-#[inline]
-pub fn release(env: &Env) {
-    env.storage()
-        .instance()
-        .set(&DataKey::ReentrancyLock, &false);
+/// Runs `f` while holding the reentrancy lock.
+pub fn with_lock<T>(
+    env: &Env,
+    f: impl FnOnce() -> Result<T, ContractError>,
+) -> Result<T, ContractError> {
+    let _guard = Guard::acquire(env)?;
+    f()
 }
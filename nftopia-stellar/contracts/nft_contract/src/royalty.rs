@@ -1,41 +1,126 @@
 use crate::error::ContractError;
 use crate::events;
 use crate::storage::DataKey;
-use crate::types::RoyaltyInfo;
+use crate::types::{RoyaltyDecay, RoyaltyInfo};
 use crate::utils::{calculate_royalty, validate_royalty_bps};
 use soroban_sdk::Address;
 use soroban_sdk::Env;
+use soroban_sdk::{contractclient, token};
+
+/// Platform-wide royalty policy shared by collections (e.g. those deployed by the factory).
+#[allow(dead_code)]
+#[contractclient(name = "RoyaltyRegistryClient")]
+pub trait RoyaltyRegistryInterface {
+    /// Royalty `(recipient, bps)` for a token of `collection`, if the registry has a policy.
+    fn royalty(env: Env, collection: Address, token_id: u64) -> Option<(Address, u32)>;
+}
 
 /// Returns (recipient, royalty_amount) for a given token and sale price (EIP-2981 equivalent).
+/// The token's override wins, then the collection default; when neither sets a royalty (no
+/// default, or a zero one) the royalty registry is consulted.
 pub fn get_royalty_info(
     env: &Env,
     token_id: u64,
     sale_price: i128,
 ) -> Result<(Address, i128), ContractError> {
-    let _owner: Address = env
-        .storage()
-        .instance()
-        .get(&DataKey::Owner(token_id))
-        .ok_or(ContractError::TokenNotFound)?;
-    let default_royalty: RoyaltyInfo = env
-        .storage()
-        .instance()
-        .get(&DataKey::DefaultRoyalty)
-        .ok_or(ContractError::NotFound)?;
-    let royalty_bps: u32 = env
-        .storage()
-        .instance()
-        .get(&DataKey::TokenRoyaltyBps(token_id))
-        .unwrap_or(default_royalty.percentage);
-    let recipient: Address = env
-        .storage()
-        .instance()
-        .get(&DataKey::TokenRoyaltyRecipient(token_id))
-        .unwrap_or(default_royalty.recipient);
+    let record = crate::record::load(env, token_id).ok_or(ContractError::TokenNotFound)?;
+    let default_royalty: Option<RoyaltyInfo> =
+        env.storage().instance().get(&DataKey::DefaultRoyalty);
+    let unset = record.royalty_bps.is_none()
+        && record.royalty_recipient.is_none()
+        && default_royalty.as_ref().is_none_or(|r| r.percentage == 0);
+    let (recipient, royalty_bps) = match unset.then(|| registry_royalty(env, token_id)).flatten() {
+        Some(royalty) => royalty,
+        None => {
+            let default_royalty = default_royalty.ok_or(ContractError::NotFound)?;
+            (
+                record
+                    .royalty_recipient
+                    .unwrap_or(default_royalty.recipient),
+                record.royalty_bps.unwrap_or(default_royalty.percentage),
+            )
+        }
+    };
+    let royalty_bps = apply_decay(env, token_id, record.created_at, royalty_bps);
     let (royalty_amount, _) = calculate_royalty(sale_price, royalty_bps);
     Ok((recipient, royalty_amount))
 }
 
+/// Registry consulted when neither the token nor the collection sets a royalty.
+pub fn royalty_registry(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::RoyaltyRegistry)
+}
+
+/// Sets (or clears) the royalty registry. Admin only.
+pub fn set_royalty_registry(
+    env: &Env,
+    caller: Address,
+    registry: Option<Address>,
+) -> Result<(), ContractError> {
+    crate::access_control::require_admin(env, &caller)?;
+    match &registry {
+        Some(registry) => env
+            .storage()
+            .instance()
+            .set(&DataKey::RoyaltyRegistry, registry),
+        None => env.storage().instance().remove(&DataKey::RoyaltyRegistry),
+    }
+    events::emit_royalty_registry_updated(env, registry);
+    Ok(())
+}
+
+/// The registry's valid royalty for `token_id`; a failing or out-of-range answer counts as none.
+fn registry_royalty(env: &Env, token_id: u64) -> Option<(Address, u32)> {
+    let registry = royalty_registry(env)?;
+    match RoyaltyRegistryClient::new(env, &registry)
+        .try_royalty(&env.current_contract_address(), &token_id)
+    {
+        Ok(Ok(Some((recipient, bps)))) if validate_royalty_bps(bps).is_ok() => {
+            Some((recipient, bps))
+        }
+        _ => None,
+    }
+}
+
+/// Settles the royalty on a sale: transfers the royalty for `sale_price` in `currency` from
+/// `payer` straight to the token's royalty recipient and records it in the recipient's
+/// earnings. Returns the amount paid.
+pub fn pay_royalty(
+    env: &Env,
+    payer: Address,
+    token_id: u64,
+    sale_price: i128,
+    currency: Address,
+) -> Result<i128, ContractError> {
+    if sale_price <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    payer.require_auth();
+    let (recipient, amount) = get_royalty_info(env, token_id, sale_price)?;
+    if amount > 0 {
+        crate::reentrancy::with_lock(env, || {
+            token::Client::new(env, &currency).transfer(&payer, &recipient, &amount);
+            Ok(())
+        })?;
+        crate::earnings::record_paid(env, recipient.clone(), currency.clone(), amount, false);
+    }
+    let key = DataKey::RoyaltiesPaid(token_id, currency.clone());
+    let paid = royalties_paid(env, token_id, currency.clone());
+    env.storage()
+        .instance()
+        .set(&key, &paid.saturating_add(amount));
+    events::emit_royalty_paid(env, token_id, payer, recipient, currency, amount);
+    Ok(amount)
+}
+
+/// Total royalties settled through `pay_royalty` for a token in `currency`.
+pub fn royalties_paid(env: &Env, token_id: u64, currency: Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::RoyaltiesPaid(token_id, currency))
+        .unwrap_or(0)
+}
+
 /// Sets default royalty for the collection. Admin only.
 pub fn set_default_royalty(
     env: &Env,
@@ -45,13 +130,26 @@ pub fn set_default_royalty(
 ) -> Result<(), ContractError> {
     validate_royalty_bps(percentage)?;
     crate::access_control::require_admin(env, &caller)?;
+    crate::council::require_no_council(env)?;
+    crate::timelock::require_no_timelock(env)?;
+    store_default_royalty(env, recipient, percentage)
+}
+
+/// Stores the default royalty once authorized (by an admin or a council proposal).
+pub(crate) fn store_default_royalty(
+    env: &Env,
+    recipient: Address,
+    percentage: u32,
+) -> Result<(), ContractError> {
+    validate_royalty_bps(percentage)?;
     let info = RoyaltyInfo {
-        recipient,
+        recipient: recipient.clone(),
         percentage,
     };
     env.storage()
         .instance()
         .set(&DataKey::DefaultRoyalty, &info);
+    crate::events::emit_royalty_default_updated(env, recipient, percentage);
     Ok(())
 }
 
@@ -64,22 +162,73 @@ pub fn set_royalty_info(
     percentage: u32,
 ) -> Result<(), ContractError> {
     validate_royalty_bps(percentage)?;
-    let owner: Address = env
-        .storage()
-        .instance()
-        .get(&DataKey::Owner(token_id))
-        .ok_or(ContractError::TokenNotFound)?;
-    if caller != owner {
+    let mut record = crate::record::load(env, token_id).ok_or(ContractError::TokenNotFound)?;
+    if caller != record.owner {
         crate::access_control::require_admin(env, &caller)?;
     } else {
         caller.require_auth();
     }
-    env.storage()
-        .instance()
-        .set(&DataKey::TokenRoyaltyBps(token_id), &percentage);
-    env.storage()
-        .instance()
-        .set(&DataKey::TokenRoyaltyRecipient(token_id), &recipient);
+    record.royalty_bps = Some(percentage);
+    record.royalty_recipient = Some(recipient.clone());
+    crate::record::save(env, token_id, &record);
     events::emit_royalty_updated(env, token_id, recipient, percentage);
     Ok(())
 }
+
+/// Removes a token's royalty override so it falls back to the collection default.
+/// Token creator or admin only.
+pub fn reset_token_royalty(env: &Env, caller: Address, token_id: u64) -> Result<(), ContractError> {
+    let mut record = crate::record::load(env, token_id).ok_or(ContractError::TokenNotFound)?;
+    if caller == record.creator {
+        caller.require_auth();
+    } else {
+        crate::access_control::require_admin(env, &caller)?;
+    }
+    record.royalty_bps = None;
+    record.royalty_recipient = None;
+    crate::record::save(env, token_id, &record);
+    events::emit_royalty_reset(env, token_id, caller);
+    Ok(())
+}
+
+/// Royalty basis points after the collection's decay schedule, if it has kicked in.
+fn apply_decay(env: &Env, token_id: u64, created_at: u64, royalty_bps: u32) -> u32 {
+    let Some(decay) = royalty_decay(env) else {
+        return royalty_bps;
+    };
+    let transfers = crate::transfer::transfer_count(env, token_id);
+    let aged = decay
+        .after_seconds
+        .is_some_and(|s| env.ledger().timestamp().saturating_sub(created_at) >= s);
+    let traded = decay.after_transfers.is_some_and(|n| transfers >= n);
+    if aged || traded {
+        royalty_bps.min(decay.decayed_percentage)
+    } else {
+        royalty_bps
+    }
+}
+
+pub fn royalty_decay(env: &Env) -> Option<RoyaltyDecay> {
+    env.storage().instance().get(&DataKey::RoyaltyDecay)
+}
+
+/// Sets or clears the collection's royalty decay schedule. Admin only; at least one trigger
+/// is required.
+pub fn set_royalty_decay(
+    env: &Env,
+    caller: Address,
+    decay: Option<RoyaltyDecay>,
+) -> Result<(), ContractError> {
+    crate::access_control::require_admin(env, &caller)?;
+    match decay {
+        Some(decay) => {
+            validate_royalty_bps(decay.decayed_percentage)?;
+            if decay.after_seconds.is_none() && decay.after_transfers.is_none() {
+                return Err(ContractError::InvalidRoyalty);
+            }
+            env.storage().instance().set(&DataKey::RoyaltyDecay, &decay);
+        }
+        None => env.storage().instance().remove(&DataKey::RoyaltyDecay),
+    }
+    Ok(())
+}
@@ -0,0 +1,321 @@
+//! Paid minting: anyone may mint by paying the price of one of the accepted SEP-41 currencies
+//! (e.g. XLM and USDC, each priced separately). Proceeds accumulate in the contract per
+//! currency until `withdraw` pays them out across the payout splits.
+
+use crate::access_control;
+use crate::error::ContractError;
+use crate::events;
+use crate::reentrancy;
+use crate::storage::DataKey;
+use crate::types::{MintPrice, PayoutSplit, PriceOracle, TokenAttribute};
+use crate::utils::{BPS_DENOMINATOR, MAX_MINT_CURRENCIES, MAX_PAYOUT_RECIPIENTS};
+use soroban_sdk::{Address, Env, String, Vec, token};
+
+/// Accepted currencies and their prices; empty when paid minting is off.
+pub fn mint_prices(env: &Env) -> Vec<MintPrice> {
+    env.storage()
+        .instance()
+        .get(&DataKey::MintPrices)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Price of a mint in `currency`, if it is accepted.
+pub fn mint_price(env: &Env, currency: &Address) -> Option<i128> {
+    mint_prices(env)
+        .iter()
+        .find(|p| p.currency == *currency)
+        .map(|p| p.price)
+}
+
+/// Accepts `currency` for `mint_paid` at `price`, replacing its earlier price; a price of 0
+/// stops accepting it. Admin only.
+pub fn set_mint_price(
+    env: &Env,
+    caller: Address,
+    price: i128,
+    currency: Address,
+) -> Result<(), ContractError> {
+    access_control::require_admin(env, &caller)?;
+    if price < 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    let mut prices = mint_prices(env);
+    let entry = MintPrice {
+        price,
+        currency: currency.clone(),
+    };
+    match prices.iter().position(|p| p.currency == currency) {
+        Some(i) if price == 0 => {
+            prices.remove(i as u32);
+        }
+        Some(i) => prices.set(i as u32, entry),
+        None if price == 0 => return Err(ContractError::CurrencyNotAccepted),
+        None => {
+            if prices.len() >= MAX_MINT_CURRENCIES {
+                return Err(ContractError::BatchTooLarge);
+            }
+            prices.push_back(entry);
+        }
+    }
+    env.storage().instance().set(&DataKey::MintPrices, &prices);
+    events::emit_mint_price_updated(env, price, currency);
+    Ok(())
+}
+
+/// Points paid mints at a USD price converted through a SEP-40 price feed, or back to fixed
+/// prices with `None`. Currencies must still be accepted; their fixed price is the fallback
+/// while the feed is stale. Admin only.
+pub fn set_price_oracle(
+    env: &Env,
+    caller: Address,
+    oracle: Option<PriceOracle>,
+) -> Result<(), ContractError> {
+    access_control::require_admin(env, &caller)?;
+    match oracle {
+        Some(o) => {
+            if o.usd_price <= 0 || o.max_age == 0 {
+                return Err(ContractError::InvalidAmount);
+            }
+            env.storage().instance().set(&DataKey::PriceOracle, &o);
+            events::emit_price_oracle_updated(env, Some(o.feed), o.usd_price, o.max_age);
+        }
+        None => {
+            env.storage().instance().remove(&DataKey::PriceOracle);
+            events::emit_price_oracle_updated(env, None, 0, 0);
+        }
+    }
+    Ok(())
+}
+
+/// What `mint_paid` currently charges in `currency`: the oracle quote when fresh, otherwise
+/// the fixed price.
+pub fn quote_mint_price(env: &Env, currency: &Address) -> Result<i128, ContractError> {
+    let fixed = mint_price(env, currency).ok_or(ContractError::CurrencyNotAccepted)?;
+    Ok(crate::oracle::quote(env, currency).unwrap_or(fixed))
+}
+
+/// Mints one token to `to`, charging `buyer` the mint price in `currency`, which must be
+/// accepted. No minter role is needed, but pause, whitelist-only mode and the supply cap apply
+/// as for `mint`.
+pub fn mint_paid(
+    env: &Env,
+    buyer: Address,
+    to: Address,
+    currency: Address,
+    metadata_uri: String,
+    attributes: Vec<TokenAttribute>,
+) -> Result<u64, ContractError> {
+    buyer.require_auth();
+    sell(
+        env,
+        &buyer,
+        buyer.clone(),
+        to,
+        currency,
+        metadata_uri,
+        attributes,
+    )
+}
+
+/// Mints one token to `to` on a relayer's call, charging `payer` (e.g. the relayer's
+/// treasury after a card payment) the mint price. Whitelist-only mode checks the recipient.
+/// Consumes one mint of the relayer's quota. Relayer role only.
+pub fn relay_mint(
+    env: &Env,
+    relayer: Address,
+    payer: Address,
+    to: Address,
+    currency: Address,
+    metadata_uri: String,
+    attributes: Vec<TokenAttribute>,
+) -> Result<u64, ContractError> {
+    access_control::require_relayer(env, &relayer)?;
+    if payer != relayer {
+        payer.require_auth();
+    }
+    consume_relayer_quota(env, &relayer)?;
+    let token_id = sell(
+        env,
+        &to,
+        payer.clone(),
+        to.clone(),
+        currency,
+        metadata_uri,
+        attributes,
+    )?;
+    events::emit_relayed_mint(env, relayer, to, payer, token_id);
+    Ok(token_id)
+}
+
+/// Charges `payer` the price in `currency` and mints one token to `to`, with mint-open checks
+/// (whitelist allocation included) applied to `minter`.
+fn sell(
+    env: &Env,
+    minter: &Address,
+    payer: Address,
+    to: Address,
+    currency: Address,
+    metadata_uri: String,
+    attributes: Vec<TokenAttribute>,
+) -> Result<u64, ContractError> {
+    let price = quote_mint_price(env, &currency)?;
+    crate::escrow::require_not_failed(env)?;
+    crate::token::require_mint_open(env, minter, 1)?;
+    let creator: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::OwnerRole)
+        .ok_or(ContractError::NotAuthorized)?;
+    reentrancy::with_lock(env, || {
+        token::Client::new(env, &currency).transfer(&payer, env.current_contract_address(), &price);
+        let proceeds = proceeds(env, currency.clone());
+        env.storage().instance().set(
+            &DataKey::Proceeds(currency.clone()),
+            &proceeds.saturating_add(price),
+        );
+        let token_id =
+            crate::token::mint_internal(env, creator, to, metadata_uri, attributes, None)?;
+        crate::escrow::record_sale(env, token_id, currency, price);
+        Ok(token_id)
+    })
+}
+
+/// Mints `relayer` has left; `None` means unlimited.
+pub fn relayer_quota(env: &Env, relayer: &Address) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RelayerQuota(relayer.clone()))
+}
+
+/// Sets how many more mints `relayer` may relay (`None` for unlimited). Admin only.
+pub fn set_relayer_quota(
+    env: &Env,
+    caller: Address,
+    relayer: Address,
+    quota: Option<u32>,
+) -> Result<(), ContractError> {
+    access_control::require_admin(env, &caller)?;
+    let key = DataKey::RelayerQuota(relayer);
+    match quota {
+        Some(quota) => env.storage().instance().set(&key, &quota),
+        None => env.storage().instance().remove(&key),
+    }
+    Ok(())
+}
+
+fn consume_relayer_quota(env: &Env, relayer: &Address) -> Result<(), ContractError> {
+    if let Some(quota) = relayer_quota(env, relayer) {
+        let remaining = quota
+            .checked_sub(1)
+            .ok_or(ContractError::AllocationExhausted)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::RelayerQuota(relayer.clone()), &remaining);
+    }
+    Ok(())
+}
+
+/// Primary-sale proceeds in `currency` awaiting `withdraw`.
+pub fn proceeds(env: &Env, currency: Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Proceeds(currency))
+        .unwrap_or(0)
+}
+
+/// Recipients of primary-sale proceeds; empty means everything goes to the owner.
+pub fn payout_splits(env: &Env) -> Vec<PayoutSplit> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PayoutSplits)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Sets the payout splits; shares must add up to 100%. An empty list pays the owner. Admin only.
+pub fn set_payout_splits(
+    env: &Env,
+    caller: Address,
+    splits: Vec<PayoutSplit>,
+) -> Result<(), ContractError> {
+    access_control::require_admin(env, &caller)?;
+    if splits.len() > MAX_PAYOUT_RECIPIENTS {
+        return Err(ContractError::BatchTooLarge);
+    }
+    if !splits.is_empty() {
+        let mut total: u32 = 0;
+        for split in splits.iter() {
+            if split.share_bps == 0 {
+                return Err(ContractError::InvalidAmount);
+            }
+            total = total
+                .checked_add(split.share_bps)
+                .ok_or(ContractError::Overflow)?;
+        }
+        if total != BPS_DENOMINATOR {
+            return Err(ContractError::InvalidAmount);
+        }
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::PayoutSplits, &splits);
+    Ok(())
+}
+
+/// Pays out the proceeds in `currency` across the payout splits, recording each payment as a
+/// primary sale in the recipient's earnings. After a failed escrow, refundable
+/// payments stay behind. Rounding dust goes to the first recipient. Anyone may trigger it;
+/// returns the amount distributed.
+pub fn withdraw(env: &Env, currency: Address) -> Result<i128, ContractError> {
+    let locked = crate::escrow::locked(env, currency.clone())?;
+    let total = proceeds(env, currency.clone()) - locked;
+    if total <= 0 {
+        return Err(ContractError::InsufficientBalance);
+    }
+    let mut splits = payout_splits(env);
+    if splits.is_empty() {
+        let owner: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::OwnerRole)
+            .ok_or(ContractError::NotFound)?;
+        splits.push_back(PayoutSplit {
+            recipient: owner,
+            share_bps: BPS_DENOMINATOR,
+        });
+    }
+    let key = DataKey::Proceeds(currency.clone());
+    if locked > 0 {
+        env.storage().instance().set(&key, &locked);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+    reentrancy::with_lock(env, || {
+        let mut first_amount = total;
+        for split in splits.iter().skip(1) {
+            let amount = total * split.share_bps as i128 / BPS_DENOMINATOR as i128;
+            first_amount -= amount;
+            pay(env, &currency, split.recipient, amount);
+        }
+        pay(
+            env,
+            &currency,
+            splits.get(0).unwrap().recipient,
+            first_amount,
+        );
+        Ok(())
+    })?;
+    events::emit_proceeds_withdrawn(env, currency, total);
+    Ok(total)
+}
+
+fn pay(env: &Env, currency: &Address, recipient: Address, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    token::Client::new(env, currency).transfer(
+        &env.current_contract_address(),
+        &recipient,
+        &amount,
+    );
+    crate::earnings::record_paid(env, recipient, currency.clone(), amount, true);
+}
@@ -13,40 +13,127 @@ pub enum DataKey {
     DefaultRoyalty,
     /// Total number of tokens ever minted (also next token id if sequential).
     NextTokenId,
+    /// Tokens ever minted (sequential and explicit ids).
+    TotalMinted,
+    /// Tokens ever burned.
+    TotalBurned,
+    /// Set for every burned token id.
+    Burned(u64),
+    /// Explicitly minted id at or above NextTokenId; never reissued, even after burn.
+    UsedTokenId(u64),
+    /// Set when `mint`/`batch_mint` derive token ids from content hashes.
+    ContentAddressedIds,
+    /// Per-call batch limit from the collection config (optional).
+    MaxBatchSize,
+    /// Number of addresses holding at least one token.
+    HolderCount,
     /// Total supply (number of tokens in existence).
     TotalSupply,
     /// Max supply cap (optional).
     MaxSupply,
-    /// Owner of a specific token.
+    /// Who may burn tokens (`BurnPolicy::Enabled` when absent).
+    BurnPolicy,
+    /// Most tokens a single mint call may create (optional).
+    MaxPerTransaction,
+    /// Mints a relayer has left; no entry means unlimited.
+    RelayerQuota(Address),
+    /// Timestamp minting opens at (optional).
+    MintStart,
+    /// Timestamp minting closes at (optional).
+    MintEnd,
+    /// Currencies accepted for paid mints, each with its price.
+    MintPrices,
+    /// Price feed for USD-denominated paid mints (optional).
+    PriceOracle,
+    /// Fair-launch escrow holding paid-mint proceeds (optional).
+    MintEscrow,
+    /// Paid mints sold while the escrow was pending.
+    EscrowSold,
+    /// Refundable payment for a token minted under the escrow.
+    EscrowPayment(u64),
+    /// Sum of outstanding `EscrowPayment`s, per currency.
+    EscrowHeld(Address),
+    /// Paid-mint proceeds awaiting `withdraw`, per currency.
+    Proceeds(Address),
+    /// Recipients and shares of primary-sale proceeds.
+    PayoutSplits,
+    /// Packed core fields of a token (owner, URI, creator, royalty, editions).
+    Token(u64),
+    /// Legacy owner of a token minted before `Token` records; see `record::migrate`.
     Owner(u64),
     /// Approved address for a specific token.
     Approved(u64),
+    /// Timestamp at which the token's approval lapses (time-limited approvals only).
+    ApprovalExpiry(u64),
     /// Balance of an address (number of tokens owned).
     Balance(Address),
     /// Operator approval: owner -> operator -> allowed.
     OperatorApproval(Address, Address),
-    /// Token metadata URI.
+    /// Tokens of an owner that currently carry an approval (for revoke_all_approvals).
+    OwnerApprovedTokens(Address),
+    /// Operators an owner has approved (for revoke_all_approvals).
+    OwnerOperators(Address),
+    /// Legacy token metadata URI (now in `Token`).
     TokenUri(u64),
-    /// Token creation timestamp.
+    /// Legacy token creation timestamp (now in `Token`).
     TokenCreatedAt(u64),
-    /// Token creator.
+    /// Legacy token creator (now in `Token`).
     TokenCreator(u64),
-    /// Token-level royalty percentage (basis points). Overrides default if set.
+    /// Legacy token-level royalty percentage (now in `Token`).
     TokenRoyaltyBps(u64),
-    /// Token-level royalty recipient. Overrides default if set.
+    /// Legacy token-level royalty recipient (now in `Token`).
     TokenRoyaltyRecipient(u64),
     /// Token attributes (on-chain metadata).
     TokenAttributes(u64),
-    /// Edition number for limited editions.
+    /// Attribute count and length limits (optional; defaults in utils).
+    AttributeLimits,
+    /// sha256 of the token's media, committed at mint.
+    ContentHash(u64),
+    /// Legacy edition number (now in `Token`).
     TokenEditionNumber(u64),
-    /// Total editions for limited editions.
+    /// Legacy total editions (now in `Token`).
     TokenTotalEditions(u64),
+    /// Token this one replaced through `evolve`.
+    EvolvedFrom(u64),
+    /// Highest edition number issued from a master token via mint_edition.
+    EditionsIssued(u64),
+    /// Admin freeze on a single token (blocks transfers and approvals).
+    TokenFrozen(u64),
+    /// Number of times the token has changed hands.
+    TransferCount(u64),
+    /// Royalties settled through pay_royalty for a token, per payment token: (token_id, currency).
+    RoyaltiesPaid(u64, Address),
+    /// Earnings paid out to a creator, per payment token: (creator, currency).
+    CreatorEarnings(Address, Address),
+    /// Collection royalty decay schedule (optional).
+    RoyaltyDecay,
+    /// Timestamp of the token's last transfer.
+    LastTransferAt(u64),
+    /// Minimum seconds between transfers of the same token (optional).
+    TransferCooldown,
+    /// Registry that deploys token-bound accounts (optional).
+    AccountRegistry,
+    /// Platform royalty registry consulted when no royalty is set (optional).
+    RoyaltyRegistry,
     /// Base URI for the collection.
     BaseUri,
+    /// Suffix for on-chain composed token URIs. Kept when composition is disabled, so tokens
+    /// minted under it keep resolving to the same URI.
+    UriSuffix,
+    /// Whether tokens minted with an empty URI get a composed one.
+    ComposeUris,
+    /// Set once a token is minted with a composed URI; the suffix is fixed from then on.
+    UriSuffixLocked,
+    /// Collection-level metadata URI (contract_uri).
+    ContractUri,
     /// Whether metadata is frozen (immutable).
     MetadataFrozen,
-    /// Contract paused state.
-    Paused,
+    /// Minting paused.
+    MintPaused,
+    /// Transfers paused.
+    TransferPaused,
+    /// Burning paused.
+    BurnPaused,
     /// Contract owner (admin owner).
     OwnerRole,
     /// Admin addresses.
@@ -57,10 +144,74 @@ pub enum DataKey {
     Burner(Address),
     /// Metadata updater role.
     MetadataUpdater(Address),
+    /// Renewer role (extends subscription expiries).
+    Renewer(Address),
+    /// Holders of roles without a dedicated key (e.g. `MinterAdmin`).
+    RoleMember(crate::types::Role, Address),
+    /// When a time-bound role grant lapses.
+    RoleExpiry(crate::types::Role, Address),
+    /// Role whose holders administer the given role, when not the default.
+    RoleAdmin(crate::types::Role),
+    /// Delegation of claim/utility rights: (owner, delegate, scope).
+    Delegation(Address, Address, crate::types::DelegationScope),
     /// Whitelist for minting.
     Whitelist(Address),
+    /// Addresses barred from sending, receiving, minting or approving (compliance).
+    Denylisted(Address),
+    /// Whitelist mints left for an address (optional; unlimited when absent).
+    WhitelistAllocation(Address),
+    /// Time-limited mint quota for an address without the minter role.
+    MintAllowance(Address),
+    /// Escrowed token awaiting its claimant (see `mint_claimable`).
+    Claimable(u64),
+    /// Recipient a token has been offered to, pending their acceptance.
+    TransferOffer(u64),
+    /// Expiry timestamp of a subscription token.
+    ValidUntil(u64),
+    /// Recent owners of a token (bounded provenance log).
+    Provenance(u64),
+    /// Contract holding a lock on a token (staking without custody).
+    Locker(u64),
+    /// When true, transfer recipients must be on the transfer allowlist (KYC mode).
+    TransferRestricted,
+    /// Allowlist of transfer recipients, separate from the mint whitelist.
+    TransferAllowlist(Address),
+    /// When true, approvals may only go to operators on the operator allowlist.
+    OperatorFilter,
+    /// Royalty-honoring marketplaces that may be approved while the operator filter is on.
+    AllowedOperators,
     /// When true, only whitelisted addresses can mint.
     WhitelistOnlyMint,
-    /// Reentrancy lock.
+    /// Contracts notified before and after every transfer.
+    TransferHooks,
+    /// Reentrancy lock (temporary storage).
     ReentrancyLock,
+    /// URI served for every token before reveal.
+    PlaceholderUri,
+    /// Provenance hash committed before reveal.
+    ProvenanceHash,
+    /// Address (e.g. the deploying factory) allowed to push WASM upgrades besides the owner.
+    Upgrader,
+    /// Interface IDs reported by `supports_interface`.
+    Interfaces,
+    /// Contract version that last initialized, upgraded or migrated this collection's storage.
+    ContractVersion,
+    /// Storage layout version (see `migration`); absent on collections predating it.
+    SchemaVersion,
+    /// Progress of a migration step that spans several `migrate` calls.
+    MigrationCursor,
+    /// Owner already counted while migration step 2 rebuilds `HolderCount`.
+    HolderSeen(Address),
+    /// Council whose approval privileged operations need (council mode).
+    Council,
+    /// Pending council proposal.
+    Proposal(u32),
+    /// Id of the next council proposal.
+    NextProposalId,
+    /// Seconds sensitive admin actions wait in the timelock queue.
+    TimelockDelay,
+    /// Timelock-queued operation.
+    QueuedOperation(u32),
+    /// Id of the next timelock-queued operation.
+    NextQueuedOperationId,
 }
@@ -0,0 +1,38 @@
+//! Expirable tokens for memberships and passes: a token with a `valid_until` timestamp stays
+//! owned and transferable after expiry, but `is_valid` reports false until it is renewed.
+
+use crate::error::ContractError;
+use crate::events;
+use crate::storage::DataKey;
+use soroban_sdk::{Address, Env};
+
+/// Sets `token_id`'s expiry to `new_expiry`. Renewer role (or owner/admin).
+pub fn renew(
+    env: &Env,
+    caller: Address,
+    token_id: u64,
+    new_expiry: u64,
+) -> Result<(), ContractError> {
+    crate::access_control::require_renewer(env, &caller)?;
+    if !crate::record::exists(env, token_id) {
+        return Err(ContractError::TokenNotFound);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::ValidUntil(token_id), &new_expiry);
+    events::emit_renewed(env, token_id, new_expiry);
+    Ok(())
+}
+
+/// Expiry timestamp of `token_id`, or `None` if it never expires.
+pub fn valid_until(env: &Env, token_id: u64) -> Option<u64> {
+    env.storage().instance().get(&DataKey::ValidUntil(token_id))
+}
+
+/// Whether `token_id` exists and has not expired.
+pub fn is_valid(env: &Env, token_id: u64) -> Result<bool, ContractError> {
+    if !crate::record::exists(env, token_id) {
+        return Err(ContractError::TokenNotFound);
+    }
+    Ok(valid_until(env, token_id).is_none_or(|until| env.ledger().timestamp() <= until))
+}
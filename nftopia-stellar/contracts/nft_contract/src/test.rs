@@ -1,9 +1,12 @@
 #![cfg(test)]
 
-use crate::types::{CollectionConfig, RoyaltyInfo, TokenAttribute};
+use crate::types::{
+    AttributeLimits, AttributeValue, CallSpec, CollectionConfig, DelegationScope, RoyaltyDecay,
+    RoyaltyInfo, TokenAttribute,
+};
 use crate::{NftContract, NftContractClient};
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{Address, Env, String, Vec};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Env, String, Vec, symbol_short};
 
 fn create_test_config(env: &Env, admin: &Address) -> CollectionConfig {
     CollectionConfig {
@@ -11,13 +14,14 @@ fn create_test_config(env: &Env, admin: &Address) -> CollectionConfig {
         symbol: String::from_str(env, "TNFT"),
         base_uri: String::from_str(env, "https://nftopia.test/"),
         max_supply: Some(1000),
-        mint_price: None,
         is_revealed: true,
         royalty_default: RoyaltyInfo {
             recipient: admin.clone(),
             percentage: 500, // 5%
         },
         metadata_is_frozen: false,
+        content_addressed_ids: false,
+        max_batch_size: None,
     }
 }
 
@@ -164,9 +168,23 @@ fn test_burn() {
 #[test]
 fn test_supports_interface() {
     let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
     let contract_id = env.register(NftContract, ());
     let client = NftContractClient::new(&env, &contract_id);
+    assert!(!client.supports_interface(&0x80ac58cd));
+
+    client.initialize(&admin, &create_test_config(&env, &admin));
     assert!(client.supports_interface(&0x80ac58cd));
+    assert!(client.supports_interface(&crate::interface::INTERFACE_ID_LOCKABLE));
+    assert!(!client.supports_interface(&crate::interface::INTERFACE_ID_TOKEN_BOUND));
+
+    let registry = Address::generate(&env);
+    client.set_account_registry(&admin, &Some(registry));
+    assert!(client.supports_interface(&crate::interface::INTERFACE_ID_TOKEN_BOUND));
+    client.set_account_registry(&admin, &None);
+    assert!(!client.supports_interface(&crate::interface::INTERFACE_ID_TOKEN_BOUND));
 }
 
 #[test]
@@ -197,3 +215,3353 @@ fn test_edition_info() {
     assert_eq!(meta.edition_number, Some(1));
     assert_eq!(meta.total_editions, Some(10));
 }
+
+#[test]
+fn test_airdrop() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+
+    let config = create_test_config(&env, &admin);
+    client.initialize(&admin, &config);
+
+    let mut recipients: Vec<Address> = Vec::new(&env);
+    recipients.push_back(user1.clone());
+    recipients.push_back(user2.clone());
+    let uri = String::from_str(&env, "ipfs://edition");
+
+    let ids = client.airdrop(&admin, &recipients, &uri);
+    assert_eq!(ids.len(), 2);
+    assert_eq!(client.owner_of(&ids.get(0).unwrap()), user1);
+    assert_eq!(client.owner_of(&ids.get(1).unwrap()), user2);
+    assert_eq!(client.token_uri(&ids.get(1).unwrap()), uri);
+    assert_eq!(client.total_supply(), 2);
+
+    let mut too_many: Vec<Address> = Vec::new(&env);
+    for _ in 0..101 {
+        too_many.push_back(user1.clone());
+    }
+    assert_eq!(
+        client.try_airdrop(&admin, &too_many, &uri),
+        Err(Ok(crate::ContractError::BatchTooLarge))
+    );
+}
+
+#[test]
+fn test_delayed_reveal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+
+    let mut config = create_test_config(&env, &admin);
+    config.is_revealed = false;
+    client.initialize(&admin, &config);
+
+    let uri = String::from_str(&env, "1.json");
+    assert_eq!(
+        client.try_mint(&admin, &user, &uri, &Vec::new(&env), &None),
+        Err(Ok(crate::ContractError::ProvenanceNotSet))
+    );
+    assert_eq!(
+        client.try_reveal(&admin, &String::from_str(&env, "ipfs://real/")),
+        Err(Ok(crate::ContractError::ProvenanceNotSet))
+    );
+
+    let hash = BytesN::from_array(&env, &[7u8; 32]);
+    client.commit_provenance(&hash);
+    assert_eq!(client.provenance_hash(), Some(hash.clone()));
+    assert_eq!(
+        client.try_commit_provenance(&hash),
+        Err(Ok(crate::ContractError::ProvenanceAlreadySet))
+    );
+
+    let placeholder = String::from_str(&env, "ipfs://hidden.json");
+    client.set_placeholder_uri(&admin, &placeholder);
+
+    let id = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+    assert!(!client.is_revealed());
+    assert_eq!(client.token_uri(&id), placeholder);
+
+    client.reveal(&admin, &String::from_str(&env, "ipfs://real/"));
+    assert!(client.is_revealed());
+    assert_eq!(client.token_uri(&id), uri);
+    assert_eq!(
+        client.try_reveal(&admin, &String::from_str(&env, "ipfs://other/")),
+        Err(Ok(crate::ContractError::AlreadyRevealed))
+    );
+}
+
+#[test]
+fn test_update_and_remove_attribute() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let game = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+
+    let config = create_test_config(&env, &admin);
+    client.initialize(&admin, &config);
+    client.set_metadata_updater(&admin, &game, &true);
+
+    let mut attrs: Vec<TokenAttribute> = Vec::new(&env);
+    attrs.push_back(TokenAttribute {
+        trait_type: String::from_str(&env, "level"),
+        value: String::from_str(&env, "1"),
+        display_type: Some(String::from_str(&env, "number")),
+        typed_value: AttributeValue::String,
+    });
+    let id = client.mint(
+        &admin,
+        &user,
+        &String::from_str(&env, "ipfs://hash"),
+        &attrs,
+        &None,
+    );
+
+    let level = String::from_str(&env, "level");
+    client.update_attribute(&game, &id, &level, &String::from_str(&env, "2"));
+    client.update_attribute(
+        &game,
+        &id,
+        &String::from_str(&env, "class"),
+        &String::from_str(&env, "mage"),
+    );
+    let meta = client.token_metadata(&id);
+    assert_eq!(meta.attributes.len(), 2);
+    assert_eq!(
+        meta.attributes.get(0).unwrap().value,
+        String::from_str(&env, "2")
+    );
+
+    client.remove_attribute(&game, &id, &level);
+    let meta = client.token_metadata(&id);
+    assert_eq!(meta.attributes.len(), 1);
+
+    assert_eq!(
+        client.try_update_attribute(&user, &id, &level, &String::from_str(&env, "99")),
+        Err(Ok(crate::ContractError::NotMetadataUpdater))
+    );
+}
+
+#[test]
+fn test_contract_uri() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+
+    let config = create_test_config(&env, &admin);
+    client.initialize(&admin, &config);
+    assert_eq!(client.contract_uri(), None);
+
+    let uri = String::from_str(&env, "ipfs://collection.json");
+    client.set_contract_uri(&admin, &uri);
+    assert_eq!(client.contract_uri(), Some(uri.clone()));
+
+    client.freeze_metadata(&admin);
+    assert_eq!(
+        client.try_set_contract_uri(&admin, &uri),
+        Err(Ok(crate::ContractError::MetadataFrozen))
+    );
+}
+
+#[test]
+fn test_upgrade_authorization() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let factory = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+
+    let hash = BytesN::from_array(&env, &[7; 32]);
+    assert_eq!(
+        client.try_upgrade(&stranger, &hash),
+        Err(Ok(crate::ContractError::NotAuthorized))
+    );
+    assert_eq!(
+        client.try_set_upgrader(&stranger, &Some(stranger.clone())),
+        Err(Ok(crate::ContractError::NotAdmin))
+    );
+
+    client.set_upgrader(&admin, &Some(factory.clone()));
+    assert_eq!(client.upgrader(), Some(factory));
+    client.set_upgrader(&admin, &None);
+    assert_eq!(client.upgrader(), None);
+}
+
+#[test]
+fn test_granular_pause() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+
+    let uri = String::from_str(&env, "ipfs://token");
+    let first = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+    let second = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+
+    // Freeze trading but keep burns open.
+    client.pause_transfer(&admin, &true);
+    assert!(client.is_transfer_paused());
+    assert!(!client.is_burn_paused());
+    assert_eq!(
+        client.try_transfer(&user, &admin, &first),
+        Err(Ok(crate::ContractError::TransferPaused))
+    );
+    client.burn(&user, &first, &true);
+    client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+
+    client.pause_burn(&admin, &true);
+    assert_eq!(
+        client.try_burn(&user, &second, &true),
+        Err(Ok(crate::ContractError::BurnPaused))
+    );
+
+    client.set_pause(&admin, &false);
+    assert!(!client.is_mint_paused() && !client.is_transfer_paused() && !client.is_burn_paused());
+    client.pause_mint(&admin, &true);
+    assert_eq!(
+        client.try_mint(&admin, &user, &uri, &Vec::new(&env), &None),
+        Err(Ok(crate::ContractError::MintPaused))
+    );
+    client.transfer(&user, &admin, &second);
+}
+
+#[test]
+fn test_denylist() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let flagged = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+
+    let uri = String::from_str(&env, "ipfs://token");
+    let token_id = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+
+    client.set_denylisted(&admin, &flagged, &true);
+    assert!(client.is_denylisted(&flagged));
+    let denied = crate::ContractError::Denylisted;
+    assert_eq!(
+        client.try_mint(&admin, &flagged, &uri, &Vec::new(&env), &None),
+        Err(Ok(denied))
+    );
+    assert_eq!(
+        client.try_transfer(&user, &flagged, &token_id),
+        Err(Ok(denied))
+    );
+    assert_eq!(
+        client.try_approve(&user, &flagged, &token_id),
+        Err(Ok(denied))
+    );
+    assert_eq!(
+        client.try_batch_transfer(&user, &flagged, &Vec::from_array(&env, [token_id])),
+        Err(Ok(denied))
+    );
+
+    client.set_denylisted(&admin, &flagged, &false);
+    client.transfer(&user, &flagged, &token_id);
+    client.set_denylisted(&admin, &flagged, &true);
+    assert_eq!(
+        client.try_transfer(&flagged, &user, &token_id),
+        Err(Ok(denied))
+    );
+}
+
+#[test]
+fn test_freeze_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+
+    let uri = String::from_str(&env, "ipfs://token");
+    let disputed = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+    let other = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+
+    client.freeze_token(&admin, &disputed, &true);
+    assert!(client.token_metadata(&disputed).frozen);
+    assert_eq!(
+        client.try_transfer(&user, &buyer, &disputed),
+        Err(Ok(crate::ContractError::TokenFrozen))
+    );
+    assert_eq!(
+        client.try_approve(&user, &buyer, &disputed),
+        Err(Ok(crate::ContractError::TokenFrozen))
+    );
+    client.transfer(&user, &buyer, &other);
+
+    client.freeze_token(&admin, &disputed, &false);
+    assert!(!client.token_metadata(&disputed).frozen);
+    client.transfer(&user, &buyer, &disputed);
+}
+
+#[test]
+fn test_transfer_cooldown() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    client.set_transfer_cooldown(&admin, &3_600);
+
+    let uri = String::from_str(&env, "ipfs://token");
+    let token_id = client.mint(&admin, &alice, &uri, &Vec::new(&env), &None);
+    assert_eq!(client.transferable_at(&token_id), 0);
+
+    client.transfer(&alice, &bob, &token_id);
+    assert_eq!(client.transferable_at(&token_id), 4_600);
+    assert_eq!(
+        client.try_transfer(&bob, &alice, &token_id),
+        Err(Ok(crate::ContractError::TransferCooldown))
+    );
+
+    env.ledger().set_timestamp(4_600);
+    client.transfer(&bob, &alice, &token_id);
+    assert_eq!(client.owner_of(&token_id), alice);
+}
+
+#[soroban_sdk::contract]
+struct MockTransferHook;
+
+#[soroban_sdk::contractimpl]
+impl MockTransferHook {
+    pub fn set_blocking(env: Env, blocking: bool) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("block"), &blocking);
+    }
+
+    pub fn seen(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("seen"))
+            .unwrap_or(0)
+    }
+}
+
+#[soroban_sdk::contractimpl]
+impl nft_transfer_hook::TransferHook for MockTransferHook {
+    fn before_transfer(
+        env: Env,
+        _collection: Address,
+        _from: Address,
+        _to: Address,
+        _token_id: u64,
+    ) -> Result<(), soroban_sdk::Error> {
+        let blocking: bool = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("block"))
+            .unwrap_or(false);
+        if blocking {
+            return Err(soroban_sdk::Error::from_contract_error(1));
+        }
+        Ok(())
+    }
+
+    fn after_transfer(
+        env: Env,
+        _collection: Address,
+        _from: Address,
+        _to: Address,
+        _token_id: u64,
+    ) -> Result<(), soroban_sdk::Error> {
+        let seen = Self::seen(env.clone()) + 1;
+        env.storage().instance().set(&symbol_short!("seen"), &seen);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_transfer_hooks() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+
+    let hook_id = env.register(MockTransferHook, ());
+    let hook = MockTransferHookClient::new(&env, &hook_id);
+    client.add_transfer_hook(&admin, &hook_id);
+    client.add_transfer_hook(&admin, &hook_id);
+    assert_eq!(client.transfer_hooks().len(), 1);
+
+    let uri = String::from_str(&env, "ipfs://token");
+    let token_id = client.mint(&admin, &alice, &uri, &Vec::new(&env), &None);
+    client.transfer(&alice, &bob, &token_id);
+    assert_eq!(hook.seen(), 1);
+
+    hook.set_blocking(&true);
+    assert_eq!(
+        client.try_transfer(&bob, &alice, &token_id),
+        Err(Ok(crate::ContractError::TransferHookRejected))
+    );
+    assert_eq!(client.owner_of(&token_id), bob);
+
+    client.remove_transfer_hook(&admin, &hook_id);
+    client.transfer(&bob, &alice, &token_id);
+    assert_eq!(hook.seen(), 1);
+}
+
+#[test]
+fn test_batch_transfer_to() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+
+    let uri = String::from_str(&env, "ipfs://token");
+    let first = client.mint(&admin, &admin, &uri, &Vec::new(&env), &None);
+    let second = client.mint(&admin, &admin, &uri, &Vec::new(&env), &None);
+
+    let recipients = Vec::from_array(&env, [alice.clone(), bob.clone()]);
+    assert_eq!(
+        client.try_batch_transfer_to(&admin, &recipients, &Vec::from_array(&env, [first])),
+        Err(Ok(crate::ContractError::BatchLengthMismatch))
+    );
+
+    client.batch_transfer_to(&admin, &recipients, &Vec::from_array(&env, [first, second]));
+    assert_eq!(client.owner_of(&first), alice);
+    assert_eq!(client.owner_of(&second), bob);
+    assert_eq!(client.balance_of(&admin), 0);
+}
+
+#[test]
+fn test_approval_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let market = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+
+    let uri = String::from_str(&env, "ipfs://token");
+    let token_id = client.mint(&admin, &owner, &uri, &Vec::new(&env), &None);
+    assert_eq!(
+        client.try_approve_with_expiry(&owner, &market, &token_id, &1_000),
+        Err(Ok(crate::ContractError::InvalidAmount))
+    );
+
+    client.approve_with_expiry(&owner, &market, &token_id, &2_000);
+    assert_eq!(client.get_approved(&token_id), Some(market.clone()));
+    assert_eq!(client.approval_expiry(&token_id), Some(2_000));
+
+    env.ledger().set_timestamp(2_000);
+    assert_eq!(client.get_approved(&token_id), None);
+    assert_eq!(
+        client.try_transfer(&market, &buyer, &token_id),
+        Err(Ok(crate::ContractError::ApprovalExpired))
+    );
+
+    // A plain approval replaces the expiring one and never lapses.
+    client.approve(&owner, &market, &token_id);
+    assert_eq!(client.approval_expiry(&token_id), None);
+    env.ledger().set_timestamp(100_000);
+    assert_eq!(client.get_approved(&token_id), Some(market));
+}
+
+#[test]
+fn test_revoke_all_approvals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let phisher = Address::generate(&env);
+    let market = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+
+    let uri = String::from_str(&env, "ipfs://token");
+    let first = client.mint(&admin, &owner, &uri, &Vec::new(&env), &None);
+    let second = client.mint(&admin, &owner, &uri, &Vec::new(&env), &None);
+    client.approve(&owner, &market, &first);
+    client.approve(&owner, &market, &second);
+    client.set_approval_for_all(&owner, &phisher, &true);
+    client.set_approval_for_all(&owner, &market, &true);
+    client.set_approval_for_all(&owner, &market, &false);
+    assert_eq!(
+        client.operators_of(&owner),
+        Vec::from_array(&env, [phisher.clone()])
+    );
+
+    client.revoke_all_approvals(&owner);
+    assert!(!client.is_approved_for_all(&owner, &phisher));
+    assert_eq!(client.get_approved(&first), None);
+    assert_eq!(client.get_approved(&second), None);
+    assert_eq!(client.operators_of(&owner).len(), 0);
+}
+
+#[test]
+fn test_multicall() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+
+    let uri = String::from_str(&env, "ipfs://token");
+    let token_id = client.mint(&admin, &admin, &uri, &Vec::new(&env), &None);
+
+    let new_uri = String::from_str(&env, "ipfs://updated");
+    client.multicall(
+        &admin,
+        &Vec::from_array(
+            &env,
+            [
+                CallSpec::SetTokenUri(token_id, new_uri.clone()),
+                CallSpec::SetEditionInfo(token_id, Some(1), Some(10)),
+                CallSpec::Transfer(bob.clone(), token_id),
+            ],
+        ),
+    );
+    let metadata = client.token_metadata(&token_id);
+    assert_eq!(metadata.owner, bob);
+    assert_eq!(metadata.edition_number, Some(1));
+
+    // A failing call aborts the calls before it too.
+    let second = client.mint(&admin, &admin, &uri, &Vec::new(&env), &None);
+    assert_eq!(
+        client.try_multicall(
+            &admin,
+            &Vec::from_array(
+                &env,
+                [
+                    CallSpec::Transfer(bob.clone(), second),
+                    CallSpec::Burn(second, false),
+                ],
+            ),
+        ),
+        Err(Ok(crate::ContractError::BurnNotConfirmed))
+    );
+    assert_eq!(client.owner_of(&second), admin);
+}
+
+/// Receiver that tries to approve an attacker as operator of whoever sent it a token.
+#[soroban_sdk::contract]
+struct MockGreedyReceiver;
+
+#[soroban_sdk::contractimpl]
+impl MockGreedyReceiver {
+    pub fn init(env: Env, collection: Address, attacker: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("nft"), &collection);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("attacker"), &attacker);
+    }
+}
+
+#[soroban_sdk::contractimpl]
+impl nft_receiver::NftReceiver for MockGreedyReceiver {
+    fn nft_recv(
+        env: Env,
+        from: Address,
+        _token_id: u64,
+        _data: Option<soroban_sdk::Bytes>,
+    ) -> Result<soroban_sdk::Symbol, soroban_sdk::Error> {
+        let collection: Address = env.storage().instance().get(&symbol_short!("nft")).unwrap();
+        let attacker: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("attacker"))
+            .unwrap();
+        let _ = NftContractClient::new(&env, &collection)
+            .try_set_approval_for_all(&from, &attacker, &true);
+        Ok(nft_receiver::NFT_RECV)
+    }
+
+    fn nft_batch_recv(
+        _env: Env,
+        _from: Address,
+        _token_ids: Vec<u64>,
+        _data: Option<soroban_sdk::Bytes>,
+    ) -> Result<soroban_sdk::Symbol, soroban_sdk::Error> {
+        Ok(nft_receiver::NFT_BATCH_RECV)
+    }
+}
+
+#[test]
+fn test_multicall_does_not_lend_caller_auth() {
+    use soroban_sdk::IntoVal;
+    use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let victim = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&victim, &create_test_config(&env, &victim));
+    let uri = String::from_str(&env, "ipfs://token");
+    let token_id = client.mint(&victim, &victim, &uri, &Vec::new(&env), &None);
+
+    let receiver = env.register(MockGreedyReceiver, ());
+    MockGreedyReceiverClient::new(&env, &receiver).init(&contract_id, &attacker);
+
+    // The victim signs only the multicall itself.
+    let calls = Vec::from_array(
+        &env,
+        [CallSpec::SafeTransfer(receiver.clone(), token_id, None)],
+    );
+    env.mock_auths(&[MockAuth {
+        address: &victim,
+        invoke: &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "multicall",
+            args: (victim.clone(), calls.clone()).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.multicall(&victim, &calls);
+
+    assert_eq!(client.owner_of(&token_id), receiver);
+    assert!(!client.is_approved_for_all(&victim, &attacker));
+}
+
+#[test]
+fn test_composed_token_uri() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    client.set_base_uri(&admin, &String::from_str(&env, "ipfs://collection/"));
+    client.set_uri_composition(&admin, &Some(String::from_str(&env, ".json")));
+
+    let empty = String::from_str(&env, "");
+    for _ in 0..12 {
+        client.mint(&admin, &user, &empty, &Vec::new(&env), &None);
+    }
+    assert_eq!(
+        client.token_uri(&11),
+        String::from_str(&env, "ipfs://collection/11.json")
+    );
+    assert_eq!(
+        client.token_metadata(&0).metadata_uri,
+        String::from_str(&env, "ipfs://collection/0.json")
+    );
+
+    let custom = String::from_str(&env, "ipfs://custom");
+    let token_id = client.mint(&admin, &user, &custom, &Vec::new(&env), &None);
+    assert_eq!(client.token_uri(&token_id), custom);
+    assert_eq!(
+        client.try_token_uri(&99),
+        Err(Ok(crate::ContractError::TokenNotFound))
+    );
+
+    // Disabling composition only affects later mints; composed tokens keep their suffix.
+    assert_eq!(
+        client.try_set_uri_composition(&admin, &Some(String::from_str(&env, ".xml"))),
+        Err(Ok(crate::ContractError::UriSuffixInUse))
+    );
+    client.set_uri_composition(&admin, &None);
+    assert_eq!(
+        client.token_uri(&11),
+        String::from_str(&env, "ipfs://collection/11.json")
+    );
+    assert_eq!(client.token_uri(&token_id), custom);
+    client.set_uri_composition(&admin, &Some(String::from_str(&env, ".json")));
+
+    // A base URI that could not be composed with every token id is rejected up front.
+    let long = String::from_bytes(&env, &[b'a'; 232]);
+    assert_eq!(
+        client.try_set_base_uri(&admin, &long),
+        Err(Ok(crate::ContractError::UriTooLong))
+    );
+    let fits = String::from_bytes(&env, &[b'a'; 231]);
+    client.set_base_uri(&admin, &fits);
+    assert_eq!(client.token_uri(&0).len(), 237);
+}
+
+#[test]
+fn test_content_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+
+    let uri = String::from_str(&env, "ipfs://token");
+    let media = soroban_sdk::Bytes::from_array(&env, b"image bytes");
+    let hash: BytesN<32> = env.crypto().sha256(&media).into();
+    let token_id =
+        client.mint_with_content_hash(&admin, &user, &uri, &Vec::new(&env), &None, &hash);
+
+    assert!(client.verify_content(&token_id, &hash));
+    assert!(!client.verify_content(&token_id, &BytesN::from_array(&env, &[0; 32])));
+    assert_eq!(
+        client.token_metadata(&token_id).content_hash,
+        Some(hash.clone())
+    );
+
+    let plain = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+    assert!(!client.verify_content(&plain, &hash));
+    assert_eq!(client.token_metadata(&plain).content_hash, None);
+}
+
+#[test]
+fn test_typed_attributes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+
+    let uri = String::from_str(&env, "ipfs://token");
+    let id = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+    let power = String::from_str(&env, "power");
+    let class = String::from_str(&env, "class");
+
+    client.update_typed_attribute(&admin, &id, &power, &AttributeValue::I128(-42));
+    client.update_attribute(&admin, &id, &class, &String::from_str(&env, "mage"));
+    assert_eq!(client.get_numeric_attribute(&id, &power), -42);
+    assert_eq!(
+        client.token_metadata(&id).attributes.get(0).unwrap().value,
+        String::from_str(&env, "-42")
+    );
+    let attr = client.get_attribute(&id, &class);
+    assert_eq!(attr.typed_value, AttributeValue::String);
+    assert_eq!(attr.value, String::from_str(&env, "mage"));
+    assert_eq!(
+        client.try_get_numeric_attribute(&id, &class),
+        Err(Ok(crate::ContractError::AttributeNotNumeric))
+    );
+}
+
+#[test]
+fn test_attribute_limits() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    client.set_attribute_limits(
+        &admin,
+        &AttributeLimits {
+            max_attributes: 1,
+            max_trait_len: 8,
+            max_value_len: 8,
+        },
+    );
+
+    let uri = String::from_str(&env, "ipfs://token");
+    let attr = |trait_type: &str, value: &str| TokenAttribute {
+        trait_type: String::from_str(&env, trait_type),
+        value: String::from_str(&env, value),
+        display_type: None,
+        typed_value: AttributeValue::String,
+    };
+    let exceeded = Err(Ok(crate::ContractError::AttributeLimitExceeded));
+
+    let too_many = Vec::from_array(&env, [attr("a", "1"), attr("b", "2")]);
+    assert_eq!(
+        client.try_mint(&admin, &user, &uri, &too_many, &None),
+        exceeded
+    );
+    let too_long = Vec::from_array(&env, [attr("a", "longer than eight")]);
+    assert_eq!(
+        client.try_mint(&admin, &user, &uri, &too_long, &None),
+        exceeded
+    );
+
+    let id = client.mint(
+        &admin,
+        &user,
+        &uri,
+        &Vec::from_array(&env, [attr("a", "1")]),
+        &None,
+    );
+    client.update_attribute(
+        &admin,
+        &id,
+        &String::from_str(&env, "a"),
+        &String::from_str(&env, "2"),
+    );
+    assert_eq!(
+        client.try_update_attribute(
+            &admin,
+            &id,
+            &String::from_str(&env, "b"),
+            &String::from_str(&env, "3")
+        ),
+        Err(Ok(crate::ContractError::AttributeLimitExceeded))
+    );
+}
+
+#[test]
+fn test_mint_edition() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+
+    let uri = String::from_str(&env, "ipfs://master");
+    let attrs = Vec::from_array(
+        &env,
+        [TokenAttribute {
+            trait_type: String::from_str(&env, "artist"),
+            value: String::from_str(&env, "ada"),
+            display_type: None,
+            typed_value: AttributeValue::String,
+        }],
+    );
+    let master = client.mint(&admin, &admin, &uri, &attrs, &None);
+    assert_eq!(
+        client.try_mint_edition(&admin, &master, &user, &1),
+        Err(Ok(crate::ContractError::NotFound))
+    );
+    client.set_edition_info(&admin, &master, &Some(1), &Some(4));
+
+    let ids = client.mint_edition(&admin, &master, &user, &2);
+    assert_eq!(ids.len(), 2);
+    let copy = client.token_metadata(&ids.get(1).unwrap());
+    assert_eq!(copy.owner, user);
+    assert_eq!(copy.metadata_uri, uri);
+    assert_eq!(copy.attributes.len(), 1);
+    assert_eq!(copy.edition_number, Some(3));
+    assert_eq!(copy.total_editions, Some(4));
+
+    assert_eq!(
+        client.try_mint_edition(&admin, &master, &user, &2),
+        Err(Ok(crate::ContractError::EditionsExhausted))
+    );
+    let last = client.mint_edition(&admin, &master, &user, &1);
+    assert_eq!(
+        client.token_metadata(&last.get(0).unwrap()).edition_number,
+        Some(4)
+    );
+}
+
+#[test]
+fn test_batch_burn() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+
+    let uri = String::from_str(&env, "ipfs://token");
+    let mine = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+    let theirs = client.mint(&admin, &other, &uri, &Vec::new(&env), &None);
+    let extra = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+
+    assert_eq!(
+        client.try_batch_burn(&user, &Vec::from_array(&env, [mine]), &false),
+        Err(Ok(crate::ContractError::BurnNotConfirmed))
+    );
+    // A token the caller neither owns nor may burn reverts the whole batch.
+    assert_eq!(
+        client.try_batch_burn(&user, &Vec::from_array(&env, [mine, theirs]), &true),
+        Err(Ok(crate::ContractError::NotBurner))
+    );
+    assert_eq!(client.balance_of(&user), 2);
+
+    client.batch_burn(&user, &Vec::from_array(&env, [mine, extra]), &true);
+    assert_eq!(client.balance_of(&user), 0);
+    client.batch_burn(&admin, &Vec::from_array(&env, [theirs]), &true);
+    assert_eq!(client.total_supply(), 0);
+}
+
+#[test]
+fn test_evolve() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+
+    let egg = client.mint(
+        &admin,
+        &user,
+        &String::from_str(&env, "ipfs://egg"),
+        &Vec::new(&env),
+        &None,
+    );
+    assert_eq!(
+        client.try_evolve(
+            &user,
+            &egg,
+            &String::from_str(&env, "ipfs://x"),
+            &Vec::new(&env)
+        ),
+        Err(Ok(crate::ContractError::NotMetadataUpdater))
+    );
+
+    let dragon_uri = String::from_str(&env, "ipfs://dragon");
+    let dragon = client.evolve(&admin, &egg, &dragon_uri, &Vec::new(&env));
+    assert_ne!(dragon, egg);
+    assert_eq!(
+        client.try_owner_of(&egg),
+        Err(Ok(crate::ContractError::TokenNotFound))
+    );
+    let meta = client.token_metadata(&dragon);
+    assert_eq!(meta.owner, user);
+    assert_eq!(meta.metadata_uri, dragon_uri);
+    assert_eq!(meta.creator, admin);
+    assert_eq!(meta.evolved_from, Some(egg));
+    assert_eq!(client.balance_of(&user), 1);
+    assert_eq!(client.total_supply(), 1);
+}
+
+#[soroban_sdk::contract]
+struct MockAccountRegistry;
+
+#[soroban_sdk::contractimpl]
+impl MockAccountRegistry {
+    pub fn account(env: Env, nft: Address, token_id: u64) -> Address {
+        env.storage().instance().get(&(nft, token_id)).unwrap()
+    }
+
+    pub fn set_account(env: Env, nft: Address, token_id: u64, account: Address) {
+        env.storage().instance().set(&(nft, token_id), &account);
+    }
+}
+
+#[test]
+fn test_account_of() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let id = client.mint(
+        &admin,
+        &user,
+        &String::from_str(&env, "ipfs://token"),
+        &Vec::new(&env),
+        &None,
+    );
+    assert_eq!(
+        client.try_account_of(&id),
+        Err(Ok(crate::ContractError::NotFound))
+    );
+
+    let registry_id = env.register(MockAccountRegistry, ());
+    let account = Address::generate(&env);
+    MockAccountRegistryClient::new(&env, &registry_id).set_account(&contract_id, &id, &account);
+    client.set_account_registry(&admin, &Some(registry_id));
+    assert_eq!(client.account_of(&id), account);
+    assert_eq!(
+        client.try_account_of(&(id + 1)),
+        Err(Ok(crate::ContractError::TokenNotFound))
+    );
+}
+
+#[test]
+fn test_royalty_decay() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+
+    let uri = String::from_str(&env, "ipfs://token");
+    let old = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+    let traded = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+
+    assert_eq!(
+        client.try_set_royalty_decay(
+            &admin,
+            &Some(RoyaltyDecay {
+                after_seconds: None,
+                after_transfers: None,
+                decayed_percentage: 100,
+            })
+        ),
+        Err(Ok(crate::ContractError::InvalidRoyalty))
+    );
+    client.set_royalty_decay(
+        &admin,
+        &Some(RoyaltyDecay {
+            after_seconds: Some(365 * 24 * 60 * 60),
+            after_transfers: Some(1),
+            decayed_percentage: 250,
+        }),
+    );
+    assert_eq!(client.get_royalty_info(&old, &10_000).1, 500);
+
+    client.transfer(&user, &buyer, &traded);
+    assert_eq!(client.get_royalty_info(&traded, &10_000).1, 250);
+    assert_eq!(client.get_royalty_info(&old, &10_000).1, 500);
+
+    env.ledger().with_mut(|l| l.timestamp += 365 * 24 * 60 * 60);
+    assert_eq!(client.get_royalty_info(&old, &10_000).1, 250);
+
+    client.set_royalty_decay(&admin, &None);
+    assert_eq!(client.get_royalty_info(&old, &10_000).1, 500);
+}
+
+#[test]
+fn test_pay_royalty() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let id = client.mint(
+        &admin,
+        &user,
+        &String::from_str(&env, "ipfs://token"),
+        &Vec::new(&env),
+        &None,
+    );
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let currency = sac.address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &currency).mint(&buyer, &10_000);
+
+    assert_eq!(client.pay_royalty(&buyer, &id, &4_000, &currency), 200);
+    assert_eq!(client.pay_royalty(&buyer, &id, &2_000, &currency), 100);
+    let token = soroban_sdk::token::TokenClient::new(&env, &currency);
+    assert_eq!(token.balance(&admin), 300);
+    assert_eq!(token.balance(&contract_id), 0);
+    assert_eq!(token.balance(&buyer), 9_700);
+    assert_eq!(client.royalties_paid(&id, &currency), 300);
+    assert_eq!(
+        client.try_pay_royalty(&buyer, &id, &0, &currency),
+        Err(Ok(crate::ContractError::InvalidAmount))
+    );
+
+    // Royalties are paid out directly; the earnings ledger records them.
+    let earnings = client.earnings_of(&admin, &currency);
+    assert_eq!((earnings.primary_sales, earnings.royalties), (0, 300));
+}
+
+#[test]
+fn test_reset_token_royalty() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let artist = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let id = client.mint(
+        &admin,
+        &user,
+        &String::from_str(&env, "ipfs://token"),
+        &Vec::new(&env),
+        &Some(RoyaltyInfo {
+            recipient: artist.clone(),
+            percentage: 1_000,
+        }),
+    );
+    assert_eq!(client.get_royalty_info(&id, &10_000), (artist, 1_000));
+
+    assert_eq!(
+        client.try_reset_token_royalty(&user, &id),
+        Err(Ok(crate::ContractError::NotAdmin))
+    );
+    client.reset_token_royalty(&admin, &id);
+    assert_eq!(client.get_royalty_info(&id, &10_000), (admin, 500));
+}
+
+#[test]
+fn test_whitelist_allocation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    client.set_minter(&admin, &user, &true);
+    client.set_whitelist_only_mint(&admin, &true);
+    client.set_whitelist_allocation(&admin, &user, &3);
+    assert_eq!(client.get_allocation(&user), Some(3));
+
+    let uri = String::from_str(&env, "ipfs://token");
+    client.mint(&user, &user, &uri, &Vec::new(&env), &None);
+    let recipients = Vec::from_array(&env, [user.clone(), user.clone(), user.clone()]);
+    assert_eq!(
+        client.try_airdrop(&user, &recipients, &uri),
+        Err(Ok(crate::ContractError::AllocationExhausted))
+    );
+    client.airdrop(&user, &recipients.slice(0..2), &uri);
+    assert_eq!(client.get_allocation(&user), Some(0));
+    assert_eq!(
+        client.try_mint(&user, &user, &uri, &Vec::new(&env), &None),
+        Err(Ok(crate::ContractError::AllocationExhausted))
+    );
+
+    client.set_whitelist(&admin, &user, &true);
+    assert_eq!(client.get_allocation(&user), None);
+    client.mint(&user, &user, &uri, &Vec::new(&env), &None);
+}
+
+#[test]
+fn test_restricted_transfers() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let verified = Address::generate(&env);
+    let unverified = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let id = client.mint(
+        &admin,
+        &user,
+        &String::from_str(&env, "ipfs://token"),
+        &Vec::new(&env),
+        &None,
+    );
+
+    client.set_transfer_restricted(&admin, &true);
+    assert!(client.is_transfer_restricted());
+    client.set_transfer_allowlisted(&admin, &verified, &true);
+    assert_eq!(
+        client.try_transfer(&user, &unverified, &id),
+        Err(Ok(crate::ContractError::RecipientNotAllowed))
+    );
+    client.transfer(&user, &verified, &id);
+
+    client.set_transfer_restricted(&admin, &false);
+    client.transfer(&verified, &unverified, &id);
+    assert_eq!(client.owner_of(&id), unverified);
+}
+
+#[test]
+fn test_delegation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let cold = Address::generate(&env);
+    let hot = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let uri = String::from_str(&env, "ipfs://token");
+    let first = client.mint(&admin, &cold, &uri, &Vec::new(&env), &None);
+    let second = client.mint(&admin, &cold, &uri, &Vec::new(&env), &None);
+
+    client.delegate(&cold, &hot, &DelegationScope::Token(first));
+    assert!(client.is_token_delegate(&hot, &first));
+    assert!(!client.is_token_delegate(&hot, &second));
+    assert_eq!(
+        client.try_delegate(&buyer, &hot, &DelegationScope::Token(first)),
+        Err(Ok(crate::ContractError::NotAuthorized))
+    );
+
+    client.delegate(&cold, &hot, &DelegationScope::Collection);
+    assert!(client.is_token_delegate(&hot, &second));
+    assert!(client.is_delegate(&cold, &hot, &DelegationScope::Token(second)));
+
+    // Delegations follow the owner, not the token.
+    client.transfer(&cold, &buyer, &second);
+    assert!(!client.is_token_delegate(&hot, &second));
+
+    client.revoke_delegate(&cold, &hot, &DelegationScope::Collection);
+    assert!(client.is_token_delegate(&hot, &first));
+    assert!(!client.is_delegate(&cold, &hot, &DelegationScope::Collection));
+}
+
+#[test]
+fn test_approve_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+    let market = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let uri = String::from_str(&env, "ipfs://token");
+    let first = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+    let second = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+    let foreign = client.mint(&admin, &other, &uri, &Vec::new(&env), &None);
+
+    assert_eq!(
+        client.try_approve_batch(&user, &market, &Vec::from_array(&env, [first, foreign])),
+        Err(Ok(crate::ContractError::NotAuthorized))
+    );
+    assert_eq!(client.get_approved(&first), None);
+
+    client.approve_batch(&user, &market, &Vec::from_array(&env, [first, second]));
+    assert_eq!(client.get_approved(&first), Some(market.clone()));
+    assert_eq!(client.get_approved(&second), Some(market));
+}
+
+#[test]
+fn test_is_approved_or_owner() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let operator = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let id = client.mint(
+        &admin,
+        &user,
+        &String::from_str(&env, "ipfs://token"),
+        &Vec::new(&env),
+        &None,
+    );
+
+    assert!(client.is_approved_or_owner(&user, &id));
+    assert!(!client.is_approved_or_owner(&spender, &id));
+    client.approve(&user, &spender, &id);
+    assert!(client.is_approved_or_owner(&spender, &id));
+    assert!(!client.is_approved_or_owner(&operator, &id));
+    client.set_approval_for_all(&user, &operator, &true);
+    assert!(client.is_approved_or_owner(&operator, &id));
+    assert_eq!(
+        client.try_is_approved_or_owner(&user, &(id + 1)),
+        Err(Ok(crate::ContractError::TokenNotFound))
+    );
+}
+
+#[test]
+fn test_owners_of() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let uri = String::from_str(&env, "ipfs://token");
+    let first = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+    let second = client.mint(&admin, &admin, &uri, &Vec::new(&env), &None);
+
+    let owners = client.owners_of(&Vec::from_array(&env, [first, second, second + 1]));
+    assert_eq!(
+        owners,
+        Vec::from_array(&env, [Some(user), Some(admin), None])
+    );
+}
+
+#[test]
+fn test_balance_of_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let nobody = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let uri = String::from_str(&env, "ipfs://token");
+    client.airdrop(
+        &admin,
+        &Vec::from_array(&env, [user.clone(), user.clone(), admin.clone()]),
+        &uri,
+    );
+
+    assert_eq!(
+        client.balance_of_batch(&Vec::from_array(&env, [user, admin, nobody])),
+        Vec::from_array(&env, [2u64, 1, 0])
+    );
+}
+
+#[test]
+fn test_token_metadata_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let uri = String::from_str(&env, "ipfs://token");
+    let attrs = Vec::from_array(
+        &env,
+        [TokenAttribute {
+            trait_type: String::from_str(&env, "level"),
+            value: String::from_str(&env, "1"),
+            display_type: None,
+            typed_value: AttributeValue::String,
+        }],
+    );
+    let first = client.mint(&admin, &user, &uri, &attrs, &None);
+    let second = client.mint(&admin, &user, &uri, &attrs, &None);
+    let ids = Vec::from_array(&env, [first, second + 1, second]);
+
+    let full = client.token_metadata_batch(&ids, &crate::FIELDS_ALL);
+    assert_eq!(full.len(), 2);
+    assert_eq!(full.get(1).unwrap().id, second);
+    assert_eq!(full.get(0).unwrap().metadata_uri, uri);
+    assert_eq!(full.get(0).unwrap().attributes.len(), 1);
+    assert_eq!(full.get(0).unwrap().royalty_percentage, 500);
+
+    let trimmed = client.token_metadata_batch(&ids, &crate::FIELD_URI);
+    let card = trimmed.get(0).unwrap();
+    assert_eq!(card.owner, user);
+    assert_eq!(card.metadata_uri, uri);
+    assert_eq!(card.attributes.len(), 0);
+    assert_eq!(card.royalty_percentage, 0);
+}
+
+#[test]
+fn test_collection_stats() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let uri = String::from_str(&env, "ipfs://token");
+    let first = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+    let second = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+    client.mint(&admin, &other, &uri, &Vec::new(&env), &None);
+
+    client.transfer(&user, &other, &first);
+    client.burn(&user, &second, &true);
+
+    let stats = client.get_collection_stats();
+    assert_eq!(stats.total_supply, 2);
+    assert_eq!(stats.total_minted, 3);
+    assert_eq!(stats.total_burned, 1);
+    assert_eq!(stats.holders, 1);
+    assert_eq!(stats.max_supply, Some(1000));
+}
+
+#[test]
+fn test_mint_with_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let uri = String::from_str(&env, "ipfs://token");
+    let none = Vec::new(&env);
+
+    assert_eq!(client.mint(&admin, &user, &uri, &none, &None), 0);
+    assert_eq!(
+        client.mint_with_id(&admin, &user, &2, &uri, &none, &None),
+        2
+    );
+    let taken = Err(Ok(crate::ContractError::TokenIdTaken));
+    assert_eq!(
+        client.try_mint_with_id(&admin, &user, &0, &uri, &none, &None),
+        taken
+    );
+    assert_eq!(
+        client.try_mint_with_id(&admin, &user, &2, &uri, &none, &None),
+        taken
+    );
+
+    // Sequential minting skips explicitly used ids.
+    assert_eq!(client.mint(&admin, &user, &uri, &none, &None), 1);
+    assert_eq!(client.mint(&admin, &user, &uri, &none, &None), 3);
+
+    // Burned ids stay used.
+    client.burn(&user, &2, &true);
+    assert_eq!(
+        client.try_mint_with_id(&admin, &user, &2, &uri, &none, &None),
+        taken
+    );
+    client.mint_with_id(&admin, &user, &10, &uri, &none, &None);
+    client.burn(&user, &10, &true);
+    assert_eq!(
+        client.try_mint_with_id(&admin, &user, &10, &uri, &none, &None),
+        taken
+    );
+
+    let stats = client.get_collection_stats();
+    assert_eq!(stats.total_minted, 5);
+    assert_eq!(stats.total_burned, 2);
+}
+
+#[test]
+fn test_content_addressed_ids() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    let mut config = create_test_config(&env, &admin);
+    config.content_addressed_ids = true;
+    client.initialize(&admin, &config);
+    let uri = String::from_str(&env, "ipfs://token");
+    let none = Vec::new(&env);
+
+    let digest = env.crypto().sha256(&uri.to_bytes()).to_array();
+    let expected = u64::from_be_bytes(digest[..8].try_into().unwrap());
+    let id = client.mint(&admin, &user, &uri, &none, &None);
+    assert_eq!(id, expected);
+
+    // Retrying the same mint is a no-op; the same content for someone else is rejected.
+    assert_eq!(client.mint(&admin, &user, &uri, &none, &None), id);
+    assert_eq!(client.total_supply(), 1);
+    assert_eq!(
+        client.try_mint(&admin, &other, &uri, &none, &None),
+        Err(Ok(crate::ContractError::TokenAlreadyExists))
+    );
+    assert_ne!(
+        client.mint(
+            &admin,
+            &user,
+            &String::from_str(&env, "ipfs://other"),
+            &none,
+            &None
+        ),
+        id
+    );
+    assert_eq!(
+        client.try_mint(&admin, &user, &String::from_str(&env, ""), &none, &None),
+        Err(Ok(crate::ContractError::InvalidTokenId))
+    );
+
+    // An explicit content hash takes precedence over the URI.
+    let hash = BytesN::from_array(&env, &[7; 32]);
+    let hashed = client.mint_with_content_hash(&admin, &user, &uri, &none, &None, &hash);
+    assert_eq!(hashed, u64::from_be_bytes([7; 8]));
+
+    // Burned content can never be minted again.
+    client.burn(&user, &id, &true);
+    assert_eq!(
+        client.try_mint(&admin, &user, &uri, &none, &None),
+        Err(Ok(crate::ContractError::TokenAlreadyExists))
+    );
+}
+
+#[test]
+fn test_mint_allowance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let launchpad = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let uri = String::from_str(&env, "ipfs://token");
+    let none = Vec::new(&env);
+
+    assert_eq!(
+        client.try_mint(&launchpad, &user, &uri, &none, &None),
+        Err(Ok(crate::ContractError::NotMinter))
+    );
+    assert_eq!(
+        client.try_grant_mint_allowance(&user, &launchpad, &2, &100),
+        Err(Ok(crate::ContractError::NotAdmin))
+    );
+    client.grant_mint_allowance(&admin, &launchpad, &2, &100);
+    assert_eq!(client.remaining_allowance(&launchpad), 2);
+
+    client.mint(&launchpad, &user, &uri, &none, &None);
+    client.mint(&launchpad, &user, &uri, &none, &None);
+    assert_eq!(client.remaining_allowance(&launchpad), 0);
+    assert_eq!(
+        client.try_mint(&launchpad, &user, &uri, &none, &None),
+        Err(Ok(crate::ContractError::AllocationExhausted))
+    );
+
+    // Allowances lapse at expiry.
+    client.grant_mint_allowance(&admin, &launchpad, &5, &100);
+    env.ledger().set_timestamp(101);
+    assert_eq!(client.remaining_allowance(&launchpad), 0);
+    assert_eq!(
+        client.try_mint(&launchpad, &user, &uri, &none, &None),
+        Err(Ok(crate::ContractError::NotMinter))
+    );
+    assert_eq!(
+        client.try_grant_mint_allowance(&admin, &launchpad, &5, &100),
+        Err(Ok(crate::ContractError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_mint_claimable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let uri = String::from_str(&env, "ipfs://token");
+    let none = Vec::new(&env);
+
+    let id = client.mint_claimable(&admin, &user, &uri, &none, &None, &100);
+    assert_eq!(client.owner_of(&id), contract_id);
+    assert_eq!(client.claimable(&id).unwrap().claimant, user);
+    assert_eq!(
+        client.try_claim(&stranger, &id),
+        Err(Ok(crate::ContractError::NotAuthorized))
+    );
+    assert_eq!(
+        client.try_claim(&admin, &id),
+        Err(Ok(crate::ContractError::ClaimNotExpired))
+    );
+    client.claim(&user, &id);
+    assert_eq!(client.owner_of(&id), user);
+    assert_eq!(client.claimable(&id), None);
+    assert_eq!(
+        client.try_claim(&user, &id),
+        Err(Ok(crate::ContractError::NotClaimable))
+    );
+
+    // Unclaimed tokens go back to the minter after expiry.
+    let id = client.mint_claimable(&admin, &user, &uri, &none, &None, &100);
+    env.ledger().set_timestamp(101);
+    assert_eq!(
+        client.try_claim(&user, &id),
+        Err(Ok(crate::ContractError::ClaimExpired))
+    );
+    client.claim(&admin, &id);
+    assert_eq!(client.owner_of(&id), admin);
+    assert_eq!(
+        client.try_mint_claimable(&admin, &user, &uri, &none, &None, &100),
+        Err(Ok(crate::ContractError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_mint_claimable_content_addressed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    let mut config = create_test_config(&env, &admin);
+    config.content_addressed_ids = true;
+    client.initialize(&admin, &config);
+    let uri = String::from_str(&env, "ipfs://token");
+    let none = Vec::new(&env);
+
+    // The same URI resolves to the escrowed token; it must not move to another claimant.
+    let id = client.mint_claimable(&admin, &user, &uri, &none, &None, &100);
+    assert_eq!(
+        client.try_mint_claimable(&admin, &other, &uri, &none, &None, &100),
+        Err(Ok(crate::ContractError::TokenAlreadyExists))
+    );
+    assert_eq!(client.claimable(&id).unwrap().claimant, user);
+    assert_eq!(client.total_supply(), 1);
+}
+
+#[test]
+fn test_offer_and_accept_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let friend = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let id = client.mint(
+        &admin,
+        &user,
+        &String::from_str(&env, "ipfs://token"),
+        &Vec::new(&env),
+        &None,
+    );
+
+    assert_eq!(
+        client.try_offer_transfer(&stranger, &friend, &id),
+        Err(Ok(crate::ContractError::NotAuthorized))
+    );
+    client.offer_transfer(&user, &friend, &id);
+    assert_eq!(client.pending_offer(&id), Some(friend.clone()));
+    assert_eq!(client.owner_of(&id), user);
+    assert_eq!(
+        client.try_accept_transfer(&stranger, &id),
+        Err(Ok(crate::ContractError::NotFound))
+    );
+    assert_eq!(
+        client.try_cancel_offer(&stranger, &id),
+        Err(Ok(crate::ContractError::NotAuthorized))
+    );
+    client.accept_transfer(&friend, &id);
+    assert_eq!(client.owner_of(&id), friend);
+    assert_eq!(client.pending_offer(&id), None);
+
+    // Recipients can decline, and offers lapse when the token moves.
+    client.offer_transfer(&friend, &user, &id);
+    client.cancel_offer(&user, &id);
+    assert_eq!(client.pending_offer(&id), None);
+    client.offer_transfer(&friend, &user, &id);
+    client.transfer(&friend, &stranger, &id);
+    assert_eq!(
+        client.try_accept_transfer(&user, &id),
+        Err(Ok(crate::ContractError::NotFound))
+    );
+}
+
+#[test]
+fn test_subscription_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let renewer = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let id = client.mint(
+        &admin,
+        &user,
+        &String::from_str(&env, "ipfs://pass"),
+        &Vec::new(&env),
+        &None,
+    );
+    assert!(client.is_valid(&id));
+    assert_eq!(client.token_metadata(&id).valid_until, None);
+
+    assert_eq!(
+        client.try_renew(&renewer, &id, &100),
+        Err(Ok(crate::ContractError::NotRenewer))
+    );
+    client.set_renewer(&admin, &renewer, &true);
+    client.renew(&renewer, &id, &100);
+    assert_eq!(client.token_metadata(&id).valid_until, Some(100));
+
+    env.ledger().set_timestamp(100);
+    assert!(client.is_valid(&id));
+    env.ledger().set_timestamp(101);
+    assert!(!client.is_valid(&id));
+    client.renew(&renewer, &id, &200);
+    assert!(client.is_valid(&id));
+
+    assert_eq!(
+        client.try_is_valid(&99),
+        Err(Ok(crate::ContractError::TokenNotFound))
+    );
+}
+
+#[test]
+fn test_provenance_log() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    env.ledger().set_timestamp(10);
+    let id = client.mint(
+        &admin,
+        &user,
+        &String::from_str(&env, "ipfs://art"),
+        &Vec::new(&env),
+        &None,
+    );
+    env.ledger().set_timestamp(20);
+    client.transfer(&user, &buyer, &id);
+    client.transfer(&buyer, &user, &id);
+
+    let log = client.get_provenance(&id, &0, &10);
+    assert_eq!(log.len(), 3);
+    assert_eq!(log.get(0).unwrap().owner, user);
+    assert_eq!(log.get(0).unwrap().timestamp, 10);
+    assert_eq!(log.get(1).unwrap().owner, buyer);
+    assert_eq!(log.get(1).unwrap().timestamp, 20);
+    assert_eq!(
+        client.get_provenance(&id, &2, &10).get(0).unwrap().owner,
+        user
+    );
+    assert_eq!(client.get_provenance(&id, &1, &1).len(), 1);
+    assert_eq!(client.get_provenance(&id, &5, &10).len(), 0);
+
+    // Only the most recent entries are kept.
+    for _ in 0..30 {
+        client.transfer(&user, &buyer, &id);
+        client.transfer(&buyer, &user, &id);
+    }
+    let log = client.get_provenance(&id, &0, &100);
+    assert_eq!(log.len(), crate::utils::MAX_PROVENANCE_ENTRIES);
+    assert_eq!(log.last().unwrap().owner, user);
+}
+
+#[test]
+fn test_lock_and_unlock() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let staking = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let id = client.mint(
+        &admin,
+        &user,
+        &String::from_str(&env, "ipfs://token"),
+        &Vec::new(&env),
+        &None,
+    );
+
+    assert_eq!(
+        client.try_lock(&buyer, &id, &staking),
+        Err(Ok(crate::ContractError::NotAuthorized))
+    );
+    client.lock(&user, &id, &staking);
+    assert!(client.is_locked(&id));
+    assert_eq!(client.locker_of(&id), Some(staking.clone()));
+    assert_eq!(client.owner_of(&id), user);
+
+    let locked = Err(Ok(crate::ContractError::TokenLocked));
+    assert_eq!(client.try_transfer(&user, &buyer, &id), locked);
+    assert_eq!(client.try_approve(&user, &buyer, &id), locked);
+    assert_eq!(client.try_burn(&user, &id, &true), locked);
+    assert_eq!(
+        client.try_batch_burn(&user, &Vec::from_array(&env, [id]), &true),
+        locked
+    );
+    assert_eq!(client.try_lock(&user, &id, &buyer), locked);
+    assert_eq!(
+        client.try_unlock(&user, &id),
+        Err(Ok(crate::ContractError::NotAuthorized))
+    );
+
+    client.unlock(&staking, &id);
+    assert!(!client.is_locked(&id));
+    client.transfer(&user, &buyer, &id);
+    assert_eq!(client.owner_of(&id), buyer);
+}
+
+#[test]
+fn test_reentrancy_lock_recovery() {
+    use crate::storage::DataKey;
+    use soroban_sdk::testutils::storage::Temporary as _;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let id = client.mint(
+        &admin,
+        &user,
+        &String::from_str(&env, "ipfs://token"),
+        &Vec::new(&env),
+        &None,
+    );
+    let is_locked = || {
+        env.as_contract(&contract_id, || {
+            env.storage().temporary().has(&DataKey::ReentrancyLock)
+        })
+    };
+    assert!(!is_locked());
+
+    // A lock left behind (e.g. by a guard that was never dropped) blocks guarded calls only
+    // until its temporary entry expires.
+    let ttl = env.as_contract(&contract_id, || {
+        env.storage()
+            .temporary()
+            .set(&DataKey::ReentrancyLock, &true);
+        env.storage().temporary().get_ttl(&DataKey::ReentrancyLock)
+    });
+    assert_eq!(
+        client.try_transfer(&user, &buyer, &id),
+        Err(Ok(crate::ContractError::ReentrancyDetected))
+    );
+    env.ledger().with_mut(|l| l.sequence_number += ttl + 1);
+    assert!(!is_locked());
+    client.transfer(&user, &buyer, &id);
+    assert_eq!(client.owner_of(&id), buyer);
+    assert!(!is_locked());
+}
+
+#[test]
+fn test_legacy_token_records() {
+    use crate::storage::DataKey;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+
+    // A token written with the pre-record per-field layout.
+    let uri = String::from_str(&env, "ipfs://legacy");
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().instance();
+        storage.set(&DataKey::Owner(7), &user);
+        storage.set(&DataKey::TokenUri(7), &uri);
+        storage.set(&DataKey::TokenCreator(7), &admin);
+        storage.set(&DataKey::TokenCreatedAt(7), &5u64);
+        storage.set(&DataKey::TokenRoyaltyBps(7), &1_000u32);
+        storage.set(&DataKey::TokenRoyaltyRecipient(7), &admin);
+        storage.set(&DataKey::Balance(user.clone()), &1u64);
+        storage.set(&DataKey::TotalSupply, &1u64);
+    });
+    assert_eq!(client.owner_of(&7), user);
+    assert_eq!(client.token_uri(&7), uri);
+    assert_eq!(client.get_royalty_info(&7, &10_000), (admin.clone(), 1_000));
+    assert_eq!(client.token_metadata(&7).created_at, 5);
+
+    assert_eq!(
+        client.try_migrate_token_records(&user, &Vec::from_array(&env, [7])),
+        Err(Ok(crate::ContractError::NotAdmin))
+    );
+    assert_eq!(
+        client.migrate_token_records(&admin, &Vec::from_array(&env, [7, 8])),
+        1
+    );
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().instance();
+        assert!(storage.has(&DataKey::Token(7)));
+        assert!(!storage.has(&DataKey::Owner(7)));
+        assert!(!storage.has(&DataKey::TokenUri(7)));
+    });
+    assert_eq!(
+        client.migrate_token_records(&admin, &Vec::from_array(&env, [7])),
+        0
+    );
+    assert_eq!(client.token_uri(&7), uri);
+    client.transfer(&user, &buyer, &7);
+    assert_eq!(client.owner_of(&7), buyer);
+    assert_eq!(client.get_royalty_info(&7, &10_000), (admin, 1_000));
+}
+
+#[test]
+fn test_error_message() {
+    let env = Env::default();
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+
+    assert_eq!(
+        client.error_message(&(crate::ContractError::NotMinter as u32)),
+        String::from_str(&env, "not minter")
+    );
+    assert_eq!(
+        client.error_message(&(crate::ContractError::ApprovalExpired as u32)),
+        String::from_str(&env, "approval expired")
+    );
+    assert_eq!(
+        client.error_message(&0),
+        String::from_str(&env, "unknown error")
+    );
+}
+
+#[test]
+fn test_version() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    let version = String::from_str(&env, env!("CARGO_PKG_VERSION"));
+
+    assert_eq!(client.get_version(), version);
+    assert_eq!(client.storage_version(), None);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    assert_eq!(client.storage_version(), Some(version));
+}
+
+#[test]
+fn test_migrate() {
+    use crate::storage::DataKey;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    assert_eq!(client.schema_version(), 2);
+
+    // A collection predating the schema version, with legacy tokens across two chunks.
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().instance();
+        storage.remove(&DataKey::SchemaVersion);
+        storage.set(&DataKey::NextTokenId, &251u64);
+        for token_id in [0u64, 250] {
+            storage.set(&DataKey::Owner(token_id), &user);
+            storage.set(&DataKey::TokenCreator(token_id), &admin);
+        }
+    });
+    assert_eq!(client.schema_version(), 0);
+
+    assert_eq!(
+        client.try_migrate(&user),
+        Err(Ok(crate::ContractError::NotAdmin))
+    );
+    assert_eq!(client.migrate(&admin), 0);
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().instance();
+        assert!(storage.has(&DataKey::Token(0)));
+        assert!(storage.has(&DataKey::Owner(250)));
+    });
+    // Step 2 rebuilds the holder count and needs ownership frozen.
+    assert_eq!(
+        client.try_migrate(&admin),
+        Err(Ok(crate::ContractError::MigrationNotPaused))
+    );
+    client.pause_mint(&admin, &true);
+    client.pause_transfer(&admin, &true);
+    client.pause_burn(&admin, &true);
+    assert_eq!(client.migrate(&admin), 1);
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().instance();
+        assert!(storage.has(&DataKey::Token(250)));
+        assert!(!storage.has(&DataKey::Owner(250)));
+    });
+    assert_eq!(client.owner_of(&250), user);
+    assert_eq!(client.migrate(&admin), 1);
+    assert_eq!(client.migrate(&admin), 2);
+    assert_eq!(client.migrate(&admin), 2);
+}
+
+#[test]
+fn test_migrate_seeds_holder_count() {
+    use crate::storage::DataKey;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let uri = String::from_str(&env, "ipfs://a");
+    for owner in [&alice, &alice, &bob, &alice] {
+        client.mint(&admin, owner, &uri, &Vec::new(&env), &None);
+    }
+    client.burn(&bob, &2, &true);
+
+    // An upgraded collection whose tokens were minted before holders were counted.
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().instance();
+        storage.set(&DataKey::SchemaVersion, &1u32);
+        storage.remove(&DataKey::HolderCount);
+    });
+    assert_eq!(client.get_collection_stats().holders, 0);
+
+    client.pause_mint(&admin, &true);
+    client.pause_transfer(&admin, &true);
+    client.pause_burn(&admin, &true);
+    assert_eq!(client.migrate(&admin), 2);
+    assert_eq!(client.get_collection_stats().holders, 1);
+    env.as_contract(&contract_id, || {
+        assert!(
+            !env.storage()
+                .instance()
+                .has(&DataKey::HolderSeen(alice.clone()))
+        );
+    });
+
+    client.pause_mint(&admin, &false);
+    client.mint(&admin, &bob, &uri, &Vec::new(&env), &None);
+    assert_eq!(client.get_collection_stats().holders, 2);
+}
+
+#[test]
+fn test_transfer_event_topics() {
+    use soroban_sdk::testutils::Events;
+    use soroban_sdk::xdr::{ContractEventBody, ScVal};
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let id = client.mint(
+        &admin,
+        &user,
+        &String::from_str(&env, "ipfs://a"),
+        &Vec::new(&env),
+        &None,
+    );
+    client.transfer(&user, &buyer, &id);
+
+    let events = env.events().all().filter_by_contract(&contract_id);
+    let ContractEventBody::V0(transfer) = &events.events().last().unwrap().body;
+    // Name, from, to and token id are all topics, leaving nothing to decode for filtering.
+    assert_eq!(transfer.topics.len(), 4);
+    assert_eq!(transfer.topics[3], ScVal::U64(id));
+}
+
+#[test]
+fn test_admin_events() {
+    use soroban_sdk::testutils::Events;
+    use soroban_sdk::xdr::{ContractEventBody, ScSymbol, ScVal, StringM};
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let minter = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+
+    let last_event_name = || {
+        let events = env.events().all().filter_by_contract(&contract_id);
+        let ContractEventBody::V0(event) = &events.events().last().unwrap().body;
+        event.topics[0].clone()
+    };
+    let name = |s: &str| ScVal::Symbol(ScSymbol(StringM::try_from(s).unwrap()));
+
+    client.set_minter(&admin, &minter, &true);
+    assert_eq!(last_event_name(), name("role_granted"));
+    client.set_minter(&admin, &minter, &false);
+    assert_eq!(last_event_name(), name("role_revoked"));
+    client.pause_mint(&admin, &true);
+    assert_eq!(last_event_name(), name("paused"));
+    client.set_whitelist(&admin, &minter, &true);
+    assert_eq!(last_event_name(), name("whitelist_updated"));
+    client.set_whitelist_only_mint(&admin, &true);
+    assert_eq!(last_event_name(), name("whitelist_only_mint_updated"));
+    client.set_default_royalty(&admin, &admin, &250);
+    assert_eq!(last_event_name(), name("royalty_default_updated"));
+}
+
+#[test]
+fn test_role_admin_hierarchy() {
+    use crate::types::Role;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let other_admin = Address::generate(&env);
+    let minter_admin = Address::generate(&env);
+    let minter = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    client.set_admin(&other_admin, &true);
+    assert_eq!(client.get_role_admin(&Role::Minter), Role::Admin);
+
+    client.set_role_admin(&Role::Minter, &Role::MinterAdmin);
+    assert_eq!(client.get_role_admin(&Role::Minter), Role::MinterAdmin);
+    assert_eq!(
+        client.try_set_role_admin(&Role::Admin, &Role::MinterAdmin),
+        Err(Ok(crate::ContractError::InvalidRole))
+    );
+    client.set_role(&admin, &Role::MinterAdmin, &minter_admin, &true, &None);
+
+    // Admins no longer grant Minter; the minter admin does, and nothing else.
+    assert_eq!(
+        client.try_set_minter(&other_admin, &minter, &true),
+        Err(Ok(crate::ContractError::NotAdmin))
+    );
+    client.set_minter(&minter_admin, &minter, &true);
+    assert!(client.has_role(&Role::Minter, &minter));
+    assert_eq!(
+        client.try_set_burner(&minter_admin, &minter, &true),
+        Err(Ok(crate::ContractError::NotAdmin))
+    );
+    assert_eq!(
+        client.try_set_role(&admin, &Role::Owner, &minter, &true, &None),
+        Err(Ok(crate::ContractError::InvalidRole))
+    );
+}
+
+#[test]
+fn test_time_bound_role() {
+    use crate::types::Role;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let partner = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let uri = String::from_str(&env, "ipfs://a");
+    let none = Vec::new(&env);
+
+    env.ledger().set_timestamp(1_000);
+    assert_eq!(
+        client.try_set_role(&admin, &Role::Minter, &partner, &true, &Some(1_000)),
+        Err(Ok(crate::ContractError::InvalidAmount))
+    );
+    client.set_role(
+        &admin,
+        &Role::Minter,
+        &partner,
+        &true,
+        &Some(1_000 + 48 * 3600),
+    );
+    assert_eq!(
+        client.role_expiry(&Role::Minter, &partner),
+        Some(1_000 + 48 * 3600)
+    );
+    client.mint(&partner, &user, &uri, &none, &None);
+
+    env.ledger().set_timestamp(1_000 + 48 * 3600);
+    assert!(!client.has_role(&Role::Minter, &partner));
+    assert_eq!(
+        client.try_mint(&partner, &user, &uri, &none, &None),
+        Err(Ok(crate::ContractError::NotMinter))
+    );
+
+    // A plain grant replaces the time-bound one.
+    client.set_minter(&admin, &partner, &true);
+    assert_eq!(client.role_expiry(&Role::Minter, &partner), None);
+    client.mint(&partner, &user, &uri, &none, &None);
+}
+
+#[test]
+fn test_council_mode() {
+    use crate::types::CouncilAction;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&owner, &create_test_config(&env, &owner));
+
+    let members = Vec::from_array(&env, [a.clone(), b.clone(), c.clone()]);
+    assert_eq!(
+        client.try_set_council(&members, &4),
+        Err(Ok(crate::ContractError::InvalidAmount))
+    );
+    client.set_council(&members, &2);
+    assert_eq!(
+        client.try_set_admin(&new_admin, &true),
+        Err(Ok(crate::ContractError::CouncilRequired))
+    );
+    assert_eq!(
+        client.try_set_role(&owner, &crate::types::Role::Admin, &new_admin, &true, &None),
+        Err(Ok(crate::ContractError::CouncilRequired))
+    );
+    assert_eq!(
+        client.try_freeze_metadata(&owner),
+        Err(Ok(crate::ContractError::CouncilRequired))
+    );
+    assert_eq!(
+        client.try_propose(&owner, &CouncilAction::FreezeMetadata),
+        Err(Ok(crate::ContractError::NotCouncilMember))
+    );
+
+    let id = client.propose(&a, &CouncilAction::SetAdmin(new_admin.clone(), true));
+    assert_eq!(
+        client.try_execute_proposal(&a, &id),
+        Err(Ok(crate::ContractError::InsufficientApprovals))
+    );
+    assert_eq!(
+        client.try_approve_proposal(&a, &id),
+        Err(Ok(crate::ContractError::AlreadyApproved))
+    );
+    client.approve_proposal(&b, &id);
+    client.execute_proposal(&c, &id);
+    assert!(client.has_role(&crate::types::Role::Admin, &new_admin));
+    assert_eq!(client.proposal(&id), None);
+
+    let id = client.propose(&b, &CouncilAction::FreezeMetadata);
+    assert_eq!(
+        client.try_cancel_proposal(&a, &id),
+        Err(Ok(crate::ContractError::NotAuthorized))
+    );
+    client.cancel_proposal(&b, &id);
+    assert_eq!(client.proposal(&id), None);
+
+    // Dissolving the council restores direct calls.
+    let id = client.propose(&c, &CouncilAction::SetCouncil(Vec::new(&env), 0));
+    client.approve_proposal(&a, &id);
+    client.execute_proposal(&a, &id);
+    assert_eq!(client.council(), None);
+    client.set_admin(&new_admin, &false);
+}
+
+#[test]
+fn test_council_and_timelock_compose() {
+    use crate::types::{CouncilAction, TimelockAction};
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&owner, &create_test_config(&env, &owner));
+    let uri = String::from_str(&env, "ipfs://token");
+    let token_id = client.mint(&owner, &owner, &uri, &Vec::new(&env), &None);
+    let royalty = RoyaltyInfo {
+        recipient: a.clone(),
+        percentage: 900,
+    };
+
+    client.set_council(&Vec::from_array(&env, [a.clone(), b.clone()]), &2);
+    client.set_timelock_delay(&3_600);
+
+    // A single admin cannot queue around the council.
+    assert_eq!(
+        client.try_queue_operation(&owner, &TimelockAction::SetDefaultRoyalty(royalty.clone())),
+        Err(Ok(crate::ContractError::CouncilRequired))
+    );
+
+    // An executed proposal is queued, not applied.
+    env.ledger().set_timestamp(100);
+    let proposal_id = client.propose(&a, &CouncilAction::SetDefaultRoyalty(royalty.clone()));
+    client.approve_proposal(&b, &proposal_id);
+    client.execute_proposal(&a, &proposal_id);
+    assert_eq!(client.get_royalty_info(&token_id, &10_000).0, owner);
+    let queued = client.queued_operation(&0).unwrap();
+    assert_eq!(queued.eta, 3_700);
+    assert_eq!(
+        client.try_execute_operation(&b, &0),
+        Err(Ok(crate::ContractError::TimelockNotReady))
+    );
+
+    env.ledger().set_timestamp(3_700);
+    client.execute_operation(&b, &0);
+    assert_eq!(
+        client.get_royalty_info(&token_id, &10_000),
+        (a.clone(), 900)
+    );
+
+    // A council-queued operation can only be dropped by another proposal.
+    let proposal_id = client.propose(
+        &a,
+        &CouncilAction::Upgrade(BytesN::from_array(&env, &[7; 32])),
+    );
+    client.approve_proposal(&b, &proposal_id);
+    client.execute_proposal(&b, &proposal_id);
+    assert!(client.queued_operation(&1).unwrap().by_council);
+    assert_eq!(
+        client.try_cancel_operation(&owner, &1),
+        Err(Ok(crate::ContractError::CouncilRequired))
+    );
+    let proposal_id = client.propose(&a, &CouncilAction::CancelOperation(1));
+    client.approve_proposal(&b, &proposal_id);
+    client.execute_proposal(&a, &proposal_id);
+    assert_eq!(client.queued_operation(&1), None);
+}
+
+#[test]
+fn test_timelock() {
+    use crate::types::TimelockAction;
+    use soroban_sdk::testutils::Events;
+    use soroban_sdk::xdr::{ContractEventBody, ScSymbol, ScVal, StringM};
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let anyone = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let base_uri = String::from_str(&env, "ipfs://new/");
+
+    client.set_timelock_delay(&3_600);
+    let events = env.events().all().filter_by_contract(&contract_id);
+    let ContractEventBody::V0(event) = &events.events().last().unwrap().body;
+    assert_eq!(
+        event.topics[0],
+        ScVal::Symbol(ScSymbol(
+            StringM::try_from("timelock_delay_updated").unwrap()
+        ))
+    );
+    assert_eq!(
+        client.try_set_base_uri(&admin, &base_uri),
+        Err(Ok(crate::ContractError::TimelockRequired))
+    );
+    assert_eq!(
+        client.try_set_timelock_delay(&0),
+        Err(Ok(crate::ContractError::TimelockRequired))
+    );
+
+    env.ledger().set_timestamp(100);
+    let id = client.queue_operation(&admin, &TimelockAction::SetBaseUri(base_uri.clone()));
+    assert_eq!(client.queued_operation(&id).unwrap().eta, 3_700);
+    assert_eq!(
+        client.try_execute_operation(&anyone, &id),
+        Err(Ok(crate::ContractError::TimelockNotReady))
+    );
+    env.ledger().set_timestamp(3_700);
+    client.execute_operation(&anyone, &id);
+    assert_eq!(client.queued_operation(&id), None);
+
+    let id = client.queue_operation(&admin, &TimelockAction::SetDelay(0));
+    client.cancel_operation(&admin, &id);
+    assert_eq!(
+        client.try_execute_operation(&anyone, &id),
+        Err(Ok(crate::ContractError::NotFound))
+    );
+    assert_eq!(client.timelock_delay(), 3_600);
+}
+
+#[test]
+fn test_guardian_pause_only() {
+    use crate::types::Role;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    client.set_role(&admin, &Role::Guardian, &guardian, &true, &None);
+
+    client.set_pause(&guardian, &true);
+    assert!(client.is_mint_paused() && client.is_transfer_paused() && client.is_burn_paused());
+    assert_eq!(
+        client.try_set_pause(&guardian, &false),
+        Err(Ok(crate::ContractError::NotAdmin))
+    );
+    assert_eq!(
+        client.try_pause_mint(&guardian, &true),
+        Err(Ok(crate::ContractError::NotAdmin))
+    );
+    client.set_pause(&admin, &false);
+    assert!(!client.is_mint_paused());
+}
+
+#[test]
+fn test_renounce() {
+    use crate::types::Role;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&owner, &create_test_config(&env, &owner));
+    client.set_admin(&admin, &true);
+    client.freeze_metadata(&owner);
+
+    client.renounce_ownership();
+    assert_eq!(client.owner(), None);
+    assert_eq!(
+        client.try_set_admin(&user, &true),
+        Err(Ok(crate::ContractError::NotAuthorized))
+    );
+    assert_eq!(
+        client.try_set_minter(&owner, &user, &true),
+        Err(Ok(crate::ContractError::NotAdmin))
+    );
+
+    // The remaining admin can still act until it renounces too.
+    client.set_minter(&admin, &user, &true);
+    assert_eq!(
+        client.try_renounce_role(&admin, &Role::Owner),
+        Err(Ok(crate::ContractError::InvalidRole))
+    );
+    client.renounce_role(&admin, &Role::Admin);
+    assert!(!client.has_role(&Role::Admin, &admin));
+    assert_eq!(
+        client.try_set_minter(&admin, &user, &false),
+        Err(Ok(crate::ContractError::NotAdmin))
+    );
+    assert_eq!(
+        client.try_renounce_role(&admin, &Role::Admin),
+        Err(Ok(crate::ContractError::NotFound))
+    );
+}
+
+#[test]
+fn test_set_max_supply() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let uri = String::from_str(&env, "ipfs://a");
+    client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+    client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+
+    client.set_max_supply(&admin, &3);
+    assert_eq!(client.get_collection_stats().max_supply, Some(3));
+    assert_eq!(
+        client.try_set_max_supply(&admin, &4),
+        Err(Ok(crate::ContractError::InvalidMaxSupply))
+    );
+    assert_eq!(
+        client.try_set_max_supply(&admin, &1),
+        Err(Ok(crate::ContractError::InvalidMaxSupply))
+    );
+    client.set_max_supply(&admin, &2);
+    assert_eq!(
+        client.try_mint(&admin, &user, &uri, &Vec::new(&env), &None),
+        Err(Ok(crate::ContractError::SupplyLimitExceeded))
+    );
+}
+
+#[test]
+fn test_mint_paid() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let currency = sac.address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &currency).mint(&buyer, &1_000);
+    let uri = String::from_str(&env, "ipfs://a");
+
+    assert_eq!(
+        client.try_mint_paid(&buyer, &buyer, &currency, &uri, &Vec::new(&env)),
+        Err(Ok(crate::ContractError::CurrencyNotAccepted))
+    );
+    assert_eq!(
+        client.try_set_mint_price(&buyer, &300, &currency),
+        Err(Ok(crate::ContractError::NotAdmin))
+    );
+    client.set_mint_price(&admin, &300, &currency);
+    assert_eq!(client.get_mint_price(&currency), Some(300));
+
+    let id = client.mint_paid(&buyer, &buyer, &currency, &uri, &Vec::new(&env));
+    assert_eq!(client.owner_of(&id), buyer);
+    let token = soroban_sdk::token::TokenClient::new(&env, &currency);
+    assert_eq!(token.balance(&buyer), 700);
+    assert_eq!(client.proceeds(&currency), 300);
+}
+
+#[test]
+fn test_payout_splits() {
+    use crate::types::PayoutSplit;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let artist = Address::generate(&env);
+    let dev = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let currency = sac.address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &currency).mint(&buyer, &1_000);
+    let token = soroban_sdk::token::TokenClient::new(&env, &currency);
+    let uri = String::from_str(&env, "ipfs://a");
+
+    assert_eq!(
+        client.try_withdraw(&currency),
+        Err(Ok(crate::ContractError::InsufficientBalance))
+    );
+    assert_eq!(
+        client.try_set_payout_splits(
+            &admin,
+            &Vec::from_array(
+                &env,
+                [PayoutSplit {
+                    recipient: artist.clone(),
+                    share_bps: 5_000,
+                }]
+            )
+        ),
+        Err(Ok(crate::ContractError::InvalidAmount))
+    );
+    client.set_payout_splits(
+        &admin,
+        &Vec::from_array(
+            &env,
+            [
+                PayoutSplit {
+                    recipient: artist.clone(),
+                    share_bps: 6_667,
+                },
+                PayoutSplit {
+                    recipient: dev.clone(),
+                    share_bps: 3_333,
+                },
+            ],
+        ),
+    );
+    client.set_mint_price(&admin, &101, &currency);
+    client.mint_paid(&buyer, &buyer, &currency, &uri, &Vec::new(&env));
+
+    // 3333 bps of 101 rounds down to 33; the dust goes to the first recipient.
+    assert_eq!(client.withdraw(&currency), 101);
+    assert_eq!(token.balance(&artist), 68);
+    assert_eq!(token.balance(&dev), 33);
+    assert_eq!(client.proceeds(&currency), 0);
+    let earnings = client.earnings_of(&artist, &currency);
+    assert_eq!((earnings.primary_sales, earnings.royalties), (68, 0));
+}
+
+#[test]
+fn test_mint_paid_multiple_currencies() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let xlm = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let usdc = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &xlm).mint(&buyer, &1_000);
+    soroban_sdk::token::StellarAssetClient::new(&env, &usdc).mint(&buyer, &1_000);
+    let uri = String::from_str(&env, "ipfs://a");
+
+    client.set_mint_price(&admin, &500, &xlm);
+    client.set_mint_price(&admin, &50, &usdc);
+    assert_eq!(client.mint_prices().len(), 2);
+
+    client.mint_paid(&buyer, &buyer, &xlm, &uri, &Vec::new(&env));
+    client.mint_paid(&buyer, &buyer, &usdc, &uri, &Vec::new(&env));
+    assert_eq!(client.proceeds(&xlm), 500);
+    assert_eq!(client.proceeds(&usdc), 50);
+
+    client.set_mint_price(&admin, &0, &usdc);
+    assert_eq!(client.get_mint_price(&usdc), None);
+    assert_eq!(
+        client.try_mint_paid(&buyer, &buyer, &usdc, &uri, &Vec::new(&env)),
+        Err(Ok(crate::ContractError::CurrencyNotAccepted))
+    );
+}
+
+#[soroban_sdk::contract]
+struct MockPriceFeed;
+
+#[soroban_sdk::contractimpl]
+impl MockPriceFeed {
+    pub fn decimals(_env: Env) -> u32 {
+        14
+    }
+
+    pub fn lastprice(env: Env, asset: crate::oracle::Asset) -> Option<crate::oracle::PriceData> {
+        env.storage().instance().get(&asset)
+    }
+
+    pub fn set_price(env: Env, asset: crate::oracle::Asset, price: i128, timestamp: u64) {
+        env.storage()
+            .instance()
+            .set(&asset, &crate::oracle::PriceData { price, timestamp });
+    }
+}
+
+#[test]
+fn test_mint_paid_oracle_price() {
+    use crate::types::PriceOracle;
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(10_000);
+
+    let admin = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let xlm = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &xlm).mint(&buyer, &1_000_000_000);
+    let uri = String::from_str(&env, "ipfs://a");
+    client.set_mint_price(&admin, &300_000_000, &xlm);
+
+    let feed_id = env.register(MockPriceFeed, ());
+    let feed = MockPriceFeedClient::new(&env, &feed_id);
+    let asset = crate::oracle::Asset::Stellar(xlm.clone());
+    // 1 XLM = 0.25 USD at 14 decimals.
+    feed.set_price(&asset, &25_000_000_000_000, &10_000);
+
+    let oracle = PriceOracle {
+        feed: feed_id.clone(),
+        usd_price: 5_0000000,
+        max_age: 300,
+    };
+    assert_eq!(
+        client.try_set_price_oracle(
+            &admin,
+            &Some(PriceOracle {
+                usd_price: 0,
+                ..oracle.clone()
+            })
+        ),
+        Err(Ok(crate::ContractError::InvalidAmount))
+    );
+    client.set_price_oracle(&admin, &Some(oracle));
+
+    // 5 USD at 0.25 USD/XLM is 20 XLM (7 decimals).
+    assert_eq!(client.quote_mint_price(&xlm), 200_000_000);
+    client.mint_paid(&buyer, &buyer, &xlm, &uri, &Vec::new(&env));
+    assert_eq!(client.proceeds(&xlm), 200_000_000);
+
+    // A stale feed price falls back to the fixed price.
+    env.ledger().set_timestamp(10_301);
+    assert_eq!(client.quote_mint_price(&xlm), 300_000_000);
+    client.mint_paid(&buyer, &buyer, &xlm, &uri, &Vec::new(&env));
+    assert_eq!(client.proceeds(&xlm), 500_000_000);
+
+    client.set_price_oracle(&admin, &None);
+    assert_eq!(client.price_oracle(), None);
+}
+
+#[test]
+fn test_mint_escrow() {
+    use crate::types::EscrowStatus;
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let holder = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let currency = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &currency).mint(&buyer, &1_000);
+    let token = soroban_sdk::token::TokenClient::new(&env, &currency);
+    let uri = String::from_str(&env, "ipfs://a");
+    client.set_mint_price(&admin, &100, &currency);
+
+    assert_eq!(
+        client.try_set_mint_escrow(&admin, &3, &0),
+        Err(Ok(crate::ContractError::InvalidAmount))
+    );
+    // Proceeds from before the escrow must not end up held by it.
+    client.mint_paid(&buyer, &buyer, &currency, &uri, &Vec::new(&env));
+    assert_eq!(
+        client.try_set_mint_escrow(&admin, &4, &1_000),
+        Err(Ok(crate::ContractError::ProceedsNotWithdrawn))
+    );
+    assert_eq!(client.withdraw(&currency), 100);
+    client.set_mint_escrow(&admin, &4, &1_000);
+    assert_eq!(client.escrow_status(), Some(EscrowStatus::Pending));
+
+    let id = client.mint_paid(&buyer, &buyer, &currency, &uri, &Vec::new(&env));
+    let forfeited = client.mint_paid(&buyer, &buyer, &currency, &uri, &Vec::new(&env));
+    client.mint_paid(&buyer, &buyer, &currency, &uri, &Vec::new(&env));
+    assert_eq!(
+        client.try_withdraw(&currency),
+        Err(Ok(crate::ContractError::EscrowPending))
+    );
+    assert_eq!(
+        client.try_refund(&buyer, &id),
+        Err(Ok(crate::ContractError::EscrowPending))
+    );
+    assert_eq!(
+        client.try_set_mint_escrow(&admin, &1, &2_000),
+        Err(Ok(crate::ContractError::EscrowPending))
+    );
+
+    // The drop misses its threshold: refunds go to whoever holds the token.
+    client.transfer(&buyer, &holder, &id);
+    env.ledger().set_timestamp(1_000);
+    assert_eq!(client.escrow_status(), Some(EscrowStatus::Failed));
+    assert_eq!(
+        client.try_mint_paid(&buyer, &buyer, &currency, &uri, &Vec::new(&env)),
+        Err(Ok(crate::ContractError::EscrowFailed))
+    );
+    // Every payment still backs a live token, so nothing is free to withdraw yet.
+    assert_eq!(
+        client.try_withdraw(&currency),
+        Err(Ok(crate::ContractError::InsufficientBalance))
+    );
+    assert_eq!(
+        client.try_refund(&buyer, &id),
+        Err(Ok(crate::ContractError::NotAuthorized))
+    );
+    assert_eq!(client.refund(&holder, &id), 100);
+    assert_eq!(token.balance(&holder), 100);
+    assert_eq!(client.proceeds(&currency), 200);
+
+    // Burning an escrowed token forfeits its payment to the creator.
+    client.burn(&buyer, &forfeited, &true);
+    assert_eq!(client.withdraw(&currency), 100);
+    assert_eq!(client.proceeds(&currency), 100);
+    assert_eq!(
+        client.try_owner_of(&id),
+        Err(Ok(crate::ContractError::TokenNotFound))
+    );
+    assert_eq!(
+        client.try_refund(&holder, &id),
+        Err(Ok(crate::ContractError::NotFound))
+    );
+}
+
+#[test]
+fn test_mint_escrow_succeeds() {
+    use crate::types::EscrowStatus;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let currency = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &currency).mint(&buyer, &1_000);
+    let uri = String::from_str(&env, "ipfs://a");
+    client.set_mint_price(&admin, &100, &currency);
+    client.set_mint_escrow(&admin, &2, &1_000);
+
+    let id = client.mint_paid(&buyer, &buyer, &currency, &uri, &Vec::new(&env));
+    client.mint_paid(&buyer, &buyer, &currency, &uri, &Vec::new(&env));
+    assert_eq!(client.escrow_status(), Some(EscrowStatus::Succeeded));
+    assert_eq!(
+        client.try_refund(&buyer, &id),
+        Err(Ok(crate::ContractError::EscrowSucceeded))
+    );
+    assert_eq!(client.withdraw(&currency), 200);
+}
+
+#[test]
+fn test_max_per_transaction() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let uri = String::from_str(&env, "ipfs://a");
+
+    assert_eq!(
+        client.try_set_max_per_transaction(&admin, &Some(0)),
+        Err(Ok(crate::ContractError::InvalidAmount))
+    );
+    client.set_max_per_transaction(&admin, &Some(2));
+    assert_eq!(client.get_collection_stats().max_per_transaction, Some(2));
+
+    let three = Vec::from_array(&env, [user.clone(), user.clone(), user.clone()]);
+    assert_eq!(
+        client.try_airdrop(&admin, &three, &uri),
+        Err(Ok(crate::ContractError::MaxPerTransactionExceeded))
+    );
+    assert_eq!(
+        client.try_batch_mint(
+            &admin,
+            &three,
+            &Vec::from_array(&env, [uri.clone(), uri.clone(), uri.clone()]),
+            &Vec::from_array(&env, [Vec::new(&env), Vec::new(&env), Vec::new(&env)]),
+        ),
+        Err(Ok(crate::ContractError::MaxPerTransactionExceeded))
+    );
+    client.airdrop(
+        &admin,
+        &Vec::from_array(&env, [user.clone(), user.clone()]),
+        &uri,
+    );
+
+    client.set_max_per_transaction(&admin, &None);
+    client.airdrop(&admin, &three, &uri);
+    assert_eq!(client.balance_of(&user), 5);
+}
+
+#[test]
+fn test_mint_window() {
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(100);
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let uri = String::from_str(&env, "ipfs://a");
+
+    assert_eq!(
+        client.try_set_mint_window(&admin, &Some(500), &Some(500)),
+        Err(Ok(crate::ContractError::InvalidAmount))
+    );
+    client.set_mint_window(&admin, &Some(200), &Some(300));
+    assert_eq!(client.mint_start(), Some(200));
+    assert_eq!(client.mint_end(), Some(300));
+    assert_eq!(
+        client.try_mint(&admin, &user, &uri, &Vec::new(&env), &None),
+        Err(Ok(crate::ContractError::MintNotActive))
+    );
+
+    env.ledger().set_timestamp(200);
+    client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+
+    env.ledger().set_timestamp(300);
+    assert_eq!(
+        client.try_batch_mint(
+            &admin,
+            &Vec::from_array(&env, [user.clone()]),
+            &Vec::from_array(&env, [uri.clone()]),
+            &Vec::from_array(&env, [Vec::new(&env)]),
+        ),
+        Err(Ok(crate::ContractError::MintNotActive))
+    );
+
+    client.set_mint_window(&admin, &None, &None);
+    client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+    assert_eq!(client.balance_of(&user), 2);
+}
+
+#[test]
+fn test_relay_mint() {
+    use crate::Role;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let currency = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &currency).mint(&treasury, &1_000);
+    let token = soroban_sdk::token::TokenClient::new(&env, &currency);
+    let uri = String::from_str(&env, "ipfs://a");
+    client.set_mint_price(&admin, &100, &currency);
+
+    assert_eq!(
+        client.try_relay_mint(&relayer, &treasury, &user, &currency, &uri, &Vec::new(&env)),
+        Err(Ok(crate::ContractError::NotRelayer))
+    );
+    client.set_role(&admin, &Role::Relayer, &relayer, &true, &None);
+    client.set_relayer_quota(&admin, &relayer, &Some(1));
+
+    let id = client.relay_mint(&relayer, &treasury, &user, &currency, &uri, &Vec::new(&env));
+    assert_eq!(client.owner_of(&id), user);
+    assert_eq!(token.balance(&treasury), 900);
+    assert_eq!(client.proceeds(&currency), 100);
+    assert_eq!(client.relayer_quota(&relayer), Some(0));
+    assert_eq!(
+        client.try_relay_mint(&relayer, &treasury, &user, &currency, &uri, &Vec::new(&env)),
+        Err(Ok(crate::ContractError::AllocationExhausted))
+    );
+
+    client.set_relayer_quota(&admin, &relayer, &None);
+    client.relay_mint(&relayer, &treasury, &user, &currency, &uri, &Vec::new(&env));
+    assert_eq!(client.balance_of(&user), 2);
+}
+
+#[soroban_sdk::contract]
+struct MockRoyaltyRegistry;
+
+#[soroban_sdk::contractimpl]
+impl MockRoyaltyRegistry {
+    pub fn royalty(env: Env, _collection: Address, _token_id: u64) -> Option<(Address, u32)> {
+        env.storage().instance().get(&0u32)
+    }
+
+    pub fn set_royalty(env: Env, recipient: Address, bps: u32) {
+        env.storage().instance().set(&0u32, &(recipient, bps));
+    }
+}
+
+#[test]
+fn test_royalty_registry_fallback() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let platform = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let id = client.mint(
+        &admin,
+        &user,
+        &String::from_str(&env, "ipfs://a"),
+        &Vec::new(&env),
+        &None,
+    );
+    let registry_id = env.register(MockRoyaltyRegistry, ());
+    MockRoyaltyRegistryClient::new(&env, &registry_id).set_royalty(&platform, &250);
+    client.set_royalty_registry(&admin, &Some(registry_id.clone()));
+    assert_eq!(client.royalty_registry(), Some(registry_id));
+
+    // The collection default takes precedence while it sets a royalty.
+    let (recipient, _) = client.get_royalty_info(&id, &10_000);
+    assert_eq!(recipient, admin);
+
+    client.set_default_royalty(&admin, &admin, &0);
+    assert_eq!(client.get_royalty_info(&id, &10_000), (platform, 250));
+
+    client.set_royalty_info(&admin, &id, &user, &100);
+    assert_eq!(client.get_royalty_info(&id, &10_000), (user, 100));
+
+    client.set_royalty_registry(&admin, &None);
+    client.reset_token_royalty(&admin, &id);
+    assert_eq!(client.get_royalty_info(&id, &10_000), (admin, 0));
+}
+
+#[test]
+fn test_operator_filter() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let marketplace = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let id = client.mint(
+        &admin,
+        &user,
+        &String::from_str(&env, "ipfs://a"),
+        &Vec::new(&env),
+        &None,
+    );
+
+    client.set_operator_filter(&admin, &true);
+    assert!(client.is_operator_filtered());
+    client.set_operator_allowed(&admin, &marketplace, &true);
+    assert_eq!(
+        client.allowed_operators(),
+        Vec::from_array(&env, [marketplace.clone()])
+    );
+
+    assert_eq!(
+        client.try_set_approval_for_all(&user, &other, &true),
+        Err(Ok(crate::ContractError::OperatorNotAllowed))
+    );
+    assert_eq!(
+        client.try_approve(&user, &other, &id),
+        Err(Ok(crate::ContractError::OperatorNotAllowed))
+    );
+    client.set_approval_for_all(&user, &marketplace, &true);
+    client.approve(&user, &marketplace, &id);
+
+    client.set_operator_allowed(&admin, &marketplace, &false);
+    assert!(client.allowed_operators().is_empty());
+    // Revoking stays possible for delisted operators.
+    client.set_approval_for_all(&user, &marketplace, &false);
+
+    client.set_operator_filter(&admin, &false);
+    client.set_approval_for_all(&user, &other, &true);
+}
+
+#[test]
+fn test_burn_policy() {
+    use crate::types::BurnPolicy;
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let uri = String::from_str(&env, "ipfs://a");
+    let a = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+    let b = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+    assert_eq!(client.burn_policy(), BurnPolicy::Enabled);
+
+    client.set_burn_policy(&admin, &BurnPolicy::Disabled);
+    assert_eq!(
+        client.try_burn(&user, &a, &true),
+        Err(Ok(crate::ContractError::BurnNotAllowed))
+    );
+
+    client.set_burn_policy(&admin, &BurnPolicy::OwnerOnly);
+    assert_eq!(
+        client.try_batch_burn(&admin, &Vec::from_array(&env, [a]), &true),
+        Err(Ok(crate::ContractError::BurnNotAllowed))
+    );
+    client.burn(&user, &a, &true);
+
+    client.set_burn_policy(&admin, &BurnPolicy::AfterTimestamp(500));
+    assert_eq!(
+        client.try_burn(&user, &b, &true),
+        Err(Ok(crate::ContractError::BurnNotAllowed))
+    );
+    env.ledger().set_timestamp(500);
+    client.burn(&admin, &b, &true);
+    assert_eq!(client.total_supply(), 0);
+}
+
+#[test]
+fn test_burned_registry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let uri = String::from_str(&env, "ipfs://a");
+    let a = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+    let b = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+
+    client.burn(&user, &a, &true);
+    assert!(client.was_burned(&a));
+    assert!(!client.was_burned(&b));
+    assert!(!client.was_burned(&(b + 1)));
+    assert_eq!(client.total_burned(), 1);
+    assert_eq!(client.get_collection_stats().total_burned, 1);
+}
+
+#[test]
+fn test_transfer_count() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let id = client.mint(
+        &admin,
+        &user,
+        &String::from_str(&env, "ipfs://a"),
+        &Vec::new(&env),
+        &None,
+    );
+    assert_eq!(client.transfer_count(&id), 0);
+
+    client.transfer(&user, &other, &id);
+    client.transfer(&other, &user, &id);
+    assert_eq!(client.transfer_count(&id), 2);
+    assert_eq!(client.token_metadata(&id).transfer_count, 2);
+    assert_eq!(
+        client.try_transfer_count(&(id + 1)),
+        Err(Ok(crate::ContractError::TokenNotFound))
+    );
+}
+
+#[test]
+fn test_collect_garbage() {
+    use crate::Role;
+    use crate::types::StaleEntry;
+    use soroban_sdk::testutils::Ledger;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let minter = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &create_test_config(&env, &admin));
+    let id = client.mint(
+        &admin,
+        &user,
+        &String::from_str(&env, "ipfs://a"),
+        &Vec::new(&env),
+        &None,
+    );
+    client.approve_with_expiry(&user, &spender, &id, &100);
+    client.set_role(&admin, &Role::Minter, &minter, &true, &Some(100));
+    client.set_whitelist_allocation(&admin, &buyer, &0);
+
+    let entries = Vec::from_array(
+        &env,
+        [
+            StaleEntry::Approval(id),
+            StaleEntry::Role(Role::Minter, minter.clone()),
+            StaleEntry::WhitelistAllocation(buyer.clone()),
+        ],
+    );
+    // Only the spent allocation is stale before the expiries.
+    assert_eq!(client.collect_garbage(&entries), 1);
+    assert_eq!(client.get_allocation(&buyer), None);
+
+    env.ledger().set_timestamp(100);
+    assert_eq!(client.collect_garbage(&entries), 2);
+    assert_eq!(client.role_expiry(&Role::Minter, &minter), None);
+    assert_eq!(client.collect_garbage(&entries), 0);
+}
+
+#[test]
+fn test_max_batch_size() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let contract_id = env.register(NftContract, ());
+    let client = NftContractClient::new(&env, &contract_id);
+    let mut config = create_test_config(&env, &admin);
+    config.max_batch_size = Some(0);
+    assert_eq!(
+        client.try_initialize(&admin, &config),
+        Err(Ok(crate::ContractError::InvalidAmount))
+    );
+    config.max_batch_size = Some(2);
+    client.initialize(&admin, &config);
+    assert_eq!(client.max_batch_size(), Some(2));
+    let uri = String::from_str(&env, "ipfs://a");
+
+    let three = Vec::from_array(&env, [user.clone(), user.clone(), user.clone()]);
+    assert_eq!(
+        client.try_airdrop(&admin, &three, &uri),
+        Err(Ok(crate::ContractError::BatchTooLarge))
+    );
+    let ids = client.airdrop(
+        &admin,
+        &Vec::from_array(&env, [user.clone(), user.clone()]),
+        &uri,
+    );
+    let id = client.mint(&admin, &user, &uri, &Vec::new(&env), &None);
+    let mut all = ids.clone();
+    all.push_back(id);
+
+    assert_eq!(
+        client.try_batch_transfer(&user, &other, &all),
+        Err(Ok(crate::ContractError::BatchTooLarge))
+    );
+    assert_eq!(
+        client.try_batch_burn(&user, &all, &true),
+        Err(Ok(crate::ContractError::BatchTooLarge))
+    );
+    client.batch_transfer(&user, &other, &ids);
+    client.batch_burn(&other, &ids, &true);
+    assert_eq!(client.total_supply(), 1);
+}
+
+#[test]
+fn test_page_range() {
+    use crate::utils::page_range;
+    use nft_interface::MAX_PAGE_SIZE;
+
+    assert_eq!(page_range(0, 10, 25), 0..10);
+    assert_eq!(page_range(20, 10, 25), 20..25);
+    assert_eq!(page_range(30, 10, 25), 25..25);
+    assert_eq!(page_range(u32::MAX, u32::MAX, 25), 25..25);
+    assert_eq!(page_range(0, u32::MAX, 1_000), 0..MAX_PAGE_SIZE);
+}
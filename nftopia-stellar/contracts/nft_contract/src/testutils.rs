@@ -0,0 +1,33 @@
+//! Test helpers for contracts that exercise a collection, behind the `testutils` feature.
+
+use crate::types::{CollectionConfig, RoyaltyInfo};
+use crate::{NftContract, NftContractClient};
+use soroban_sdk::{Address, Env, String};
+
+/// Registers and initializes a revealed, uncapped collection owned by `creator`, which also
+/// receives the default royalty of `royalty_bps`.
+pub fn deploy_test_collection<'a>(
+    env: &Env,
+    creator: &Address,
+    royalty_bps: u32,
+) -> NftContractClient<'a> {
+    let nft = NftContractClient::new(env, &env.register(NftContract, ()));
+    nft.initialize(
+        creator,
+        &CollectionConfig {
+            name: String::from_str(env, "Test NFT"),
+            symbol: String::from_str(env, "TNFT"),
+            base_uri: String::from_str(env, "https://nftopia.test/"),
+            max_supply: None,
+            is_revealed: true,
+            royalty_default: RoyaltyInfo {
+                recipient: creator.clone(),
+                percentage: royalty_bps,
+            },
+            metadata_is_frozen: false,
+            content_addressed_ids: false,
+            max_batch_size: None,
+        },
+    );
+    nft
+}
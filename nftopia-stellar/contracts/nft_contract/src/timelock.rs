@@ -0,0 +1,161 @@
+//! Timelock for sensitive admin actions. While a delay is set, `upgrade`, `set_base_uri` and
+//! `set_default_royalty` cannot be called directly: the action is queued and only executes
+//! once the delay has passed, giving holders time to react. Lowering or removing the delay
+//! goes through the queue as well.
+//!
+//! In council mode, upgrades and default royalty changes are queued only by an executed
+//! council proposal, so both the council's approvals and the delay apply. Such operations are
+//! cancelled by a council proposal too, so no single admin can veto them.
+
+use crate::access_control;
+use crate::error::ContractError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::{QueuedOperation, TimelockAction};
+use crate::utils::{MAX_TIMELOCK_DELAY, validate_royalty_bps};
+use soroban_sdk::{Address, Env};
+
+/// Seconds a queued action must wait; 0 when the timelock is off.
+pub fn delay(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TimelockDelay)
+        .unwrap_or(0)
+}
+
+/// Fails with `TimelockRequired` while the action has to be queued instead.
+pub fn require_no_timelock(env: &Env) -> Result<(), ContractError> {
+    if delay(env) > 0 {
+        return Err(ContractError::TimelockRequired);
+    }
+    Ok(())
+}
+
+/// Turns the timelock on, or raises its delay. Owner only; lowering it must be queued.
+pub fn set_delay(env: &Env, delay_secs: u64) -> Result<(), ContractError> {
+    access_control::require_owner(env)?;
+    if delay_secs < delay(env) {
+        return Err(ContractError::TimelockRequired);
+    }
+    store_delay(env, delay_secs)
+}
+
+fn store_delay(env: &Env, delay_secs: u64) -> Result<(), ContractError> {
+    if delay_secs > MAX_TIMELOCK_DELAY {
+        return Err(ContractError::InvalidAmount);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::TimelockDelay, &delay_secs);
+    events::emit_timelock_delay_updated(env, delay_secs);
+    Ok(())
+}
+
+/// Queued operation `operation_id`, if still pending.
+pub fn queued(env: &Env, operation_id: u32) -> Option<QueuedOperation> {
+    env.storage()
+        .instance()
+        .get(&DataKey::QueuedOperation(operation_id))
+}
+
+/// Queues `action`, checking the same authorization its direct call would; returns its id.
+/// Council-gated actions must be queued through a council proposal instead.
+pub fn queue(env: &Env, caller: Address, action: TimelockAction) -> Result<u32, ContractError> {
+    match &action {
+        TimelockAction::Upgrade(_) => {
+            crate::require_upgrader(env, &caller)?;
+            crate::council::require_no_council(env)?;
+        }
+        TimelockAction::SetBaseUri(_) => access_control::require_admin(env, &caller)?,
+        TimelockAction::SetDefaultRoyalty(info) => {
+            validate_royalty_bps(info.percentage)?;
+            access_control::require_admin(env, &caller)?;
+            crate::council::require_no_council(env)?;
+        }
+        TimelockAction::SetDelay(_) => {
+            let owner = access_control::require_owner(env)?;
+            if caller != owner {
+                return Err(ContractError::NotAuthorized);
+            }
+        }
+    }
+    Ok(enqueue(env, caller, action, false))
+}
+
+/// Stores `action` to run once the delay has passed; the caller has been authorized.
+/// `by_council` marks operations queued by an executed council proposal.
+pub(crate) fn enqueue(env: &Env, caller: Address, action: TimelockAction, by_council: bool) -> u32 {
+    let operation_id: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextQueuedOperationId)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextQueuedOperationId, &(operation_id + 1));
+    let eta = env.ledger().timestamp().saturating_add(delay(env));
+    env.storage().instance().set(
+        &DataKey::QueuedOperation(operation_id),
+        &QueuedOperation {
+            action: action.clone(),
+            eta,
+            queued_by: caller.clone(),
+            by_council,
+        },
+    );
+    events::emit_operation_queued(env, operation_id, caller, action, eta);
+    operation_id
+}
+
+/// Executes a queued operation once its eta has passed. Anyone may trigger it: the action
+/// was authorized when queued.
+pub fn execute(env: &Env, caller: Address, operation_id: u32) -> Result<(), ContractError> {
+    caller.require_auth();
+    let operation = queued(env, operation_id).ok_or(ContractError::NotFound)?;
+    if env.ledger().timestamp() < operation.eta {
+        return Err(ContractError::TimelockNotReady);
+    }
+    env.storage()
+        .instance()
+        .remove(&DataKey::QueuedOperation(operation_id));
+    match operation.action {
+        TimelockAction::Upgrade(wasm_hash) => crate::apply_upgrade(env, caller.clone(), wasm_hash),
+        TimelockAction::SetBaseUri(base_uri) => crate::metadata::store_base_uri(env, base_uri)?,
+        TimelockAction::SetDefaultRoyalty(info) => {
+            crate::royalty::store_default_royalty(env, info.recipient, info.percentage)?
+        }
+        TimelockAction::SetDelay(delay_secs) => store_delay(env, delay_secs)?,
+    }
+    events::emit_operation_executed(env, operation_id, caller);
+    Ok(())
+}
+
+/// Drops a queued operation. Admin only; while a council is registered, operations queued by
+/// a council proposal need a `CancelOperation` proposal instead.
+pub fn cancel(env: &Env, caller: Address, operation_id: u32) -> Result<(), ContractError> {
+    access_control::require_admin(env, &caller)?;
+    let operation = queued(env, operation_id).ok_or(ContractError::NotFound)?;
+    if operation.by_council {
+        crate::council::require_no_council(env)?;
+    }
+    remove(env, caller, operation_id);
+    Ok(())
+}
+
+/// Drops a queued operation on behalf of an executed council proposal.
+pub(crate) fn cancel_by_council(
+    env: &Env,
+    member: Address,
+    operation_id: u32,
+) -> Result<(), ContractError> {
+    queued(env, operation_id).ok_or(ContractError::NotFound)?;
+    remove(env, member, operation_id);
+    Ok(())
+}
+
+fn remove(env: &Env, caller: Address, operation_id: u32) {
+    env.storage()
+        .instance()
+        .remove(&DataKey::QueuedOperation(operation_id));
+    events::emit_operation_cancelled(env, operation_id, caller);
+}
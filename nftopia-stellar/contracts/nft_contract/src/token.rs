@@ -3,9 +3,9 @@ use crate::error::ContractError;
 use crate::events;
 use crate::reentrancy;
 use crate::storage::DataKey;
-use crate::types::{RoyaltyInfo, TokenAttribute};
-use crate::utils::validate_royalty_bps;
-use soroban_sdk::{Address, Env, Vec};
+use crate::types::{BurnPolicy, RoyaltyInfo, TokenAttribute, TokenRecord};
+use crate::utils::{MAX_AIRDROP_RECIPIENTS, MAX_BATCH_TRANSFER, validate_royalty_bps};
+use soroban_sdk::{Address, BytesN, Env, Vec};
 
 /// Mints a new token. Requires minter role; if whitelist-only mode, caller must be whitelisted.
 pub fn mint(
@@ -16,20 +16,254 @@ pub fn mint(
     attributes: Vec<TokenAttribute>,
     royalty_override: Option<RoyaltyInfo>,
 ) -> Result<u64, ContractError> {
-    access_control::require_minter(env, &caller)?;
-    access_control::require_not_paused(env)?;
+    require_can_mint(env, &caller, 1)?;
+    reentrancy::with_lock(env, || {
+        mint_internal(env, caller, to, metadata_uri, attributes, royalty_override)
+    })
+}
+
+/// Like `mint`, also recording the immutable `content_hash`. In a content-addressed collection
+/// the id is derived from `content_hash` rather than the URI.
+pub fn mint_with_content_hash(
+    env: &Env,
+    caller: Address,
+    to: Address,
+    metadata_uri: soroban_sdk::String,
+    attributes: Vec<TokenAttribute>,
+    royalty_override: Option<RoyaltyInfo>,
+    content_hash: BytesN<32>,
+) -> Result<u64, ContractError> {
+    require_can_mint(env, &caller, 1)?;
+    reentrancy::with_lock(env, || {
+        let token_id = content_token_id(env, &metadata_uri, Some(&content_hash))?;
+        if let Some(id) = token_id
+            && let Some(existing) = existing_content_token(env, id, &to)?
+        {
+            return Ok(existing);
+        }
+        let id = store_token(
+            env,
+            token_id,
+            caller.clone(),
+            to.clone(),
+            metadata_uri,
+            attributes,
+            royalty_override,
+        )?;
+        env.storage()
+            .instance()
+            .set(&DataKey::ContentHash(id), &content_hash);
+        events::emit_mint(env, to, id, caller);
+        Ok(id)
+    })
+}
+
+/// The id a content-addressed collection assigns to this content: the first 8 bytes of
+/// `content_hash`, or of sha256(`metadata_uri`) without one. `None` for sequential collections.
+fn content_token_id(
+    env: &Env,
+    metadata_uri: &soroban_sdk::String,
+    content_hash: Option<&BytesN<32>>,
+) -> Result<Option<u64>, ContractError> {
+    if !env.storage().instance().has(&DataKey::ContentAddressedIds) {
+        return Ok(None);
+    }
+    let digest = match content_hash {
+        Some(hash) => hash.to_array(),
+        None => {
+            if metadata_uri.is_empty() {
+                return Err(ContractError::InvalidTokenId);
+            }
+            env.crypto().sha256(&metadata_uri.to_bytes()).to_array()
+        }
+    };
+    let mut id = [0u8; 8];
+    id.copy_from_slice(&digest[..8]);
+    Ok(Some(u64::from_be_bytes(id)))
+}
+
+/// For a retried content-addressed mint: `Some(id)` if the token already exists and belongs to
+/// `to`, TokenAlreadyExists if the content was minted to someone else or burned.
+fn existing_content_token(env: &Env, id: u64, to: &Address) -> Result<Option<u64>, ContractError> {
+    match crate::record::owner(env, id) {
+        Some(owner) if owner == *to => Ok(Some(id)),
+        Some(_) => Err(ContractError::TokenAlreadyExists),
+        None if env.storage().instance().has(&DataKey::UsedTokenId(id)) => {
+            Err(ContractError::TokenAlreadyExists)
+        }
+        None => Ok(None),
+    }
+}
+
+/// Mints under a caller-chosen `token_id` (e.g. to preserve ids in a migration). Minter role;
+/// fails with TokenIdTaken if the id was ever used, including by a burned token.
+pub fn mint_with_id(
+    env: &Env,
+    caller: Address,
+    to: Address,
+    token_id: u64,
+    metadata_uri: soroban_sdk::String,
+    attributes: Vec<TokenAttribute>,
+    royalty_override: Option<RoyaltyInfo>,
+) -> Result<u64, ContractError> {
+    require_can_mint(env, &caller, 1)?;
+    let id = reentrancy::with_lock(env, || {
+        store_token(
+            env,
+            Some(token_id),
+            caller.clone(),
+            to.clone(),
+            metadata_uri,
+            attributes,
+            royalty_override,
+        )
+    })?;
+    events::emit_mint(env, to, id, caller);
+    Ok(id)
+}
+
+/// Checks that `caller` may mint `count` tokens right now: within the per-transaction cap,
+/// minter role or enough mint allowance, not paused, and in whitelist-only mode whitelisted
+/// with enough allocation left. Allowance and allocation are consumed.
+pub(crate) fn require_can_mint(
+    env: &Env,
+    caller: &Address,
+    count: u32,
+) -> Result<(), ContractError> {
+    if max_per_transaction(env).is_some_and(|max| count > max) {
+        return Err(ContractError::MaxPerTransactionExceeded);
+    }
+    if access_control::require_minter(env, caller).is_err() {
+        access_control::consume_mint_allowance(env, caller, count)?;
+    }
+    require_mint_open(env, caller, count)
+}
+
+/// Checks that minting is not paused, the mint window is open, an unrevealed collection has
+/// committed its provenance hash and, in whitelist-only mode, that `caller` is whitelisted
+/// with enough allocation left (which is consumed).
+pub(crate) fn require_mint_open(
+    env: &Env,
+    caller: &Address,
+    count: u32,
+) -> Result<(), ContractError> {
+    access_control::require_not_paused(env, &DataKey::MintPaused)?;
+    let now = env.ledger().timestamp();
+    if mint_start(env).is_some_and(|start| now < start)
+        || mint_end(env).is_some_and(|end| now >= end)
+    {
+        return Err(ContractError::MintNotActive);
+    }
+    if !crate::metadata::is_revealed(env) && crate::metadata::provenance_hash(env).is_none() {
+        return Err(ContractError::ProvenanceNotSet);
+    }
     let whitelist_only: bool = env
         .storage()
         .instance()
         .get(&DataKey::WhitelistOnlyMint)
         .unwrap_or(false);
     if whitelist_only {
-        access_control::require_whitelisted(env, &caller)?;
+        access_control::require_whitelisted(env, caller)?;
+        access_control::consume_allocation(env, caller, count)?;
+    }
+    Ok(())
+}
+
+/// Mints one token per recipient, all sharing `uri_template` and no attributes.
+/// Token IDs are assigned sequentially in recipient order; a single Airdrop event summarizes the batch.
+pub fn airdrop(
+    env: &Env,
+    caller: Address,
+    recipients: Vec<Address>,
+    uri_template: soroban_sdk::String,
+) -> Result<Vec<u64>, ContractError> {
+    if recipients.is_empty() {
+        return Err(ContractError::InvalidAmount);
+    }
+    if recipients.len() > MAX_AIRDROP_RECIPIENTS {
+        return Err(ContractError::BatchTooLarge);
+    }
+    crate::utils::require_batch_size(env, recipients.len())?;
+    require_can_mint(env, &caller, recipients.len())?;
+    reentrancy::with_lock(env, || {
+        let mut ids = Vec::new(env);
+        for to in recipients.iter() {
+            let id = store_new_token(
+                env,
+                caller.clone(),
+                to,
+                uri_template.clone(),
+                Vec::new(env),
+                None,
+            )?;
+            ids.push_back(id);
+        }
+        events::emit_airdrop(env, caller.clone(), ids.get(0).unwrap(), recipients.clone());
+        Ok(ids)
+    })
+}
+
+/// Mints `count` numbered copies of `master_token_id` to `to`. Copies share the master's URI,
+/// attributes and royalty; edition numbers continue after the master's own (1 if unset), and the
+/// master's `total_editions` caps the run.
+pub fn mint_edition(
+    env: &Env,
+    caller: Address,
+    master_token_id: u64,
+    to: Address,
+    count: u32,
+) -> Result<Vec<u64>, ContractError> {
+    if count == 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    if count > MAX_AIRDROP_RECIPIENTS {
+        return Err(ContractError::BatchTooLarge);
     }
-    reentrancy::acquire(env)?;
-    let result = mint_internal(env, caller, to, metadata_uri, attributes, royalty_override);
-    reentrancy::release(env);
-    result
+    require_can_mint(env, &caller, count)?;
+    let storage = env.storage().instance();
+    let master = crate::record::load(env, master_token_id).ok_or(ContractError::NotFound)?;
+    let total_editions = master.total_editions.ok_or(ContractError::NotFound)?;
+    let issued: u32 = storage
+        .get(&DataKey::EditionsIssued(master_token_id))
+        .or(master.edition_number)
+        .unwrap_or(1);
+    if issued.saturating_add(count) > total_editions {
+        return Err(ContractError::EditionsExhausted);
+    }
+    let uri = crate::metadata::stored_or_composed_uri(env, master_token_id)?;
+    let attributes: Vec<TokenAttribute> = storage
+        .get(&DataKey::TokenAttributes(master_token_id))
+        .unwrap_or_else(|| Vec::new(env));
+    let royalty = royalty_override(&master);
+
+    reentrancy::with_lock(env, || {
+        let mut ids = Vec::new(env);
+        for i in 1..=count {
+            let id = store_new_token(
+                env,
+                caller.clone(),
+                to.clone(),
+                uri.clone(),
+                attributes.clone(),
+                royalty.clone(),
+            )?;
+            let mut record = crate::record::load(env, id).ok_or(ContractError::TokenNotFound)?;
+            record.edition_number = Some(issued + i);
+            record.total_editions = Some(total_editions);
+            crate::record::save(env, id, &record);
+            ids.push_back(id);
+        }
+        storage.set(&DataKey::EditionsIssued(master_token_id), &(issued + count));
+        events::emit_editions_minted(
+            env,
+            caller.clone(),
+            master_token_id,
+            to.clone(),
+            ids.get(0).unwrap(),
+            count,
+        );
+        Ok(ids)
+    })
 }
 
 /// Internal mint without auth/role checks. Caller must have already verified minter, paused, whitelist.
@@ -41,7 +275,61 @@ pub(crate) fn mint_internal(
     attributes: Vec<TokenAttribute>,
     royalty_override: Option<RoyaltyInfo>,
 ) -> Result<u64, ContractError> {
-    let next_id: u64 = env
+    let token_id = content_token_id(env, &metadata_uri, None)?;
+    if let Some(id) = token_id
+        && let Some(existing) = existing_content_token(env, id, &to)?
+    {
+        return Ok(existing);
+    }
+    let id = store_token(
+        env,
+        token_id,
+        caller.clone(),
+        to.clone(),
+        metadata_uri,
+        attributes,
+        royalty_override,
+    )?;
+    events::emit_mint(env, to, id, caller);
+    Ok(id)
+}
+
+/// Writes a new token under the next sequential id and updates supply counters without
+/// emitting events.
+fn store_new_token(
+    env: &Env,
+    caller: Address,
+    to: Address,
+    metadata_uri: soroban_sdk::String,
+    attributes: Vec<TokenAttribute>,
+    royalty_override: Option<RoyaltyInfo>,
+) -> Result<u64, ContractError> {
+    store_token(
+        env,
+        None,
+        caller,
+        to,
+        metadata_uri,
+        attributes,
+        royalty_override,
+    )
+}
+
+/// Writes a new token under `token_id`, or the next sequential id if `None`. Explicit ids must
+/// never have been used (even if burned); sequential minting skips over them.
+pub(crate) fn store_token(
+    env: &Env,
+    token_id: Option<u64>,
+    caller: Address,
+    to: Address,
+    metadata_uri: soroban_sdk::String,
+    attributes: Vec<TokenAttribute>,
+    royalty_override: Option<RoyaltyInfo>,
+) -> Result<u64, ContractError> {
+    access_control::require_not_denylisted(env, &caller)?;
+    access_control::require_not_denylisted(env, &to)?;
+    crate::metadata::validate_token_attributes(env, &attributes)?;
+    let mut next_id: u64 = env
         .storage()
         .instance()
         .get(&DataKey::NextTokenId)
@@ -58,49 +346,65 @@ pub(crate) fn mint_internal(
         }
     }
 
-    env.storage().instance().set(&DataKey::Owner(next_id), &to);
-    env.storage()
-        .instance()
-        .set(&DataKey::TokenUri(next_id), &metadata_uri);
-    env.storage()
-        .instance()
-        .set(&DataKey::TokenCreatedAt(next_id), &env.ledger().timestamp());
-    env.storage()
-        .instance()
-        .set(&DataKey::TokenCreator(next_id), &caller);
-    env.storage()
+    // Collections predating the counter minted exactly NextTokenId tokens.
+    let minted: u64 = env
+        .storage()
         .instance()
-        .set(&DataKey::TokenAttributes(next_id), &attributes);
-
-    let (_royalty_bps, _royalty_recipient) = match royalty_override {
-        Some(r) => {
-            validate_royalty_bps(r.percentage)?;
-            env.storage()
-                .instance()
-                .set(&DataKey::TokenRoyaltyBps(next_id), &r.percentage);
+        .get(&DataKey::TotalMinted)
+        .unwrap_or(next_id);
+    let token_id = match token_id {
+        Some(id) => {
+            if id < next_id || env.storage().instance().has(&DataKey::UsedTokenId(id)) {
+                return Err(ContractError::TokenIdTaken);
+            }
             env.storage()
                 .instance()
-                .set(&DataKey::TokenRoyaltyRecipient(next_id), &r.recipient);
-            (r.percentage, r.recipient)
+                .set(&DataKey::UsedTokenId(id), &true);
+            id
         }
         None => {
-            let def: RoyaltyInfo = env
-                .storage()
-                .instance()
-                .get(&DataKey::DefaultRoyalty)
-                .ok_or(ContractError::NotFound)?;
-            (def.percentage, def.recipient)
+            // Ids below NextTokenId are implicitly used, so markers behind it can go.
+            while env.storage().instance().has(&DataKey::UsedTokenId(next_id)) {
+                env.storage()
+                    .instance()
+                    .remove(&DataKey::UsedTokenId(next_id));
+                next_id += 1;
+            }
+            let id = next_id;
+            next_id += 1;
+            id
         }
     };
-
-    let balance: u64 = env
-        .storage()
-        .instance()
-        .get(&DataKey::Balance(to.clone()))
-        .unwrap_or(0);
+    if let Some(r) = &royalty_override {
+        validate_royalty_bps(r.percentage)?;
+    } else if !env.storage().instance().has(&DataKey::DefaultRoyalty) {
+        return Err(ContractError::NotFound);
+    }
+    // Composed URIs cost no storage; an explicit URI always wins.
+    let composed = metadata_uri.is_empty() && crate::metadata::composes_uris(env);
+    if composed {
+        crate::metadata::lock_uri_suffix(env);
+    }
+    crate::record::save(
+        env,
+        token_id,
+        &TokenRecord {
+            owner: to.clone(),
+            uri: if composed { None } else { Some(metadata_uri) },
+            creator: caller,
+            created_at: env.ledger().timestamp(),
+            royalty_bps: royalty_override.as_ref().map(|r| r.percentage),
+            royalty_recipient: royalty_override.map(|r| r.recipient),
+            edition_number: None,
+            total_editions: None,
+        },
+    );
     env.storage()
         .instance()
-        .set(&DataKey::Balance(to.clone()), &(balance + 1));
+        .set(&DataKey::TokenAttributes(token_id), &attributes);
+
+    credit_balance(env, &to);
+    crate::provenance::record(env, token_id, &to);
 
     let total: u64 = env
         .storage()
@@ -112,74 +416,235 @@ pub(crate) fn mint_internal(
         .set(&DataKey::TotalSupply, &(total + 1));
     env.storage()
         .instance()
-        .set(&DataKey::NextTokenId, &(next_id + 1));
+        .set(&DataKey::NextTokenId, &next_id);
+    env.storage()
+        .instance()
+        .set(&DataKey::TotalMinted, &(minted + 1));
 
-    events::emit_mint(env, to, next_id, caller);
-    Ok(next_id)
+    Ok(token_id)
 }
 
 /// Burns a token. Requires owner or burner role. `confirm` must be true for safety.
 pub fn burn(env: &Env, caller: Address, token_id: u64, confirm: bool) -> Result<(), ContractError> {
+    caller.require_auth();
+    burn_authorized(env, caller, token_id, confirm)
+}
+
+/// `burn` after the caller's auth has been checked.
+pub(crate) fn burn_authorized(
+    env: &Env,
+    caller: Address,
+    token_id: u64,
+    confirm: bool,
+) -> Result<(), ContractError> {
     if !confirm {
         return Err(ContractError::BurnNotConfirmed);
     }
-    reentrancy::acquire(env)?;
-    let result = burn_internal(env, caller, token_id);
-    reentrancy::release(env);
-    result
+    access_control::require_not_paused(env, &DataKey::BurnPaused)?;
+    reentrancy::with_lock(env, || burn_internal(env, caller, token_id))
 }
 
 fn burn_internal(env: &Env, caller: Address, token_id: u64) -> Result<(), ContractError> {
-    let owner: Address = env
-        .storage()
+    let owner: Address = crate::record::owner(env, token_id).ok_or(ContractError::TokenNotFound)?;
+
+    if caller != owner && !access_control::is_burner(env, &caller) {
+        return Err(ContractError::NotBurner);
+    }
+    require_burn_allowed(env, &caller, &owner)?;
+    access_control::require_not_locked(env, token_id)?;
+    destroy_token(env, owner, token_id);
+    Ok(())
+}
+
+/// Tokens ever burned.
+pub fn total_burned(env: &Env) -> u64 {
+    let storage = env.storage().instance();
+    storage.get(&DataKey::TotalBurned).unwrap_or_else(|| {
+        // Collections predating the counter burned every minted token no longer in supply.
+        let minted: u64 = storage
+            .get(&DataKey::TotalMinted)
+            .or_else(|| storage.get(&DataKey::NextTokenId))
+            .unwrap_or(0);
+        let supply: u64 = storage.get(&DataKey::TotalSupply).unwrap_or(0);
+        minted.saturating_sub(supply)
+    })
+}
+
+/// Whether `token_id` existed and was burned, as opposed to never having been minted.
+pub fn was_burned(env: &Env, token_id: u64) -> bool {
+    env.storage().instance().has(&DataKey::Burned(token_id))
+}
+
+/// Collection's burn policy.
+pub fn burn_policy(env: &Env) -> BurnPolicy {
+    env.storage()
         .instance()
-        .get(&DataKey::Owner(token_id))
-        .ok_or(ContractError::TokenNotFound)?;
+        .get(&DataKey::BurnPolicy)
+        .unwrap_or(BurnPolicy::Enabled)
+}
+
+/// Sets who may burn tokens. Admin only.
+pub fn set_burn_policy(
+    env: &Env,
+    caller: Address,
+    policy: BurnPolicy,
+) -> Result<(), ContractError> {
+    access_control::require_admin(env, &caller)?;
+    env.storage().instance().set(&DataKey::BurnPolicy, &policy);
+    events::emit_burn_policy_updated(env, policy);
+    Ok(())
+}
 
-    if caller == owner {
-        caller.require_auth();
+/// Checks the burn policy for `caller` burning a token of `owner`; permissions are checked
+/// separately.
+fn require_burn_allowed(env: &Env, caller: &Address, owner: &Address) -> Result<(), ContractError> {
+    let allowed = match burn_policy(env) {
+        BurnPolicy::Enabled => true,
+        BurnPolicy::Disabled => false,
+        BurnPolicy::OwnerOnly => caller == owner,
+        BurnPolicy::AfterTimestamp(opens_at) => env.ledger().timestamp() >= opens_at,
+    };
+    if allowed {
+        Ok(())
     } else {
-        access_control::require_burner(env, &caller)?;
+        Err(ContractError::BurnNotAllowed)
+    }
+}
+
+/// Burns every token in `token_ids` under one confirmation. The caller must own each token or
+/// hold the burner role; any failure reverts the whole batch.
+pub fn batch_burn(
+    env: &Env,
+    caller: Address,
+    token_ids: Vec<u64>,
+    confirm: bool,
+) -> Result<(), ContractError> {
+    if !confirm {
+        return Err(ContractError::BurnNotConfirmed);
+    }
+    if token_ids.is_empty() {
+        return Err(ContractError::InvalidAmount);
+    }
+    if token_ids.len() > MAX_BATCH_TRANSFER {
+        return Err(ContractError::BatchTooLarge);
+    }
+    crate::utils::require_batch_size(env, token_ids.len())?;
+    access_control::require_not_paused(env, &DataKey::BurnPaused)?;
+    caller.require_auth();
+    reentrancy::with_lock(env, || {
+        let burner = access_control::is_burner(env, &caller);
+        for token_id in token_ids.iter() {
+            let owner: Address =
+                crate::record::owner(env, token_id).ok_or(ContractError::TokenNotFound)?;
+            if owner != caller && !burner {
+                return Err(ContractError::NotBurner);
+            }
+            require_burn_allowed(env, &caller, &owner)?;
+            access_control::require_not_locked(env, token_id)?;
+            destroy_token(env, owner, token_id);
+        }
+        Ok(())
+    })
+}
+
+/// Burns `token_id` and mints its replacement to the same owner with `new_uri` and
+/// `new_attributes`, keeping the original creator and royalty. Metadata updater role (or
+/// owner/admin) only; fails if metadata or the token is frozen. Returns the new token id.
+pub fn evolve(
+    env: &Env,
+    caller: Address,
+    token_id: u64,
+    new_uri: soroban_sdk::String,
+    new_attributes: Vec<TokenAttribute>,
+) -> Result<u64, ContractError> {
+    let storage = env.storage().instance();
+    if storage.get(&DataKey::MetadataFrozen).unwrap_or(false) {
+        return Err(ContractError::MetadataFrozen);
     }
+    let record = crate::record::load(env, token_id).ok_or(ContractError::TokenNotFound)?;
+    access_control::require_metadata_updater(env, &caller)?;
+    access_control::require_not_frozen(env, token_id)?;
+    access_control::require_not_locked(env, token_id)?;
+    let royalty = royalty_override(&record);
+    let (owner, creator) = (record.owner, record.creator);
+
+    reentrancy::with_lock(env, || {
+        destroy_token(env, owner.clone(), token_id);
+        let new_id = store_new_token(
+            env,
+            creator.clone(),
+            owner.clone(),
+            new_uri,
+            new_attributes,
+            royalty,
+        )?;
+        storage.set(&DataKey::EvolvedFrom(new_id), &token_id);
+        events::emit_mint(env, owner.clone(), new_id, creator);
+        events::emit_evolved(env, owner, token_id, new_id);
+        Ok(new_id)
+    })
+}
+
+/// A token's royalty override, if it has one.
+fn royalty_override(record: &TokenRecord) -> Option<RoyaltyInfo> {
+    record
+        .royalty_bps
+        .zip(record.royalty_recipient.clone())
+        .map(|(percentage, recipient)| RoyaltyInfo {
+            recipient,
+            percentage,
+        })
+}
 
-    env.storage().instance().remove(&DataKey::Owner(token_id));
+/// Removes all of a token's state, updates counters and emits Burn. Callers check permissions.
+pub(crate) fn destroy_token(env: &Env, owner: Address, token_id: u64) {
+    crate::record::remove(env, token_id);
+    crate::approval::clear(env, &owner, token_id);
     env.storage()
         .instance()
-        .remove(&DataKey::Approved(token_id));
+        .remove(&DataKey::TokenAttributes(token_id));
     env.storage()
         .instance()
-        .remove(&DataKey::TokenUri(token_id));
+        .remove(&DataKey::EditionsIssued(token_id));
     env.storage()
         .instance()
-        .remove(&DataKey::TokenCreatedAt(token_id));
+        .remove(&DataKey::EvolvedFrom(token_id));
     env.storage()
         .instance()
-        .remove(&DataKey::TokenCreator(token_id));
+        .remove(&DataKey::TokenFrozen(token_id));
     env.storage()
         .instance()
-        .remove(&DataKey::TokenAttributes(token_id));
+        .remove(&DataKey::ContentHash(token_id));
     env.storage()
         .instance()
-        .remove(&DataKey::TokenRoyaltyBps(token_id));
+        .remove(&DataKey::Claimable(token_id));
     env.storage()
         .instance()
-        .remove(&DataKey::TokenRoyaltyRecipient(token_id));
+        .remove(&DataKey::TransferOffer(token_id));
     env.storage()
         .instance()
-        .remove(&DataKey::TokenEditionNumber(token_id));
+        .remove(&DataKey::ValidUntil(token_id));
     env.storage()
         .instance()
-        .remove(&DataKey::TokenTotalEditions(token_id));
-
-    let balance: u64 = env
-        .storage()
+        .remove(&DataKey::Provenance(token_id));
+    env.storage()
         .instance()
-        .get(&DataKey::Balance(owner.clone()))
-        .unwrap_or(0);
+        .remove(&DataKey::LastTransferAt(token_id));
     env.storage()
         .instance()
-        .set(&DataKey::Balance(owner.clone()), &balance.saturating_sub(1));
+        .remove(&DataKey::TransferCount(token_id));
+    crate::escrow::release(env, token_id);
+    env.storage().instance().remove(&DataKey::Locker(token_id));
+
+    debit_balance(env, &owner);
 
+    let burned = total_burned(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::TotalBurned, &(burned + 1));
+    env.storage()
+        .instance()
+        .set(&DataKey::Burned(token_id), &true);
     let total: u64 = env
         .storage()
         .instance()
@@ -190,5 +655,120 @@ fn burn_internal(env: &Env, caller: Address, token_id: u64) -> Result<(), Contra
         .set(&DataKey::TotalSupply, &total.saturating_sub(1));
 
     events::emit_burn(env, owner, token_id);
+}
+
+/// Timestamp minting opens at, if scheduled.
+pub fn mint_start(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&DataKey::MintStart)
+}
+
+/// Timestamp minting closes at (exclusive), if scheduled.
+pub fn mint_end(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&DataKey::MintEnd)
+}
+
+/// Schedules the mint window; either bound may be left open with `None`. Admin only.
+pub fn set_mint_window(
+    env: &Env,
+    caller: Address,
+    start: Option<u64>,
+    end: Option<u64>,
+) -> Result<(), ContractError> {
+    access_control::require_admin(env, &caller)?;
+    if let (Some(start), Some(end)) = (start, end)
+        && start >= end
+    {
+        return Err(ContractError::InvalidAmount);
+    }
+    for (key, value) in [(DataKey::MintStart, start), (DataKey::MintEnd, end)] {
+        match value {
+            Some(timestamp) => env.storage().instance().set(&key, &timestamp),
+            None => env.storage().instance().remove(&key),
+        }
+    }
+    events::emit_mint_window_updated(env, start, end);
     Ok(())
 }
+
+/// Most tokens one `batch_mint`, `airdrop` or `mint_edition` call may create, if capped.
+pub fn max_per_transaction(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&DataKey::MaxPerTransaction)
+}
+
+/// Caps the tokens a single mint call may create (`None` removes the cap). Admin only.
+pub fn set_max_per_transaction(
+    env: &Env,
+    caller: Address,
+    max: Option<u32>,
+) -> Result<(), ContractError> {
+    access_control::require_admin(env, &caller)?;
+    match max {
+        Some(0) => return Err(ContractError::InvalidAmount),
+        Some(max) => env
+            .storage()
+            .instance()
+            .set(&DataKey::MaxPerTransaction, &max),
+        None => env.storage().instance().remove(&DataKey::MaxPerTransaction),
+    }
+    events::emit_max_per_transaction_updated(env, max);
+    Ok(())
+}
+
+/// Lowers the collection's supply cap (or sets one on an uncapped collection). The cap can
+/// never be raised, nor set below the current total supply. Admin only.
+pub fn set_max_supply(env: &Env, caller: Address, new_max: u64) -> Result<(), ContractError> {
+    access_control::require_admin(env, &caller)?;
+    let previous: Option<u64> = env.storage().instance().get(&DataKey::MaxSupply);
+    let total: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::TotalSupply)
+        .unwrap_or(0);
+    if previous.is_some_and(|max| new_max > max) || new_max < total {
+        return Err(ContractError::InvalidMaxSupply);
+    }
+    env.storage().instance().set(&DataKey::MaxSupply, &new_max);
+    events::emit_max_supply_reduced(env, previous, new_max);
+    Ok(())
+}
+
+/// Adds one token to `owner`'s balance, counting a new holder if it had none.
+pub(crate) fn credit_balance(env: &Env, owner: &Address) {
+    let balance: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::Balance(owner.clone()))
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::Balance(owner.clone()), &balance.saturating_add(1));
+    if balance == 0 {
+        adjust_holders(env, 1);
+    }
+}
+
+/// Removes one token from `owner`'s balance, dropping it from the holder count at zero.
+pub(crate) fn debit_balance(env: &Env, owner: &Address) {
+    let balance: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::Balance(owner.clone()))
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::Balance(owner.clone()), &balance.saturating_sub(1));
+    if balance == 1 {
+        adjust_holders(env, -1);
+    }
+}
+
+fn adjust_holders(env: &Env, delta: i64) {
+    let holders: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::HolderCount)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::HolderCount, &holders.saturating_add_signed(delta));
+}
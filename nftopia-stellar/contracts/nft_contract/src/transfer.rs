@@ -1,100 +1,220 @@
 use crate::access_control;
+use crate::approval;
 use crate::error::ContractError;
 use crate::events;
+use crate::hooks;
 use crate::reentrancy;
 use crate::storage::DataKey;
-use soroban_sdk::{Address, Bytes, Env, Vec};
+use crate::types::TokenRecord;
+use crate::utils::MAX_BATCH_TRANSFER;
+use nft_receiver::NftReceiverClient;
+use soroban_sdk::{Address, Bytes, Env, Executable, Vec};
 
-/// Validates that `from` (the one who authed) can transfer: must be owner, approved, or operator.
-fn require_can_transfer(env: &Env, from: &Address, token_id: u64) -> Result<(), ContractError> {
-    let owner: Address = env
+/// Earliest timestamp at which `token_id` may be transferred again under the collection's
+/// transfer cooldown (0 when there is no cooldown or the token was never transferred).
+pub fn transferable_at(env: &Env, token_id: u64) -> u64 {
+    let cooldown: u64 = env
         .storage()
         .instance()
-        .get(&DataKey::Owner(token_id))
-        .ok_or(ContractError::TokenNotFound)?;
-    if owner == *from {
-        return Ok(());
-    }
-    let approved: Option<Address> = env.storage().instance().get(&DataKey::Approved(token_id));
-    if let Some(a) = approved {
-        if a == *from {
-            return Ok(());
-        }
+        .get(&DataKey::TransferCooldown)
+        .unwrap_or(0);
+    if cooldown == 0 {
+        return 0;
     }
-    let is_operator: bool = env
-        .storage()
+    env.storage()
         .instance()
-        .get(&DataKey::OperatorApproval(owner.clone(), from.clone()))
-        .unwrap_or(false);
-    if is_operator {
-        return Ok(());
+        .get::<_, u64>(&DataKey::LastTransferAt(token_id))
+        .map(|last| last.saturating_add(cooldown))
+        .unwrap_or(0)
+}
+
+/// Times `token_id` has changed hands since it was minted.
+pub fn transfer_count(env: &Env, token_id: u64) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TransferCount(token_id))
+        .unwrap_or(0)
+}
+
+/// Validates that `from` (the one who authed) can transfer: must be owner, approved, or operator,
+/// and the token's transfer cooldown must have elapsed. Returns the token's record so the
+/// transfer itself need not load it again.
+fn require_can_transfer(
+    env: &Env,
+    from: &Address,
+    token_id: u64,
+) -> Result<TokenRecord, ContractError> {
+    let record = crate::record::load(env, token_id).ok_or(ContractError::TokenNotFound)?;
+    check_can_transfer(env, from, token_id, &record.owner)?;
+    Ok(record)
+}
+
+fn check_can_transfer(
+    env: &Env,
+    from: &Address,
+    token_id: u64,
+    owner: &Address,
+) -> Result<(), ContractError> {
+    if env.ledger().timestamp() < transferable_at(env, token_id) {
+        return Err(ContractError::TransferCooldown);
     }
-    Err(ContractError::NotApproved)
+    if is_approved_or_owner(env, from, owner, token_id) {
+        Ok(())
+    } else if approval::is_expired_for(env, from, token_id) {
+        Err(ContractError::ApprovalExpired)
+    } else {
+        Err(ContractError::NotApproved)
+    }
+}
+
+/// Whether `spender` is `owner`, the token's (unexpired) approved address, or an operator of
+/// `owner`.
+pub fn is_approved_or_owner(env: &Env, spender: &Address, owner: &Address, token_id: u64) -> bool {
+    spender == owner
+        || approval::approved(env, token_id).as_ref() == Some(spender)
+        || env
+            .storage()
+            .instance()
+            .get(&DataKey::OperatorApproval(owner.clone(), spender.clone()))
+            .unwrap_or(false)
 }
 
 /// Internal transfer implementation (no auth check - caller must have verified).
-fn do_transfer(
+pub(crate) fn do_transfer(
     env: &Env,
     from: &Address,
     to: &Address,
     token_id: u64,
 ) -> Result<(), ContractError> {
-    access_control::require_not_paused(env)?;
+    let record = crate::record::load(env, token_id).ok_or(ContractError::TokenNotFound)?;
+    move_token(env, from, to, token_id, record)
+}
 
-    let owner: Address = env
-        .storage()
-        .instance()
-        .get(&DataKey::Owner(token_id))
-        .ok_or(ContractError::TokenNotFound)?;
-    if owner != *from {
+/// `do_transfer` for a token whose current record the caller has already loaded.
+fn move_token(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    token_id: u64,
+    mut record: TokenRecord,
+) -> Result<(), ContractError> {
+    access_control::require_not_paused(env, &DataKey::TransferPaused)?;
+    access_control::require_not_denylisted(env, from)?;
+    access_control::require_not_denylisted(env, to)?;
+    access_control::require_transfer_allowed(env, to)?;
+    access_control::require_not_frozen(env, token_id)?;
+    access_control::require_not_locked(env, token_id)?;
+
+    if record.owner != *from {
         return Err(ContractError::NotAuthorized);
     }
     if from == to {
         return Ok(());
     }
+    hooks::before_transfer(env, from, to, token_id)?;
 
-    env.storage().instance().set(&DataKey::Owner(token_id), to);
+    record.owner = to.clone();
+    crate::record::save(env, token_id, &record);
+    approval::clear(env, from, token_id);
     env.storage()
         .instance()
-        .remove(&DataKey::Approved(token_id));
-
-    let from_balance: u64 = env
-        .storage()
-        .instance()
-        .get(&DataKey::Balance(from.clone()))
-        .unwrap_or(0);
+        .remove(&DataKey::TransferOffer(token_id));
     env.storage().instance().set(
-        &DataKey::Balance(from.clone()),
-        &from_balance.saturating_sub(1),
+        &DataKey::LastTransferAt(token_id),
+        &env.ledger().timestamp(),
+    );
+    env.storage().instance().set(
+        &DataKey::TransferCount(token_id),
+        &transfer_count(env, token_id).saturating_add(1),
     );
 
-    let to_balance: u64 = env
-        .storage()
-        .instance()
-        .get(&DataKey::Balance(to.clone()))
-        .unwrap_or(0);
-    env.storage()
-        .instance()
-        .set(&DataKey::Balance(to.clone()), &to_balance.saturating_add(1));
+    crate::token::debit_balance(env, from);
+    crate::token::credit_balance(env, to);
+    crate::provenance::record(env, token_id, to);
 
     events::emit_transfer(env, from.clone(), to.clone(), token_id);
-    Ok(())
+    hooks::after_transfer(env, from, to, token_id)
 }
 
 /// Transfers token from one address to another. Caller must be owner, approved, or operator.
 pub fn transfer(env: &Env, from: Address, to: Address, token_id: u64) -> Result<(), ContractError> {
     from.require_auth();
-    reentrancy::acquire(env)?;
-    let result = (|| {
-        require_can_transfer(env, &from, token_id)?;
-        do_transfer(env, &from, &to, token_id)
-    })();
-    reentrancy::release(env);
-    result
+    transfer_authorized(env, from, to, token_id)
+}
+
+/// `transfer` after `from`'s auth has been checked.
+pub(crate) fn transfer_authorized(
+    env: &Env,
+    from: Address,
+    to: Address,
+    token_id: u64,
+) -> Result<(), ContractError> {
+    reentrancy::with_lock(env, || {
+        let record = require_can_transfer(env, &from, token_id)?;
+        move_token(env, &from, &to, token_id, record)
+    })
+}
+
+/// Offers `token_id` to `to`, who takes it with `accept_transfer`. Owner only; replaces any
+/// earlier offer, and lapses once the token moves.
+pub fn offer_transfer(
+    env: &Env,
+    from: Address,
+    to: Address,
+    token_id: u64,
+) -> Result<(), ContractError> {
+    from.require_auth();
+    let owner: Address = crate::record::owner(env, token_id).ok_or(ContractError::TokenNotFound)?;
+    if owner != from {
+        return Err(ContractError::NotAuthorized);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::TransferOffer(token_id), &to);
+    events::emit_transfer_offered(env, from, to, token_id);
+    Ok(())
+}
+
+/// Recipient of the pending transfer offer for `token_id`, if any.
+pub fn pending_offer(env: &Env, token_id: u64) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::TransferOffer(token_id))
+}
+
+/// Completes a pending offer: moves the token from its owner to `to`, the offered recipient.
+pub fn accept_transfer(env: &Env, to: Address, token_id: u64) -> Result<(), ContractError> {
+    to.require_auth();
+    if pending_offer(env, token_id).as_ref() != Some(&to) {
+        return Err(ContractError::NotFound);
+    }
+    let record = crate::record::load(env, token_id).ok_or(ContractError::TokenNotFound)?;
+    let from = record.owner.clone();
+    reentrancy::with_lock(env, || {
+        check_can_transfer(env, &from, token_id, &from)?;
+        move_token(env, &from, &to, token_id, record)
+    })
+}
+
+/// Withdraws the pending offer for `token_id`. Either the owner or the offered recipient
+/// may cancel.
+pub fn cancel_offer(env: &Env, caller: Address, token_id: u64) -> Result<(), ContractError> {
+    caller.require_auth();
+    let to = pending_offer(env, token_id).ok_or(ContractError::NotFound)?;
+    let owner: Option<Address> = crate::record::owner(env, token_id);
+    if caller != to && owner.as_ref() != Some(&caller) {
+        return Err(ContractError::NotAuthorized);
+    }
+    env.storage()
+        .instance()
+        .remove(&DataKey::TransferOffer(token_id));
+    events::emit_transfer_offer_cancelled(env, caller, token_id);
+    Ok(())
 }
 
-/// Transfers token; if `to` is a contract, invokes nft_recv for validation.
-/// Reverts (transfers back) if the receiver contract rejects. Caller must be owner, approved, or operator.
+/// Transfers token; if `to` is a contract, it must accept through `nft_recv`.
+/// A rejection returns an error, which aborts the whole invocation so no state or events
+/// from the transfer persist. Caller must be owner, approved, or operator.
 pub fn safe_transfer_from(
     env: &Env,
     from: Address,
@@ -103,34 +223,46 @@ pub fn safe_transfer_from(
     data: Option<Bytes>,
 ) -> Result<(), ContractError> {
     from.require_auth();
-    reentrancy::acquire(env)?;
-    let result = (|| -> Result<(), ContractError> {
-        require_can_transfer(env, &from, token_id)?;
-        do_transfer(env, &from, &to, token_id)?;
-
-        // Notify receiver contract if different from self (ERC-721 receiver callback).
-        if to != env.current_contract_address() {
-            use soroban_sdk::IntoVal;
-            let invoke_result = env.try_invoke_contract::<(), ContractError>(
-                &to,
-                &soroban_sdk::symbol_short!("nft_recv"),
-                soroban_sdk::vec![
-                    &env,
-                    from.clone().into_val(env),
-                    token_id.into_val(env),
-                    data.into_val(env),
-                ],
-            );
-            if let Ok(Err(_)) = invoke_result {
-                // Revert: transfer back to from.
-                let _ = do_transfer(env, &to, &from, token_id);
-                return Err(ContractError::TransferRejected);
-            }
-        }
-        Ok(())
-    })();
-    reentrancy::release(env);
-    result
+    safe_transfer_authorized(env, from, to, token_id, data)
+}
+
+/// `safe_transfer_from` after `from`'s auth has been checked.
+pub(crate) fn safe_transfer_authorized(
+    env: &Env,
+    from: Address,
+    to: Address,
+    token_id: u64,
+    data: Option<Bytes>,
+) -> Result<(), ContractError> {
+    reentrancy::with_lock(env, || -> Result<(), ContractError> {
+        let record = require_can_transfer(env, &from, token_id)?;
+        move_token(env, &from, &to, token_id, record)?;
+        notify_receiver(env, &from, &to, token_id, data)
+    })
+}
+
+/// Whether `to` is a contract that must acknowledge safe transfers. Accounts, and the
+/// collection itself, are not notified.
+fn is_receiver_contract(env: &Env, to: &Address) -> bool {
+    *to != env.current_contract_address() && matches!(to.executable(), Some(Executable::Wasm(_)))
+}
+
+/// Calls `nft_recv` on `to` when it is a WASM contract and requires the `NFT_RECV` magic value
+/// back.
+fn notify_receiver(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    token_id: u64,
+    data: Option<Bytes>,
+) -> Result<(), ContractError> {
+    if !is_receiver_contract(env, to) {
+        return Ok(());
+    }
+    match NftReceiverClient::new(env, to).try_nft_recv(from, &token_id, &data) {
+        Ok(Ok(magic)) if magic == nft_receiver::NFT_RECV => Ok(()),
+        _ => Err(ContractError::TransferRejected),
+    }
 }
 
 /// Batch transfer: transfers multiple tokens from one address to another.
@@ -140,9 +272,9 @@ pub fn batch_transfer(
     to: Address,
     token_ids: Vec<u64>,
 ) -> Result<(), ContractError> {
+    crate::utils::require_batch_size(env, token_ids.len())?;
     from.require_auth();
-    reentrancy::acquire(env)?;
-    let result = (|| {
+    reentrancy::with_lock(env, || {
         for i in 0..token_ids.len() {
             let token_id = token_ids.get(i).unwrap();
             require_can_transfer(env, &from, token_id)?;
@@ -152,7 +284,68 @@ pub fn batch_transfer(
             do_transfer(env, &from, &to, token_id)?;
         }
         Ok(())
-    })();
-    reentrancy::release(env);
-    result
+    })
+}
+
+/// Batch version of `safe_transfer_from`: moves every token to `to`, then calls
+/// `nft_batch_recv` once with the full list. Rejection aborts the whole batch.
+pub fn safe_batch_transfer(
+    env: &Env,
+    from: Address,
+    to: Address,
+    token_ids: Vec<u64>,
+    data: Option<Bytes>,
+) -> Result<(), ContractError> {
+    if token_ids.is_empty() {
+        return Err(ContractError::InvalidAmount);
+    }
+    if token_ids.len() > MAX_BATCH_TRANSFER {
+        return Err(ContractError::BatchTooLarge);
+    }
+    crate::utils::require_batch_size(env, token_ids.len())?;
+    from.require_auth();
+    reentrancy::with_lock(env, || -> Result<(), ContractError> {
+        for token_id in token_ids.iter() {
+            require_can_transfer(env, &from, token_id)?;
+        }
+        for token_id in token_ids.iter() {
+            do_transfer(env, &from, &to, token_id)?;
+        }
+        if !is_receiver_contract(env, &to) {
+            return Ok(());
+        }
+        match NftReceiverClient::new(env, &to).try_nft_batch_recv(&from, &token_ids, &data) {
+            Ok(Ok(magic)) if magic == nft_receiver::NFT_BATCH_RECV => Ok(()),
+            _ => Err(ContractError::TransferRejected),
+        }
+    })
+}
+
+/// Transfers `token_ids[i]` to `recipients[i]` for every i, emitting one Transfer event per token.
+pub fn batch_transfer_to(
+    env: &Env,
+    from: Address,
+    recipients: Vec<Address>,
+    token_ids: Vec<u64>,
+) -> Result<(), ContractError> {
+    if recipients.len() != token_ids.len() {
+        return Err(ContractError::BatchLengthMismatch);
+    }
+    if token_ids.is_empty() {
+        return Err(ContractError::InvalidAmount);
+    }
+    if token_ids.len() > MAX_BATCH_TRANSFER {
+        return Err(ContractError::BatchTooLarge);
+    }
+    crate::utils::require_batch_size(env, token_ids.len())?;
+    from.require_auth();
+    reentrancy::with_lock(env, || {
+        for token_id in token_ids.iter() {
+            require_can_transfer(env, &from, token_id)?;
+        }
+        for (to, token_id) in recipients.iter().zip(token_ids.iter()) {
+            do_transfer(env, &from, &to, token_id)?;
+        }
+        Ok(())
+    })
 }
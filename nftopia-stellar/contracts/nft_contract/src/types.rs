@@ -1,37 +1,162 @@
-use soroban_sdk::{Address, String, Vec, contracttype};
+use soroban_sdk::{Address, Bytes, BytesN, String, Vec, contracttype};
 
-/// Token attribute for on-chain metadata (OpenSea standard support).
-#[derive(Clone, Debug)]
+pub use nft_interface::{
+    AttributeValue, CollectionConfig, DelegationScope, RoyaltyInfo, TokenAttribute,
+};
+
+/// Bounds on token attributes, so no single token's metadata outgrows the invocation budget.
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
-pub struct TokenAttribute {
-    pub trait_type: String,
-    pub value: String,
-    /// "number", "date", "boost_percentage", etc.
-    pub display_type: Option<String>,
+pub struct AttributeLimits {
+    pub max_attributes: u32,
+    /// Maximum `trait_type` length in bytes.
+    pub max_trait_len: u32,
+    /// Maximum `value` (and `display_type`) length in bytes.
+    pub max_value_len: u32,
 }
 
-/// Royalty information (EIP-2981 equivalent).
-#[derive(Clone, Debug)]
+/// Royalty decay: once a token is older than `after_seconds` or has been transferred
+/// `after_transfers` times (whichever comes first), its royalty drops to `decayed_percentage`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RoyaltyDecay {
+    pub after_seconds: Option<u64>,
+    pub after_transfers: Option<u32>,
+    /// Basis points applied after decay; never raises a lower royalty.
+    pub decayed_percentage: u32,
+}
+
+/// Who may burn tokens, checked by `burn` and `batch_burn`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum BurnPolicy {
+    /// Token owners and the burner role (the default).
+    Enabled,
+    /// Nobody; the supply only ever grows.
+    Disabled,
+    /// Token owners only; the burner role cannot burn others' tokens.
+    OwnerOnly,
+    /// As `Enabled`, but only from this timestamp on (e.g. when a redemption window opens).
+    AfterTimestamp(u64),
+}
+
+/// Cumulative earnings paid out to one creator in one currency. Payments go straight to the
+/// recipient, so this is an audit ledger, not a balance held by the contract.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CreatorEarnings {
+    pub primary_sales: i128,
+    pub royalties: i128,
+}
+
+/// Mint quota granted to a contract (e.g. a launchpad) that does not hold the minter role.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MintAllowance {
+    pub remaining: u32,
+    /// Ledger timestamp after which the allowance no longer applies.
+    pub expires_at: u64,
+}
+
+/// A token held in escrow by the collection until `claimant` claims it, or `minter`
+/// reclaims it once `expires_at` has passed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ClaimableMint {
+    pub claimant: Address,
+    pub minter: Address,
+    pub expires_at: u64,
+}
+
+/// One link in a token's chain of custody: `owner` received it at `timestamp` / `ledger`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ProvenanceEntry {
+    pub owner: Address,
+    pub timestamp: u64,
+    pub ledger: u32,
+}
+
+/// Fair-launch escrow: proceeds are released once `threshold` paid mints sell, or refunded if
+/// `deadline` passes first.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EscrowConfig {
+    pub threshold: u64,
+    pub deadline: u64,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum EscrowStatus {
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+/// What was paid for an escrowed mint, refunded if the drop fails.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EscrowPayment {
+    pub currency: Address,
+    pub amount: i128,
+}
+
+/// USD-denominated mint price, converted to the payment currency through a SEP-40 `feed`.
+/// Feed prices older than `max_age` seconds are ignored in favour of the fixed mint price.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PriceOracle {
+    pub feed: Address,
+    /// Price in USD with `USD_PRICE_DECIMALS` decimals.
+    pub usd_price: i128,
+    pub max_age: u64,
+}
+
+/// Price charged by `mint_paid` when paying in the SEP-41 `currency` token.
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
-pub struct RoyaltyInfo {
+pub struct MintPrice {
+    pub price: i128,
+    pub currency: Address,
+}
+
+/// One recipient of primary-sale proceeds and its share in basis points.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PayoutSplit {
     pub recipient: Address,
-    /// Basis points (0-10000, where 10000 = 100%)
-    pub percentage: u32,
+    pub share_bps: u32,
 }
 
-/// Collection-level configuration.
-#[derive(Clone, Debug)]
+/// Candidate for `collect_garbage`; removed only if actually stale.
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
-pub struct CollectionConfig {
-    pub name: String,
-    pub symbol: String,
-    pub base_uri: String,
+pub enum StaleEntry {
+    /// An expired token approval.
+    Approval(u64),
+    /// An expired time-bound role grant.
+    Role(Role, Address),
+    /// A mint allowance that expired or was used up.
+    MintAllowance(Address),
+    /// A whitelist entry whose allocation is fully claimed.
+    WhitelistAllocation(Address),
+}
+
+/// Collection-wide counters for dashboards (get_collection_stats).
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CollectionStats {
+    /// Tokens currently in existence.
+    pub total_supply: u64,
+    /// Tokens ever minted.
+    pub total_minted: u64,
+    pub total_burned: u64,
+    /// Addresses holding at least one token.
+    pub holders: u64,
     pub max_supply: Option<u64>,
-    /// Optional mint cost in stroops
-    pub mint_price: Option<i128>,
-    pub is_revealed: bool,
-    pub royalty_default: RoyaltyInfo,
-    pub metadata_is_frozen: bool,
+    /// Most tokens a single mint call may create.
+    pub max_per_transaction: Option<u32>,
 }
 
 /// Role-based access control.
@@ -43,6 +168,86 @@ pub enum Role {
     Minter = 2,
     Burner = 3,
     MetadataUpdater = 4,
+    Renewer = 5,
+    /// Grants and revokes Minter when made Minter's admin role (see `set_role_admin`).
+    MinterAdmin = 6,
+    /// May pause the whole collection during an incident, but never unpause.
+    Guardian = 7,
+    /// Mints on behalf of users through `relay_mint`, within its quota.
+    Relayer = 8,
+}
+
+/// Members who jointly approve privileged operations in council mode.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Council {
+    pub members: Vec<Address>,
+    /// Approvals needed to execute a proposal.
+    pub threshold: u32,
+}
+
+/// A privileged operation that needs council approval while council mode is on.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum CouncilAction {
+    /// `upgrade` to this WASM hash.
+    Upgrade(BytesN<32>),
+    FreezeMetadata,
+    SetDefaultRoyalty(RoyaltyInfo),
+    /// `set_admin(admin, granted)`.
+    SetAdmin(Address, bool),
+    /// Replace the council (members, threshold); no members turns council mode off.
+    SetCouncil(Vec<Address>, u32),
+    /// Drop a timelocked operation queued by an earlier proposal.
+    CancelOperation(u32),
+}
+
+/// A pending council proposal.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Proposal {
+    pub action: CouncilAction,
+    pub proposer: Address,
+    pub approvals: Vec<Address>,
+}
+
+/// A sensitive admin action that waits out the timelock delay before it executes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum TimelockAction {
+    /// `upgrade` to this WASM hash.
+    Upgrade(BytesN<32>),
+    SetBaseUri(String),
+    SetDefaultRoyalty(RoyaltyInfo),
+    /// Change the timelock delay (seconds).
+    SetDelay(u64),
+}
+
+/// A queued timelock action, executable from `eta` on.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct QueuedOperation {
+    pub action: TimelockAction,
+    pub eta: u64,
+    pub queued_by: Address,
+    /// Queued by an executed council proposal; only another proposal may cancel it.
+    pub by_council: bool,
+}
+
+/// A token's core fields, stored together under one key.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TokenRecord {
+    pub owner: Address,
+    /// Explicit metadata URI; `None` when the URI is composed from the base URI.
+    pub uri: Option<String>,
+    pub creator: Address,
+    pub created_at: u64,
+    /// Royalty override; the collection default applies when unset.
+    pub royalty_bps: Option<u32>,
+    pub royalty_recipient: Option<Address>,
+    pub edition_number: Option<u32>,
+    pub total_editions: Option<u32>,
 }
 
 /// Full token metadata view (for token_metadata query). Equivalent to TokenData in spec.
@@ -62,4 +267,37 @@ pub struct TokenMetadata {
     pub edition_number: Option<u32>,
     /// For limited editions.
     pub total_editions: Option<u32>,
+    /// Transfers and approvals blocked by an admin freeze.
+    pub frozen: bool,
+    /// sha256 of the token's media, if committed at mint.
+    pub content_hash: Option<BytesN<32>>,
+    /// Token this one was evolved from, if any.
+    pub evolved_from: Option<u64>,
+    /// Expiry of a subscription token; `None` if it never expires.
+    pub valid_until: Option<u64>,
+    /// Times the token has changed hands since mint.
+    pub transfer_count: u32,
+}
+
+/// One operation in a `multicall`, executed as `caller`. Mirrors the entrypoint of the same
+/// name without the caller argument.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub enum CallSpec {
+    /// `approve(approved, token_id)`
+    Approve(Address, u64),
+    /// `transfer(to, token_id)`
+    Transfer(Address, u64),
+    /// `safe_transfer_from(to, token_id, data)`
+    SafeTransfer(Address, u64, Option<Bytes>),
+    /// `burn(token_id, confirm)`
+    Burn(u64, bool),
+    /// `set_token_uri(token_id, uri)`
+    SetTokenUri(u64, String),
+    /// `set_edition_info(token_id, edition_number, total_editions)`
+    SetEditionInfo(u64, Option<u32>, Option<u32>),
+    /// `update_attribute(token_id, trait_type, value)`
+    UpdateAttribute(u64, String, String),
+    /// `remove_attribute(token_id, trait_type)`
+    RemoveAttribute(u64, String),
 }
@@ -1,4 +1,11 @@
 use crate::error::ContractError;
+use crate::types::{AttributeLimits, TokenAttribute};
+use soroban_sdk::Vec;
+
+pub use nft_interface::page_range;
+
+/// Semver of this build of the contract, from the crate manifest.
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 /// Basis points denominator (10000 = 100%).
 pub const BPS_DENOMINATOR: u32 = 10_000;
@@ -6,6 +13,99 @@ pub const BPS_DENOMINATOR: u32 = 10_000;
 /// Maximum royalty in basis points (100%).
 pub const MAX_ROYALTY_BPS: u32 = 10_000;
 
+/// Maximum recipients per airdrop call, keeping a single invocation within Soroban budget.
+pub const MAX_AIRDROP_RECIPIENTS: u32 = 100;
+
+/// Maximum tokens per multi-recipient batch transfer.
+pub const MAX_BATCH_TRANSFER: u32 = 100;
+
+/// Maximum entries per batch read (owners_of, balance_of_batch).
+pub const MAX_BATCH_QUERY: u32 = 200;
+
+/// Decimal places of USD-denominated mint prices (1 USD = 10^7).
+pub const USD_PRICE_DECIMALS: u32 = 7;
+
+/// Maximum currencies accepted for paid mints.
+pub const MAX_MINT_CURRENCIES: u32 = 10;
+
+/// Maximum entries per `collect_garbage` call.
+pub const MAX_GC_BATCH: u32 = 100;
+
+/// Maximum marketplaces on the operator allowlist.
+pub const MAX_ALLOWED_OPERATORS: u32 = 50;
+
+/// Maximum recipients in the payout splits.
+pub const MAX_PAYOUT_RECIPIENTS: u32 = 10;
+
+/// Maximum members of a council.
+pub const MAX_COUNCIL_MEMBERS: u32 = 20;
+
+/// Longest allowed timelock delay (30 days), so a collection cannot lock itself out.
+pub const MAX_TIMELOCK_DELAY: u64 = 30 * 24 * 60 * 60;
+
+/// Ownership entries kept per token in the provenance log.
+pub const MAX_PROVENANCE_ENTRIES: u32 = 50;
+
+/// Default maximum attributes per token.
+pub const DEFAULT_MAX_ATTRIBUTES: u32 = 32;
+
+/// Default maximum `trait_type` length in bytes.
+pub const DEFAULT_MAX_TRAIT_LEN: u32 = 64;
+
+/// Default maximum attribute `value` length in bytes.
+pub const DEFAULT_MAX_VALUE_LEN: u32 = 256;
+
+/// Limits applied when the admin has not configured any.
+pub fn default_attribute_limits() -> AttributeLimits {
+    AttributeLimits {
+        max_attributes: DEFAULT_MAX_ATTRIBUTES,
+        max_trait_len: DEFAULT_MAX_TRAIT_LEN,
+        max_value_len: DEFAULT_MAX_VALUE_LEN,
+    }
+}
+
+/// Validates a single attribute's trait type, value and display type lengths.
+pub fn validate_attribute(
+    attribute: &TokenAttribute,
+    limits: &AttributeLimits,
+) -> Result<(), ContractError> {
+    let display_len = attribute.display_type.as_ref().map_or(0, |d| d.len());
+    if attribute.trait_type.len() > limits.max_trait_len
+        || attribute.value.len() > limits.max_value_len
+        || display_len > limits.max_value_len
+    {
+        return Err(ContractError::AttributeLimitExceeded);
+    }
+    Ok(())
+}
+
+/// Validates an attribute set's size and every entry in it.
+pub fn validate_attributes(
+    attributes: &Vec<TokenAttribute>,
+    limits: &AttributeLimits,
+) -> Result<(), ContractError> {
+    if attributes.len() > limits.max_attributes {
+        return Err(ContractError::AttributeLimitExceeded);
+    }
+    for attribute in attributes.iter() {
+        validate_attribute(&attribute, limits)?;
+    }
+    Ok(())
+}
+
+/// Rejects a batch of `len` items larger than the collection's configured `max_batch_size`,
+/// before any work is done.
+pub fn require_batch_size(env: &soroban_sdk::Env, len: u32) -> Result<(), ContractError> {
+    let max: Option<u32> = env
+        .storage()
+        .instance()
+        .get(&crate::storage::DataKey::MaxBatchSize);
+    if max.is_some_and(|max| len > max) {
+        return Err(ContractError::BatchTooLarge);
+    }
+    Ok(())
+}
+
 /// Validates royalty percentage (0-10000 basis points).
 #[inline]
 pub fn validate_royalty_bps(percentage: u32) -> Result<(), ContractError> {
@@ -29,3 +129,27 @@ pub fn calculate_royalty(sale_price: i128, royalty_bps: u32) -> (i128, i128) {
     let seller_amount = sale_price.saturating_sub(royalty);
     (royalty, seller_amount)
 }
+
+/// Writes `n` in decimal at the end of `buf` and returns the index of its first digit.
+pub fn write_decimal(mut n: u128, buf: &mut [u8; 40]) -> usize {
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            return start;
+        }
+    }
+}
+
+/// Decimal rendering of a signed integer, e.g. for typed attribute values.
+pub fn decimal_string(env: &soroban_sdk::Env, n: i128) -> soroban_sdk::String {
+    let mut buf = [0u8; 40];
+    let mut start = write_decimal(n.unsigned_abs(), &mut buf);
+    if n < 0 {
+        start -= 1;
+        buf[start] = b'-';
+    }
+    soroban_sdk::String::from_bytes(env, &buf[start..])
+}
@@ -0,0 +1,40 @@
+//! Core token events, shared so indexers decode every NFTopia collection the same way.
+//!
+//! The addresses and token id are topics, so RPC event filters can subscribe per token or
+//! per address without decoding event data. The emitting contract is the collection, so it
+//! is not repeated in the topics.
+
+use soroban_sdk::{Address, contractevent};
+
+/// Transfer event (ERC-721 equivalent).
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Transfer {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    #[topic]
+    pub token_id: u64,
+}
+
+/// Mint event.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Mint {
+    #[topic]
+    pub to: Address,
+    #[topic]
+    pub token_id: u64,
+    pub creator: Address,
+}
+
+/// Burn event.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Burn {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub token_id: u64,
+}
@@ -0,0 +1,132 @@
+//! Cross-contract interface of NFTopia collections.
+//!
+//! Marketplaces, auctions, launchpads and other contracts that drive a collection depend on
+//! this crate for the [`NftClient`] and the `#[contracttype]` values that cross the contract
+//! boundary, rather than each keeping its own mirror. `nft_contract` re-exports the same
+//! definitions, so a change here is a change to the collection's ABI. The core token
+//! [`events`] live here too, so the factory's collections and `nft_contract` publish
+//! identical Mint/Transfer/Burn events, and [`page_range`] keeps their paged views consistent.
+
+#![no_std]
+
+pub mod events;
+pub mod paging;
+
+pub use events::{Burn, Mint, Transfer};
+pub use paging::{MAX_PAGE_SIZE, page_range};
+
+use soroban_sdk::{Address, BytesN, Env, String, Vec, contractclient, contracttype};
+
+/// Interface ID for core NFT (ERC-721 equivalent).
+pub const INTERFACE_ID_NFT: u32 = 0x80ac58cd;
+
+/// Interface ID for royalty info (EIP-2981 equivalent).
+pub const INTERFACE_ID_ROYALTY: u32 = 0x2a55205a;
+
+/// Interface ID for metadata.
+pub const INTERFACE_ID_METADATA: u32 = 0x5b5e139f;
+
+// IDs for nftopia extensions are the first four bytes of sha256("nftopia.<name>").
+
+/// Interface ID for in-place token locking (`lock` / `unlock` / `locker_of`).
+pub const INTERFACE_ID_LOCKABLE: u32 = 0x0a60e7d6;
+
+/// Interface ID for expiring subscription tokens (`renew` / `is_valid`).
+pub const INTERFACE_ID_EXPIRABLE: u32 = 0x8c83bf35;
+
+/// Interface ID for numbered editions (`mint_edition`).
+pub const INTERFACE_ID_EDITIONS: u32 = 0x4b93be71;
+
+/// Interface ID for mint-to-claim escrow (`mint_claimable` / `claim`).
+pub const INTERFACE_ID_CLAIMABLE: u32 = 0xeb08ffc2;
+
+/// Interface ID for token-bound accounts (`account_of`); registered once an account
+/// registry is set.
+pub const INTERFACE_ID_TOKEN_BOUND: u32 = 0xda89640c;
+
+/// Typed attribute value that on-chain logic (level checks, boosts) can compute with.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum AttributeValue {
+    /// Plain string; the value is the attribute's `value` field.
+    String,
+    I128(i128),
+    Bool(bool),
+    /// Unix timestamp in seconds.
+    Timestamp(u64),
+}
+
+/// Token attribute for on-chain metadata (OpenSea standard support).
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct TokenAttribute {
+    pub trait_type: String,
+    /// Display string; for typed attributes, the rendering of `typed_value`.
+    pub value: String,
+    /// "number", "date", "boost_percentage", etc.
+    pub display_type: Option<String>,
+    /// Typed value; `AttributeValue::String` for plain string attributes.
+    pub typed_value: AttributeValue,
+}
+
+/// Royalty information (EIP-2981 equivalent).
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RoyaltyInfo {
+    pub recipient: Address,
+    /// Basis points (0-10000, where 10000 = 100%)
+    pub percentage: u32,
+}
+
+/// What a delegation covers: all of the owner's tokens in this collection, or one token.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DelegationScope {
+    Collection,
+    Token(u64),
+}
+
+/// Collection-level configuration.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct CollectionConfig {
+    pub name: String,
+    pub symbol: String,
+    pub base_uri: String,
+    pub max_supply: Option<u64>,
+    pub is_revealed: bool,
+    pub royalty_default: RoyaltyInfo,
+    pub metadata_is_frozen: bool,
+    /// Derive token ids from the sha256 of each token's content instead of counting up, so
+    /// identical content can only be minted once.
+    pub content_addressed_ids: bool,
+    /// Most items one batch call (batch_mint, airdrop, batch_transfer, batch_burn) may take,
+    /// below the contract's own limits; `None` keeps those.
+    pub max_batch_size: Option<u32>,
+}
+
+/// Collection entrypoints other contracts call. Each method panics (aborting the caller)
+/// when the collection returns an error; use the generated `try_*` variants to handle it.
+#[allow(dead_code)]
+#[contractclient(name = "NftClient")]
+pub trait NftInterface {
+    fn owner_of(env: Env, token_id: u64) -> Address;
+    fn transfer(env: Env, from: Address, to: Address, token_id: u64);
+    fn burn(env: Env, caller: Address, token_id: u64, confirm: bool);
+    /// Royalty recipient and amount owed on a sale at `sale_price`.
+    fn get_royalty_info(env: Env, token_id: u64, sale_price: i128) -> (Address, i128);
+    fn supports_interface(env: Env, interface_id: u32) -> bool;
+    fn set_minter(env: Env, caller: Address, minter: Address, granted: bool);
+    fn airdrop(
+        env: Env,
+        caller: Address,
+        recipients: Vec<Address>,
+        uri_template: String,
+    ) -> Vec<u64>;
+    fn is_delegate(env: Env, owner: Address, delegate: Address, scope: DelegationScope) -> bool;
+
+    // --- Lifecycle (used by the factory that deploys collections) ---
+    fn initialize(env: Env, owner: Address, config: CollectionConfig);
+    fn set_upgrader(env: Env, caller: Address, upgrader: Option<Address>);
+    fn upgrade(env: Env, caller: Address, new_wasm_hash: BytesN<32>);
+}
@@ -0,0 +1,13 @@
+//! Cursor paging shared by the paged views of collections and the factory.
+
+/// Maximum entries per page of a paged view.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Index range of the page starting at `start` with up to `limit` items (capped at
+/// `MAX_PAGE_SIZE`) in a list of `len`; empty once `start` is past the end. Paged views go
+/// through this so cursors and limits behave the same everywhere, and keep their list's
+/// insertion order so pages stay stable as the list grows.
+pub fn page_range(start: u32, limit: u32, len: u32) -> core::ops::Range<u32> {
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(len);
+    start.min(end)..end
+}
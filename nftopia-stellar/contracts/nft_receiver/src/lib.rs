@@ -0,0 +1,41 @@
+//! Receiver callback shared by NFTopia collections and the contracts that accept their tokens.
+//!
+//! `safe_transfer_from` moves the token, then calls `nft_recv` on recipient contracts. The
+//! recipient accepts by returning [`NFT_RECV`]; any other value, an error, or a missing
+//! callback aborts the whole invocation, so the token never leaves the sender. Contracts
+//! that hold NFTs (vaults, marketplaces, games) implement [`NftReceiver`] to accept tokens.
+
+#![no_std]
+
+use soroban_sdk::{Address, Bytes, Env, Error, Symbol, Vec, contractclient, symbol_short};
+
+/// Name of the callback invoked on the recipient of a safe transfer, and the value the
+/// callback returns to accept the token.
+pub const NFT_RECV: Symbol = symbol_short!("nft_recv");
+
+/// Value `nft_batch_recv` returns to accept a batch from `safe_batch_transfer`.
+pub const NFT_BATCH_RECV: Symbol = symbol_short!("nft_batch");
+
+/// Callback a contract implements to receive tokens through `safe_transfer_from`.
+#[contractclient(name = "NftReceiverClient")]
+pub trait NftReceiver {
+    /// Called after `token_id` was transferred from `from` to this contract. `data` is
+    /// passed through unchanged from the sender. Return [`NFT_RECV`] to accept the token;
+    /// anything else rejects it.
+    fn nft_recv(
+        env: Env,
+        from: Address,
+        token_id: u64,
+        data: Option<Bytes>,
+    ) -> Result<Symbol, Error>;
+
+    /// Called once after every token in `token_ids` was transferred from `from` to this
+    /// contract by `safe_batch_transfer`. Return [`NFT_BATCH_RECV`] to accept the batch;
+    /// anything else rejects all of it.
+    fn nft_batch_recv(
+        env: Env,
+        from: Address,
+        token_ids: Vec<u64>,
+        data: Option<Bytes>,
+    ) -> Result<Symbol, Error>;
+}
@@ -0,0 +1,135 @@
+//! Reference `nft_recv` implementation. Accepts tokens while enabled, records every
+//! delivery, and rejects tokens once the admin turns acceptance off.
+
+#![no_std]
+
+use nft_receiver::{NFT_BATCH_RECV, NFT_RECV, NftReceiver};
+use soroban_sdk::{
+    Address, Bytes, Env, Error, Symbol, Vec, contract, contracterror, contractevent, contractimpl,
+    contracttype,
+};
+
+/// Errors returned by the example receiver.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    /// Contract has already been initialized.
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized.
+    NotInitialized = 2,
+    /// Receiver is not accepting tokens.
+    Rejected = 3,
+}
+
+/// Storage keys for the example receiver.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// Address allowed to toggle acceptance.
+    Admin,
+    /// Whether incoming tokens are accepted.
+    Accepting,
+    /// Number of tokens received.
+    ReceivedCount,
+}
+
+/// Token accepted through `nft_recv`.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Received {
+    pub from: Address,
+    pub token_id: u64,
+    pub data: Option<Bytes>,
+}
+
+#[contract]
+pub struct NftReceiverExample;
+
+#[contractimpl]
+impl NftReceiverExample {
+    pub fn initialize(env: Env, admin: Address) -> Result<(), ContractError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(ContractError::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Accepting, &true);
+        Ok(())
+    }
+
+    pub fn set_accepting(env: Env, accepting: bool) -> Result<(), ContractError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::NotInitialized)?;
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::Accepting, &accepting);
+        Ok(())
+    }
+
+    pub fn received_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ReceivedCount)
+            .unwrap_or(0)
+    }
+}
+
+#[contractimpl]
+impl NftReceiver for NftReceiverExample {
+    fn nft_recv(
+        env: Env,
+        from: Address,
+        token_id: u64,
+        data: Option<Bytes>,
+    ) -> Result<Symbol, Error> {
+        record_received(&env, &from, token_id, &data)?;
+        Ok(NFT_RECV)
+    }
+
+    fn nft_batch_recv(
+        env: Env,
+        from: Address,
+        token_ids: Vec<u64>,
+        data: Option<Bytes>,
+    ) -> Result<Symbol, Error> {
+        for token_id in token_ids.iter() {
+            record_received(&env, &from, token_id, &data)?;
+        }
+        Ok(NFT_BATCH_RECV)
+    }
+}
+
+/// Counts an incoming token, or rejects it when acceptance is off.
+fn record_received(
+    env: &Env,
+    from: &Address,
+    token_id: u64,
+    data: &Option<Bytes>,
+) -> Result<(), Error> {
+    let accepting: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey::Accepting)
+        .unwrap_or(false);
+    if !accepting {
+        return Err(ContractError::Rejected.into());
+    }
+    let count = NftReceiverExample::received_count(env.clone()) + 1;
+    env.storage()
+        .instance()
+        .set(&DataKey::ReceivedCount, &count);
+    Received {
+        from: from.clone(),
+        token_id,
+        data: data.clone(),
+    }
+    .publish(env);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,76 @@
+#![cfg(test)]
+
+use crate::{NftReceiverExample, NftReceiverExampleClient};
+use nft_contract::testutils::deploy_test_collection;
+use nft_contract::{ContractError, NftContractClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Bytes, Env, String, Vec};
+
+fn setup<'a>(env: &Env) -> (Address, NftContractClient<'a>, NftReceiverExampleClient<'a>) {
+    env.mock_all_auths();
+    let owner = Address::generate(env);
+
+    let nft = deploy_test_collection(env, &owner, 0);
+
+    let receiver_id = env.register(NftReceiverExample, ());
+    let receiver = NftReceiverExampleClient::new(env, &receiver_id);
+    receiver.initialize(&owner);
+    (owner, nft, receiver)
+}
+
+#[test]
+fn test_safe_transfer_to_receiver() {
+    let env = Env::default();
+    let (owner, nft, receiver) = setup(&env);
+    let uri = String::from_str(&env, "ipfs://hash");
+    let token_id = nft.mint(&owner, &owner, &uri, &Vec::new(&env), &None);
+
+    let data = Some(Bytes::from_array(&env, &[1, 2, 3]));
+    nft.safe_transfer_from(&owner, &receiver.address, &token_id, &data);
+
+    assert_eq!(nft.owner_of(&token_id), receiver.address);
+    assert_eq!(receiver.received_count(), 1);
+}
+
+#[test]
+fn test_safe_batch_transfer_notifies_once() {
+    let env = Env::default();
+    let (owner, nft, receiver) = setup(&env);
+    let uri = String::from_str(&env, "ipfs://hash");
+    let first = nft.mint(&owner, &owner, &uri, &Vec::new(&env), &None);
+    let second = nft.mint(&owner, &owner, &uri, &Vec::new(&env), &None);
+
+    let token_ids = Vec::from_array(&env, [first, second]);
+    nft.safe_batch_transfer(&owner, &receiver.address, &token_ids, &None);
+    assert_eq!(nft.balance_of(&receiver.address), 2);
+    assert_eq!(receiver.received_count(), 2);
+
+    receiver.set_accepting(&false);
+    let third = nft.mint(&owner, &owner, &uri, &Vec::new(&env), &None);
+    assert_eq!(
+        nft.try_safe_batch_transfer(
+            &owner,
+            &receiver.address,
+            &Vec::from_array(&env, [third]),
+            &None
+        ),
+        Err(Ok(ContractError::TransferRejected))
+    );
+    assert_eq!(nft.owner_of(&third), owner);
+}
+
+#[test]
+fn test_rejecting_receiver_reverts_transfer() {
+    let env = Env::default();
+    let (owner, nft, receiver) = setup(&env);
+    let uri = String::from_str(&env, "ipfs://hash");
+    let token_id = nft.mint(&owner, &owner, &uri, &Vec::new(&env), &None);
+
+    receiver.set_accepting(&false);
+    assert_eq!(
+        nft.try_safe_transfer_from(&owner, &receiver.address, &token_id, &None),
+        Err(Ok(ContractError::TransferRejected))
+    );
+    assert_eq!(nft.owner_of(&token_id), owner);
+    assert_eq!(receiver.received_count(), 0);
+}
@@ -0,0 +1,34 @@
+//! Transfer hook interface shared by NFTopia collections and the contracts that observe them.
+//!
+//! A collection admin registers hook contracts with `add_transfer_hook`. On every transfer the
+//! collection calls `before_transfer` on each hook before moving the token, and
+//! `after_transfer` once the move is recorded. An error from either callback fails the
+//! transfer, so hooks can enforce staking locks, compliance rules or game state.
+
+#![no_std]
+
+use soroban_sdk::{Address, Env, Error, contractclient};
+
+/// Callbacks a contract implements to observe transfers of a collection's tokens.
+#[contractclient(name = "TransferHookClient")]
+pub trait TransferHook {
+    /// Called before `token_id` of `collection` moves from `from` to `to`. Return an error
+    /// to block the transfer.
+    fn before_transfer(
+        env: Env,
+        collection: Address,
+        from: Address,
+        to: Address,
+        token_id: u64,
+    ) -> Result<(), Error>;
+
+    /// Called after `token_id` of `collection` moved from `from` to `to`. Return an error to
+    /// revert the transfer.
+    fn after_transfer(
+        env: Env,
+        collection: Address,
+        from: Address,
+        to: Address,
+        token_id: u64,
+    ) -> Result<(), Error>;
+}
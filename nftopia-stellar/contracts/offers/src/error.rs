@@ -0,0 +1,30 @@
+use soroban_sdk::contracterror;
+
+/// Contract-specific errors for the offers contract.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    /// Caller is not authorized for the operation.
+    NotAuthorized = 1,
+    /// Contract has already been initialized.
+    AlreadyInitialized = 2,
+    /// Contract has not been initialized.
+    NotInitialized = 3,
+    /// Offer not found (never created, accepted, cancelled, or refunded).
+    OfferNotFound = 4,
+    /// Offer amount must be positive.
+    InvalidAmount = 5,
+    /// Expiry must be in the future.
+    InvalidExpiry = 6,
+    /// Offer has expired.
+    OfferExpired = 7,
+    /// Offer has not expired yet.
+    OfferNotExpired = 8,
+    /// Token does not match a token-level offer.
+    TokenMismatch = 9,
+    /// Owner cannot accept their own offer.
+    InvalidSeller = 10,
+    /// Royalty reported by the collection exceeds the offer amount.
+    InvalidRoyalty = 11,
+}
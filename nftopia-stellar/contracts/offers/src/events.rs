@@ -0,0 +1,69 @@
+use soroban_sdk::{Address, Env, contractevent};
+
+/// Offer created.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct OfferMade {
+    pub offer_id: u64,
+    pub buyer: Address,
+    pub collection: Address,
+    pub token_id: Option<u64>,
+    pub amount: i128,
+    pub expires_at: u64,
+}
+
+/// Offer accepted by the token owner.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct OfferAccepted {
+    pub offer_id: u64,
+    pub seller: Address,
+    pub token_id: u64,
+    pub royalty_amount: i128,
+}
+
+/// Offer closed and escrow refunded to the buyer (cancelled or expired).
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct OfferRefunded {
+    pub offer_id: u64,
+    pub buyer: Address,
+    pub amount: i128,
+}
+
+pub fn emit_offer_made(env: &Env, offer: &crate::types::Offer) {
+    OfferMade {
+        offer_id: offer.id,
+        buyer: offer.buyer.clone(),
+        collection: offer.collection.clone(),
+        token_id: offer.token_id,
+        amount: offer.amount,
+        expires_at: offer.expires_at,
+    }
+    .publish(env);
+}
+
+pub fn emit_offer_accepted(
+    env: &Env,
+    offer_id: u64,
+    seller: Address,
+    token_id: u64,
+    royalty_amount: i128,
+) {
+    OfferAccepted {
+        offer_id,
+        seller,
+        token_id,
+        royalty_amount,
+    }
+    .publish(env);
+}
+
+pub fn emit_offer_refunded(env: &Env, offer_id: u64, buyer: Address, amount: i128) {
+    OfferRefunded {
+        offer_id,
+        buyer,
+        amount,
+    }
+    .publish(env);
+}
@@ -0,0 +1,66 @@
+#![no_std]
+
+mod error;
+mod events;
+mod offer;
+mod storage;
+mod types;
+
+pub use error::ContractError;
+pub use types::Offer;
+
+use soroban_sdk::{Address, Env, contract, contractimpl};
+
+use crate::error::ContractError as Err;
+use crate::storage::DataKey;
+
+#[contract]
+pub struct Offers;
+
+#[contractimpl]
+impl Offers {
+    /// Initializes the offers contract.
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Err> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Err::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::NextOfferId, &0u64);
+        Ok(())
+    }
+
+    // --- Offers ---
+    /// Escrows `amount` as an offer on `token_id`, or on any token of `collection` when `token_id` is None.
+    pub fn make_offer(
+        env: Env,
+        buyer: Address,
+        collection: Address,
+        token_id: Option<u64>,
+        currency: Address,
+        amount: i128,
+        expires_at: u64,
+    ) -> Result<u64, Err> {
+        offer::make_offer(
+            &env, buyer, collection, token_id, currency, amount, expires_at,
+        )
+    }
+
+    pub fn accept_offer(env: Env, owner: Address, offer_id: u64, token_id: u64) -> Result<(), Err> {
+        offer::accept_offer(&env, owner, offer_id, token_id)
+    }
+
+    pub fn cancel_offer(env: Env, buyer: Address, offer_id: u64) -> Result<(), Err> {
+        offer::cancel_offer(&env, buyer, offer_id)
+    }
+
+    pub fn refund_expired(env: Env, offer_id: u64) -> Result<(), Err> {
+        offer::refund_expired(&env, offer_id)
+    }
+
+    pub fn get_offer(env: Env, offer_id: u64) -> Result<Offer, Err> {
+        storage::get_offer(&env, offer_id)
+    }
+}
+
+#[cfg(test)]
+mod test;
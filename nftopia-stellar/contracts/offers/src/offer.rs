@@ -0,0 +1,109 @@
+use crate::error::ContractError;
+use crate::events;
+use crate::storage::{self, DataKey};
+use crate::types::Offer;
+use nft_interface::NftClient;
+use soroban_sdk::{Address, Env, token};
+
+/// Creates an offer, escrowing `amount` of `currency` from the buyer until acceptance, cancellation, or expiry.
+pub fn make_offer(
+    env: &Env,
+    buyer: Address,
+    collection: Address,
+    token_id: Option<u64>,
+    currency: Address,
+    amount: i128,
+    expires_at: u64,
+) -> Result<u64, ContractError> {
+    buyer.require_auth();
+    if amount <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    if expires_at <= env.ledger().timestamp() {
+        return Err(ContractError::InvalidExpiry);
+    }
+    let offer_id = storage::next_offer_id(env)?;
+    let contract = env.current_contract_address();
+    token::Client::new(env, &currency).transfer(&buyer, &contract, &amount);
+
+    let offer = Offer {
+        id: offer_id,
+        buyer,
+        collection,
+        token_id,
+        currency,
+        amount,
+        expires_at,
+    };
+    env.storage()
+        .instance()
+        .set(&DataKey::Offer(offer_id), &offer);
+    events::emit_offer_made(env, &offer);
+    Ok(offer_id)
+}
+
+/// Accepts an offer: transfers `token_id` from the owner to the buyer and pays out the escrow
+/// minus the creator royalty. For collection-level offers the owner picks which token to sell.
+pub fn accept_offer(
+    env: &Env,
+    owner: Address,
+    offer_id: u64,
+    token_id: u64,
+) -> Result<(), ContractError> {
+    owner.require_auth();
+    let offer = storage::get_offer(env, offer_id)?;
+    if env.ledger().timestamp() >= offer.expires_at {
+        return Err(ContractError::OfferExpired);
+    }
+    if offer.token_id.is_some_and(|id| id != token_id) {
+        return Err(ContractError::TokenMismatch);
+    }
+    if owner == offer.buyer {
+        return Err(ContractError::InvalidSeller);
+    }
+    env.storage().instance().remove(&DataKey::Offer(offer_id));
+
+    let nft = NftClient::new(env, &offer.collection);
+    let (royalty_recipient, royalty_amount) = nft.get_royalty_info(&token_id, &offer.amount);
+    if royalty_amount < 0 || royalty_amount > offer.amount {
+        return Err(ContractError::InvalidRoyalty);
+    }
+    nft.transfer(&owner, &offer.buyer, &token_id);
+
+    let contract = env.current_contract_address();
+    let payment = token::Client::new(env, &offer.currency);
+    if royalty_amount > 0 {
+        payment.transfer(&contract, &royalty_recipient, &royalty_amount);
+    }
+    payment.transfer(&contract, &owner, &(offer.amount - royalty_amount));
+    events::emit_offer_accepted(env, offer_id, owner, token_id, royalty_amount);
+    Ok(())
+}
+
+/// Cancels an offer and refunds the escrow. Buyer only.
+pub fn cancel_offer(env: &Env, buyer: Address, offer_id: u64) -> Result<(), ContractError> {
+    buyer.require_auth();
+    let offer = storage::get_offer(env, offer_id)?;
+    if offer.buyer != buyer {
+        return Err(ContractError::NotAuthorized);
+    }
+    refund(env, offer);
+    Ok(())
+}
+
+/// Refunds an expired offer to its buyer. Anyone may call.
+pub fn refund_expired(env: &Env, offer_id: u64) -> Result<(), ContractError> {
+    let offer = storage::get_offer(env, offer_id)?;
+    if env.ledger().timestamp() < offer.expires_at {
+        return Err(ContractError::OfferNotExpired);
+    }
+    refund(env, offer);
+    Ok(())
+}
+
+fn refund(env: &Env, offer: Offer) {
+    env.storage().instance().remove(&DataKey::Offer(offer.id));
+    let contract = env.current_contract_address();
+    token::Client::new(env, &offer.currency).transfer(&contract, &offer.buyer, &offer.amount);
+    events::emit_offer_refunded(env, offer.id, offer.buyer, offer.amount);
+}
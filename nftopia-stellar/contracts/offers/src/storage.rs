@@ -0,0 +1,34 @@
+use crate::error::ContractError;
+use crate::types::Offer;
+use soroban_sdk::{Env, contracttype};
+
+/// Storage keys for the offers contract.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// Contract admin.
+    Admin,
+    /// Next offer id to assign.
+    NextOfferId,
+    /// Open offer by id.
+    Offer(u64),
+}
+
+pub fn get_offer(env: &Env, offer_id: u64) -> Result<Offer, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Offer(offer_id))
+        .ok_or(ContractError::OfferNotFound)
+}
+
+pub fn next_offer_id(env: &Env) -> Result<u64, ContractError> {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextOfferId)
+        .ok_or(ContractError::NotInitialized)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::NextOfferId, &(id + 1));
+    Ok(id)
+}
@@ -0,0 +1,126 @@
+#![cfg(test)]
+
+use crate::{ContractError, Offers, OffersClient};
+use nft_contract::NftContractClient;
+use nft_contract::testutils::deploy_test_collection;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, String, Vec};
+
+struct Setup<'a> {
+    env: Env,
+    creator: Address,
+    owner: Address,
+    buyer: Address,
+    nft: NftContractClient<'a>,
+    token: TokenClient<'a>,
+    offers: OffersClient<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let creator = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let nft = deploy_test_collection(&env, &creator, 500);
+
+    let sac = env.register_stellar_asset_contract_v2(creator.clone());
+    let token = TokenClient::new(&env, &sac.address());
+    StellarAssetClient::new(&env, &sac.address()).mint(&buyer, &10_000);
+
+    let offers_id = env.register(Offers, ());
+    let offers = OffersClient::new(&env, &offers_id);
+    offers.initialize(&creator);
+
+    Setup {
+        env,
+        creator,
+        owner,
+        buyer,
+        nft,
+        token,
+        offers,
+    }
+}
+
+fn mint_to(s: &Setup, to: &Address) -> u64 {
+    let uri = String::from_str(&s.env, "ipfs://hash");
+    s.nft.mint(&s.creator, to, &uri, &Vec::new(&s.env), &None)
+}
+
+#[test]
+fn test_collection_offer_accepted() {
+    let s = setup();
+    let token_id = mint_to(&s, &s.owner);
+
+    let offer_id = s.offers.make_offer(
+        &s.buyer,
+        &s.nft.address,
+        &None,
+        &s.token.address,
+        &2_000,
+        &5_000,
+    );
+    assert_eq!(s.token.balance(&s.offers.address), 2_000);
+
+    s.offers.accept_offer(&s.owner, &offer_id, &token_id);
+    assert_eq!(s.nft.owner_of(&token_id), s.buyer);
+    assert_eq!(s.token.balance(&s.creator), 100);
+    assert_eq!(s.token.balance(&s.owner), 1_900);
+    assert_eq!(s.token.balance(&s.offers.address), 0);
+}
+
+#[test]
+fn test_token_offer_mismatch_and_expiry_refund() {
+    let s = setup();
+    let token_id = mint_to(&s, &s.owner);
+    let other_id = mint_to(&s, &s.owner);
+
+    let offer_id = s.offers.make_offer(
+        &s.buyer,
+        &s.nft.address,
+        &Some(token_id),
+        &s.token.address,
+        &2_000,
+        &5_000,
+    );
+    assert_eq!(
+        s.offers.try_accept_offer(&s.owner, &offer_id, &other_id),
+        Err(Ok(ContractError::TokenMismatch))
+    );
+    assert_eq!(
+        s.offers.try_refund_expired(&offer_id),
+        Err(Ok(ContractError::OfferNotExpired))
+    );
+
+    s.env.ledger().set_timestamp(5_000);
+    assert_eq!(
+        s.offers.try_accept_offer(&s.owner, &offer_id, &token_id),
+        Err(Ok(ContractError::OfferExpired))
+    );
+    s.offers.refund_expired(&offer_id);
+    assert_eq!(s.token.balance(&s.buyer), 10_000);
+}
+
+#[test]
+fn test_cancel_offer_refunds() {
+    let s = setup();
+    let offer_id = s.offers.make_offer(
+        &s.buyer,
+        &s.nft.address,
+        &None,
+        &s.token.address,
+        &2_000,
+        &5_000,
+    );
+    assert_eq!(
+        s.offers.try_cancel_offer(&s.owner, &offer_id),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+    s.offers.cancel_offer(&s.buyer, &offer_id);
+    assert_eq!(s.token.balance(&s.buyer), 10_000);
+}
@@ -0,0 +1,15 @@
+use soroban_sdk::{Address, contracttype};
+
+/// Escrowed offer. `token_id` is None for a collection-level offer on any token in `collection`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Offer {
+    pub id: u64,
+    pub buyer: Address,
+    pub collection: Address,
+    pub token_id: Option<u64>,
+    /// SEP-41 token the amount is denominated in.
+    pub currency: Address,
+    pub amount: i128,
+    pub expires_at: u64,
+}
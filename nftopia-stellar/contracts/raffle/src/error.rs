@@ -0,0 +1,40 @@
+use soroban_sdk::contracterror;
+
+/// Contract-specific errors for the raffle contract.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    /// Caller is not the raffle creator or an entrant.
+    NotAuthorized = 1,
+    /// Contract has already been initialized.
+    AlreadyInitialized = 2,
+    /// Contract has not been initialized.
+    NotInitialized = 3,
+    /// Raffle not found (never created, drawn, or cancelled).
+    RaffleNotFound = 4,
+    /// Ticket price, ticket cap, or duration is invalid.
+    InvalidTerms = 5,
+    /// Ticket sales have ended.
+    RaffleEnded = 6,
+    /// Ticket sales are still open.
+    RaffleActive = 7,
+    /// Not enough tickets left, or ticket count is zero.
+    InvalidTicketCount = 8,
+    /// Raffle already has the maximum number of distinct entrants.
+    TooManyEntrants = 9,
+    /// Revealed secret does not match the commitment.
+    InvalidReveal = 10,
+    /// Reveal window has passed; the raffle can only be drawn.
+    RevealExpired = 11,
+    /// Reveal window is still open.
+    RevealPending = 12,
+    /// Tickets have been sold, so the creator can no longer cancel.
+    TicketsSold = 13,
+    /// Fee exceeds 100% or reveal window is zero.
+    InvalidConfig = 14,
+    /// No refund is owed to the caller in this currency.
+    NothingToRefund = 15,
+    /// Secret has already been revealed.
+    AlreadyRevealed = 16,
+}
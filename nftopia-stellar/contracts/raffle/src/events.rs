@@ -0,0 +1,133 @@
+use soroban_sdk::{Address, Env, contractevent};
+
+/// Raffle created and prize escrowed.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RaffleCreated {
+    pub raffle_id: u64,
+    pub creator: Address,
+    pub collection: Address,
+    pub token_id: u64,
+    pub ticket_price: i128,
+    pub end_time: u64,
+}
+
+/// Tickets bought.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct TicketsBought {
+    pub raffle_id: u64,
+    pub buyer: Address,
+    pub count: u32,
+}
+
+/// Entrant revealed their secret.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct EntryRevealed {
+    pub raffle_id: u64,
+    pub entrant: Address,
+}
+
+/// Creator secret revealed.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RaffleRevealed {
+    pub raffle_id: u64,
+}
+
+/// Winner drawn and prize delivered. Proceeds are 0 when they were refunded after a missed
+/// reveal.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RaffleDrawn {
+    pub raffle_id: u64,
+    pub winner: Address,
+    pub winning_ticket: u32,
+    pub proceeds: i128,
+    pub fee: i128,
+}
+
+/// Raffle cancelled, or drawn with no tickets sold; prize returned.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RaffleCancelled {
+    pub raffle_id: u64,
+}
+
+/// Refund withdrawn by its owner.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RefundWithdrawn {
+    pub owner: Address,
+    pub currency: Address,
+    pub amount: i128,
+}
+
+pub fn emit_raffle_created(
+    env: &Env,
+    raffle_id: u64,
+    creator: Address,
+    collection: Address,
+    token_id: u64,
+    ticket_price: i128,
+    end_time: u64,
+) {
+    RaffleCreated {
+        raffle_id,
+        creator,
+        collection,
+        token_id,
+        ticket_price,
+        end_time,
+    }
+    .publish(env);
+}
+
+pub fn emit_tickets_bought(env: &Env, raffle_id: u64, buyer: Address, count: u32) {
+    TicketsBought {
+        raffle_id,
+        buyer,
+        count,
+    }
+    .publish(env);
+}
+
+pub fn emit_entry_revealed(env: &Env, raffle_id: u64, entrant: Address) {
+    EntryRevealed { raffle_id, entrant }.publish(env);
+}
+
+pub fn emit_raffle_revealed(env: &Env, raffle_id: u64) {
+    RaffleRevealed { raffle_id }.publish(env);
+}
+
+pub fn emit_raffle_drawn(
+    env: &Env,
+    raffle_id: u64,
+    winner: Address,
+    winning_ticket: u32,
+    proceeds: i128,
+    fee: i128,
+) {
+    RaffleDrawn {
+        raffle_id,
+        winner,
+        winning_ticket,
+        proceeds,
+        fee,
+    }
+    .publish(env);
+}
+
+pub fn emit_raffle_cancelled(env: &Env, raffle_id: u64) {
+    RaffleCancelled { raffle_id }.publish(env);
+}
+
+pub fn emit_refund_withdrawn(env: &Env, owner: Address, currency: Address, amount: i128) {
+    RefundWithdrawn {
+        owner,
+        currency,
+        amount,
+    }
+    .publish(env);
+}
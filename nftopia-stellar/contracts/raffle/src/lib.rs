@@ -0,0 +1,104 @@
+#![no_std]
+
+mod error;
+mod events;
+mod raffle;
+mod storage;
+mod types;
+
+pub use error::ContractError;
+pub use raffle::MAX_ENTRANTS;
+pub use types::{Entry, Raffle, RaffleConfig, RaffleTerms};
+
+use soroban_sdk::{Address, Bytes, BytesN, Env, contract, contractimpl};
+
+use crate::error::ContractError as Err;
+use crate::storage::DataKey;
+
+#[contract]
+pub struct RaffleHouse;
+
+#[contractimpl]
+impl RaffleHouse {
+    /// Initializes the raffle house with its protocol fee and reveal window.
+    pub fn initialize(env: Env, admin: Address, config: RaffleConfig) -> Result<(), Err> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Err::AlreadyInitialized);
+        }
+        raffle::validate_config(&config)?;
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Config, &config);
+        env.storage().instance().set(&DataKey::NextRaffleId, &0u64);
+        Ok(())
+    }
+
+    pub fn set_config(env: Env, config: RaffleConfig) -> Result<(), Err> {
+        storage::require_admin(&env)?;
+        raffle::validate_config(&config)?;
+        env.storage().instance().set(&DataKey::Config, &config);
+        Ok(())
+    }
+
+    pub fn get_config(env: Env) -> Result<RaffleConfig, Err> {
+        storage::get_config(&env)
+    }
+
+    // --- Raffles ---
+    pub fn create_raffle(
+        env: Env,
+        creator: Address,
+        collection: Address,
+        token_id: u64,
+        terms: RaffleTerms,
+        commitment: BytesN<32>,
+    ) -> Result<u64, Err> {
+        raffle::create_raffle(&env, creator, collection, token_id, terms, commitment)
+    }
+
+    pub fn buy_tickets(
+        env: Env,
+        buyer: Address,
+        raffle_id: u64,
+        count: u32,
+        commitment: BytesN<32>,
+    ) -> Result<(), Err> {
+        raffle::buy_tickets(&env, buyer, raffle_id, count, commitment)
+    }
+
+    pub fn reveal_entry(
+        env: Env,
+        entrant: Address,
+        raffle_id: u64,
+        secret: Bytes,
+    ) -> Result<(), Err> {
+        raffle::reveal_entry(&env, entrant, raffle_id, secret)
+    }
+
+    pub fn reveal(env: Env, raffle_id: u64, secret: Bytes) -> Result<(), Err> {
+        raffle::reveal(&env, raffle_id, secret)
+    }
+
+    pub fn draw(env: Env, raffle_id: u64) -> Result<Option<Address>, Err> {
+        raffle::draw(&env, raffle_id)
+    }
+
+    pub fn cancel(env: Env, creator: Address, raffle_id: u64) -> Result<(), Err> {
+        raffle::cancel(&env, creator, raffle_id)
+    }
+
+    // --- Refunds ---
+    pub fn withdraw_refund(env: Env, entrant: Address, currency: Address) -> Result<i128, Err> {
+        raffle::withdraw_refund(&env, entrant, currency)
+    }
+
+    pub fn get_refund(env: Env, entrant: Address, currency: Address) -> i128 {
+        storage::refund_of(&env, &entrant, &currency)
+    }
+
+    pub fn get_raffle(env: Env, raffle_id: u64) -> Result<Raffle, Err> {
+        storage::get_raffle(&env, raffle_id)
+    }
+}
+
+#[cfg(test)]
+mod test;
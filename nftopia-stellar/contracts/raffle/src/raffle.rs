@@ -0,0 +1,289 @@
+use crate::error::ContractError;
+use crate::events;
+use crate::storage::{self, DataKey};
+use crate::types::{Entry, Raffle, RaffleConfig, RaffleTerms};
+use nft_interface::NftClient;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec, token};
+
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Maximum distinct entrants per raffle, bounding the draw and refund loops.
+pub const MAX_ENTRANTS: u32 = 100;
+
+pub fn validate_config(config: &RaffleConfig) -> Result<(), ContractError> {
+    if config.fee_bps as i128 > BPS_DENOMINATOR || config.reveal_window == 0 {
+        return Err(ContractError::InvalidConfig);
+    }
+    Ok(())
+}
+
+/// Creates a raffle, moving the prize into escrow. `commitment` is `sha256(secret)`; the
+/// creator reveals `secret` within the reveal window after ticket sales end.
+pub fn create_raffle(
+    env: &Env,
+    creator: Address,
+    collection: Address,
+    token_id: u64,
+    terms: RaffleTerms,
+    commitment: BytesN<32>,
+) -> Result<u64, ContractError> {
+    creator.require_auth();
+    if terms.ticket_price <= 0 || terms.max_tickets == 0 || terms.duration == 0 {
+        return Err(ContractError::InvalidTerms);
+    }
+    let raffle_id = storage::next_raffle_id(env)?;
+    NftClient::new(env, &collection).transfer(&creator, &env.current_contract_address(), &token_id);
+
+    let end_time = env.ledger().timestamp() + terms.duration;
+    let raffle = Raffle {
+        id: raffle_id,
+        creator: creator.clone(),
+        collection: collection.clone(),
+        token_id,
+        currency: terms.currency,
+        ticket_price: terms.ticket_price,
+        max_tickets: terms.max_tickets,
+        end_time,
+        commitment: commitment.clone(),
+        tickets_sold: 0,
+        entries: Vec::new(env),
+        entropy: commitment.clone(),
+        secret: None,
+    };
+    storage::set_raffle(env, &raffle);
+    events::emit_raffle_created(
+        env,
+        raffle_id,
+        creator,
+        collection,
+        token_id,
+        terms.ticket_price,
+        end_time,
+    );
+    Ok(raffle_id)
+}
+
+/// Buys `count` tickets. `commitment` is `sha256(secret)` for the entrant's secret, revealed
+/// with `reveal_entry` after sales end; it is set by the entrant's first purchase and ignored
+/// afterwards.
+pub fn buy_tickets(
+    env: &Env,
+    buyer: Address,
+    raffle_id: u64,
+    count: u32,
+    commitment: BytesN<32>,
+) -> Result<(), ContractError> {
+    buyer.require_auth();
+    let mut raffle = storage::get_raffle(env, raffle_id)?;
+    if env.ledger().timestamp() >= raffle.end_time {
+        return Err(ContractError::RaffleEnded);
+    }
+    if count == 0 || raffle.tickets_sold + count > raffle.max_tickets {
+        return Err(ContractError::InvalidTicketCount);
+    }
+
+    match raffle.entries.iter().position(|e| e.buyer == buyer) {
+        Some(i) => {
+            let mut entry = raffle.entries.get(i as u32).unwrap();
+            entry.tickets += count;
+            raffle.entries.set(i as u32, entry);
+        }
+        None => {
+            if raffle.entries.len() >= MAX_ENTRANTS {
+                return Err(ContractError::TooManyEntrants);
+            }
+            raffle.entries.push_back(Entry {
+                buyer: buyer.clone(),
+                tickets: count,
+                commitment,
+                revealed: false,
+            });
+        }
+    }
+    raffle.tickets_sold += count;
+
+    let cost = raffle.ticket_price * count as i128;
+    let contract = env.current_contract_address();
+    token::Client::new(env, &raffle.currency).transfer(&buyer, &contract, &cost);
+    storage::set_raffle(env, &raffle);
+    events::emit_tickets_bought(env, raffle_id, buyer, count);
+    Ok(())
+}
+
+/// Reveals an entrant's secret within the reveal window, chaining it into the raffle's
+/// entropy. Nobody knows these secrets while tickets are on sale, so the draw cannot be
+/// simulated before sales close.
+pub fn reveal_entry(
+    env: &Env,
+    entrant: Address,
+    raffle_id: u64,
+    secret: Bytes,
+) -> Result<(), ContractError> {
+    entrant.require_auth();
+    let mut raffle = storage::get_raffle(env, raffle_id)?;
+    require_reveal_window(env, &raffle)?;
+    let i = raffle
+        .entries
+        .iter()
+        .position(|e| e.buyer == entrant)
+        .ok_or(ContractError::NotAuthorized)? as u32;
+    let mut entry = raffle.entries.get(i).unwrap();
+    if entry.revealed {
+        return Err(ContractError::AlreadyRevealed);
+    }
+    if BytesN::from(env.crypto().sha256(&secret)) != entry.commitment {
+        return Err(ContractError::InvalidReveal);
+    }
+    entry.revealed = true;
+    raffle.entries.set(i, entry);
+    let mut chained = Bytes::from(raffle.entropy.clone());
+    chained.append(&secret);
+    raffle.entropy = env.crypto().sha256(&chained).into();
+    storage::set_raffle(env, &raffle);
+    events::emit_entry_revealed(env, raffle_id, entrant);
+    Ok(())
+}
+
+/// Reveals the creator's secret within the reveal window. Anyone holding it may call this.
+pub fn reveal(env: &Env, raffle_id: u64, secret: Bytes) -> Result<(), ContractError> {
+    let mut raffle = storage::get_raffle(env, raffle_id)?;
+    require_reveal_window(env, &raffle)?;
+    if raffle.secret.is_some() {
+        return Err(ContractError::AlreadyRevealed);
+    }
+    if BytesN::from(env.crypto().sha256(&secret)) != raffle.commitment {
+        return Err(ContractError::InvalidReveal);
+    }
+    raffle.secret = Some(secret);
+    storage::set_raffle(env, &raffle);
+    events::emit_raffle_revealed(env, raffle_id);
+    Ok(())
+}
+
+fn require_reveal_window(env: &Env, raffle: &Raffle) -> Result<(), ContractError> {
+    let config = storage::get_config(env)?;
+    let now = env.ledger().timestamp();
+    if now < raffle.end_time {
+        return Err(ContractError::RaffleActive);
+    }
+    if now >= raffle.end_time + config.reveal_window {
+        return Err(ContractError::RevealExpired);
+    }
+    Ok(())
+}
+
+/// Draws the winner once the reveal window has closed. Callable by anyone. The winning
+/// ticket depends on the creator's secret and the entrant secrets revealed after sales
+/// closed, so nobody can steer it by buying tickets last. If the creator missed the reveal,
+/// the draw runs without its secret and every entrant's tickets are credited to their refund
+/// balance: the creator forfeits both the prize and the proceeds. Returns the winner, or
+/// `None` if no tickets were sold (the prize goes back to the creator).
+pub fn draw(env: &Env, raffle_id: u64) -> Result<Option<Address>, ContractError> {
+    let raffle = storage::get_raffle(env, raffle_id)?;
+    let config = storage::get_config(env)?;
+    if env.ledger().timestamp() < raffle.end_time + config.reveal_window {
+        return Err(ContractError::RevealPending);
+    }
+    storage::remove_raffle(env, raffle_id);
+
+    let nft = NftClient::new(env, &raffle.collection);
+    let contract = env.current_contract_address();
+    if raffle.tickets_sold == 0 {
+        nft.transfer(&contract, &raffle.creator, &raffle.token_id);
+        events::emit_raffle_cancelled(env, raffle_id);
+        return Ok(None);
+    }
+
+    let winning_ticket = pick_ticket(env, &raffle);
+    let winner = ticket_owner(&raffle.entries, winning_ticket);
+
+    let (proceeds, fee) = if raffle.secret.is_some() {
+        let proceeds = raffle.ticket_price * raffle.tickets_sold as i128;
+        let fee = proceeds * config.fee_bps as i128 / BPS_DENOMINATOR;
+        let payment = token::Client::new(env, &raffle.currency);
+        if fee > 0 {
+            payment.transfer(&contract, &config.fee_recipient, &fee);
+        }
+        payment.transfer(&contract, &raffle.creator, &(proceeds - fee));
+        (proceeds, fee)
+    } else {
+        for entry in raffle.entries.iter() {
+            let refund = raffle.ticket_price * entry.tickets as i128;
+            storage::credit_refund(env, &entry.buyer, &raffle.currency, refund);
+        }
+        (0, 0)
+    };
+    nft.transfer(&contract, &winner, &raffle.token_id);
+
+    events::emit_raffle_drawn(
+        env,
+        raffle_id,
+        winner.clone(),
+        winning_ticket,
+        proceeds,
+        fee,
+    );
+    Ok(Some(winner))
+}
+
+/// Cancels a raffle before any tickets are sold.
+pub fn cancel(env: &Env, creator: Address, raffle_id: u64) -> Result<(), ContractError> {
+    creator.require_auth();
+    let raffle = storage::get_raffle(env, raffle_id)?;
+    if raffle.creator != creator {
+        return Err(ContractError::NotAuthorized);
+    }
+    if raffle.tickets_sold > 0 {
+        return Err(ContractError::TicketsSold);
+    }
+    storage::remove_raffle(env, raffle_id);
+    NftClient::new(env, &raffle.collection).transfer(
+        &env.current_contract_address(),
+        &creator,
+        &raffle.token_id,
+    );
+    events::emit_raffle_cancelled(env, raffle_id);
+    Ok(())
+}
+
+/// Sends `owner` every refund owed to them in `currency`. Returns the amount.
+pub fn withdraw_refund(
+    env: &Env,
+    owner: Address,
+    currency: Address,
+) -> Result<i128, ContractError> {
+    owner.require_auth();
+    let amount = storage::refund_of(env, &owner, &currency);
+    if amount <= 0 {
+        return Err(ContractError::NothingToRefund);
+    }
+    env.storage()
+        .instance()
+        .remove(&DataKey::Refund(owner.clone(), currency.clone()));
+    token::Client::new(env, &currency).transfer(&env.current_contract_address(), &owner, &amount);
+    events::emit_refund_withdrawn(env, owner, currency, amount);
+    Ok(amount)
+}
+
+/// Winning ticket index, from `sha256(secret || raffle id || entropy)`. The secret is empty
+/// after a missed reveal.
+fn pick_ticket(env: &Env, raffle: &Raffle) -> u32 {
+    let mut seed = raffle.secret.clone().unwrap_or(Bytes::new(env));
+    seed.extend_from_array(&raffle.id.to_be_bytes());
+    seed.extend_from_array(&raffle.entropy.to_array());
+    let digest = env.crypto().sha256(&seed).to_array();
+    let mut head = [0u8; 8];
+    head.copy_from_slice(&digest[..8]);
+    (u64::from_be_bytes(head) % raffle.tickets_sold as u64) as u32
+}
+
+fn ticket_owner(entries: &Vec<Entry>, ticket: u32) -> Address {
+    let mut upper = 0;
+    for entry in entries.iter() {
+        upper += entry.tickets;
+        if ticket < upper {
+            return entry.buyer;
+        }
+    }
+    unreachable!("ticket index is below tickets_sold")
+}
@@ -0,0 +1,80 @@
+use crate::error::ContractError;
+use crate::types::{Raffle, RaffleConfig};
+use soroban_sdk::{Address, Env, contracttype};
+
+/// Storage keys for the raffle contract.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// Contract admin.
+    Admin,
+    /// Protocol fee and reveal window.
+    Config,
+    /// Next raffle id to assign.
+    NextRaffleId,
+    /// Active raffle by id.
+    Raffle(u64),
+    /// Ticket refunds awaiting `withdraw_refund`: (entrant, currency).
+    Refund(Address, Address),
+}
+
+pub fn require_admin(env: &Env) -> Result<Address, ContractError> {
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(ContractError::NotInitialized)?;
+    admin.require_auth();
+    Ok(admin)
+}
+
+pub fn get_config(env: &Env) -> Result<RaffleConfig, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config)
+        .ok_or(ContractError::NotInitialized)
+}
+
+pub fn get_raffle(env: &Env, raffle_id: u64) -> Result<Raffle, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Raffle(raffle_id))
+        .ok_or(ContractError::RaffleNotFound)
+}
+
+pub fn set_raffle(env: &Env, raffle: &Raffle) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Raffle(raffle.id), raffle);
+}
+
+pub fn remove_raffle(env: &Env, raffle_id: u64) {
+    env.storage().instance().remove(&DataKey::Raffle(raffle_id));
+}
+
+/// Amount of `currency` owed to `owner` and awaiting `withdraw_refund`.
+pub fn refund_of(env: &Env, owner: &Address, currency: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Refund(owner.clone(), currency.clone()))
+        .unwrap_or(0)
+}
+
+pub fn credit_refund(env: &Env, owner: &Address, currency: &Address, amount: i128) {
+    let total = refund_of(env, owner, currency) + amount;
+    env.storage()
+        .instance()
+        .set(&DataKey::Refund(owner.clone(), currency.clone()), &total);
+}
+
+pub fn next_raffle_id(env: &Env) -> Result<u64, ContractError> {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextRaffleId)
+        .ok_or(ContractError::NotInitialized)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::NextRaffleId, &(id + 1));
+    Ok(id)
+}
@@ -0,0 +1,196 @@
+#![cfg(test)]
+
+use crate::{ContractError, RaffleConfig, RaffleHouse, RaffleHouseClient, RaffleTerms};
+use nft_contract::NftContractClient;
+use nft_contract::testutils::deploy_test_collection;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Bytes, BytesN, Env, String, Vec};
+
+struct Setup<'a> {
+    env: Env,
+    creator: Address,
+    treasury: Address,
+    alice: Address,
+    bob: Address,
+    nft: NftContractClient<'a>,
+    token: TokenClient<'a>,
+    house: RaffleHouseClient<'a>,
+    token_id: u64,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let nft = deploy_test_collection(&env, &creator, 0);
+    let uri = String::from_str(&env, "ipfs://prize");
+    let token_id = nft.mint(&creator, &creator, &uri, &Vec::new(&env), &None);
+
+    let sac = env.register_stellar_asset_contract_v2(creator.clone());
+    let token = TokenClient::new(&env, &sac.address());
+    let assets = StellarAssetClient::new(&env, &sac.address());
+    assets.mint(&alice, &1_000);
+    assets.mint(&bob, &1_000);
+
+    let house_id = env.register(RaffleHouse, ());
+    let house = RaffleHouseClient::new(&env, &house_id);
+    house.initialize(
+        &creator,
+        &RaffleConfig {
+            fee_recipient: treasury.clone(),
+            fee_bps: 500, // 5%
+            reveal_window: 3_600,
+        },
+    );
+
+    Setup {
+        env,
+        creator,
+        treasury,
+        alice,
+        bob,
+        nft,
+        token,
+        house,
+        token_id,
+    }
+}
+
+fn commit(s: &Setup, secret: &Bytes) -> BytesN<32> {
+    s.env.crypto().sha256(secret).into()
+}
+
+fn create_raffle(s: &Setup, secret: &Bytes) -> u64 {
+    s.house.create_raffle(
+        &s.creator,
+        &s.nft.address,
+        &s.token_id,
+        &RaffleTerms {
+            currency: s.token.address.clone(),
+            ticket_price: 100,
+            max_tickets: 10,
+            duration: 1_000,
+        },
+        &commit(s, secret),
+    )
+}
+
+#[test]
+fn test_draw_pays_creator_and_fee() {
+    let s = setup();
+    let secret = Bytes::from_array(&s.env, &[7; 32]);
+    let alice_secret = Bytes::from_array(&s.env, &[1; 32]);
+    let raffle_id = create_raffle(&s, &secret);
+    assert_eq!(s.nft.owner_of(&s.token_id), s.house.address);
+
+    s.house
+        .buy_tickets(&s.alice, &raffle_id, &3, &commit(&s, &alice_secret));
+    let bob_secret = Bytes::from_array(&s.env, &[2; 32]);
+    s.house
+        .buy_tickets(&s.bob, &raffle_id, &2, &commit(&s, &bob_secret));
+    assert_eq!(
+        s.house.try_reveal(&raffle_id, &secret),
+        Err(Ok(ContractError::RaffleActive))
+    );
+
+    s.env.ledger().set_timestamp(1_000);
+    assert_eq!(
+        s.house
+            .try_buy_tickets(&s.alice, &raffle_id, &1, &commit(&s, &alice_secret)),
+        Err(Ok(ContractError::RaffleEnded))
+    );
+    let wrong = Bytes::from_array(&s.env, &[8; 32]);
+    assert_eq!(
+        s.house.try_reveal(&raffle_id, &wrong),
+        Err(Ok(ContractError::InvalidReveal))
+    );
+    s.house.reveal(&raffle_id, &secret);
+    assert_eq!(
+        s.house.try_reveal(&raffle_id, &secret),
+        Err(Ok(ContractError::AlreadyRevealed))
+    );
+    assert_eq!(
+        s.house.try_reveal_entry(&s.alice, &raffle_id, &bob_secret),
+        Err(Ok(ContractError::InvalidReveal))
+    );
+    s.house.reveal_entry(&s.alice, &raffle_id, &alice_secret);
+    assert_eq!(
+        s.house
+            .try_reveal_entry(&s.alice, &raffle_id, &alice_secret),
+        Err(Ok(ContractError::AlreadyRevealed))
+    );
+    assert_eq!(
+        s.house.try_draw(&raffle_id),
+        Err(Ok(ContractError::RevealPending))
+    );
+
+    s.env.ledger().set_timestamp(1_000 + 3_600);
+    let winner = s.house.draw(&raffle_id).unwrap();
+
+    assert!(winner == s.alice || winner == s.bob);
+    assert_eq!(s.nft.owner_of(&s.token_id), winner);
+    assert_eq!(s.token.balance(&s.treasury), 25);
+    assert_eq!(s.token.balance(&s.creator), 475);
+}
+
+#[test]
+fn test_missed_reveal_forfeits_prize_and_proceeds() {
+    let s = setup();
+    let secret = Bytes::from_array(&s.env, &[7; 32]);
+    let raffle_id = create_raffle(&s, &secret);
+    let alice_secret = Bytes::from_array(&s.env, &[1; 32]);
+    s.house
+        .buy_tickets(&s.alice, &raffle_id, &4, &commit(&s, &alice_secret));
+
+    s.env.ledger().set_timestamp(1_000 + 3_600);
+    assert_eq!(
+        s.house.try_reveal(&raffle_id, &secret),
+        Err(Ok(ContractError::RevealExpired))
+    );
+    // The prize is still drawn, and every ticket is refunded.
+    assert_eq!(s.house.draw(&raffle_id), Some(s.alice.clone()));
+    assert_eq!(s.nft.owner_of(&s.token_id), s.alice);
+
+    assert_eq!(s.house.get_refund(&s.alice, &s.token.address), 400);
+    assert_eq!(s.house.withdraw_refund(&s.alice, &s.token.address), 400);
+    assert_eq!(s.token.balance(&s.alice), 1_000);
+    assert_eq!(s.token.balance(&s.creator), 0);
+    assert_eq!(
+        s.house.try_withdraw_refund(&s.alice, &s.token.address),
+        Err(Ok(ContractError::NothingToRefund))
+    );
+}
+
+#[test]
+fn test_draw_is_fixed_by_revealed_secrets() {
+    let draw_with_prng_seed = |prng_seed: u8| {
+        let s = setup();
+        let secret = Bytes::from_array(&s.env, &[9; 32]);
+        let raffle_id = create_raffle(&s, &secret);
+        let alice_secret = Bytes::from_array(&s.env, &[1; 32]);
+        let bob_secret = Bytes::from_array(&s.env, &[2; 32]);
+        s.house
+            .buy_tickets(&s.alice, &raffle_id, &5, &commit(&s, &alice_secret));
+        s.house
+            .buy_tickets(&s.bob, &raffle_id, &5, &commit(&s, &bob_secret));
+        s.env.ledger().set_timestamp(1_000);
+        s.house.reveal(&raffle_id, &secret);
+        s.house.reveal_entry(&s.alice, &raffle_id, &alice_secret);
+        s.house.reveal_entry(&s.bob, &raffle_id, &bob_secret);
+        s.env.ledger().set_timestamp(1_000 + 3_600);
+        // Drawing in another ledger (another PRNG seed) must not re-roll the result.
+        s.env.host().set_base_prng_seed([prng_seed; 32]).unwrap();
+        let winner = s.house.draw(&raffle_id).unwrap();
+        if winner == s.alice { 0 } else { 1 }
+    };
+    let first = draw_with_prng_seed(0);
+    for prng_seed in 1..8 {
+        assert_eq!(draw_with_prng_seed(prng_seed), first);
+    }
+}
@@ -0,0 +1,61 @@
+use soroban_sdk::{Address, Bytes, BytesN, Vec, contracttype};
+
+/// Protocol settings shared by all raffles.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RaffleConfig {
+    /// Receives the protocol fee on ticket proceeds.
+    pub fee_recipient: Address,
+    /// Protocol fee in basis points.
+    pub fee_bps: u32,
+    /// Seconds after ticket sales end during which the creator and entrants reveal their
+    /// secrets; the draw follows.
+    pub reveal_window: u64,
+}
+
+/// Ticket sale terms chosen by the creator.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RaffleTerms {
+    /// SEP-41 token tickets are paid in.
+    pub currency: Address,
+    pub ticket_price: i128,
+    pub max_tickets: u32,
+    /// Seconds ticket sales stay open.
+    pub duration: u64,
+}
+
+/// Tickets held by one entrant.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Entry {
+    pub buyer: Address,
+    pub tickets: u32,
+    /// `sha256(secret)` for the entrant secret mixed into the draw.
+    pub commitment: BytesN<32>,
+    /// Whether the entrant secret has been revealed.
+    pub revealed: bool,
+}
+
+/// NFT raffle. The prize and ticket proceeds are held in escrow until the draw.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Raffle {
+    pub id: u64,
+    pub creator: Address,
+    pub collection: Address,
+    pub token_id: u64,
+    /// SEP-41 token tickets are paid in.
+    pub currency: Address,
+    pub ticket_price: i128,
+    pub max_tickets: u32,
+    pub end_time: u64,
+    /// `sha256(secret)`, committed by the creator and revealed after sales end.
+    pub commitment: BytesN<32>,
+    pub tickets_sold: u32,
+    pub entries: Vec<Entry>,
+    /// Hash chain over the creator commitment and the entrant secrets, in reveal order.
+    pub entropy: BytesN<32>,
+    /// Creator secret, set once revealed.
+    pub secret: Option<Bytes>,
+}
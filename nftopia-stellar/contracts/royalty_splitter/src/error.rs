@@ -0,0 +1,20 @@
+use soroban_sdk::contracterror;
+
+/// Contract-specific errors for the royalty splitter.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    /// Contract has already been initialized.
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized.
+    NotInitialized = 2,
+    /// Payee list is empty, too long, has duplicates, or a zero share.
+    InvalidPayees = 3,
+    /// Address is not a configured payee.
+    NotPayee = 4,
+    /// Payee has nothing left to release for this token.
+    NothingDue = 5,
+    /// Deposit amount must be positive.
+    InvalidAmount = 6,
+}
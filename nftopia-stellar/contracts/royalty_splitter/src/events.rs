@@ -0,0 +1,37 @@
+use soroban_sdk::{Address, Env, contractevent};
+
+/// Payment tokens deposited into the splitter.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Deposited {
+    pub from: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// A payee's accrued share was paid out.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Released {
+    pub token: Address,
+    pub payee: Address,
+    pub amount: i128,
+}
+
+pub fn emit_deposited(env: &Env, from: Address, token: Address, amount: i128) {
+    Deposited {
+        from,
+        token,
+        amount,
+    }
+    .publish(env);
+}
+
+pub fn emit_released(env: &Env, token: Address, payee: Address, amount: i128) {
+    Released {
+        token,
+        payee,
+        amount,
+    }
+    .publish(env);
+}
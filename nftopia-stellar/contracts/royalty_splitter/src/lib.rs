@@ -0,0 +1,93 @@
+#![no_std]
+
+mod error;
+mod events;
+mod splitter;
+mod storage;
+mod types;
+
+pub use error::ContractError;
+pub use splitter::MAX_PAYEES;
+pub use types::Payee;
+
+use soroban_sdk::{Address, Env, Vec, contract, contractimpl};
+
+use crate::error::ContractError as Err;
+use crate::storage::DataKey;
+
+#[contract]
+pub struct RoyaltySplitter;
+
+#[contractimpl]
+impl RoyaltySplitter {
+    /// Initializes the splitter with a fixed set of payees and shares. Payees cannot be
+    /// changed afterwards, so the split can be trusted by everyone it pays.
+    pub fn initialize(env: Env, payees: Vec<Payee>) -> Result<(), Err> {
+        if env.storage().instance().has(&DataKey::Payees) {
+            return Err(Err::AlreadyInitialized);
+        }
+        if payees.is_empty() || payees.len() > MAX_PAYEES {
+            return Err(Err::InvalidPayees);
+        }
+        let mut total_shares: u32 = 0;
+        for (i, payee) in payees.iter().enumerate() {
+            if payee.shares == 0
+                || payees
+                    .iter()
+                    .skip(i + 1)
+                    .any(|p| p.address == payee.address)
+            {
+                return Err(Err::InvalidPayees);
+            }
+            total_shares = total_shares
+                .checked_add(payee.shares)
+                .ok_or(Err::InvalidPayees)?;
+        }
+        env.storage().instance().set(&DataKey::Payees, &payees);
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalShares, &total_shares);
+        Ok(())
+    }
+
+    // --- Payments ---
+    pub fn deposit(env: Env, from: Address, token: Address, amount: i128) -> Result<(), Err> {
+        splitter::deposit(&env, from, token, amount)
+    }
+
+    pub fn release(env: Env, token: Address, payee: Address) -> Result<i128, Err> {
+        splitter::release(&env, token, payee)
+    }
+
+    pub fn release_all(env: Env, token: Address) -> Result<i128, Err> {
+        splitter::release_all(&env, token)
+    }
+
+    pub fn releasable(env: Env, token: Address, payee: Address) -> Result<i128, Err> {
+        splitter::releasable(&env, &token, &payee)
+    }
+
+    // --- Views ---
+    pub fn payees(env: Env) -> Result<Vec<Payee>, Err> {
+        storage::get_payees(&env)
+    }
+
+    pub fn shares(env: Env, payee: Address) -> Result<u32, Err> {
+        splitter::shares(&env, &payee)
+    }
+
+    pub fn total_shares(env: Env) -> Result<u32, Err> {
+        storage::total_shares(&env)
+    }
+
+    pub fn released(env: Env, token: Address, payee: Address) -> i128 {
+        storage::released(&env, &token, &payee)
+    }
+
+    pub fn total_released(env: Env, token: Address) -> i128 {
+        storage::total_released(&env, &token)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,91 @@
+use crate::error::ContractError;
+use crate::events;
+use crate::storage;
+use crate::types::Payee;
+use soroban_sdk::{Address, Env, token};
+
+/// Maximum number of payees, keeping `release_all` within Soroban budget.
+pub const MAX_PAYEES: u32 = 50;
+
+/// Moves `amount` of `token` from `from` into the splitter. Plain transfers to the
+/// contract address (e.g. marketplace royalty payouts) are accounted for the same way.
+pub fn deposit(
+    env: &Env,
+    from: Address,
+    token: Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    from.require_auth();
+    storage::total_shares(env)?;
+    if amount <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    let contract = env.current_contract_address();
+    token::Client::new(env, &token).transfer(&from, &contract, &amount);
+    events::emit_deposited(env, from, token, amount);
+    Ok(())
+}
+
+/// Amount of `token` currently owed to `payee`: its share of everything the splitter has
+/// ever received (current balance plus prior releases), minus what it was already paid.
+pub fn releasable(env: &Env, token: &Address, payee: &Address) -> Result<i128, ContractError> {
+    let shares = find_payee(env, payee)?.shares;
+    Ok(pending(
+        env,
+        token,
+        payee,
+        shares,
+        storage::total_shares(env)?,
+    ))
+}
+
+/// Pays `payee` its accrued share of `token`. Callable by anyone; funds only go to the payee.
+pub fn release(env: &Env, token: Address, payee: Address) -> Result<i128, ContractError> {
+    let shares = find_payee(env, &payee)?.shares;
+    let amount = pending(env, &token, &payee, shares, storage::total_shares(env)?);
+    if amount <= 0 {
+        return Err(ContractError::NothingDue);
+    }
+    pay(env, &token, payee, amount);
+    Ok(amount)
+}
+
+/// Pays every payee its accrued share of `token`, skipping payees with nothing due.
+/// Returns the total amount released.
+pub fn release_all(env: &Env, token: Address) -> Result<i128, ContractError> {
+    let total_shares = storage::total_shares(env)?;
+    // Each payout lowers the balance and raises total released by the same amount, so
+    // total received (and therefore every later payee's due) is unaffected.
+    let mut total = 0;
+    for payee in storage::get_payees(env)?.iter() {
+        let amount = pending(env, &token, &payee.address, payee.shares, total_shares);
+        if amount > 0 {
+            pay(env, &token, payee.address, amount);
+            total += amount;
+        }
+    }
+    Ok(total)
+}
+
+pub fn shares(env: &Env, payee: &Address) -> Result<u32, ContractError> {
+    Ok(find_payee(env, payee)?.shares)
+}
+
+fn find_payee(env: &Env, address: &Address) -> Result<Payee, ContractError> {
+    storage::get_payees(env)?
+        .iter()
+        .find(|p| p.address == *address)
+        .ok_or(ContractError::NotPayee)
+}
+
+fn pending(env: &Env, token: &Address, payee: &Address, shares: u32, total_shares: u32) -> i128 {
+    let balance = token::Client::new(env, token).balance(&env.current_contract_address());
+    let total_received = balance + storage::total_released(env, token);
+    total_received * shares as i128 / total_shares as i128 - storage::released(env, token, payee)
+}
+
+fn pay(env: &Env, token: &Address, payee: Address, amount: i128) {
+    storage::add_released(env, token, &payee, amount);
+    token::Client::new(env, token).transfer(&env.current_contract_address(), &payee, &amount);
+    events::emit_released(env, token.clone(), payee, amount);
+}
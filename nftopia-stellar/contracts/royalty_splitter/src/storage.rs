@@ -0,0 +1,57 @@
+use crate::error::ContractError;
+use crate::types::Payee;
+use soroban_sdk::{Address, Env, Vec, contracttype};
+
+/// Storage keys for the royalty splitter.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// Configured payees, fixed at initialization.
+    Payees,
+    /// Sum of all payee shares.
+    TotalShares,
+    /// Total amount of a payment token released to payees.
+    TotalReleased(Address),
+    /// Amount of a payment token released to a single payee: (token, payee).
+    Released(Address, Address),
+}
+
+pub fn get_payees(env: &Env) -> Result<Vec<Payee>, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Payees)
+        .ok_or(ContractError::NotInitialized)
+}
+
+pub fn total_shares(env: &Env) -> Result<u32, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalShares)
+        .ok_or(ContractError::NotInitialized)
+}
+
+pub fn total_released(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalReleased(token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn released(env: &Env, token: &Address, payee: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Released(token.clone(), payee.clone()))
+        .unwrap_or(0)
+}
+
+pub fn add_released(env: &Env, token: &Address, payee: &Address, amount: i128) {
+    let storage = env.storage().instance();
+    storage.set(
+        &DataKey::Released(token.clone(), payee.clone()),
+        &(released(env, token, payee) + amount),
+    );
+    storage.set(
+        &DataKey::TotalReleased(token.clone()),
+        &(total_released(env, token) + amount),
+    );
+}
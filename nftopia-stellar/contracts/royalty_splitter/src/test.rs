@@ -0,0 +1,100 @@
+#![cfg(test)]
+
+use crate::{ContractError, Payee, RoyaltySplitter, RoyaltySplitterClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, vec};
+
+struct Setup<'a> {
+    env: Env,
+    alice: Address,
+    bob: Address,
+    payer: Address,
+    token: TokenClient<'a>,
+    splitter: RoyaltySplitterClient<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    let sac = env.register_stellar_asset_contract_v2(payer.clone());
+    let token = TokenClient::new(&env, &sac.address());
+    StellarAssetClient::new(&env, &sac.address()).mint(&payer, &10_000);
+
+    let splitter_id = env.register(RoyaltySplitter, ());
+    let splitter = RoyaltySplitterClient::new(&env, &splitter_id);
+    splitter.initialize(&vec![
+        &env,
+        Payee {
+            address: alice.clone(),
+            shares: 70,
+        },
+        Payee {
+            address: bob.clone(),
+            shares: 30,
+        },
+    ]);
+
+    Setup {
+        env,
+        alice,
+        bob,
+        payer,
+        token,
+        splitter,
+    }
+}
+
+#[test]
+fn test_release_splits_deposits_and_direct_transfers() {
+    let s = setup();
+    s.splitter.deposit(&s.payer, &s.token.address, &1_000);
+
+    assert_eq!(s.splitter.releasable(&s.token.address, &s.alice), 700);
+    assert_eq!(s.splitter.release(&s.token.address, &s.alice), 700);
+    assert_eq!(
+        s.splitter.try_release(&s.token.address, &s.alice),
+        Err(Ok(ContractError::NothingDue))
+    );
+
+    // Royalties sent straight to the splitter address are split the same way.
+    s.token.transfer(&s.payer, &s.splitter.address, &1_000);
+    assert_eq!(s.splitter.release_all(&s.token.address), 1_300);
+
+    assert_eq!(s.token.balance(&s.alice), 1_400);
+    assert_eq!(s.token.balance(&s.bob), 600);
+    assert_eq!(s.splitter.total_released(&s.token.address), 2_000);
+    assert_eq!(s.token.balance(&s.splitter.address), 0);
+}
+
+#[test]
+fn test_invalid_payees_rejected() {
+    let s = setup();
+    let id = s.env.register(RoyaltySplitter, ());
+    let splitter = RoyaltySplitterClient::new(&s.env, &id);
+    let duplicate = vec![
+        &s.env,
+        Payee {
+            address: s.alice.clone(),
+            shares: 1,
+        },
+        Payee {
+            address: s.alice.clone(),
+            shares: 1,
+        },
+    ];
+    assert_eq!(
+        splitter.try_initialize(&duplicate),
+        Err(Ok(ContractError::InvalidPayees))
+    );
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.splitter.try_release(&s.token.address, &stranger),
+        Err(Ok(ContractError::NotPayee))
+    );
+}
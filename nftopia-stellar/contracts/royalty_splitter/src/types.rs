@@ -0,0 +1,9 @@
+use soroban_sdk::{Address, contracttype};
+
+/// A payee and its fixed share of every payment the splitter receives.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Payee {
+    pub address: Address,
+    pub shares: u32,
+}
@@ -0,0 +1,24 @@
+use soroban_sdk::contracterror;
+
+/// Contract-specific errors for the swap contract.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    /// Caller is not a party to the swap.
+    NotAuthorized = 1,
+    /// Contract has already been initialized.
+    AlreadyInitialized = 2,
+    /// Contract has not been initialized.
+    NotInitialized = 3,
+    /// Swap not found (never proposed, executed, or withdrawn).
+    SwapNotFound = 4,
+    /// Swap terms are invalid (empty side, too many tokens, negative payment, same party).
+    InvalidTerms = 5,
+    /// Party has already deposited.
+    AlreadyDeposited = 6,
+    /// Party must deposit before confirming.
+    NotDeposited = 7,
+    /// Party has already confirmed.
+    AlreadyConfirmed = 8,
+}
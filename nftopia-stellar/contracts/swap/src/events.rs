@@ -0,0 +1,66 @@
+use soroban_sdk::{Address, Env, contractevent};
+
+/// Swap proposed.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct SwapProposed {
+    pub swap_id: u64,
+    pub maker: Address,
+    pub taker: Address,
+}
+
+/// A party moved its assets into escrow.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct SwapDeposited {
+    pub swap_id: u64,
+    pub party: Address,
+}
+
+/// A party confirmed the swap.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct SwapConfirmed {
+    pub swap_id: u64,
+    pub party: Address,
+}
+
+/// Both sides exchanged.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct SwapExecuted {
+    pub swap_id: u64,
+}
+
+/// Swap abandoned; escrowed assets returned to their depositors.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct SwapWithdrawn {
+    pub swap_id: u64,
+    pub by: Address,
+}
+
+pub fn emit_swap_proposed(env: &Env, swap_id: u64, maker: Address, taker: Address) {
+    SwapProposed {
+        swap_id,
+        maker,
+        taker,
+    }
+    .publish(env);
+}
+
+pub fn emit_swap_deposited(env: &Env, swap_id: u64, party: Address) {
+    SwapDeposited { swap_id, party }.publish(env);
+}
+
+pub fn emit_swap_confirmed(env: &Env, swap_id: u64, party: Address) {
+    SwapConfirmed { swap_id, party }.publish(env);
+}
+
+pub fn emit_swap_executed(env: &Env, swap_id: u64) {
+    SwapExecuted { swap_id }.publish(env);
+}
+
+pub fn emit_swap_withdrawn(env: &Env, swap_id: u64, by: Address) {
+    SwapWithdrawn { swap_id, by }.publish(env);
+}
@@ -0,0 +1,62 @@
+#![no_std]
+
+mod error;
+mod events;
+mod storage;
+mod swap;
+mod types;
+
+pub use error::ContractError;
+pub use types::{NftRef, Payment, Swap, SwapAssets};
+
+use soroban_sdk::{Address, Env, contract, contractimpl};
+
+use crate::error::ContractError as Err;
+use crate::storage::DataKey;
+
+#[contract]
+pub struct NftSwap;
+
+#[contractimpl]
+impl NftSwap {
+    /// Initializes the swap contract.
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Err> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Err::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::NextSwapId, &0u64);
+        Ok(())
+    }
+
+    // --- Swaps ---
+    /// Proposes a swap between `maker` and `taker` with fixed terms for each side.
+    pub fn propose(
+        env: Env,
+        maker: Address,
+        taker: Address,
+        maker_assets: SwapAssets,
+        taker_assets: SwapAssets,
+    ) -> Result<u64, Err> {
+        swap::propose(&env, maker, taker, maker_assets, taker_assets)
+    }
+
+    pub fn deposit(env: Env, party: Address, swap_id: u64) -> Result<(), Err> {
+        swap::deposit(&env, party, swap_id)
+    }
+
+    pub fn confirm(env: Env, party: Address, swap_id: u64) -> Result<(), Err> {
+        swap::confirm(&env, party, swap_id)
+    }
+
+    pub fn withdraw(env: Env, party: Address, swap_id: u64) -> Result<(), Err> {
+        swap::withdraw(&env, party, swap_id)
+    }
+
+    pub fn get_swap(env: Env, swap_id: u64) -> Result<Swap, Err> {
+        storage::get_swap(&env, swap_id)
+    }
+}
+
+#[cfg(test)]
+mod test;
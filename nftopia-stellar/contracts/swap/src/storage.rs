@@ -0,0 +1,38 @@
+use crate::error::ContractError;
+use crate::types::Swap;
+use soroban_sdk::{Env, contracttype};
+
+/// Storage keys for the swap contract.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// Contract admin.
+    Admin,
+    /// Next swap id to assign.
+    NextSwapId,
+    /// Open swap by id.
+    Swap(u64),
+}
+
+pub fn get_swap(env: &Env, swap_id: u64) -> Result<Swap, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Swap(swap_id))
+        .ok_or(ContractError::SwapNotFound)
+}
+
+pub fn set_swap(env: &Env, swap: &Swap) {
+    env.storage().instance().set(&DataKey::Swap(swap.id), swap);
+}
+
+pub fn next_swap_id(env: &Env) -> Result<u64, ContractError> {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextSwapId)
+        .ok_or(ContractError::NotInitialized)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::NextSwapId, &(id + 1));
+    Ok(id)
+}
@@ -0,0 +1,135 @@
+use crate::error::ContractError;
+use crate::events;
+use crate::storage::{self, DataKey};
+use crate::types::{Swap, SwapAssets};
+use nft_interface::NftClient;
+use soroban_sdk::{Address, Env, token};
+
+/// Maximum NFTs plus payments per side, keeping deposit and execution within Soroban budget.
+pub const MAX_ASSETS_PER_SIDE: u32 = 20;
+
+fn validate_assets(assets: &SwapAssets) -> Result<(), ContractError> {
+    if assets.nfts.len() + assets.payments.len() > MAX_ASSETS_PER_SIDE
+        || assets.payments.iter().any(|p| p.amount <= 0)
+    {
+        return Err(ContractError::InvalidTerms);
+    }
+    Ok(())
+}
+
+/// Moves `assets` between `from` and `to`. Either side may be this contract (escrow).
+fn move_assets(env: &Env, assets: &SwapAssets, from: &Address, to: &Address) {
+    for nft in assets.nfts.iter() {
+        NftClient::new(env, &nft.collection).transfer(from, to, &nft.token_id);
+    }
+    for p in assets.payments.iter() {
+        token::Client::new(env, &p.currency).transfer(from, to, &p.amount);
+    }
+}
+
+/// Proposes a swap. Nothing is escrowed until each party calls deposit.
+pub fn propose(
+    env: &Env,
+    maker: Address,
+    taker: Address,
+    maker_assets: SwapAssets,
+    taker_assets: SwapAssets,
+) -> Result<u64, ContractError> {
+    maker.require_auth();
+    if maker == taker || (maker_assets.nfts.is_empty() && taker_assets.nfts.is_empty()) {
+        return Err(ContractError::InvalidTerms);
+    }
+    validate_assets(&maker_assets)?;
+    validate_assets(&taker_assets)?;
+    let swap = Swap {
+        id: storage::next_swap_id(env)?,
+        maker: maker.clone(),
+        taker: taker.clone(),
+        maker_assets,
+        taker_assets,
+        maker_deposited: false,
+        taker_deposited: false,
+        maker_confirmed: false,
+        taker_confirmed: false,
+    };
+    storage::set_swap(env, &swap);
+    events::emit_swap_proposed(env, swap.id, maker, taker);
+    Ok(swap.id)
+}
+
+/// Escrows the caller's side of the swap.
+pub fn deposit(env: &Env, party: Address, swap_id: u64) -> Result<(), ContractError> {
+    party.require_auth();
+    let mut swap = storage::get_swap(env, swap_id)?;
+    let contract = env.current_contract_address();
+    if party == swap.maker {
+        if swap.maker_deposited {
+            return Err(ContractError::AlreadyDeposited);
+        }
+        move_assets(env, &swap.maker_assets, &party, &contract);
+        swap.maker_deposited = true;
+    } else if party == swap.taker {
+        if swap.taker_deposited {
+            return Err(ContractError::AlreadyDeposited);
+        }
+        move_assets(env, &swap.taker_assets, &party, &contract);
+        swap.taker_deposited = true;
+    } else {
+        return Err(ContractError::NotAuthorized);
+    }
+    storage::set_swap(env, &swap);
+    events::emit_swap_deposited(env, swap_id, party);
+    Ok(())
+}
+
+/// Confirms the swap. Requires the caller's deposit; the second confirmation executes the exchange.
+pub fn confirm(env: &Env, party: Address, swap_id: u64) -> Result<(), ContractError> {
+    party.require_auth();
+    let mut swap = storage::get_swap(env, swap_id)?;
+    let (deposited, confirmed) = if party == swap.maker {
+        (swap.maker_deposited, &mut swap.maker_confirmed)
+    } else if party == swap.taker {
+        (swap.taker_deposited, &mut swap.taker_confirmed)
+    } else {
+        return Err(ContractError::NotAuthorized);
+    };
+    if !deposited {
+        return Err(ContractError::NotDeposited);
+    }
+    if *confirmed {
+        return Err(ContractError::AlreadyConfirmed);
+    }
+    *confirmed = true;
+    events::emit_swap_confirmed(env, swap_id, party);
+
+    if swap.maker_confirmed && swap.taker_confirmed {
+        env.storage().instance().remove(&DataKey::Swap(swap_id));
+        let contract = env.current_contract_address();
+        move_assets(env, &swap.maker_assets, &contract, &swap.taker);
+        move_assets(env, &swap.taker_assets, &contract, &swap.maker);
+        events::emit_swap_executed(env, swap_id);
+    } else {
+        storage::set_swap(env, &swap);
+    }
+    Ok(())
+}
+
+/// Abandons the swap before it executes, returning every escrowed asset to its depositor.
+/// Either party may call.
+pub fn withdraw(env: &Env, party: Address, swap_id: u64) -> Result<(), ContractError> {
+    party.require_auth();
+    let swap = storage::get_swap(env, swap_id)?;
+    if party != swap.maker && party != swap.taker {
+        return Err(ContractError::NotAuthorized);
+    }
+    env.storage().instance().remove(&DataKey::Swap(swap_id));
+    let contract = env.current_contract_address();
+    if swap.maker_deposited {
+        move_assets(env, &swap.maker_assets, &contract, &swap.maker);
+    }
+    if swap.taker_deposited {
+        move_assets(env, &swap.taker_assets, &contract, &swap.taker);
+    }
+    events::emit_swap_withdrawn(env, swap_id, party);
+    Ok(())
+}
@@ -0,0 +1,127 @@
+#![cfg(test)]
+
+use crate::{ContractError, NftRef, NftSwap, NftSwapClient, Payment, SwapAssets};
+use nft_contract::NftContractClient;
+use nft_contract::testutils::deploy_test_collection;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, String, Vec, vec};
+
+struct Setup<'a> {
+    env: Env,
+    alice: Address,
+    bob: Address,
+    nft: NftContractClient<'a>,
+    token: TokenClient<'a>,
+    swap: NftSwapClient<'a>,
+    alice_token: u64,
+    bob_token: u64,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let creator = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let nft = deploy_test_collection(&env, &creator, 0);
+    let uri = String::from_str(&env, "ipfs://hash");
+    let alice_token = nft.mint(&creator, &alice, &uri, &Vec::new(&env), &None);
+    let bob_token = nft.mint(&creator, &bob, &uri, &Vec::new(&env), &None);
+
+    let sac = env.register_stellar_asset_contract_v2(creator.clone());
+    let token = TokenClient::new(&env, &sac.address());
+    StellarAssetClient::new(&env, &sac.address()).mint(&bob, &1_000);
+
+    let swap_id = env.register(NftSwap, ());
+    let swap = NftSwapClient::new(&env, &swap_id);
+    swap.initialize(&creator);
+
+    Setup {
+        env,
+        alice,
+        bob,
+        nft,
+        token,
+        swap,
+        alice_token,
+        bob_token,
+    }
+}
+
+fn propose(s: &Setup) -> u64 {
+    let maker_assets = SwapAssets {
+        nfts: vec![
+            &s.env,
+            NftRef {
+                collection: s.nft.address.clone(),
+                token_id: s.alice_token,
+            },
+        ],
+        payments: Vec::new(&s.env),
+    };
+    let taker_assets = SwapAssets {
+        nfts: vec![
+            &s.env,
+            NftRef {
+                collection: s.nft.address.clone(),
+                token_id: s.bob_token,
+            },
+        ],
+        payments: vec![
+            &s.env,
+            Payment {
+                currency: s.token.address.clone(),
+                amount: 250,
+            },
+        ],
+    };
+    s.swap
+        .propose(&s.alice, &s.bob, &maker_assets, &taker_assets)
+}
+
+#[test]
+fn test_swap_executes_after_both_confirm() {
+    let s = setup();
+    let swap_id = propose(&s);
+
+    assert_eq!(
+        s.swap.try_confirm(&s.alice, &swap_id),
+        Err(Ok(ContractError::NotDeposited))
+    );
+    s.swap.deposit(&s.alice, &swap_id);
+    s.swap.confirm(&s.alice, &swap_id);
+    s.swap.deposit(&s.bob, &swap_id);
+    assert_eq!(s.nft.owner_of(&s.bob_token), s.swap.address);
+    s.swap.confirm(&s.bob, &swap_id);
+
+    assert_eq!(s.nft.owner_of(&s.alice_token), s.bob);
+    assert_eq!(s.nft.owner_of(&s.bob_token), s.alice);
+    assert_eq!(s.token.balance(&s.alice), 250);
+    assert_eq!(
+        s.swap.try_get_swap(&swap_id),
+        Err(Ok(ContractError::SwapNotFound))
+    );
+}
+
+#[test]
+fn test_withdraw_returns_deposits() {
+    let s = setup();
+    let swap_id = propose(&s);
+
+    s.swap.deposit(&s.alice, &swap_id);
+    s.swap.confirm(&s.alice, &swap_id);
+    s.swap.deposit(&s.bob, &swap_id);
+    s.swap.withdraw(&s.bob, &swap_id);
+
+    assert_eq!(s.nft.owner_of(&s.alice_token), s.alice);
+    assert_eq!(s.nft.owner_of(&s.bob_token), s.bob);
+    assert_eq!(s.token.balance(&s.bob), 1_000);
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.swap.try_withdraw(&stranger, &swap_id),
+        Err(Ok(ContractError::SwapNotFound))
+    );
+}
@@ -0,0 +1,42 @@
+use soroban_sdk::{Address, Vec, contracttype};
+
+/// A token in an NFTopia collection.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct NftRef {
+    pub collection: Address,
+    pub token_id: u64,
+}
+
+/// A payment-token amount added to one side of a swap.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Payment {
+    /// SEP-41 token.
+    pub currency: Address,
+    pub amount: i128,
+}
+
+/// Everything one party puts into a swap.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct SwapAssets {
+    pub nfts: Vec<NftRef>,
+    /// Optional payment-token sweeteners (empty for none).
+    pub payments: Vec<Payment>,
+}
+
+/// Two-party escrow swap. Executes atomically once both parties have deposited and confirmed.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Swap {
+    pub id: u64,
+    pub maker: Address,
+    pub taker: Address,
+    pub maker_assets: SwapAssets,
+    pub taker_assets: SwapAssets,
+    pub maker_deposited: bool,
+    pub taker_deposited: bool,
+    pub maker_confirmed: bool,
+    pub taker_confirmed: bool,
+}
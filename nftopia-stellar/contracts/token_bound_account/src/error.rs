@@ -0,0 +1,14 @@
+use soroban_sdk::contracterror;
+
+/// Contract-specific errors for token-bound accounts.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    /// Account has already been bound to a token.
+    AlreadyInitialized = 1,
+    /// Account has not been bound to a token.
+    NotInitialized = 2,
+    /// The bound token does not exist (e.g. it was burned).
+    TokenNotFound = 3,
+}
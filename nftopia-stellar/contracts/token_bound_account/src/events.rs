@@ -0,0 +1,19 @@
+use soroban_sdk::{Address, Env, Symbol, contractevent};
+
+/// The token owner made the account call `func` on `contract`.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Executed {
+    pub owner: Address,
+    pub contract: Address,
+    pub func: Symbol,
+}
+
+pub fn emit_executed(env: &Env, owner: Address, contract: Address, func: Symbol) {
+    Executed {
+        owner,
+        contract,
+        func,
+    }
+    .publish(env);
+}
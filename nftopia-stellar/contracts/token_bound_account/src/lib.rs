@@ -0,0 +1,79 @@
+#![no_std]
+
+mod error;
+mod events;
+
+pub use error::ContractError;
+
+use soroban_sdk::{Address, Env, Symbol, Val, Vec, contract, contractimpl, contracttype};
+
+use crate::error::ContractError as Err;
+use nft_interface::NftClient;
+
+/// Storage keys for a token-bound account.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// NFT contract of the token that controls this account.
+    Nft,
+    /// Id of the token that controls this account.
+    TokenId,
+}
+
+/// Account contract bound to a single NFT. Whoever currently owns the token controls the
+/// account, so assets held here move with the NFT when it is sold or transferred.
+#[contract]
+pub struct TokenBoundAccount;
+
+#[contractimpl]
+impl TokenBoundAccount {
+    /// Binds the account to `token_id` of `nft`. Called once by the registry right after
+    /// deployment.
+    pub fn initialize(env: Env, nft: Address, token_id: u64) -> Result<(), Err> {
+        if env.storage().instance().has(&DataKey::Nft) {
+            return Err(Err::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Nft, &nft);
+        env.storage().instance().set(&DataKey::TokenId, &token_id);
+        Ok(())
+    }
+
+    /// Calls `func` on `contract` as this account. Only the token's current owner may execute;
+    /// contracts that `require_auth` on the account accept the call since it invokes them
+    /// directly.
+    pub fn execute(env: Env, contract: Address, func: Symbol, args: Vec<Val>) -> Result<Val, Err> {
+        let owner = Self::owner(env.clone())?;
+        owner.require_auth();
+        let result = env.invoke_contract::<Val>(&contract, &func, args);
+        events::emit_executed(&env, owner, contract, func);
+        Ok(result)
+    }
+
+    // --- Views ---
+    /// The NFT contract and token id this account is bound to.
+    pub fn token(env: Env) -> Result<(Address, u64), Err> {
+        let nft: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Nft)
+            .ok_or(Err::NotInitialized)?;
+        let token_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TokenId)
+            .ok_or(Err::NotInitialized)?;
+        Ok((nft, token_id))
+    }
+
+    /// Current owner of the bound token, i.e. the account's controller.
+    pub fn owner(env: Env) -> Result<Address, Err> {
+        let (nft, token_id) = Self::token(env.clone())?;
+        match NftClient::new(&env, &nft).try_owner_of(&token_id) {
+            Ok(Ok(owner)) => Ok(owner),
+            _ => Err(Err::TokenNotFound),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test;
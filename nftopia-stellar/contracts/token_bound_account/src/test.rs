@@ -0,0 +1,83 @@
+#![cfg(test)]
+
+use crate::{ContractError, TokenBoundAccount, TokenBoundAccountClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{
+    Address, Env, IntoVal, Symbol, contract, contractimpl, contracttype, symbol_short, vec,
+};
+
+#[contracttype]
+enum MockKey {
+    Owner(u64),
+}
+
+/// Minimal NFT: owners are set directly by the test.
+#[contract]
+struct MockNft;
+
+#[contractimpl]
+impl MockNft {
+    pub fn set_owner(env: Env, token_id: u64, owner: Address) {
+        env.storage()
+            .instance()
+            .set(&MockKey::Owner(token_id), &owner);
+    }
+
+    pub fn owner_of(env: Env, token_id: u64) -> Address {
+        env.storage()
+            .instance()
+            .get(&MockKey::Owner(token_id))
+            .unwrap()
+    }
+}
+
+#[test]
+fn test_owner_follows_token_and_executes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let nft_id = env.register(MockNft, ());
+    let nft = MockNftClient::new(&env, &nft_id);
+    nft.set_owner(&7, &alice);
+
+    let account_id = env.register(TokenBoundAccount, ());
+    let account = TokenBoundAccountClient::new(&env, &account_id);
+    account.initialize(&nft_id, &7);
+    assert_eq!(
+        account.try_initialize(&nft_id, &8),
+        Err(Ok(ContractError::AlreadyInitialized))
+    );
+    assert_eq!(account.token(), (nft_id.clone(), 7));
+    assert_eq!(account.owner(), alice);
+
+    // Assets held by the account follow the NFT to its new owner.
+    let sac = env.register_stellar_asset_contract_v2(alice.clone());
+    let token = TokenClient::new(&env, &sac.address());
+    StellarAssetClient::new(&env, &sac.address()).mint(&account_id, &500);
+    nft.set_owner(&7, &bob);
+    assert_eq!(account.owner(), bob);
+
+    let transfer: Symbol = symbol_short!("transfer");
+    let args = vec![
+        &env,
+        account_id.into_val(&env),
+        bob.into_val(&env),
+        200_i128.into_val(&env),
+    ];
+    account.execute(&sac.address(), &transfer, &args);
+    assert_eq!(env.auths()[0].0, bob);
+    assert_eq!(token.balance(&bob), 200);
+    assert_eq!(token.balance(&account_id), 300);
+}
+
+#[test]
+fn test_unbound_account() {
+    let env = Env::default();
+    let account_id = env.register(TokenBoundAccount, ());
+    let account = TokenBoundAccountClient::new(&env, &account_id);
+    assert_eq!(account.try_owner(), Err(Ok(ContractError::NotInitialized)));
+}
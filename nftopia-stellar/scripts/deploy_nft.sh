@@ -37,4 +37,4 @@ echo "NFT Contract ID: $CONTRACT_ID"
 echo ""
 echo "Next: Initialize with initialize(owner, config)"
 echo "  owner: Your admin address"
-echo "  config: CollectionConfig { name, symbol, base_uri, max_supply, mint_price, is_revealed, royalty_default, metadata_is_frozen }"
+echo "  config: CollectionConfig { name, symbol, base_uri, max_supply, is_revealed, royalty_default, metadata_is_frozen, content_addressed_ids, max_batch_size }"
@@ -0,0 +1,88 @@
+//! Decoding of the core token events every NFTopia collection publishes.
+
+use soroban_sdk::xdr::{ContractEvent, ContractEventBody, ScAddress, ScVal};
+
+/// A decoded `Mint`, `Transfer` or `Burn` event (see `nft_interface::events`).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NftEvent {
+    Mint {
+        to: ScAddress,
+        token_id: u64,
+        creator: ScAddress,
+    },
+    Transfer {
+        from: ScAddress,
+        to: ScAddress,
+        token_id: u64,
+    },
+    Burn {
+        from: ScAddress,
+        token_id: u64,
+    },
+}
+
+impl NftEvent {
+    /// Decodes an event from its `topics` and `data`, as returned by RPC `getEvents`.
+    /// Returns `None` for any other event, including other contracts' events that happen to
+    /// share a name but not the shape.
+    pub fn from_parts(topics: &[ScVal], data: &ScVal) -> Option<Self> {
+        let ScVal::Symbol(name) = topics.first()? else {
+            return None;
+        };
+        match (name.0.as_slice(), &topics[1..]) {
+            (b"mint", [to, token_id]) => Some(NftEvent::Mint {
+                to: address(to)?,
+                token_id: u64_val(token_id)?,
+                creator: address(field(data, "creator")?)?,
+            }),
+            (b"transfer", [from, to, token_id]) => Some(NftEvent::Transfer {
+                from: address(from)?,
+                to: address(to)?,
+                token_id: u64_val(token_id)?,
+            }),
+            (b"burn", [from, token_id]) => Some(NftEvent::Burn {
+                from: address(from)?,
+                token_id: u64_val(token_id)?,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Decodes a contract event as it appears in transaction meta.
+    pub fn decode(event: &ContractEvent) -> Option<Self> {
+        let ContractEventBody::V0(body) = &event.body;
+        Self::from_parts(&body.topics, &body.data)
+    }
+
+    pub fn token_id(&self) -> u64 {
+        match self {
+            NftEvent::Mint { token_id, .. }
+            | NftEvent::Transfer { token_id, .. }
+            | NftEvent::Burn { token_id, .. } => *token_id,
+        }
+    }
+}
+
+fn address(val: &ScVal) -> Option<ScAddress> {
+    match val {
+        ScVal::Address(a) => Some(a.clone()),
+        _ => None,
+    }
+}
+
+fn u64_val(val: &ScVal) -> Option<u64> {
+    match val {
+        ScVal::U64(v) => Some(*v),
+        _ => None,
+    }
+}
+
+/// Value of `key` in an event's data map.
+fn field<'a>(data: &'a ScVal, key: &str) -> Option<&'a ScVal> {
+    let ScVal::Map(Some(map)) = data else {
+        return None;
+    };
+    map.iter()
+        .find(|e| matches!(&e.key, ScVal::Symbol(s) if s.0.as_slice() == key.as_bytes()))
+        .map(|e| &e.val)
+}
@@ -0,0 +1,24 @@
+//! Off-chain Rust SDK for services that integrate NFTopia collections.
+//!
+//! Contracts call a collection through `nft_interface::NftClient`, which needs a contract
+//! `Env`. Backends have no `Env`; they build transactions for a wallet or RPC to simulate
+//! and sign, and read events back from `getEvents`. This crate covers that side:
+//!
+//! - [`Collection`] builds `mint`, `transfer` and arbitrary invocation operations, and
+//!   [`transaction`] wraps one into an unsigned transaction envelope.
+//! - [`NftEvent`] decodes the core `Mint`, `Transfer` and `Burn` events.
+//! - [`royalty`] computes royalty amounts exactly as the collection does.
+//!
+//! Addresses are strkeys (`G...` for accounts, `C...` for contracts), and all XDR types come
+//! from `soroban_sdk::xdr`.
+
+pub mod events;
+pub mod royalty;
+pub mod tx;
+
+pub use events::NftEvent;
+pub use soroban_sdk::xdr::Error;
+pub use tx::{Attribute, AttributeValue, Collection, transaction};
+
+#[cfg(test)]
+mod test;
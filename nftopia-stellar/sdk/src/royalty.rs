@@ -0,0 +1,23 @@
+//! Royalty arithmetic matching `nft_contract`'s `get_royalty_info`.
+
+/// Basis-point denominator (10000 = 100%).
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Splits `sale_price` into `(royalty, seller_amount)` at `royalty_bps`. The royalty rounds
+/// down, so the seller keeps any remainder; an overflowing product yields no royalty, as
+/// on-chain.
+pub fn split(sale_price: i128, royalty_bps: u32) -> (i128, i128) {
+    if royalty_bps == 0 {
+        return (0, sale_price);
+    }
+    let royalty = sale_price
+        .checked_mul(royalty_bps as i128)
+        .and_then(|v| v.checked_div(BPS_DENOMINATOR as i128))
+        .unwrap_or(0);
+    (royalty, sale_price.saturating_sub(royalty))
+}
+
+/// Royalty owed on a sale at `sale_price`.
+pub fn amount(sale_price: i128, royalty_bps: u32) -> i128 {
+    split(sale_price, royalty_bps).0
+}
@@ -0,0 +1,181 @@
+use crate::{Attribute, AttributeValue, Collection, NftEvent, royalty, transaction};
+use nft_contract::{CollectionConfig, NftContract, NftContractClient, RoyaltyInfo};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::xdr::{
+    ContractId, Hash, HostFunction, Limits, MuxedAccount, Operation, OperationBody, ReadXdr,
+    ScAddress, TransactionEnvelope, Uint256, WriteXdr,
+};
+use soroban_sdk::{Address, Env, String, Symbol, TryFromVal, Val, Vec};
+
+fn strkey(address: &Address) -> std::string::String {
+    ScAddress::from(address).to_string()
+}
+
+fn setup(env: &Env) -> (Address, Address) {
+    env.mock_all_auths();
+    let admin = Address::generate(env);
+    let contract_id = env.register(NftContract, ());
+    let config = CollectionConfig {
+        name: String::from_str(env, "Test NFT"),
+        symbol: String::from_str(env, "TNFT"),
+        base_uri: String::from_str(env, "https://nftopia.test/"),
+        max_supply: None,
+        is_revealed: true,
+        royalty_default: RoyaltyInfo {
+            recipient: admin.clone(),
+            percentage: 250,
+        },
+        metadata_is_frozen: false,
+        content_addressed_ids: false,
+        max_batch_size: None,
+    };
+    NftContractClient::new(env, &contract_id).initialize(&admin, &config);
+    (contract_id, admin)
+}
+
+/// Submits `op` to the test environment as a direct invocation.
+fn submit<T: TryFromVal<Env, Val>>(env: &Env, op: &Operation) -> T {
+    let OperationBody::InvokeHostFunction(invoke) = &op.body else {
+        panic!("not an invocation");
+    };
+    let HostFunction::InvokeContract(call) = &invoke.host_function else {
+        panic!("not a contract call");
+    };
+    let contract = Address::try_from_val(env, &call.contract_address).unwrap();
+    let function = Symbol::new(env, core::str::from_utf8(&call.function_name.0).unwrap());
+    let mut args = Vec::new(env);
+    for arg in call.args.iter() {
+        args.push_back(Val::try_from_val(env, arg).unwrap());
+    }
+    env.invoke_contract(&contract, &function, args)
+}
+
+#[test]
+fn test_operations_invoke_collection() {
+    let env = Env::default();
+    let (contract_id, admin) = setup(&env);
+    let client = NftContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let collection = Collection::new(&strkey(&contract_id)).unwrap();
+    assert!(Collection::new(&MuxedAccount::Ed25519(Uint256([1; 32])).to_string()).is_err());
+
+    let attributes = [
+        Attribute::string("background", "blue"),
+        Attribute {
+            trait_type: "level".into(),
+            value: "7".into(),
+            display_type: Some("number".into()),
+            typed_value: AttributeValue::I128(7),
+        },
+    ];
+    let mint = collection
+        .mint(&strkey(&admin), &strkey(&user), "ipfs://a", &attributes)
+        .unwrap();
+    let id: u64 = submit(&env, &mint);
+    assert_eq!(client.owner_of(&id), user);
+    let stored = client.token_metadata(&id).attributes;
+    assert_eq!(stored.len(), 2);
+    assert_eq!(
+        stored.get(1).unwrap().typed_value,
+        nft_contract::AttributeValue::I128(7)
+    );
+
+    submit::<()>(
+        &env,
+        &collection
+            .transfer(&strkey(&user), &strkey(&buyer), id)
+            .unwrap(),
+    );
+    assert_eq!(client.owner_of(&id), buyer);
+}
+
+#[test]
+fn test_decode_events() {
+    let env = Env::default();
+    let (contract_id, admin) = setup(&env);
+    let client = NftContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    // `all()` holds the events of the latest invocation only.
+    let mut decoded = std::vec::Vec::new();
+    let mut collect = || {
+        let events = env.events().all().filter_by_contract(&contract_id);
+        decoded.extend(events.events().iter().filter_map(NftEvent::decode));
+    };
+    let id = client.mint(
+        &admin,
+        &user,
+        &String::from_str(&env, "ipfs://a"),
+        &Vec::new(&env),
+        &None,
+    );
+    collect();
+    client.transfer(&user, &buyer, &id);
+    collect();
+    client.burn(&buyer, &id, &true);
+    collect();
+
+    assert_eq!(
+        decoded,
+        [
+            NftEvent::Mint {
+                to: (&user).into(),
+                token_id: id,
+                creator: (&admin).into(),
+            },
+            NftEvent::Transfer {
+                from: (&user).into(),
+                to: (&buyer).into(),
+                token_id: id,
+            },
+            NftEvent::Burn {
+                from: (&buyer).into(),
+                token_id: id,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_royalty_matches_collection() {
+    let env = Env::default();
+    let (contract_id, admin) = setup(&env);
+    let client = NftContractClient::new(&env, &contract_id);
+    let id = client.mint(
+        &admin,
+        &admin,
+        &String::from_str(&env, "ipfs://a"),
+        &Vec::new(&env),
+        &None,
+    );
+
+    for price in [0, 1, 399, 10_000, 1_234_567] {
+        let (_, on_chain) = client.get_royalty_info(&id, &price);
+        assert_eq!(royalty::amount(price, 250), on_chain);
+    }
+    assert_eq!(royalty::split(1_000, 250), (25, 975));
+    assert_eq!(royalty::split(i128::MAX, 250), (0, i128::MAX));
+}
+
+#[test]
+fn test_transaction_envelope() {
+    let contract = ScAddress::Contract(ContractId(Hash([7; 32])));
+    let collection = Collection::new(&contract.to_string()).unwrap();
+    let source = MuxedAccount::Ed25519(Uint256([1; 32])).to_string();
+    let op = collection.transfer(&source, &source, 3).unwrap();
+
+    let envelope = transaction(&source, 42, 100, op.clone()).unwrap();
+    let encoded = envelope.to_xdr_base64(Limits::none()).unwrap();
+    let TransactionEnvelope::Tx(decoded) =
+        TransactionEnvelope::from_xdr_base64(encoded, Limits::none()).unwrap()
+    else {
+        panic!("expected a v1 envelope");
+    };
+    assert_eq!(decoded.tx.seq_num.0, 42);
+    assert_eq!(decoded.tx.fee, 100);
+    assert_eq!(decoded.tx.operations.to_vec(), [op]);
+    assert!(decoded.signatures.is_empty());
+}
@@ -0,0 +1,193 @@
+//! Builders for collection invocations and the transactions that carry them.
+//!
+//! Operations are returned without authorization entries or resource data; run the
+//! transaction through RPC `simulateTransaction` to fill those in before signing.
+
+use soroban_sdk::xdr::{
+    Error, HostFunction, Int128Parts, InvokeContractArgs, InvokeHostFunctionOp, Memo, MuxedAccount,
+    Operation, OperationBody, Preconditions, ScAddress, ScMap, ScMapEntry, ScString, ScSymbol,
+    ScVal, ScVec, SequenceNumber, Transaction, TransactionEnvelope, TransactionExt,
+    TransactionV1Envelope,
+};
+
+/// Typed attribute value, mirroring `nft_contract::AttributeValue`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AttributeValue {
+    /// Plain string; the value is the attribute's `value` field.
+    String,
+    I128(i128),
+    Bool(bool),
+    /// Unix timestamp in seconds.
+    Timestamp(u64),
+}
+
+/// Token attribute passed to `mint`, mirroring `nft_contract::TokenAttribute`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attribute {
+    pub trait_type: String,
+    pub value: String,
+    pub display_type: Option<String>,
+    pub typed_value: AttributeValue,
+}
+
+impl Attribute {
+    /// A plain string attribute.
+    pub fn string(trait_type: &str, value: &str) -> Self {
+        Attribute {
+            trait_type: trait_type.into(),
+            value: value.into(),
+            display_type: None,
+            typed_value: AttributeValue::String,
+        }
+    }
+
+    fn to_scval(&self) -> Result<ScVal, Error> {
+        let typed_value = match &self.typed_value {
+            AttributeValue::String => vec![symbol("String")?],
+            AttributeValue::I128(v) => vec![symbol("I128")?, i128_val(*v)],
+            AttributeValue::Bool(v) => vec![symbol("Bool")?, ScVal::Bool(*v)],
+            AttributeValue::Timestamp(v) => vec![symbol("Timestamp")?, ScVal::U64(*v)],
+        };
+        let display_type = match &self.display_type {
+            Some(s) => string(s)?,
+            None => ScVal::Void,
+        };
+        // Struct fields are encoded as a map keyed by field name, in sorted key order.
+        let entries = vec![
+            entry("display_type", display_type)?,
+            entry("trait_type", string(&self.trait_type)?)?,
+            entry("typed_value", vec_val(typed_value)?)?,
+            entry("value", string(&self.value)?)?,
+        ];
+        Ok(ScVal::Map(Some(ScMap(entries.try_into()?))))
+    }
+}
+
+/// A deployed NFTopia collection, addressed by its contract strkey.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Collection {
+    contract: ScAddress,
+}
+
+impl Collection {
+    /// Parses `contract_id` (`C...`).
+    pub fn new(contract_id: &str) -> Result<Self, Error> {
+        match contract_id.parse()? {
+            contract @ ScAddress::Contract(_) => Ok(Collection { contract }),
+            _ => Err(Error::Invalid),
+        }
+    }
+
+    pub fn contract(&self) -> &ScAddress {
+        &self.contract
+    }
+
+    /// Operation invoking `function` with already encoded `args`.
+    pub fn invoke(&self, function: &str, args: Vec<ScVal>) -> Result<Operation, Error> {
+        Ok(Operation {
+            source_account: None,
+            body: OperationBody::InvokeHostFunction(InvokeHostFunctionOp {
+                host_function: HostFunction::InvokeContract(InvokeContractArgs {
+                    contract_address: self.contract.clone(),
+                    function_name: ScSymbol(function.try_into()?),
+                    args: args.try_into()?,
+                }),
+                auth: Default::default(),
+            }),
+        })
+    }
+
+    /// `mint(caller, to, metadata_uri, attributes, None)`; the collection's default royalty
+    /// applies.
+    pub fn mint(
+        &self,
+        caller: &str,
+        to: &str,
+        metadata_uri: &str,
+        attributes: &[Attribute],
+    ) -> Result<Operation, Error> {
+        let attributes = attributes
+            .iter()
+            .map(Attribute::to_scval)
+            .collect::<Result<Vec<_>, _>>()?;
+        self.invoke(
+            "mint",
+            vec![
+                address(caller)?,
+                address(to)?,
+                string(metadata_uri)?,
+                vec_val(attributes)?,
+                ScVal::Void,
+            ],
+        )
+    }
+
+    /// `transfer(from, to, token_id)`.
+    pub fn transfer(&self, from: &str, to: &str, token_id: u64) -> Result<Operation, Error> {
+        self.invoke(
+            "transfer",
+            vec![address(from)?, address(to)?, ScVal::U64(token_id)],
+        )
+    }
+
+    /// `burn(caller, token_id, true)`.
+    pub fn burn(&self, caller: &str, token_id: u64) -> Result<Operation, Error> {
+        self.invoke(
+            "burn",
+            vec![address(caller)?, ScVal::U64(token_id), ScVal::Bool(true)],
+        )
+    }
+}
+
+/// Unsigned envelope carrying `operation`, sent from `source` (`G...`) with sequence number
+/// `sequence` (the account's current sequence plus one) and an inclusion `fee` in stroops.
+pub fn transaction(
+    source: &str,
+    sequence: i64,
+    fee: u32,
+    operation: Operation,
+) -> Result<TransactionEnvelope, Error> {
+    let tx = Transaction {
+        source_account: source.parse::<MuxedAccount>()?,
+        fee,
+        seq_num: SequenceNumber(sequence),
+        cond: Preconditions::None,
+        memo: Memo::None,
+        operations: vec![operation].try_into()?,
+        ext: TransactionExt::V0,
+    };
+    Ok(TransactionEnvelope::Tx(TransactionV1Envelope {
+        tx,
+        signatures: Default::default(),
+    }))
+}
+
+fn address(strkey: &str) -> Result<ScVal, Error> {
+    Ok(ScVal::Address(strkey.parse()?))
+}
+
+fn string(s: &str) -> Result<ScVal, Error> {
+    Ok(ScVal::String(ScString(s.try_into()?)))
+}
+
+fn symbol(s: &str) -> Result<ScVal, Error> {
+    Ok(ScVal::Symbol(ScSymbol(s.try_into()?)))
+}
+
+fn i128_val(v: i128) -> ScVal {
+    ScVal::I128(Int128Parts {
+        hi: (v >> 64) as i64,
+        lo: v as u64,
+    })
+}
+
+fn vec_val(items: Vec<ScVal>) -> Result<ScVal, Error> {
+    Ok(ScVal::Vec(Some(ScVec(items.try_into()?))))
+}
+
+fn entry(key: &str, val: ScVal) -> Result<ScMapEntry, Error> {
+    Ok(ScMapEntry {
+        key: symbol(key)?,
+        val,
+    })
+}